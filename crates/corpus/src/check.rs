@@ -0,0 +1,252 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use parser::content::Content;
+use parser::error::Result;
+use parser::frame::DynFrame;
+use parser::id3v2::Tag;
+use parser::options::ParseOptions;
+use std::collections::BTreeMap;
+use std::io::Result as IoResult;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::expect::Expectation;
+use crate::expect::Outcome;
+use crate::fixture::load_dir;
+use crate::fixture::Fixture;
+
+// =============================================================================
+// Report
+// =============================================================================
+
+/// The differences between a fixture and its expectation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+  path: PathBuf,
+  mismatches: Vec<String>,
+}
+
+impl Report {
+  /// Get the path of the checked tag data.
+  #[inline]
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  /// Get a description of every mismatch.
+  #[inline]
+  pub fn mismatches(&self) -> &[String] {
+    &self.mismatches
+  }
+
+  /// Returns `true` if the fixture matched its expectation.
+  #[inline]
+  pub fn is_ok(&self) -> bool {
+    self.mismatches.is_empty()
+  }
+
+  fn diff<T>(&mut self, field: &str, expected: &T, actual: &T)
+  where
+    T: PartialEq + core::fmt::Debug + ?Sized,
+  {
+    if expected != actual {
+      self
+        .mismatches
+        .push(format!("{field}: expected {expected:?}, found {actual:?}"));
+    }
+  }
+}
+
+impl Display for Report {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    writeln!(f, "{}", self.path.display())?;
+
+    for mismatch in self.mismatches.iter() {
+      writeln!(f, "  {mismatch}")?;
+    }
+
+    Ok(())
+  }
+}
+
+// =============================================================================
+// Check
+// =============================================================================
+
+/// Parse a fixture in strict and lenient mode and compare the results with
+/// its expectation.
+///
+/// Panics in the parser are caught and reported as mismatches.
+pub fn check(fixture: &Fixture) -> Report {
+  let mut report: Report = Report {
+    path: fixture.path().to_path_buf(),
+    mismatches: Vec::new(),
+  };
+
+  let expect: &Expectation = fixture.expect();
+  let strict: Option<Tag> = check_mode(&mut report, "strict", fixture, &expect.strict, false);
+  let lenient: Option<Tag> = check_mode(&mut report, "lenient", fixture, expect.lenient(), true);
+
+  if let Some(tag) = strict.as_ref().or(lenient.as_ref()) {
+    if let Some(ref version) = expect.version {
+      report.diff("version", version, &format!("{:?}", tag.header().version()));
+    }
+
+    check_content(&mut report, expect, tag);
+  }
+
+  report
+}
+
+/// Load and check every fixture in the directory at `path`.
+pub fn run_dir(path: &Path) -> IoResult<Vec<Report>> {
+  Ok(load_dir(path)?.iter().map(check).collect())
+}
+
+fn check_mode(
+  report: &mut Report,
+  mode: &str,
+  fixture: &Fixture,
+  expect: &Outcome,
+  lenient: bool,
+) -> Option<Tag> {
+  let options: ParseOptions = ParseOptions::new().lenient(lenient);
+
+  let parsed: Result<Tag> = match catch(|| Tag::from_reader_with(fixture.data(), options)) {
+    Ok(parsed) => parsed,
+    Err(message) => {
+      report
+        .mismatches
+        .push(format!("{mode}: panicked: {message}"));
+      return None;
+    }
+  };
+
+  let tag: Tag = match parsed {
+    Ok(tag) => tag,
+    Err(error) => {
+      let actual: Option<String> = Some(format!("{:?}", error.kind()));
+      report.diff(&format!("{mode}.error"), &expect.error, &actual);
+      return None;
+    }
+  };
+
+  report.diff(&format!("{mode}.error"), &expect.error, &None);
+
+  if expect.error.is_some() {
+    return Some(tag);
+  }
+
+  let frames: Vec<String> = match catch(|| frame_ids(&tag)) {
+    Ok(frames) => frames,
+    Err(message) => vec![format!("panicked: {message}")],
+  };
+
+  let warnings: Vec<String> = tag
+    .warnings()
+    .iter()
+    .map(|warning| format!("{:?}", warning.kind()))
+    .collect();
+
+  report.diff(&format!("{mode}.frames"), &expect.frames, &frames);
+  report.diff(&format!("{mode}.warnings"), &expect.warnings, &warnings);
+
+  Some(tag)
+}
+
+fn check_content(report: &mut Report, expect: &Expectation, tag: &Tag) {
+  let mut decoded: BTreeMap<String, String> = BTreeMap::new();
+  let mut texts: BTreeMap<String, String> = BTreeMap::new();
+  let mut errors: Vec<String> = Vec::new();
+
+  for frame in tag.frames().map_while(|frame| frame.ok()) {
+    let identifier: String = frame.identifier_str().to_owned();
+
+    match catch(|| frame.decode().map(|content| describe(&content))) {
+      Ok(Ok((debug, text))) => {
+        if let Some(text) = text {
+          texts.entry(identifier.clone()).or_insert(text);
+        }
+
+        decoded.entry(identifier).or_insert(debug);
+      }
+      Ok(Err(_)) => {
+        errors.push(identifier);
+      }
+      Err(message) => {
+        report
+          .mismatches
+          .push(format!("decode.{identifier}: panicked: {message}"));
+      }
+    }
+  }
+
+  let mut expected_errors: Vec<String> = expect.decode_errors.clone();
+
+  expected_errors.sort();
+  expected_errors.dedup();
+  errors.sort();
+  errors.dedup();
+
+  report.diff("decode_errors", &expected_errors, &errors);
+
+  for (identifier, expected) in expect.text.iter() {
+    report.diff(
+      &format!("text.{identifier}"),
+      &Some(expected),
+      &texts.get(identifier),
+    );
+  }
+
+  for (identifier, expected) in expect.fields.iter() {
+    let Some(debug) = decoded.get(identifier) else {
+      report
+        .mismatches
+        .push(format!("fields.{identifier}: frame not decoded"));
+      continue;
+    };
+
+    for field in expected
+      .iter()
+      .filter(|field| !debug.contains(field.as_str()))
+    {
+      report.mismatches.push(format!(
+        "fields.{identifier}: {field:?} not found in {debug}"
+      ));
+    }
+  }
+}
+
+fn frame_ids(tag: &Tag) -> Vec<String> {
+  tag
+    .frames()
+    .map(|frame: Result<DynFrame<'_>>| match frame {
+      Ok(frame) => frame.identifier_str().to_owned(),
+      Err(error) => format!("!{:?}", error.kind()),
+    })
+    .collect()
+}
+
+fn describe(content: &Content<'_>) -> (String, Option<String>) {
+  let text: Option<String> = match content {
+    Content::Text(inner) => Some(inner.text_content().to_string()),
+    _ => None,
+  };
+
+  (format!("{content:?}"), text)
+}
+
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+  panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+      (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+      message.clone()
+    } else {
+      "unknown panic".to_owned()
+    }
+  })
+}
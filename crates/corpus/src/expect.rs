@@ -0,0 +1,59 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+// =============================================================================
+// Expectation
+// =============================================================================
+
+/// The expected results of parsing a fixture.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Expectation {
+  /// The expected tag version, formatted with `Debug` (e.g. `"ID3v24"`).
+  pub version: Option<String>,
+  /// The expected outcome when parsing in strict mode.
+  pub strict: Outcome,
+  /// The expected outcome when parsing in lenient mode.
+  ///
+  /// Note: Defaults to the strict outcome.
+  pub lenient: Option<Outcome>,
+  /// The exact text of the first text frame with each identifier.
+  pub text: BTreeMap<String, String>,
+  /// Substrings of the `Debug` output of the first decoded frame with each
+  /// identifier.
+  pub fields: BTreeMap<String, Vec<String>>,
+  /// The identifiers of frames that are expected to fail decoding.
+  pub decode_errors: Vec<String>,
+}
+
+impl Expectation {
+  /// Parse an expectation from a JSON string.
+  pub fn from_json(json: &str) -> serde_json::Result<Self> {
+    serde_json::from_str(json)
+  }
+
+  /// Get the expected outcome when parsing in lenient mode.
+  pub fn lenient(&self) -> &Outcome {
+    self.lenient.as_ref().unwrap_or(&self.strict)
+  }
+}
+
+// =============================================================================
+// Outcome
+// =============================================================================
+
+/// The expected outcome of parsing a fixture with a set of options.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Outcome {
+  /// The expected error kind, formatted with `Debug` (e.g. `"IO"`).
+  ///
+  /// Note: When set, `frames` and `warnings` are ignored.
+  pub error: Option<String>,
+  /// The identifiers of every frame yielded by the frame iterator.
+  ///
+  /// Iterator errors are listed as `"!"` followed by the error kind.
+  pub frames: Vec<String>,
+  /// The recorded warning kinds, formatted with `Debug`.
+  pub warnings: Vec<String>,
+}
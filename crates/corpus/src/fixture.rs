@@ -0,0 +1,102 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Result;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::expect::Expectation;
+
+// =============================================================================
+// Fixture
+// =============================================================================
+
+/// Raw tag data paired with the expected parsing results.
+#[derive(Clone, Debug)]
+pub struct Fixture {
+  path: PathBuf,
+  data: Vec<u8>,
+  expect: Expectation,
+}
+
+impl Fixture {
+  /// Create a new `Fixture` from in-memory tag data.
+  pub fn new(path: impl Into<PathBuf>, data: Vec<u8>, expect: Expectation) -> Self {
+    Self {
+      path: path.into(),
+      data,
+      expect,
+    }
+  }
+
+  /// Load the fixture described by the expectation file at `path`.
+  ///
+  /// The tag data is read from the sibling file with the same name and any
+  /// other extension.
+  pub fn load(path: &Path) -> Result<Self> {
+    let json: String = fs::read_to_string(path)?;
+    let expect: Expectation = Expectation::from_json(&json).map_err(|error| {
+      Error::new(
+        ErrorKind::InvalidData,
+        format!("{}: {error}", path.display()),
+      )
+    })?;
+
+    let data_path: PathBuf = find_data(path)?;
+    let data: Vec<u8> = fs::read(&data_path)?;
+
+    Ok(Self::new(data_path, data, expect))
+  }
+
+  /// Get the path of the tag data.
+  #[inline]
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  /// Get the raw tag data.
+  #[inline]
+  pub fn data(&self) -> &[u8] {
+    &self.data
+  }
+
+  /// Get the expected parsing results.
+  #[inline]
+  pub const fn expect(&self) -> &Expectation {
+    &self.expect
+  }
+}
+
+/// Load every fixture in the directory at `path`, sorted by name.
+pub fn load_dir(path: &Path) -> Result<Vec<Fixture>> {
+  let mut paths: Vec<PathBuf> = Vec::new();
+
+  for entry in fs::read_dir(path)? {
+    let path: PathBuf = entry?.path();
+
+    if path.extension() == Some(OsStr::new("json")) {
+      paths.push(path);
+    }
+  }
+
+  paths.sort();
+  paths.iter().map(|path| Fixture::load(path)).collect()
+}
+
+fn find_data(path: &Path) -> Result<PathBuf> {
+  let parent: &Path = path.parent().unwrap_or(Path::new("."));
+
+  for entry in fs::read_dir(parent)? {
+    let entry: PathBuf = entry?.path();
+
+    if entry != path && entry.file_stem() == path.file_stem() && entry.is_file() {
+      return Ok(entry);
+    }
+  }
+
+  Err(Error::new(
+    ErrorKind::NotFound,
+    format!("{}: missing tag data", path.display()),
+  ))
+}
@@ -0,0 +1,40 @@
+//! Errai Test Corpus
+//!
+//! A harness for checking parser results against a directory of fixtures.
+//!
+//! Every fixture is a pair of files sharing the same name: the raw tag data
+//! (with any extension, e.g. `artwork.id3`) and an [`Expectation`] stored as
+//! JSON (e.g. `artwork.json`). Each fixture is parsed in both strict and
+//! lenient mode and compared against the expectation:
+//!
+//! ```json
+//! {
+//!   "version": "ID3v23",
+//!   "strict": { "error": "InvalidField(ExtSize)" },
+//!   "lenient": {
+//!     "frames": ["TIT2", "APIC"],
+//!     "warnings": ["ExtHeaderSize { declared: 10, consumed: 6 }"]
+//!   },
+//!   "text": { "TIT2": "Title" },
+//!   "fields": { "APIC": ["mime_type: \"image/png\""] },
+//!   "decode_errors": []
+//! }
+//! ```
+//!
+//! Problem files can be checked by dropping them (plus an expectation) into a
+//! directory and calling [`run_dir`], or by listing the directory in the
+//! `ERRAI_CORPUS` environment variable when running `cargo test -p corpus`.
+
+#![deny(missing_docs)]
+
+mod check;
+mod expect;
+mod fixture;
+
+pub use self::check::check;
+pub use self::check::run_dir;
+pub use self::check::Report;
+pub use self::expect::Expectation;
+pub use self::expect::Outcome;
+pub use self::fixture::load_dir;
+pub use self::fixture::Fixture;
@@ -0,0 +1,42 @@
+use corpus::Report;
+use std::env;
+use std::path::Path;
+
+fn run(path: &Path) {
+  let reports: Vec<Report> = corpus::run_dir(path).unwrap();
+  let failed: Vec<&Report> = reports.iter().filter(|report| !report.is_ok()).collect();
+
+  assert!(
+    !reports.is_empty(),
+    "no fixtures found in {}",
+    path.display()
+  );
+
+  for report in failed.iter() {
+    eprintln!("{report}");
+  }
+
+  assert!(
+    failed.is_empty(),
+    "{} of {} fixtures failed",
+    failed.len(),
+    reports.len()
+  );
+}
+
+#[test]
+fn test_corpus() {
+  run(&Path::new(env!("CARGO_MANIFEST_DIR")).join("../parser/tests/corpus"));
+}
+
+/// Check additional fixture directories listed in `ERRAI_CORPUS`.
+#[test]
+fn test_corpus_env() {
+  let Some(paths) = env::var_os("ERRAI_CORPUS") else {
+    return;
+  };
+
+  for path in env::split_paths(&paths) {
+    run(&path);
+  }
+}
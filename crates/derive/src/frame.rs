@@ -1,5 +1,6 @@
 use proc_macro2::Span;
 use proc_macro2::TokenStream;
+use quote::format_ident;
 use quote::quote;
 use quote::ToTokens;
 use std::borrow::Cow;
@@ -89,6 +90,8 @@ impl ToTokens for Frame {
     let decode_name = owned_name.clone();
     let decode_expr = self.data.iter().map(FrameDecoder);
 
+    let encode_expr = self.data.iter().map(FrameEncoder);
+
     if !self.attr.skip_decoding {
       tokens.extend(quote! {
         impl #object_lifetime crate::decode::Decode #decode_lifetime for #name #object_lifetime {
@@ -101,6 +104,17 @@ impl ToTokens for Frame {
       });
     }
 
+    if !self.attr.skip_encoding {
+      tokens.extend(quote! {
+        impl #object_lifetime crate::encode::Encode for #name #object_lifetime {
+          fn encode(&self, encoder: &mut crate::encode::Encoder) -> crate::error::Result<()> {
+            #(#encode_expr;)*
+            Ok(())
+          }
+        }
+      });
+    }
+
     tokens.extend(quote! {
       impl #object_lifetime crate::traits::IntoOwned for #name #object_lifetime {
         type Owned = #name #static_lifetime;
@@ -128,6 +142,7 @@ impl ToTokens for Frame {
 
 struct FrameAttr {
   skip_decoding: bool,
+  skip_encoding: bool,
 }
 
 impl Parse for FrameAttr {
@@ -135,6 +150,7 @@ impl Parse for FrameAttr {
     let list: Vec<Attribute> = input.call(Attribute::parse_outer)?;
 
     let mut skip_decoding: bool = false;
+    let mut skip_encoding: bool = false;
 
     for attr in list {
       if !attr.path().is_ident("frame") {
@@ -151,6 +167,15 @@ impl Parse for FrameAttr {
           }
 
           skip_decoding = true;
+        } else if meta.path.is_ident("skip_encoding") {
+          if skip_encoding {
+            return Err(Error::new(
+              meta.input.span(),
+              "Duplicate `skip_encoding` Attribute.",
+            ));
+          }
+
+          skip_encoding = true;
         } else {
           return Err(Error::new(
             meta.path.span(),
@@ -162,7 +187,10 @@ impl Parse for FrameAttr {
       })?;
     }
 
-    Ok(Self { skip_decoding })
+    Ok(Self {
+      skip_decoding,
+      skip_encoding,
+    })
   }
 }
 
@@ -258,7 +286,11 @@ impl FrameField {
   }
 
   fn kind(&self) -> FrameType<'_> {
-    FrameType::new(&self.kind, self.attr.borrow)
+    if self.attr.defer {
+      FrameType::Lazy(&self.kind)
+    } else {
+      FrameType::new(&self.kind, self.attr.borrow)
+    }
   }
 
   fn info(&self) -> Cow<'_, str> {
@@ -292,25 +324,85 @@ struct FrameDecoder<'a>(&'a FrameField);
 
 impl ToTokens for FrameDecoder<'_> {
   fn to_tokens(&self, tokens: &mut TokenStream) {
-    match self.0.attr.read.as_deref() {
+    let expr: TokenStream = match self.0.attr.read.as_deref() {
       Some("@latin1") => {
-        tokens.extend(quote! {
+        quote! {
           decoder.decode_latin1()?
-        });
+        }
       }
       Some("@u64") => {
-        tokens.extend(quote! {
-          crate::utils::decode_u64_relaxed(decoder.step(0, |slice| slice.take(8)))
-        });
+        quote! {
+          decoder.decode_u64_relaxed()?
+        }
       }
       Some(expr) => {
         panic!("Unknown Expr: {:?}", expr);
       }
+      None if self.0.attr.optional => {
+        quote! {
+          decoder.decode_option()?
+        }
+      }
       None => {
-        tokens.extend(quote! {
+        quote! {
           decoder.decode()?
-        });
+        }
+      }
+    };
+
+    if self.0.attr.optional && self.0.attr.read.is_some() {
+      tokens.extend(quote! {
+        if decoder.is_empty() { None } else { Some(#expr) }
+      });
+    } else {
+      tokens.extend(expr);
+    }
+  }
+}
+
+// =============================================================================
+// Frame Encoder
+// =============================================================================
+
+struct FrameEncoder<'a>(&'a FrameField);
+
+impl ToTokens for FrameEncoder<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let name: &Ident = self.0.name();
+
+    let value: TokenStream = if self.0.attr.optional {
+      quote!(*value)
+    } else {
+      quote!(self.#name)
+    };
+
+    let expr: TokenStream = match self.0.attr.read.as_deref() {
+      Some("@latin1") => {
+        quote! {
+          encoder.encode_latin1(&#value)?
+        }
+      }
+      Some("@u64") => {
+        quote! {
+          encoder.encode_u64_relaxed(#value)?
+        }
       }
+      Some(expr) => {
+        panic!("Unknown Expr: {:?}", expr);
+      }
+      None => {
+        quote! {
+          encoder.encode(&#value)?
+        }
+      }
+    };
+
+    if self.0.attr.optional {
+      tokens.extend(quote! {
+        if let Some(value) = &self.#name { #expr; }
+      });
+    } else {
+      tokens.extend(expr);
     }
   }
 }
@@ -341,7 +433,11 @@ impl ToTokens for FrameAccessor<'_> {
     let name: &Ident = self.0.name();
     let kind: FrameType<'_> = self.0.kind();
     let info: Cow<'_, str> = self.0.info();
-    let docs: String = format!("Get the {info} of the frame.");
+    let docs: String = if let FrameType::Lazy(_) = kind {
+      format!("Get the {info} of the frame, or `None` if it was deferred.")
+    } else {
+      format!("Get the {info} of the frame.")
+    };
 
     let accessor: Expr = kind.accessor(name);
     let constant: Option<Token![const]> = kind.constant();
@@ -353,6 +449,29 @@ impl ToTokens for FrameAccessor<'_> {
         #accessor
       }
     });
+
+    if let FrameType::Lazy(_) = kind {
+      let deferred: Ident = format_ident!("{name}_deferred");
+      let deferred_docs: String = format!("Get the location of the {info} if it was deferred.");
+
+      let load: Ident = format_ident!("load_{name}");
+      let load_docs: String =
+        format!("Get the {info}, loading it from the `tag` buffer if deferred.");
+
+      tokens.extend(quote! {
+        #[doc = #deferred_docs]
+        #[inline]
+        pub const fn #deferred(&self) -> Option<crate::types::DeferredSlice> {
+          self.#name.deferred()
+        }
+
+        #[doc = #load_docs]
+        #[inline]
+        pub fn #load<'tag>(&'tag self, tag: &'tag crate::id3v2::Tag) -> crate::error::Result<&'tag crate::types::Slice> {
+          self.#name.load(tag)
+        }
+      });
+    }
   }
 }
 
@@ -362,6 +481,8 @@ impl ToTokens for FrameAccessor<'_> {
 
 struct FrameFieldAttr {
   borrow: bool,
+  defer: bool,
+  optional: bool,
   info: Option<String>,
   read: Option<String>,
 }
@@ -371,6 +492,8 @@ impl Parse for FrameFieldAttr {
     let list: Vec<Attribute> = input.call(Attribute::parse_outer)?;
 
     let mut borrow: bool = false;
+    let mut defer: bool = false;
+    let mut optional: bool = false;
     let mut info: Option<String> = None;
     let mut read: Option<String> = None;
 
@@ -395,6 +518,24 @@ impl Parse for FrameFieldAttr {
           }
 
           borrow = true;
+        } else if meta.path.is_ident("defer") {
+          if defer {
+            return Err(Error::new(
+              meta.input.span(),
+              "Duplicate `defer` Attribute.",
+            ));
+          }
+
+          defer = true;
+        } else if meta.path.is_ident("optional") {
+          if optional {
+            return Err(Error::new(
+              meta.input.span(),
+              "Duplicate `optional` Attribute.",
+            ));
+          }
+
+          optional = true;
         } else if meta.path.is_ident("read") {
           if read.is_some() {
             return Err(Error::new(meta.input.span(), "Duplicate `read` Attribute."));
@@ -415,7 +556,13 @@ impl Parse for FrameFieldAttr {
       })?;
     }
 
-    Ok(Self { borrow, info, read })
+    Ok(Self {
+      borrow,
+      defer,
+      optional,
+      info,
+      read,
+    })
   }
 }
 
@@ -426,13 +573,19 @@ impl Parse for FrameFieldAttr {
 enum FrameType<'a> {
   Raw(&'a Type),
   Ref(&'a Type),
+  Opt(&'a Type),
   Vec(&'a Type),
+  Lazy(&'a Type),
 }
 
 impl<'a> FrameType<'a> {
   fn new(kind: &'a Type, borrow: bool) -> Self {
     if let Some(kind) = Self::parse_inner(kind, "Cow", 2, 1) {
       Self::Ref(kind)
+    } else if let Some(kind) =
+      Self::parse_inner(kind, "Option", 1, 0).and_then(|kind| Self::parse_inner(kind, "Cow", 2, 1))
+    {
+      Self::Opt(kind)
     } else if let Some(kind) = Self::parse_inner(kind, "Vec", 1, 0) {
       Self::Vec(kind)
     } else if borrow {
@@ -446,7 +599,9 @@ impl<'a> FrameType<'a> {
     match self {
       Self::Raw(_) => parse_quote!(self.#name),
       Self::Ref(_) => parse_quote!(::alloc::borrow::Borrow::borrow(&self.#name)),
+      Self::Opt(_) => parse_quote!(self.#name.as_deref()),
       Self::Vec(_) => parse_quote!(self.#name.as_slice()),
+      Self::Lazy(_) => parse_quote!(self.#name.as_slice()),
     }
   }
 
@@ -454,7 +609,9 @@ impl<'a> FrameType<'a> {
     match self {
       Self::Raw(_) => Some(parse_quote!(const)),
       Self::Ref(_) => None,
+      Self::Opt(_) => None,
       Self::Vec(_) => None,
+      Self::Lazy(_) => None,
     }
   }
 
@@ -503,9 +660,15 @@ impl ToTokens for FrameType<'_> {
       Self::Ref(inner) => {
         tokens.extend(quote!(&#inner));
       }
+      Self::Opt(inner) => {
+        tokens.extend(quote!(Option<&#inner>));
+      }
       Self::Vec(inner) => {
         tokens.extend(quote!(&[#inner]));
       }
+      Self::Lazy(_) => {
+        tokens.extend(quote!(Option<&crate::types::Slice>));
+      }
     }
   }
 }
@@ -1,3 +1,7 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
 use crate::content::Aenc;
 use crate::content::Apic;
 use crate::content::Atxt;
@@ -5,6 +9,7 @@ use crate::content::Chap;
 use crate::content::Comm;
 use crate::content::Comr;
 use crate::content::Ctoc;
+use crate::content::Custom;
 use crate::content::Encr;
 use crate::content::Equa;
 use crate::content::Etco;
@@ -16,6 +21,7 @@ use crate::content::Mcdi;
 use crate::content::Mllt;
 use crate::content::Owne;
 use crate::content::Pcnt;
+use crate::content::Pcst;
 use crate::content::Popm;
 use crate::content::Poss;
 use crate::content::Priv;
@@ -34,9 +40,18 @@ use crate::content::Uslt;
 use crate::content::Wurl;
 use crate::content::Wxxx;
 use crate::decode::Decoder;
+use crate::decode::Encoding;
+use crate::encode::Encode;
+use crate::encode::Encoder;
+use crate::error::Error;
+use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::error::Warning;
+use crate::options::DecoderConfig;
+use crate::options::ParseOptions;
 use crate::traits::IntoOwned;
 use crate::types::Bytes;
+use crate::types::DynFrameId;
 use crate::types::Slice;
 use crate::types::Version;
 use crate::utils;
@@ -85,6 +100,8 @@ impl_content! {
     Owne(Owne<'a>),
     /// Play counter.
     Pcnt(Pcnt),
+    /// Podcast flag.
+    Pcst(Pcst),
     /// Popularimeter.
     Popm(Popm<'a>),
     /// Position synchronisation frame.
@@ -131,219 +148,687 @@ impl_content! {
     Wpub(Wurl<'a>),
     /// User-defined URL link frame.
     Wxxx(Wxxx<'a>),
+    /// Content decoded by a registered parser.
+    Custom(Custom),
     /// Unknown frame content.
     Unkn(Unkn<'a>),
   }
 }
 
 impl<'a> Content<'a> {
+  /// Decode a slice of bytes with the format specified by the frame `id`.
+  #[inline]
+  pub fn decode<I>(version: Version, id: I, slice: &'a Slice) -> Result<Self>
+  where
+    I: Into<DynFrameId>,
+  {
+    Self::decode_with(version, id, slice, ParseOptions::new(), &mut Vec::new())
+  }
+
   /// Decode a slice of bytes with the format specified by `name`.
-  pub fn decode(version: Version, name: &str, slice: &'a Slice) -> Result<Self> {
-    let mut decoder: Decoder<'_> = Decoder::new(slice);
+  ///
+  /// Note: Names that are not valid frame identifiers are decoded as
+  /// [`Unkn`] content.
+  #[deprecated(note = "use `Content::decode` with a `DynFrameId`")]
+  pub fn decode_str(version: Version, name: &str, slice: &'a Slice) -> Result<Self> {
+    match DynFrameId::try_from(name) {
+      Ok(id) => Self::decode(version, id, slice),
+      Err(_) => Ok(Self::Unkn(Unkn::new(name, slice))),
+    }
+  }
+
+  /// Decode a slice of bytes with the format specified by the frame `id` and
+  /// the given `options`.
+  ///
+  /// Problems tolerated in lenient mode and trailing NUL padding are appended
+  /// to `warnings`.
+  pub fn decode_with<I>(
+    version: Version,
+    id: I,
+    slice: &'a Slice,
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Self>
+  where
+    I: Into<DynFrameId>,
+  {
+    Self::decode_with_config(version, id, slice, &DecoderConfig::new(options), warnings)
+  }
+
+  /// Decode a slice of bytes with the format specified by the frame `id` and
+  /// the given `config`.
+  ///
+  /// Parsers registered with the `config` take precedence over the built-in
+  /// formats.
+  pub fn decode_with_config<I>(
+    version: Version,
+    id: I,
+    slice: &'a Slice,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Self>
+  where
+    I: Into<DynFrameId>,
+  {
+    Self::decode_in(version, id.into(), slice, config, warnings, None)
+  }
+
+  /// Decode a slice of bytes located within the `origin` tag buffer.
+  pub(crate) fn decode_in(
+    version: Version,
+    id: DynFrameId,
+    slice: &'a Slice,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+    origin: Option<&'a Slice>,
+  ) -> Result<Self> {
+    if matches!(version, Version::ID3v11 | Version::ID3v12) {
+      return Err(Error::new(ErrorKind::InvalidVersion));
+    }
+
+    if let Some(parser) = config.parser(id) {
+      return parser(slice, version).map(Self::Custom);
+    }
 
-    let this: Result<Self> = match (version, name) {
-      (Version::ID3v11, _) => panic!("Invalid Version: ID3v11"),
-      (Version::ID3v12, _) => panic!("Invalid Version: ID3v12"),
+    let mut decoder: Decoder<'_> = Decoder::new(slice)
+      .with_options(config.options())
+      .with_version(version)
+      .with_origin(origin);
+
+    let this: Result<Self> = match (version, id.as_slice()) {
+      (Version::ID3v11, _) => unreachable!(),
+      (Version::ID3v12, _) => unreachable!(),
       // =======================================================================
       // ID3v2.2 Frames
       // =======================================================================
-      (Version::ID3v22, "BUF") => decoder.decode_v2().map(Self::Rbuf), // Recommended buffer size
-      (Version::ID3v22, "CNT") => decoder.decode_v2().map(Self::Pcnt), // Play counter
-      (Version::ID3v22, "COM") => decoder.decode_v2().map(Self::Comm), // Comments
-      (Version::ID3v22, "CRA") => decoder.decode_v2().map(Self::Aenc), // Audio encryption
-      (Version::ID3v22, "CRM") => panic!("TODO: Decode CRM"),          // Encrypted meta frame
-      (Version::ID3v22, "ETC") => decoder.decode_v2().map(Self::Etco), // Event timing codes
-      (Version::ID3v22, "EQU") => decoder.decode_v2().map(Self::Equa), // Equalization
-      (Version::ID3v22, "GEO") => decoder.decode_v2().map(Self::Geob), // General encapsulated object
-      (Version::ID3v22, "IPL") => decoder.decode_v2().map(Self::Ipls), // Involved people list
-      (Version::ID3v22, "LNK") => decoder.decode_v2().map(Self::Link), // Linked information
-      (Version::ID3v22, "MCI") => decoder.decode_v2().map(Self::Mcdi), // Music CD Identifier
-      (Version::ID3v22, "MLL") => decoder.decode_v2().map(Self::Mllt), // MPEG location lookup table
-      (Version::ID3v22, "PIC") => decoder.decode_v2().map(Self::Apic), // Attached picture
-      (Version::ID3v22, "POP") => decoder.decode_v2().map(Self::Popm), // Popularimeter
-      (Version::ID3v22, "REV") => decoder.decode_v2().map(Self::Rvrb), // Reverb
-      (Version::ID3v22, "RVA") => decoder.decode_v2().map(Self::Rvad), // Relative volume adjustment
-      (Version::ID3v22, "SLT") => decoder.decode_v2().map(Self::Sylt), // Synchronized lyric/text
-      (Version::ID3v22, "STC") => decoder.decode_v2().map(Self::Sytc), // Synced tempo codes
-      (Version::ID3v22, "TAL") => decoder.decode_v2().map(Self::Text), // Album/Movie/Show title
-      (Version::ID3v22, "TBP") => decoder.decode_v2().map(Self::Text), // BPM (Beats Per Minute)
-      (Version::ID3v22, "TCM") => decoder.decode_v2().map(Self::Text), // Composer
-      (Version::ID3v22, "TCO") => decoder.decode_v2().map(Self::Text), // Self type
-      (Version::ID3v22, "TCR") => decoder.decode_v2().map(Self::Text), // Copyright message
-      (Version::ID3v22, "TDA") => decoder.decode_v2().map(Self::Text), // Date
-      (Version::ID3v22, "TDY") => decoder.decode_v2().map(Self::Text), // Playlist delay
-      (Version::ID3v22, "TEN") => decoder.decode_v2().map(Self::Text), // Encoded by
-      (Version::ID3v22, "TFT") => decoder.decode_v2().map(Self::Text), // File type
-      (Version::ID3v22, "TIM") => decoder.decode_v2().map(Self::Text), // Time
-      (Version::ID3v22, "TKE") => decoder.decode_v2().map(Self::Text), // Initial key
-      (Version::ID3v22, "TLA") => decoder.decode_v2().map(Self::Text), // Language(s)
-      (Version::ID3v22, "TLE") => decoder.decode_v2().map(Self::Text), // Length
-      (Version::ID3v22, "TMT") => decoder.decode_v2().map(Self::Text), // Media type
-      (Version::ID3v22, "TOA") => decoder.decode_v2().map(Self::Text), // Original artist(s)/performer(s)
-      (Version::ID3v22, "TOF") => decoder.decode_v2().map(Self::Text), // Original filename
-      (Version::ID3v22, "TOL") => decoder.decode_v2().map(Self::Text), // Original Lyricist(s)/text writer(s)
-      (Version::ID3v22, "TOR") => decoder.decode_v2().map(Self::Text), // Original release year
-      (Version::ID3v22, "TOT") => decoder.decode_v2().map(Self::Text), // Original album/Movie/Show title
-      (Version::ID3v22, "TP1") => decoder.decode_v2().map(Self::Text), // Lead artist(s)/Lead performer(s)/Soloist(s)/Performing group
-      (Version::ID3v22, "TP2") => decoder.decode_v2().map(Self::Text), // Band/Orchestra/Accompaniment
-      (Version::ID3v22, "TP3") => decoder.decode_v2().map(Self::Text), // Conductor/Performer refinement
-      (Version::ID3v22, "TP4") => decoder.decode_v2().map(Self::Text), // Interpreted, remixed, or otherwise modified by
-      (Version::ID3v22, "TPA") => decoder.decode_v2().map(Self::Text), // Part of a set
-      (Version::ID3v22, "TPB") => decoder.decode_v2().map(Self::Text), // Publisher
-      (Version::ID3v22, "TRC") => decoder.decode_v2().map(Self::Text), // ISRC (International Standard Recording Code)
-      (Version::ID3v22, "TRD") => decoder.decode_v2().map(Self::Text), // Recording dates
-      (Version::ID3v22, "TRK") => decoder.decode_v2().map(Self::Text), // Track number/Position in set
-      (Version::ID3v22, "TSI") => decoder.decode_v2().map(Self::Text), // Size
-      (Version::ID3v22, "TSS") => decoder.decode_v2().map(Self::Text), // Software/hardware and settings used for encoding
-      (Version::ID3v22, "TT1") => decoder.decode_v2().map(Self::Text), // Self group description
-      (Version::ID3v22, "TT2") => decoder.decode_v2().map(Self::Text), // Title/Songname/Self description
-      (Version::ID3v22, "TT3") => decoder.decode_v2().map(Self::Text), // Subtitle/Description refinement
-      (Version::ID3v22, "TXT") => decoder.decode_v2().map(Self::Text), // Lyricist/text writer
-      (Version::ID3v22, "TXX") => decoder.decode_v2().map(Self::Txxx), // User defined text information frame
-      (Version::ID3v22, "TYE") => decoder.decode_v2().map(Self::Text), // Year
-      (Version::ID3v22, "UFI") => decoder.decode_v2().map(Self::Ufid), // Unique file identifier
-      (Version::ID3v22, "ULT") => decoder.decode_v2().map(Self::Uslt), // Unsychronized lyric/text transcription
-      (Version::ID3v22, "WAF") => decoder.decode_v2().map(Self::Woaf), // Official audio file webpage
-      (Version::ID3v22, "WAR") => decoder.decode_v2().map(Self::Woar), // Official artist/performer webpage
-      (Version::ID3v22, "WAS") => decoder.decode_v2().map(Self::Woas), // Official audio source webpage
-      (Version::ID3v22, "WCM") => decoder.decode_v2().map(Self::Wcom), // Commercial information
-      (Version::ID3v22, "WCP") => decoder.decode_v2().map(Self::Wcop), // Copyright/Legal information
-      (Version::ID3v22, "WPB") => decoder.decode_v2().map(Self::Wpub), // Publishers official webpage
-      (Version::ID3v22, "WXX") => decoder.decode_v2().map(Self::Wxxx), // User defined URL link frame
+      (Version::ID3v22, b"BUF") => decoder.decode_v2().map(Self::Rbuf), // Recommended buffer size
+      (Version::ID3v22, b"CNT") => decoder.decode_v2().map(Self::Pcnt), // Play counter
+      (Version::ID3v22, b"COM") => decoder.decode_v2().map(Self::Comm), // Comments
+      (Version::ID3v22, b"CRA") => decoder.decode_v2().map(Self::Aenc), // Audio encryption
+      (Version::ID3v22, b"CRM") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // TODO: Encrypted meta frame
+      (Version::ID3v22, b"ETC") => decoder.decode_v2().map(Self::Etco), // Event timing codes
+      (Version::ID3v22, b"EQU") => decoder.decode_v2().map(Self::Equa), // Equalization
+      (Version::ID3v22, b"GEO") => decoder.decode_v2().map(Self::Geob), // General encapsulated object
+      (Version::ID3v22, b"IPL") => decoder.decode_v2().map(Self::Ipls), // Involved people list
+      (Version::ID3v22, b"LNK") => decoder.decode_v2().map(Self::Link), // Linked information
+      (Version::ID3v22, b"MCI") => decoder.decode_v2().map(Self::Mcdi), // Music CD Identifier
+      (Version::ID3v22, b"MLL") => decoder.decode_v2().map(Self::Mllt), // MPEG location lookup table
+      (Version::ID3v22, b"PIC") => decoder.decode_v2().map(Self::Apic), // Attached picture
+      (Version::ID3v22, b"POP") => decoder.decode_v2().map(Self::Popm), // Popularimeter
+      (Version::ID3v22, b"REV") => decoder.decode_v2().map(Self::Rvrb), // Reverb
+      (Version::ID3v22, b"RVA") => decoder.decode_v2().map(Self::Rvad), // Relative volume adjustment
+      (Version::ID3v22, b"SLT") => decoder.decode_v2().map(Self::Sylt), // Synchronized lyric/text
+      (Version::ID3v22, b"STC") => decoder.decode_v2().map(Self::Sytc), // Synced tempo codes
+      (Version::ID3v22, b"TAL") => decoder.decode_v2().map(Self::Text), // Album/Movie/Show title
+      (Version::ID3v22, b"TBP") => decoder.decode_v2().map(Self::Text), // BPM (Beats Per Minute)
+      (Version::ID3v22, b"TCM") => decoder.decode_v2().map(Self::Text), // Composer
+      (Version::ID3v22, b"TCO") => decoder.decode_v2().map(Self::Text), // Self type
+      (Version::ID3v22, b"TCR") => decoder.decode_v2().map(Self::Text), // Copyright message
+      (Version::ID3v22, b"TDA") => decoder.decode_v2().map(Self::Text), // Date
+      (Version::ID3v22, b"TDY") => decoder.decode_v2().map(Self::Text), // Playlist delay
+      (Version::ID3v22, b"TEN") => decoder.decode_v2().map(Self::Text), // Encoded by
+      (Version::ID3v22, b"TFT") => decoder.decode_v2().map(Self::Text), // File type
+      (Version::ID3v22, b"TIM") => decoder.decode_v2().map(Self::Text), // Time
+      (Version::ID3v22, b"TKE") => decoder.decode_v2().map(Self::Text), // Initial key
+      (Version::ID3v22, b"TLA") => decoder.decode_v2().map(Self::Text), // Language(s)
+      (Version::ID3v22, b"TLE") => decoder.decode_v2().map(Self::Text), // Length
+      (Version::ID3v22, b"TMT") => decoder.decode_v2().map(Self::Text), // Media type
+      (Version::ID3v22, b"TOA") => decoder.decode_v2().map(Self::Text), // Original artist(s)/performer(s)
+      (Version::ID3v22, b"TOF") => decoder.decode_v2().map(Self::Text), // Original filename
+      (Version::ID3v22, b"TOL") => decoder.decode_v2().map(Self::Text), // Original Lyricist(s)/text writer(s)
+      (Version::ID3v22, b"TOR") => decoder.decode_v2().map(Self::Text), // Original release year
+      (Version::ID3v22, b"TOT") => decoder.decode_v2().map(Self::Text), // Original album/Movie/Show title
+      (Version::ID3v22, b"TP1") => decoder.decode_v2().map(Self::Text), // Lead artist(s)/Lead performer(s)/Soloist(s)/Performing group
+      (Version::ID3v22, b"TP2") => decoder.decode_v2().map(Self::Text), // Band/Orchestra/Accompaniment
+      (Version::ID3v22, b"TP3") => decoder.decode_v2().map(Self::Text), // Conductor/Performer refinement
+      (Version::ID3v22, b"TP4") => decoder.decode_v2().map(Self::Text), // Interpreted, remixed, or otherwise modified by
+      (Version::ID3v22, b"TPA") => decoder.decode_v2().map(Self::Text), // Part of a set
+      (Version::ID3v22, b"TPB") => decoder.decode_v2().map(Self::Text), // Publisher
+      (Version::ID3v22, b"TRC") => decoder.decode_v2().map(Self::Text), // ISRC (International Standard Recording Code)
+      (Version::ID3v22, b"TRD") => decoder.decode_v2().map(Self::Text), // Recording dates
+      (Version::ID3v22, b"TRK") => decoder.decode_v2().map(Self::Text), // Track number/Position in set
+      (Version::ID3v22, b"TSI") => decoder.decode_v2().map(Self::Text), // Size
+      (Version::ID3v22, b"TSS") => decoder.decode_v2().map(Self::Text), // Software/hardware and settings used for encoding
+      (Version::ID3v22, b"TT1") => decoder.decode_v2().map(Self::Text), // Self group description
+      (Version::ID3v22, b"TT2") => decoder.decode_v2().map(Self::Text), // Title/Songname/Self description
+      (Version::ID3v22, b"TT3") => decoder.decode_v2().map(Self::Text), // Subtitle/Description refinement
+      (Version::ID3v22, b"TXT") => decoder.decode_v2().map(Self::Text), // Lyricist/text writer
+      (Version::ID3v22, b"TXX") => decoder.decode_v2().map(Self::Txxx), // User defined text information frame
+      (Version::ID3v22, b"TYE") => decoder.decode_v2().map(Self::Text), // Year
+      (Version::ID3v22, b"UFI") => decoder.decode_v2().map(Self::Ufid), // Unique file identifier
+      (Version::ID3v22, b"ULT") => decoder.decode_v2().map(Self::Uslt), // Unsychronized lyric/text transcription
+      (Version::ID3v22, b"WAF") => decoder.decode_v2().map(Self::Woaf), // Official audio file webpage
+      (Version::ID3v22, b"WAR") => decoder.decode_v2().map(Self::Woar), // Official artist/performer webpage
+      (Version::ID3v22, b"WAS") => decoder.decode_v2().map(Self::Woas), // Official audio source webpage
+      (Version::ID3v22, b"WCM") => decoder.decode_v2().map(Self::Wcom), // Commercial information
+      (Version::ID3v22, b"WCP") => decoder.decode_v2().map(Self::Wcop), // Copyright/Legal information
+      (Version::ID3v22, b"WPB") => decoder.decode_v2().map(Self::Wpub), // Publishers official webpage
+      (Version::ID3v22, b"WXX") => decoder.decode_v2().map(Self::Wxxx), // User defined URL link frame
       // =======================================================================
       // ID3v2.3 / ID3v2.4 Frames
       // =======================================================================
-      (Version::ID3v23 | Version::ID3v24, "AENC") => decoder.decode().map(Self::Aenc), // [[#sec4.20|Audio encryption]]
-      (Version::ID3v23 | Version::ID3v24, "APIC") => decoder.decode().map(Self::Apic), // [#sec4.15 Attached picture]
-      (Version::ID3v23 | Version::ID3v24, "COMM") => decoder.decode().map(Self::Comm), // [#sec4.11 Comments]
-      (Version::ID3v23 | Version::ID3v24, "COMR") => decoder.decode().map(Self::Comr), // [#sec4.25 Commercial frame]
-      (Version::ID3v23 | Version::ID3v24, "ENCR") => decoder.decode().map(Self::Encr), // [#sec4.26 Encryption method registration]
-      (Version::ID3v23, "EQUA") => decoder.decode().map(Self::Equa), // [#sec4.13 Equalization]
-      (Version::ID3v23 | Version::ID3v24, "ETCO") => decoder.decode().map(Self::Etco), // [#sec4.6 Event timing codes]
-      (Version::ID3v23 | Version::ID3v24, "GEOB") => decoder.decode().map(Self::Geob), // [#sec4.16 General encapsulated object]
-      (Version::ID3v23 | Version::ID3v24, "GRID") => decoder.decode().map(Self::Grid), // [#sec4.27 Group identification registration]
-      (Version::ID3v23, "IPLS") => decoder.decode().map(Self::Ipls), // [#sec4.4 Involved people list]
-      (Version::ID3v23 | Version::ID3v24, "LINK") => decoder.decode().map(Self::Link), // [#sec4.21 Linked information]
-      (Version::ID3v23 | Version::ID3v24, "MCDI") => decoder.decode().map(Self::Mcdi), // [#sec4.5 Music CD identifier]
-      (Version::ID3v23 | Version::ID3v24, "MLLT") => decoder.decode().map(Self::Mllt), // [#sec4.7 MPEG location lookup table]
-      (Version::ID3v23 | Version::ID3v24, "OWNE") => decoder.decode().map(Self::Owne), // [#sec4.24 Ownership frame]
-      (Version::ID3v23 | Version::ID3v24, "PRIV") => decoder.decode().map(Self::Priv), // [#sec4.28 Private frame]
-      (Version::ID3v23 | Version::ID3v24, "PCNT") => decoder.decode().map(Self::Pcnt), // [#sec4.17 Play counter]
-      (Version::ID3v23 | Version::ID3v24, "POPM") => decoder.decode().map(Self::Popm), // [#sec4.18 Popularimeter]
-      (Version::ID3v23 | Version::ID3v24, "POSS") => decoder.decode().map(Self::Poss), // [#sec4.22 Position synchronisation frame]
-      (Version::ID3v23 | Version::ID3v24, "RBUF") => decoder.decode().map(Self::Rbuf), // [#sec4.19 Recommended buffer size]
-      (Version::ID3v23, "RVAD") => decoder.decode().map(Self::Rvad), // [#sec4.12 Relative volume adjustment]
-      (Version::ID3v23 | Version::ID3v24, "RVRB") => decoder.decode().map(Self::Rvrb), // [#sec4.14 Reverb]
-      (Version::ID3v23 | Version::ID3v24, "SYLT") => decoder.decode().map(Self::Sylt), // [#sec4.10 Synchronized lyric/text]
-      (Version::ID3v23 | Version::ID3v24, "SYTC") => decoder.decode().map(Self::Sytc), // [#sec4.8 Synchronized tempo codes]
-      (Version::ID3v23 | Version::ID3v24, "TALB") => decoder.decode().map(Self::Text), // [#TALB Album/Movie/Show title]
-      (Version::ID3v23 | Version::ID3v24, "TBPM") => decoder.decode().map(Self::Text), // [#TBPM BPM (beats per minute)]
-      (Version::ID3v23 | Version::ID3v24, "TCOM") => decoder.decode().map(Self::Text), // [#TCOM Composer]
-      (Version::ID3v23 | Version::ID3v24, "TCON") => decoder.decode().map(Self::Text), // [#TCON Self type]
-      (Version::ID3v23 | Version::ID3v24, "TCOP") => decoder.decode().map(Self::Text), // [#TCOP Copyright message]
-      (Version::ID3v23, "TDAT") => decoder.decode().map(Self::Text), // [#TDAT Date]
-      (Version::ID3v23 | Version::ID3v24, "TDLY") => decoder.decode().map(Self::Text), // [#TDLY Playlist delay]
-      (Version::ID3v23 | Version::ID3v24, "TENC") => decoder.decode().map(Self::Text), // [#TENC Encoded by]
-      (Version::ID3v23 | Version::ID3v24, "TEXT") => decoder.decode().map(Self::Text), // [#TEXT Lyricist/Text writer]
-      (Version::ID3v23 | Version::ID3v24, "TFLT") => decoder.decode().map(Self::Text), // [#TFLT File type]
-      (Version::ID3v23, "TIME") => decoder.decode().map(Self::Text), // [#TIME Time]
-      (Version::ID3v23 | Version::ID3v24, "TIT1") => decoder.decode().map(Self::Text), // [#TIT1 Self group description]
-      (Version::ID3v23 | Version::ID3v24, "TIT2") => decoder.decode().map(Self::Text), // [#TIT2 Title/songname/content description]
-      (Version::ID3v23 | Version::ID3v24, "TIT3") => decoder.decode().map(Self::Text), // [#TIT3 Subtitle/Description refinement]
-      (Version::ID3v23 | Version::ID3v24, "TKEY") => decoder.decode().map(Self::Text), // [#TKEY Initial key]
-      (Version::ID3v23 | Version::ID3v24, "TLAN") => decoder.decode().map(Self::Text), // [#TLAN Language(s)]
-      (Version::ID3v23 | Version::ID3v24, "TLEN") => decoder.decode().map(Self::Text), // [#TLEN Length]
-      (Version::ID3v23 | Version::ID3v24, "TMED") => decoder.decode().map(Self::Text), // [#TMED Media type]
-      (Version::ID3v23 | Version::ID3v24, "TOAL") => decoder.decode().map(Self::Text), // [#TOAL Original album/movie/show title]
-      (Version::ID3v23 | Version::ID3v24, "TOFN") => decoder.decode().map(Self::Text), // [#TOFN Original filename]
-      (Version::ID3v23 | Version::ID3v24, "TOLY") => decoder.decode().map(Self::Text), // [#TOLY Original lyricist(s)/text writer(s)]
-      (Version::ID3v23 | Version::ID3v24, "TOPE") => decoder.decode().map(Self::Text), // [#TOPE Original artist(s)/performer(s)]
-      (Version::ID3v23, "TORY") => decoder.decode().map(Self::Text), // [#TORY Original release year]
-      (Version::ID3v23 | Version::ID3v24, "TOWN") => decoder.decode().map(Self::Text), // [#TOWN File owner/licensee]
-      (Version::ID3v23 | Version::ID3v24, "TPE1") => decoder.decode().map(Self::Text), // [#TPE1 Lead performer(s)/Soloist(s)]
-      (Version::ID3v23 | Version::ID3v24, "TPE2") => decoder.decode().map(Self::Text), // [#TPE2 Band/orchestra/accompaniment]
-      (Version::ID3v23 | Version::ID3v24, "TPE3") => decoder.decode().map(Self::Text), // [#TPE3 Conductor/performer refinement]
-      (Version::ID3v23 | Version::ID3v24, "TPE4") => decoder.decode().map(Self::Text), // [#TPE4 Interpreted, remixed, or otherwise modified by]
-      (Version::ID3v23 | Version::ID3v24, "TPOS") => decoder.decode().map(Self::Text), // [#TPOS Part of a set]
-      (Version::ID3v23 | Version::ID3v24, "TPUB") => decoder.decode().map(Self::Text), // [#TPUB Publisher]
-      (Version::ID3v23 | Version::ID3v24, "TRCK") => decoder.decode().map(Self::Text), // [#TRCK Track number/Position in set]
-      (Version::ID3v23, "TRDA") => decoder.decode().map(Self::Text), // [#TRDA Recording dates]
-      (Version::ID3v23 | Version::ID3v24, "TRSN") => decoder.decode().map(Self::Text), // [#TRSN Internet radio station name]
-      (Version::ID3v23 | Version::ID3v24, "TRSO") => decoder.decode().map(Self::Text), // [#TRSO Internet radio station owner]
-      (Version::ID3v23, "TSIZ") => decoder.decode().map(Self::Text), // [#TSIZ Size]
-      (Version::ID3v23 | Version::ID3v24, "TSRC") => decoder.decode().map(Self::Text), // [#TSRC ISRC (international standard recording code)]
-      (Version::ID3v23 | Version::ID3v24, "TSSE") => decoder.decode().map(Self::Text), // [#TSEE Software/Hardware and settings used for encoding]
-      (Version::ID3v23, "TYER") => decoder.decode().map(Self::Text), // [#TYER Year]
-      (Version::ID3v23 | Version::ID3v24, "TXXX") => decoder.decode().map(Self::Txxx), // [#TXXX User defined text information frame]
-      (Version::ID3v23 | Version::ID3v24, "UFID") => decoder.decode().map(Self::Ufid), // [#sec4.1 Unique file identifier]
-      (Version::ID3v23 | Version::ID3v24, "USER") => decoder.decode().map(Self::User), // [#sec4.23 Terms of use]
-      (Version::ID3v23 | Version::ID3v24, "USLT") => decoder.decode().map(Self::Uslt), // [#sec4.9 Unsychronized lyric/text transcription]
-      (Version::ID3v23 | Version::ID3v24, "WCOM") => decoder.decode().map(Self::Wcom), // [#WCOM Commercial information]
-      (Version::ID3v23 | Version::ID3v24, "WCOP") => decoder.decode().map(Self::Wcop), // [#WCOP Copyright/Legal information]
-      (Version::ID3v23 | Version::ID3v24, "WOAF") => decoder.decode().map(Self::Woaf), // [#WOAF Official audio file webpage]
-      (Version::ID3v23 | Version::ID3v24, "WOAR") => decoder.decode().map(Self::Woar), // [#WOAR Official artist/performer webpage]
-      (Version::ID3v23 | Version::ID3v24, "WOAS") => decoder.decode().map(Self::Woas), // [#WOAS Official audio source webpage]
-      (Version::ID3v23 | Version::ID3v24, "WORS") => decoder.decode().map(Self::Wors), // [#WORS Official internet radio station homepage]
-      (Version::ID3v23 | Version::ID3v24, "WPAY") => decoder.decode().map(Self::Wpay), // [#WPAY Payment]
-      (Version::ID3v23 | Version::ID3v24, "WPUB") => decoder.decode().map(Self::Wpub), // [#WPUB Publishers official webpage]
-      (Version::ID3v23 | Version::ID3v24, "WXXX") => decoder.decode().map(Self::Wxxx), // [#WXXX User defined URL link frame]
+      (Version::ID3v23 | Version::ID3v24, b"AENC") => decoder.decode().map(Self::Aenc), // [[#sec4.20|Audio encryption]]
+      (Version::ID3v23 | Version::ID3v24, b"APIC") => decoder.decode().map(Self::Apic), // [#sec4.15 Attached picture]
+      (Version::ID3v23 | Version::ID3v24, b"COMM") => decoder.decode().map(Self::Comm), // [#sec4.11 Comments]
+      (Version::ID3v23 | Version::ID3v24, b"COMR") => decoder.decode().map(Self::Comr), // [#sec4.25 Commercial frame]
+      (Version::ID3v23 | Version::ID3v24, b"ENCR") => decoder.decode().map(Self::Encr), // [#sec4.26 Encryption method registration]
+      (Version::ID3v23, b"EQUA") => decoder.decode().map(Self::Equa), // [#sec4.13 Equalization]
+      (Version::ID3v23 | Version::ID3v24, b"ETCO") => decoder.decode().map(Self::Etco), // [#sec4.6 Event timing codes]
+      (Version::ID3v23 | Version::ID3v24, b"GEOB") => decoder.decode().map(Self::Geob), // [#sec4.16 General encapsulated object]
+      (Version::ID3v23 | Version::ID3v24, b"GRID") => decoder.decode().map(Self::Grid), // [#sec4.27 Group identification registration]
+      (Version::ID3v23, b"IPLS") => decoder.decode().map(Self::Ipls), // [#sec4.4 Involved people list]
+      (Version::ID3v23 | Version::ID3v24, b"LINK") => decoder.decode().map(Self::Link), // [#sec4.21 Linked information]
+      (Version::ID3v23 | Version::ID3v24, b"MCDI") => decoder.decode().map(Self::Mcdi), // [#sec4.5 Music CD identifier]
+      (Version::ID3v23 | Version::ID3v24, b"MLLT") => decoder.decode().map(Self::Mllt), // [#sec4.7 MPEG location lookup table]
+      (Version::ID3v23 | Version::ID3v24, b"OWNE") => decoder.decode().map(Self::Owne), // [#sec4.24 Ownership frame]
+      (Version::ID3v23 | Version::ID3v24, b"PRIV") => decoder.decode().map(Self::Priv), // [#sec4.28 Private frame]
+      (Version::ID3v23 | Version::ID3v24, b"PCNT") => decoder.decode().map(Self::Pcnt), // [#sec4.17 Play counter]
+      (Version::ID3v23 | Version::ID3v24, b"POPM") => decoder.decode().map(Self::Popm), // [#sec4.18 Popularimeter]
+      (Version::ID3v23 | Version::ID3v24, b"POSS") => decoder.decode().map(Self::Poss), // [#sec4.22 Position synchronisation frame]
+      (Version::ID3v23 | Version::ID3v24, b"RBUF") => decoder.decode().map(Self::Rbuf), // [#sec4.19 Recommended buffer size]
+      (Version::ID3v23, b"RVAD") => decoder.decode().map(Self::Rvad), // [#sec4.12 Relative volume adjustment]
+      (Version::ID3v23 | Version::ID3v24, b"RVRB") => decoder.decode().map(Self::Rvrb), // [#sec4.14 Reverb]
+      (Version::ID3v23 | Version::ID3v24, b"SYLT") => decoder.decode().map(Self::Sylt), // [#sec4.10 Synchronized lyric/text]
+      (Version::ID3v23 | Version::ID3v24, b"SYTC") => decoder.decode().map(Self::Sytc), // [#sec4.8 Synchronized tempo codes]
+      (Version::ID3v23 | Version::ID3v24, b"TALB") => decoder.decode().map(Self::Text), // [#TALB Album/Movie/Show title]
+      (Version::ID3v23 | Version::ID3v24, b"TBPM") => decoder.decode().map(Self::Text), // [#TBPM BPM (beats per minute)]
+      (Version::ID3v23 | Version::ID3v24, b"TCOM") => decoder.decode().map(Self::Text), // [#TCOM Composer]
+      (Version::ID3v23 | Version::ID3v24, b"TCON") => decoder.decode().map(Self::Text), // [#TCON Self type]
+      (Version::ID3v23 | Version::ID3v24, b"TCOP") => decoder.decode().map(Self::Text), // [#TCOP Copyright message]
+      (Version::ID3v23, b"TDAT") => decoder.decode().map(Self::Text), // [#TDAT Date]
+      (Version::ID3v23 | Version::ID3v24, b"TDLY") => decoder.decode().map(Self::Text), // [#TDLY Playlist delay]
+      (Version::ID3v23 | Version::ID3v24, b"TENC") => decoder.decode().map(Self::Text), // [#TENC Encoded by]
+      (Version::ID3v23 | Version::ID3v24, b"TEXT") => decoder.decode().map(Self::Text), // [#TEXT Lyricist/Text writer]
+      (Version::ID3v23 | Version::ID3v24, b"TFLT") => decoder.decode().map(Self::Text), // [#TFLT File type]
+      (Version::ID3v23, b"TIME") => decoder.decode().map(Self::Text), // [#TIME Time]
+      (Version::ID3v23 | Version::ID3v24, b"TIT1") => decoder.decode().map(Self::Text), // [#TIT1 Self group description]
+      (Version::ID3v23 | Version::ID3v24, b"TIT2") => decoder.decode().map(Self::Text), // [#TIT2 Title/songname/content description]
+      (Version::ID3v23 | Version::ID3v24, b"TIT3") => decoder.decode().map(Self::Text), // [#TIT3 Subtitle/Description refinement]
+      (Version::ID3v23 | Version::ID3v24, b"TKEY") => decoder.decode().map(Self::Text), // [#TKEY Initial key]
+      (Version::ID3v23 | Version::ID3v24, b"TLAN") => decoder.decode().map(Self::Text), // [#TLAN Language(s)]
+      (Version::ID3v23 | Version::ID3v24, b"TLEN") => decoder.decode().map(Self::Text), // [#TLEN Length]
+      (Version::ID3v23 | Version::ID3v24, b"TMED") => decoder.decode().map(Self::Text), // [#TMED Media type]
+      (Version::ID3v23 | Version::ID3v24, b"TOAL") => decoder.decode().map(Self::Text), // [#TOAL Original album/movie/show title]
+      (Version::ID3v23 | Version::ID3v24, b"TOFN") => decoder.decode().map(Self::Text), // [#TOFN Original filename]
+      (Version::ID3v23 | Version::ID3v24, b"TOLY") => decoder.decode().map(Self::Text), // [#TOLY Original lyricist(s)/text writer(s)]
+      (Version::ID3v23 | Version::ID3v24, b"TOPE") => decoder.decode().map(Self::Text), // [#TOPE Original artist(s)/performer(s)]
+      (Version::ID3v23, b"TORY") => decoder.decode().map(Self::Text), // [#TORY Original release year]
+      (Version::ID3v23 | Version::ID3v24, b"TOWN") => decoder.decode().map(Self::Text), // [#TOWN File owner/licensee]
+      (Version::ID3v23 | Version::ID3v24, b"TPE1") => decoder.decode().map(Self::Text), // [#TPE1 Lead performer(s)/Soloist(s)]
+      (Version::ID3v23 | Version::ID3v24, b"TPE2") => decoder.decode().map(Self::Text), // [#TPE2 Band/orchestra/accompaniment]
+      (Version::ID3v23 | Version::ID3v24, b"TPE3") => decoder.decode().map(Self::Text), // [#TPE3 Conductor/performer refinement]
+      (Version::ID3v23 | Version::ID3v24, b"TPE4") => decoder.decode().map(Self::Text), // [#TPE4 Interpreted, remixed, or otherwise modified by]
+      (Version::ID3v23 | Version::ID3v24, b"TPOS") => decoder.decode().map(Self::Text), // [#TPOS Part of a set]
+      (Version::ID3v23 | Version::ID3v24, b"TPUB") => decoder.decode().map(Self::Text), // [#TPUB Publisher]
+      (Version::ID3v23 | Version::ID3v24, b"TRCK") => decoder.decode().map(Self::Text), // [#TRCK Track number/Position in set]
+      (Version::ID3v23, b"TRDA") => decoder.decode().map(Self::Text), // [#TRDA Recording dates]
+      (Version::ID3v23 | Version::ID3v24, b"TRSN") => decoder.decode().map(Self::Text), // [#TRSN Internet radio station name]
+      (Version::ID3v23 | Version::ID3v24, b"TRSO") => decoder.decode().map(Self::Text), // [#TRSO Internet radio station owner]
+      (Version::ID3v23, b"TSIZ") => decoder.decode().map(Self::Text), // [#TSIZ Size]
+      (Version::ID3v23 | Version::ID3v24, b"TSRC") => decoder.decode().map(Self::Text), // [#TSRC ISRC (international standard recording code)]
+      (Version::ID3v23 | Version::ID3v24, b"TSSE") => decoder.decode().map(Self::Text), // [#TSEE Software/Hardware and settings used for encoding]
+      (Version::ID3v23, b"TYER") => decoder.decode().map(Self::Text), // [#TYER Year]
+      (Version::ID3v23 | Version::ID3v24, b"TXXX") => decoder.decode().map(Self::Txxx), // [#TXXX User defined text information frame]
+      (Version::ID3v23 | Version::ID3v24, b"UFID") => decoder.decode().map(Self::Ufid), // [#sec4.1 Unique file identifier]
+      (Version::ID3v23 | Version::ID3v24, b"USER") => decoder.decode().map(Self::User), // [#sec4.23 Terms of use]
+      (Version::ID3v23 | Version::ID3v24, b"USLT") => decoder.decode().map(Self::Uslt), // [#sec4.9 Unsychronized lyric/text transcription]
+      (Version::ID3v23 | Version::ID3v24, b"WCOM") => decoder.decode().map(Self::Wcom), // [#WCOM Commercial information]
+      (Version::ID3v23 | Version::ID3v24, b"WCOP") => decoder.decode().map(Self::Wcop), // [#WCOP Copyright/Legal information]
+      (Version::ID3v23 | Version::ID3v24, b"WOAF") => decoder.decode().map(Self::Woaf), // [#WOAF Official audio file webpage]
+      (Version::ID3v23 | Version::ID3v24, b"WOAR") => decoder.decode().map(Self::Woar), // [#WOAR Official artist/performer webpage]
+      (Version::ID3v23 | Version::ID3v24, b"WOAS") => decoder.decode().map(Self::Woas), // [#WOAS Official audio source webpage]
+      (Version::ID3v23 | Version::ID3v24, b"WORS") => decoder.decode().map(Self::Wors), // [#WORS Official internet radio station homepage]
+      (Version::ID3v23 | Version::ID3v24, b"WPAY") => decoder.decode().map(Self::Wpay), // [#WPAY Payment]
+      (Version::ID3v23 | Version::ID3v24, b"WPUB") => decoder.decode().map(Self::Wpub), // [#WPUB Publishers official webpage]
+      (Version::ID3v23 | Version::ID3v24, b"WXXX") => decoder.decode().map(Self::Wxxx), // [#WXXX User defined URL link frame]
       // =======================================================================
       // ID3v2.4 Frames
       // =======================================================================
-      (Version::ID3v24, "ASPI") => panic!("TODO: Decode ASPI"),
-      (Version::ID3v24, "EQU2") => panic!("TODO: Decode EQU2"),
-      (Version::ID3v24, "RVA2") => decoder.decode().map(Self::Rva2), // relative volume adjustment (2)
-      (Version::ID3v24, "SEEK") => panic!("TODO: Decode SEEK"),
-      (Version::ID3v24, "SIGN") => panic!("TODO: Decode SIGN"),
-      (Version::ID3v24, "TDEN") => decoder.decode().map(Self::Text), // encoding time
-      (Version::ID3v24, "TDOR") => decoder.decode().map(Self::Text), // original release time
-      (Version::ID3v24, "TDRC") => decoder.decode().map(Self::Text), // recording time
-      (Version::ID3v24, "TDRL") => decoder.decode().map(Self::Text), // release time
-      (Version::ID3v24, "TDTG") => decoder.decode().map(Self::Text), // tagging time
-      (Version::ID3v24, "TIPL") => decoder.decode().map(Self::Text), // involved people list
-      (Version::ID3v24, "TMCL") => decoder.decode().map(Self::Text), // musician credits list
-      (Version::ID3v24, "TMOO") => decoder.decode().map(Self::Text), // mood
-      (Version::ID3v24, "TPRO") => decoder.decode().map(Self::Text), // produced notice
-      (Version::ID3v24, "TSOA") => decoder.decode().map(Self::Text), // album sort order
-      (Version::ID3v24, "TSOP") => decoder.decode().map(Self::Text), // performer sort order
-      (Version::ID3v24, "TSOT") => decoder.decode().map(Self::Text), // title sort order
-      (Version::ID3v24, "TSST") => decoder.decode().map(Self::Text), // set subtitle
+      (Version::ID3v24, b"ASPI") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // TODO: Decode ASPI
+      (Version::ID3v24, b"EQU2") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // TODO: Decode EQU2
+      (Version::ID3v24, b"RVA2") => decoder.decode().map(Self::Rva2), // relative volume adjustment (2)
+      (Version::ID3v24, b"SEEK") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // TODO: Decode SEEK
+      (Version::ID3v24, b"SIGN") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // TODO: Decode SIGN
+      (Version::ID3v24, b"TDEN") => decoder.decode().map(Self::Text), // encoding time
+      (Version::ID3v24, b"TDOR") => decoder.decode().map(Self::Text), // original release time
+      (Version::ID3v24, b"TDRC") => decoder.decode().map(Self::Text), // recording time
+      (Version::ID3v24, b"TDRL") => decoder.decode().map(Self::Text), // release time
+      (Version::ID3v24, b"TDTG") => decoder.decode().map(Self::Text), // tagging time
+      (Version::ID3v24, b"TIPL") => decoder.decode().map(Self::Text), // involved people list
+      (Version::ID3v24, b"TMCL") => decoder.decode().map(Self::Text), // musician credits list
+      (Version::ID3v24, b"TMOO") => decoder.decode().map(Self::Text), // mood
+      (Version::ID3v24, b"TPRO") => decoder.decode().map(Self::Text), // produced notice
+      (Version::ID3v24, b"TSOA") => decoder.decode().map(Self::Text), // album sort order
+      (Version::ID3v24, b"TSOP") => decoder.decode().map(Self::Text), // performer sort order
+      (Version::ID3v24, b"TSOT") => decoder.decode().map(Self::Text), // title sort order
+      (Version::ID3v24, b"TSST") => decoder.decode().map(Self::Text), // set subtitle
       // =======================================================================
       // ID3v2 Chapter Frame Addendum v1.0
       // =======================================================================
-      (Version::ID3v23 | Version::ID3v24, "CHAP") => decoder.decode().map(Self::Chap),
-      (Version::ID3v23 | Version::ID3v24, "CTOC") => decoder.decode().map(Self::Ctoc),
+      (Version::ID3v23 | Version::ID3v24, b"CHAP") => decoder.decode().map(Self::Chap),
+      (Version::ID3v23 | Version::ID3v24, b"CTOC") => decoder.decode().map(Self::Ctoc),
       // =======================================================================
       // ID3v2 Accessibility Addendum v1.0
       // =======================================================================
-      (Version::ID3v23 | Version::ID3v24, "ATXT") => decoder.decode().map(Self::Atxt),
+      (Version::ID3v23 | Version::ID3v24, b"ATXT") => decoder.decode().map(Self::Atxt),
+      // =======================================================================
+      // iTunes Frames
+      // =======================================================================
+      (Version::ID3v23 | Version::ID3v24, b"GRP1") => decoder.decode().map(Self::Text), // Grouping
+      (Version::ID3v23 | Version::ID3v24, b"MVIN") => decoder.decode().map(Self::Text), // Movement number/count
+      (Version::ID3v23 | Version::ID3v24, b"MVNM") => decoder.decode().map(Self::Text), // Movement name
+      (Version::ID3v23 | Version::ID3v24, b"PCST") => decoder.decode().map(Self::Pcst), // Podcast flag
+      (Version::ID3v23 | Version::ID3v24, b"TDES") => decoder.decode().map(Self::Text), // Podcast description
+      (Version::ID3v23 | Version::ID3v24, b"TGID") => decoder.decode().map(Self::Text), // Podcast episode GUID
+      (Version::ID3v23 | Version::ID3v24, b"TKWD") => decoder.decode().map(Self::Text), // Podcast keywords
+      (Version::ID3v23 | Version::ID3v24, b"WFED") => decoder.decode().map(Self::Text), // Podcast feed URL (stored as text)
+      // =======================================================================
+      // Sort Order Frames
+      // =======================================================================
+      (Version::ID3v23, b"TSOA") => decoder.decode().map(Self::Text), // Album sort order (ID3v2.4 backport)
+      (Version::ID3v23, b"TSOP") => decoder.decode().map(Self::Text), // Performer sort order (ID3v2.4 backport)
+      (Version::ID3v23, b"TSOT") => decoder.decode().map(Self::Text), // Title sort order (ID3v2.4 backport)
+      (Version::ID3v23 | Version::ID3v24, b"XSOA") => decoder.decode().map(Self::Text), // Album sort order (MusicBrainz)
+      (Version::ID3v23 | Version::ID3v24, b"XSOP") => decoder.decode().map(Self::Text), // Performer sort order (MusicBrainz)
+      (Version::ID3v23 | Version::ID3v24, b"XSOT") => decoder.decode().map(Self::Text), // Title sort order (MusicBrainz)
       // =======================================================================
       // Unoffical Frames
       // =======================================================================
-      (_, "RGAD") => panic!("TODO: Decode RGAD"),
-      (_, "TCMP") => panic!("TODO: Decode TCMP"),
-      (_, "TSO2") => panic!("TODO: Decode TSO2"),
-      (_, "TSOC") => panic!("TODO: Decode TSOC"),
-      (_, "XRVA") => panic!("TODO: Decode XRVA"),
+      (_, b"NCON") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // MusicMatch binary data
+      (_, b"RGAD") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // TODO: Decode RGAD
+      (_, b"TCMP") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // TODO: Decode TCMP
+      (_, b"TSO2") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // TODO: Decode TSO2
+      (_, b"TSOC") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // TODO: Decode TSOC
+      (_, b"XDOR") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // MusicMatch binary data
+      (_, b"XRVA") => Ok(Self::Unkn(Unkn::new(id.as_str(), decoder.remaining()))), // TODO: Decode XRVA
       // =======================================================================
       // Unknown Frame
       // =======================================================================
-      _ => panic!("Unknown Frame: {:?}", name),
+      _ => return Ok(Self::Unkn(Unkn::new(id.as_str(), slice))),
     };
 
-    assert!(decoder.is_empty());
+    let this: Self = this.map_err(|error| error.with_offset(decoder.position()))?;
+
+    decoder
+      .finish()
+      .map_err(|error| error.with_offset(decoder.position()))?;
+    warnings.extend(decoder.take_warnings());
 
     Ok(this)
   }
 }
 
 impl Content<'static> {
-  pub(crate) fn decode2(version: Version, name: &str, slice: &Slice, size: u32) -> Result<Self> {
-    let bytes: Bytes = utils::decompress(slice, Some(size as usize))?;
+  pub(crate) fn decode2(
+    version: Version,
+    id: DynFrameId,
+    slice: &Slice,
+    size: u32,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Self> {
+    let limit: usize = config.options().decompressed_size_limit();
+    let bytes: Bytes = utils::decompress(slice, Some(size as usize), limit)?;
     let slice: &Slice = bytes.as_slice();
 
-    let content: Content<'_> = Content::decode(version, name, slice)?;
+    let content: Content<'_> = Content::decode_in(version, id, slice, config, warnings, None)?;
 
     Ok(content.into_owned())
   }
 }
+
+impl Content<'_> {
+  /// Returns `true` if the content is a text information frame.
+  #[inline]
+  pub const fn is_text(&self) -> bool {
+    self.kind().is_text()
+  }
+
+  /// Returns `true` if the content is a URL link frame.
+  #[inline]
+  pub const fn is_url(&self) -> bool {
+    self.kind().is_url()
+  }
+
+  /// Returns `true` if the content is mostly opaque binary data.
+  #[inline]
+  pub const fn is_binary(&self) -> bool {
+    self.kind().is_binary()
+  }
+
+  /// Get the text encoding of the content (if any).
+  pub const fn text_encoding(&self) -> Option<Encoding> {
+    match self {
+      Self::Apic(inner) => Some(inner.text_encoding()),
+      Self::Atxt(inner) => Some(inner.text_encoding()),
+      Self::Comm(inner) => Some(inner.text_encoding()),
+      Self::Comr(inner) => Some(inner.text_encoding()),
+      Self::Geob(inner) => Some(inner.text_encoding()),
+      Self::Owne(inner) => Some(inner.text_encoding()),
+      Self::Sylt(inner) => Some(inner.text_encoding()),
+      Self::Text(inner) => Some(inner.text_encoding()),
+      Self::Txxx(inner) => Some(inner.text_encoding()),
+      Self::User(inner) => Some(inner.text_encoding()),
+      Self::Uslt(inner) => Some(inner.text_encoding()),
+      Self::Wxxx(inner) => Some(inner.text_encoding()),
+      _ => None,
+    }
+  }
+
+  /// Encode the content in the format of the given `version`.
+  ///
+  /// Returns an [`Unencodable`][ErrorKind::Unencodable] error for
+  /// [`Custom`] content.
+  pub fn encode(&self, version: Version) -> Result<Vec<u8>> {
+    let content: &dyn Encode = match self {
+      Self::Aenc(inner) => inner,
+      Self::Apic(inner) => inner,
+      Self::Atxt(inner) => inner,
+      Self::Chap(inner) => inner,
+      Self::Comm(inner) => inner,
+      Self::Ctoc(inner) => inner,
+      Self::Comr(inner) => inner,
+      Self::Encr(inner) => inner,
+      Self::Equa(inner) => inner,
+      Self::Etco(inner) => inner,
+      Self::Geob(inner) => inner,
+      Self::Grid(inner) => inner,
+      Self::Ipls(inner) => inner,
+      Self::Link(inner) => inner,
+      Self::Mcdi(inner) => inner,
+      Self::Mllt(inner) => inner,
+      Self::Owne(inner) => inner,
+      Self::Pcnt(inner) => inner,
+      Self::Pcst(inner) => inner,
+      Self::Popm(inner) => inner,
+      Self::Poss(inner) => inner,
+      Self::Priv(inner) => inner,
+      Self::Rbuf(inner) => inner,
+      Self::Rva2(inner) => inner,
+      Self::Rvad(inner) => inner,
+      Self::Rvrb(inner) => inner,
+      Self::Sylt(inner) => inner,
+      Self::Sytc(inner) => inner,
+      Self::Text(inner) => inner,
+      Self::Txxx(inner) => inner,
+      Self::Ufid(inner) => inner,
+      Self::User(inner) => inner,
+      Self::Uslt(inner) => inner,
+      Self::Wcom(inner)
+      | Self::Wcop(inner)
+      | Self::Woaf(inner)
+      | Self::Woar(inner)
+      | Self::Woas(inner)
+      | Self::Wors(inner)
+      | Self::Wpay(inner)
+      | Self::Wpub(inner) => inner,
+      Self::Wxxx(inner) => inner,
+      Self::Unkn(inner) => inner,
+      Self::Custom(_) => return Err(Error::new(ErrorKind::Unencodable)),
+    };
+
+    let mut encoder: Encoder = Encoder::new();
+
+    match version {
+      Version::ID3v11 | Version::ID3v12 => return Err(Error::new(ErrorKind::InvalidVersion)),
+      Version::ID3v22 => encoder.encode_v2(content)?,
+      Version::ID3v23 | Version::ID3v24 => encoder.encode(content)?,
+    }
+
+    Ok(encoder.finish())
+  }
+}
+
+impl<'a> Content<'a> {
+  /// Get the content as a text information frame.
+  #[inline]
+  pub const fn as_text(&self) -> Option<&Text<'a>> {
+    match self {
+      Self::Text(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Get the content as an attached picture frame.
+  #[inline]
+  pub const fn as_apic(&self) -> Option<&Apic<'a>> {
+    match self {
+      Self::Apic(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Get the content as a comments frame.
+  #[inline]
+  pub const fn as_comm(&self) -> Option<&Comm<'a>> {
+    match self {
+      Self::Comm(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Get the content as an unsynchronised lyrics frame.
+  #[inline]
+  pub const fn as_uslt(&self) -> Option<&Uslt<'a>> {
+    match self {
+      Self::Uslt(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Get the content as a user-defined text frame.
+  #[inline]
+  pub const fn as_txxx(&self) -> Option<&Txxx<'a>> {
+    match self {
+      Self::Txxx(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Get the content as a private frame.
+  #[inline]
+  pub const fn as_priv(&self) -> Option<&Priv<'a>> {
+    match self {
+      Self::Priv(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Get the content as a URL link frame.
+  ///
+  /// Note: This covers every frame backed by [`Wurl`] (e.g. `WCOM` or `WOAR`)
+  /// but not the user-defined `WXXX` frame.
+  #[inline]
+  pub const fn as_url(&self) -> Option<&Wurl<'a>> {
+    match self {
+      Self::Wcom(inner)
+      | Self::Wcop(inner)
+      | Self::Woaf(inner)
+      | Self::Woar(inner)
+      | Self::Woas(inner)
+      | Self::Wors(inner)
+      | Self::Wpay(inner)
+      | Self::Wpub(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Convert the content into a text information frame.
+  #[inline]
+  pub fn into_text(self) -> Option<Text<'a>> {
+    match self {
+      Self::Text(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Convert the content into an attached picture frame.
+  #[inline]
+  pub fn into_apic(self) -> Option<Apic<'a>> {
+    match self {
+      Self::Apic(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Convert the content into a comments frame.
+  #[inline]
+  pub fn into_comm(self) -> Option<Comm<'a>> {
+    match self {
+      Self::Comm(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Convert the content into an unsynchronised lyrics frame.
+  #[inline]
+  pub fn into_uslt(self) -> Option<Uslt<'a>> {
+    match self {
+      Self::Uslt(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Convert the content into a user-defined text frame.
+  #[inline]
+  pub fn into_txxx(self) -> Option<Txxx<'a>> {
+    match self {
+      Self::Txxx(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Convert the content into a private frame.
+  #[inline]
+  pub fn into_priv(self) -> Option<Priv<'a>> {
+    match self {
+      Self::Priv(inner) => Some(inner),
+      _ => None,
+    }
+  }
+
+  /// Convert the content into a URL link frame.
+  ///
+  /// See [`as_url`][Self::as_url] for the frames covered.
+  #[inline]
+  pub fn into_url(self) -> Option<Wurl<'a>> {
+    match self {
+      Self::Wcom(inner)
+      | Self::Wcop(inner)
+      | Self::Woaf(inner)
+      | Self::Woar(inner)
+      | Self::Woas(inner)
+      | Self::Wors(inner)
+      | Self::Wpay(inner)
+      | Self::Wpub(inner) => Some(inner),
+      _ => None,
+    }
+  }
+}
+
+// =============================================================================
+// Content Kind
+// =============================================================================
+
+impl ContentKind {
+  /// Get the canonical ID3v2.3/ID3v2.4 frame identifier of the content kind.
+  ///
+  /// Returns `None` for kinds shared by many identifiers ([`Text`][Self::Text])
+  /// or without a fixed identifier ([`Custom`][Self::Custom] and [`Unkn`][Self::Unkn]).
+  pub const fn frame_id(self) -> Option<&'static str> {
+    let frame_id: &str = match self {
+      Self::Aenc => "AENC",
+      Self::Apic => "APIC",
+      Self::Atxt => "ATXT",
+      Self::Chap => "CHAP",
+      Self::Comm => "COMM",
+      Self::Ctoc => "CTOC",
+      Self::Comr => "COMR",
+      Self::Encr => "ENCR",
+      Self::Equa => "EQUA",
+      Self::Etco => "ETCO",
+      Self::Geob => "GEOB",
+      Self::Grid => "GRID",
+      Self::Ipls => "IPLS",
+      Self::Link => "LINK",
+      Self::Mcdi => "MCDI",
+      Self::Mllt => "MLLT",
+      Self::Owne => "OWNE",
+      Self::Pcnt => "PCNT",
+      Self::Pcst => "PCST",
+      Self::Popm => "POPM",
+      Self::Poss => "POSS",
+      Self::Priv => "PRIV",
+      Self::Rbuf => "RBUF",
+      Self::Rva2 => "RVA2",
+      Self::Rvad => "RVAD",
+      Self::Rvrb => "RVRB",
+      Self::Sylt => "SYLT",
+      Self::Sytc => "SYTC",
+      Self::Txxx => "TXXX",
+      Self::Ufid => "UFID",
+      Self::User => "USER",
+      Self::Uslt => "USLT",
+      Self::Wcom => "WCOM",
+      Self::Wcop => "WCOP",
+      Self::Woaf => "WOAF",
+      Self::Woar => "WOAR",
+      Self::Woas => "WOAS",
+      Self::Wors => "WORS",
+      Self::Wpay => "WPAY",
+      Self::Wpub => "WPUB",
+      Self::Wxxx => "WXXX",
+      Self::Text | Self::Custom | Self::Unkn => return None,
+    };
+
+    Some(frame_id)
+  }
+
+  /// Returns `true` if the kind is a text information frame.
+  #[inline]
+  pub const fn is_text(self) -> bool {
+    matches!(self, Self::Text | Self::Txxx)
+  }
+
+  /// Returns `true` if the kind is a URL link frame.
+  #[inline]
+  pub const fn is_url(self) -> bool {
+    matches!(
+      self,
+      Self::Wcom
+        | Self::Wcop
+        | Self::Woaf
+        | Self::Woar
+        | Self::Woas
+        | Self::Wors
+        | Self::Wpay
+        | Self::Wpub
+        | Self::Wxxx
+    )
+  }
+
+  /// Returns `true` if the kind is mostly opaque binary data.
+  #[inline]
+  pub const fn is_binary(self) -> bool {
+    matches!(
+      self,
+      Self::Aenc
+        | Self::Apic
+        | Self::Atxt
+        | Self::Encr
+        | Self::Geob
+        | Self::Grid
+        | Self::Mcdi
+        | Self::Priv
+        | Self::Ufid
+        | Self::Unkn
+    )
+  }
+}
+
+impl Display for ContentKind {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(self.name())
+  }
+}
@@ -0,0 +1,113 @@
+use alloc::sync::Arc;
+use core::any::Any;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::hash::Hash;
+use core::hash::Hasher;
+
+use crate::types::DynFrameId;
+
+// =============================================================================
+// Custom
+// =============================================================================
+
+/// Frame content decoded by a parser registered with a
+/// [`DecoderConfig`][crate::options::DecoderConfig].
+///
+/// Two values are only equal if they share the same parser output.
+#[derive(Clone)]
+pub struct Custom {
+  identifier: DynFrameId,
+  value: Arc<dyn Any + Send + Sync>,
+}
+
+impl Custom {
+  pub(crate) fn new<T>(identifier: DynFrameId, value: T) -> Self
+  where
+    T: Any + Send + Sync,
+  {
+    Self {
+      identifier,
+      value: Arc::new(value),
+    }
+  }
+
+  /// Get the identifier of the decoded frame.
+  #[inline]
+  pub const fn identifier(&self) -> DynFrameId {
+    self.identifier
+  }
+
+  /// Returns `true` if the parser output is of type `T`.
+  #[inline]
+  pub fn is<T>(&self) -> bool
+  where
+    T: Any,
+  {
+    self.value.is::<T>()
+  }
+
+  /// Get the parser output if it is of type `T`.
+  #[inline]
+  pub fn downcast_ref<T>(&self) -> Option<&T>
+  where
+    T: Any,
+  {
+    self.value.downcast_ref()
+  }
+
+  fn address(&self) -> usize {
+    Arc::as_ptr(&self.value) as *const () as usize
+  }
+}
+
+impl Debug for Custom {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.debug_struct("Custom")
+      .field("identifier", &self.identifier)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Display for Custom {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}: custom content", self.identifier)
+  }
+}
+
+impl Hash for Custom {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.identifier.hash(state);
+    self.address().hash(state);
+  }
+}
+
+impl PartialEq for Custom {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier == other.identifier && Arc::ptr_eq(&self.value, &other.value)
+  }
+}
+
+impl Eq for Custom {}
+
+impl PartialOrd for Custom {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Custom {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self
+      .identifier
+      .cmp(&other.identifier)
+      .then_with(|| self.address().cmp(&other.address()))
+  }
+}
+
+copy_into_owned!(Custom);
@@ -1,6 +1,10 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Audio Encryption
@@ -14,3 +18,14 @@ pub struct Aenc<'a> {
   preview_length: u16,
   encryption_info: Cow<'a, Slice>,
 }
+
+impl Display for Aenc<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{}: {}",
+      self.owner_identifier,
+      HexPreview(&self.encryption_info)
+    )
+  }
+}
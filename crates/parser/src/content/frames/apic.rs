@@ -1,12 +1,20 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::str::from_utf8;
 
 use crate::decode::Decode;
 use crate::decode::Decoder;
 use crate::decode::Encoding;
+use crate::encode::Encode;
+use crate::encode::Encoder;
 use crate::error::Error;
 use crate::error::ErrorKind;
 use crate::error::Result;
-use crate::types::Slice;
+use crate::error::WarningKind;
+use crate::text::caseless_eq;
+use crate::types::LazySlice;
 
 // =============================================================================
 // Attached Picture
@@ -14,45 +22,193 @@ use crate::types::Slice;
 
 /// Attached picture frame content.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Frame)]
-#[frame(skip_decoding)]
+#[frame(skip_decoding, skip_encoding)]
 pub struct Apic<'a> {
   text_encoding: Encoding,
+  #[frame(info = "MIME type")]
+  mime_type: Cow<'a, str>,
   image_format: ImgType,
   picture_type: PicType,
   description: Cow<'a, str>,
-  picture_data: Cow<'a, Slice>,
+  #[frame(defer)]
+  picture_data: LazySlice<'a>,
+}
+
+impl Apic<'_> {
+  /// Get the URL of the image if the picture data is a link (MIME type
+  /// `-->`).
+  ///
+  /// Returns `None` if the picture data was deferred, see
+  /// [`load_picture_data`][Self::load_picture_data].
+  pub fn picture_url(&self) -> Option<Cow<'_, str>> {
+    if self.image_format != ImgType::Link {
+      return None;
+    }
+
+    self
+      .picture_data
+      .as_slice()
+      .map(|data| Encoding::Latin1.decode_lossy(data))
+  }
+
+  /// Get the image format from the magic bytes of the picture data.
+  ///
+  /// Returns `None` if the format is not recognized or the picture data was
+  /// deferred, see [`is_mislabeled`][Self::is_mislabeled] to compare it with
+  /// the declared format.
+  #[inline]
+  pub fn sniff_format(&self) -> Option<ImgType> {
+    ImgType::sniff(self.picture_data.as_slice()?.as_ref())
+  }
+
+  /// Returns `true` if the magic bytes of the picture data contradict the
+  /// declared image format.
+  #[inline]
+  pub fn is_mislabeled(&self) -> bool {
+    self
+      .sniff_format()
+      .is_some_and(|format| format != self.image_format)
+  }
+
+  /// Get the dimensions (in pixels) of a PNG or JPEG picture.
+  ///
+  /// Only the PNG `IHDR` chunk or the JPEG start of frame marker is parsed.
+  /// Returns `None` for other formats, truncated data or if the picture data
+  /// was deferred.
+  #[inline]
+  pub fn dimensions(&self) -> Option<(u32, u32)> {
+    Self::image_size(self.picture_data.as_slice()?.as_ref())
+  }
+
+  /// Get the dimensions (in pixels) of a PNG or JPEG image.
+  pub(crate) fn image_size(data: &[u8]) -> Option<(u32, u32)> {
+    const PNG: &[u8] = b"\x89PNG\r\n\x1A\n";
+    const JPG: &[u8] = b"\xFF\xD8";
+
+    // The IHDR chunk is always the first chunk of a PNG image.
+    if data.starts_with(PNG) {
+      let width: [u8; 4] = data.get(16..20)?.try_into().ok()?;
+      let height: [u8; 4] = data.get(20..24)?.try_into().ok()?;
+
+      return Some((u32::from_be_bytes(width), u32::from_be_bytes(height)));
+    }
+
+    if !data.starts_with(JPG) {
+      return None;
+    }
+
+    let mut offset: usize = JPG.len();
+
+    loop {
+      let &[0xFF, marker, high, low] = data.get(offset..offset + 4)? else {
+        return None;
+      };
+
+      // Start of frame markers, except DHT, JPG and DAC.
+      if matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC) {
+        let &[h1, h0, w1, w0] = data.get(offset + 5..offset + 9)? else {
+          return None;
+        };
+
+        let width: u16 = u16::from_be_bytes([w1, w0]);
+        let height: u16 = u16::from_be_bytes([h1, h0]);
+
+        return Some((u32::from(width), u32::from(height)));
+      }
+
+      offset += 2 + usize::from(u16::from_be_bytes([high, low]));
+    }
+  }
+
+  /// Get the MIME type as an ID3v2.2 image format, if it has 3 characters.
+  fn format_code(&self) -> [u8; 3] {
+    self.mime_type.as_bytes().try_into().unwrap_or_default()
+  }
 }
 
 impl<'a> Decode<'a> for Apic<'a> {
   fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+    let text_encoding: Encoding = decoder.decode()?;
+    let mime_type: Cow<'a, str> = decoder.decode_latin1()?;
+    let picture_type: PicType = decoder.decode()?;
+    let description: Cow<'a, str> = decoder.decode()?;
+    let picture_data: LazySlice<'a> = decoder.decode()?;
+
     Ok(Self {
-      text_encoding: decoder.decode()?,
-      image_format: decoder.decode()?,
-      picture_type: decoder.decode()?,
-      description: decoder.decode()?,
-      picture_data: decoder.decode()?,
+      text_encoding,
+      image_format: ImgType::classify(ImgType::from_mime(&mime_type), &picture_data),
+      mime_type,
+      picture_type,
+      description,
+      picture_data,
     })
   }
 
   fn decode_v2(decoder: &mut Decoder<'a>) -> Result<Self> {
+    let text_encoding: Encoding = decoder.decode_v2()?;
+    let format: [u8; 3] = decoder.decode_v2()?;
+    let picture_type: PicType = decoder.decode_v2()?;
+    let description: Cow<'a, str> = decoder.decode_v2()?;
+    let picture_data: LazySlice<'a> = decoder.decode_v2()?;
+
     Ok(Self {
-      text_encoding: decoder.decode_v2()?,
-      image_format: decoder.decode_v2()?,
-      picture_type: decoder.decode_v2()?,
-      description: decoder.decode_v2()?,
-      picture_data: decoder.decode_v2()?,
+      text_encoding,
+      mime_type: Cow::Owned(format.iter().copied().map(char::from).collect()),
+      image_format: ImgType::classify(ImgType::from_format(format), &picture_data),
+      picture_type,
+      description,
+      picture_data,
     })
   }
 }
 
+impl Encode for Apic<'_> {
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    // ID3v2.2 image formats are converted to MIME types.
+    let mime_type: &str = match ImgType::from_format(self.format_code()) {
+      Some(format) if ImgType::from_mime(&self.mime_type).is_none() => format.mime_type(),
+      _ => &self.mime_type,
+    };
+
+    encoder.encode(&self.text_encoding)?;
+    encoder.encode_latin1(mime_type)?;
+    encoder.encode(&self.picture_type)?;
+    encoder.encode(&self.description)?;
+    encoder.encode(&self.picture_data)
+  }
+
+  fn encode_v2(&self, encoder: &mut Encoder) -> Result<()> {
+    let format: [u8; 3] = match ImgType::from_mime(&self.mime_type) {
+      Some(format) => format.format().ok_or(Error::new(ErrorKind::Unencodable))?,
+      None if self.mime_type.len() == 3 => self.format_code(),
+      None => return Err(Error::new(ErrorKind::Unencodable)),
+    };
+
+    encoder.encode_v2(&self.text_encoding)?;
+    encoder.encode_v2(&format)?;
+    encoder.encode_v2(&self.picture_type)?;
+    encoder.encode_v2(&self.description)?;
+    encoder.encode_v2(&self.picture_data)
+  }
+}
+
+impl Display for Apic<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "({})[{:?}]: {}, {} bytes",
+      self.description,
+      self.picture_type,
+      self.mime_type,
+      self.picture_data.len()
+    )
+  }
+}
+
 // =============================================================================
 // Image Format/MIME Type
 // =============================================================================
 
-// TODO: Support image/bmp (?)
-// TODO: Support image/gif (?)
-// TODO: Support image/tiff (?)
-
 /// Image format.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ImgType {
@@ -60,34 +216,141 @@ pub enum ImgType {
   Png,
   /// JPG image format.
   Jpg,
+  /// GIF image format.
+  Gif,
+  /// BMP image format.
+  Bmp,
+  /// TIFF image format.
+  Tiff,
+  /// WebP image format.
+  Webp,
+  /// The picture data is a URL to the image (MIME type `-->`).
+  Link,
+  /// An unknown image format.
+  Unknown,
 }
 
 impl ImgType {
-  const PNG: [u8; 3] = *b"PNG";
-  const JPG: [u8; 3] = *b"JPG";
+  /// MIME types and their image formats.
+  const MIME: &'static [(&'static str, Self)] = &[
+    ("image/png", Self::Png),
+    ("image/jpeg", Self::Jpg),
+    ("image/jpg", Self::Jpg),
+    ("image/gif", Self::Gif),
+    ("image/bmp", Self::Bmp),
+    ("image/tiff", Self::Tiff),
+    ("image/webp", Self::Webp),
+    ("-->", Self::Link),
+  ];
 
-  const MIME_PNG: &'static [u8] = b"image/png";
-  const MIME_JPG: &'static [u8] = b"image/jpg";
-  const MIME_JPEG: &'static [u8] = b"image/jpeg";
-}
+  /// ID3v2.2 image formats, WebP has no 3-character equivalent.
+  const FORMAT: &'static [([u8; 3], Self)] = &[
+    (*b"PNG", Self::Png),
+    (*b"JPG", Self::Jpg),
+    (*b"GIF", Self::Gif),
+    (*b"BMP", Self::Bmp),
+    (*b"TIF", Self::Tiff),
+    (*b"-->", Self::Link),
+  ];
 
-impl Decode<'_> for ImgType {
-  fn decode(decoder: &mut Decoder<'_>) -> Result<Self> {
-    match decoder.decode_latin1()?.as_bytes() {
-      Self::MIME_PNG => Ok(Self::Png),
-      Self::MIME_JPG => Ok(Self::Jpg),
-      Self::MIME_JPEG => Ok(Self::Jpg),
-      _ => Err(Error::new(ErrorKind::InvalidFrameData)),
+  /// Returns `true` if the image format is PNG.
+  #[inline]
+  pub const fn is_png(&self) -> bool {
+    matches!(self, Self::Png)
+  }
+
+  /// Returns `true` if the image format is JPEG.
+  #[inline]
+  pub const fn is_jpeg(&self) -> bool {
+    matches!(self, Self::Jpg)
+  }
+
+  /// Get the MIME type of the image format.
+  #[inline]
+  pub const fn mime_type(&self) -> &'static str {
+    match self {
+      Self::Png => "image/png",
+      Self::Jpg => "image/jpeg",
+      Self::Gif => "image/gif",
+      Self::Bmp => "image/bmp",
+      Self::Tiff => "image/tiff",
+      Self::Webp => "image/webp",
+      Self::Link => "-->",
+      Self::Unknown => "application/octet-stream",
+    }
+  }
+
+  /// Get the file extension of the image format.
+  ///
+  /// Returns `None` for links and unknown formats.
+  pub(crate) const fn extension(&self) -> Option<&'static str> {
+    match self {
+      Self::Png => Some("png"),
+      Self::Jpg => Some("jpg"),
+      Self::Gif => Some("gif"),
+      Self::Bmp => Some("bmp"),
+      Self::Tiff => Some("tif"),
+      Self::Webp => Some("webp"),
+      Self::Link | Self::Unknown => None,
     }
   }
 
-  fn decode_v2(decoder: &mut Decoder<'_>) -> Result<Self> {
-    match decoder.decode()? {
-      Self::PNG => Ok(Self::Png),
-      Self::JPG => Ok(Self::Jpg),
-      _ => Err(Error::new(ErrorKind::InvalidFrameData)),
+  /// Get the image format declared in the frame, or sniffed from the magic
+  /// bytes of the picture `data` if unknown.
+  fn classify(declared: Option<Self>, data: &LazySlice<'_>) -> Self {
+    declared
+      .or_else(|| Self::sniff(data.as_slice()?.as_ref()))
+      .unwrap_or(Self::Unknown)
+  }
+
+  /// Get the image format from the magic bytes of the picture `data`.
+  pub(crate) fn sniff(data: &[u8]) -> Option<Self> {
+    match data {
+      [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Some(Self::Png),
+      [0xFF, 0xD8, 0xFF, ..] => Some(Self::Jpg),
+      [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some(Self::Gif),
+      [b'B', b'M', ..] => Some(Self::Bmp),
+      [b'I', b'I', 0x2A, 0x00, ..] | [b'M', b'M', 0x00, 0x2A, ..] => Some(Self::Tiff),
+      [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some(Self::Webp),
+      _ => None,
     }
   }
+
+  /// Get the image format of a `mime_type`, ignoring case.
+  ///
+  /// See [`caseless_eq`] for details.
+  pub(crate) fn from_mime(mime_type: &str) -> Option<Self> {
+    Self::MIME
+      .iter()
+      .find(|(mime, _)| caseless_eq(mime, mime_type))
+      .map(|(_, format)| *format)
+  }
+
+  /// Get the image format of an ID3v2.2 `format` code, ignoring case.
+  ///
+  /// See [`caseless_eq`] for details.
+  fn from_format(format: [u8; 3]) -> Option<Self> {
+    let format: &str = from_utf8(&format).ok()?;
+
+    Self::FORMAT
+      .iter()
+      .find(|(code, _)| from_utf8(code).is_ok_and(|code| caseless_eq(code, format)))
+      .map(|(_, format)| *format)
+  }
+
+  fn format(&self) -> Option<[u8; 3]> {
+    Self::FORMAT
+      .iter()
+      .find(|(_, format)| format == self)
+      .map(|(code, _)| *code)
+  }
+}
+
+impl Display for ImgType {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(self.mime_type())
+  }
 }
 
 copy_into_owned!(ImgType);
@@ -168,9 +431,22 @@ impl Decode<'_> for PicType {
       0x12 => Ok(Self::Illustration),
       0x13 => Ok(Self::BandLogo),
       0x14 => Ok(Self::Publisher),
-      _ => Err(Error::new(ErrorKind::InvalidFrameData)),
+      byte => {
+        decoder.recover(
+          WarningKind::PictureType { byte },
+          ErrorKind::InvalidFrameData,
+        )?;
+        Ok(Self::Other)
+      }
     }
   }
 }
 
+impl Encode for PicType {
+  #[inline]
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    encoder.encode(&(*self as u8))
+  }
+}
+
 copy_into_owned!(PicType);
@@ -1,8 +1,12 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
 use bitflags::bitflags;
 
 use crate::decode::Encoding;
-use crate::types::Slice;
+use crate::types::LazySlice;
 
 // =============================================================================
 // Audio Text
@@ -15,7 +19,20 @@ pub struct Atxt<'a> {
   mime_type: Cow<'a, str>,
   flags: AtxtFlags,
   text_content: Cow<'a, str>,
-  audio_data: Cow<'a, Slice>,
+  #[frame(defer)]
+  audio_data: LazySlice<'a>,
+}
+
+impl Display for Atxt<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{}: {}, {} bytes",
+      self.text_content,
+      self.mime_type,
+      self.audio_data.len()
+    )
+  }
 }
 
 // =============================================================================
@@ -34,4 +51,5 @@ bitflags! {
 }
 
 decode_bitflags!(AtxtFlags);
+encode_bitflags!(AtxtFlags);
 copy_into_owned!(AtxtFlags);
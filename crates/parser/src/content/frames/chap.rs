@@ -1,9 +1,17 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::decode::Decode;
 use crate::decode::Decoder;
+use crate::encode::Encode;
+use crate::encode::Encoder;
+use crate::error::Error;
+use crate::error::ErrorKind;
 use crate::error::Result;
 use crate::frame::DynFrame;
+use crate::options::ParseOptions;
 use crate::types::Slice;
 use crate::types::Version;
 
@@ -24,7 +32,24 @@ impl Chap<'_> {
   /// Get an iterator over the embedded sub-frames of the frame.
   #[inline]
   pub fn frames(&self) -> ChapIter<'_> {
-    ChapIter::new(self.sub_frames())
+    self.frames_with(ParseOptions::new())
+  }
+
+  /// Get an iterator over the embedded sub-frames of the frame with custom
+  /// `options`.
+  #[inline]
+  pub fn frames_with(&self, options: ParseOptions) -> ChapIter<'_> {
+    ChapIter::new(self.sub_frames(), options.sub_frame_limit())
+  }
+}
+
+impl Display for Chap<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{}: {}-{} ms",
+      self.element_identifier, self.timestamps.start_time, self.timestamps.end_time
+    )
   }
 }
 
@@ -82,6 +107,15 @@ impl Decode<'_> for ChapTime {
   }
 }
 
+impl Encode for ChapTime {
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    encoder.encode(&self.start_time)?;
+    encoder.encode(&self.end_time)?;
+    encoder.encode(&self.start_from)?;
+    encoder.encode(&self.end_from)
+  }
+}
+
 copy_into_owned!(ChapTime);
 
 // =============================================================================
@@ -92,12 +126,14 @@ copy_into_owned!(ChapTime);
 #[derive(Clone, Debug)]
 pub struct ChapIter<'a> {
   inner: Decoder<'a>,
+  fuel: usize,
 }
 
 impl<'a> ChapIter<'a> {
-  fn new(input: &'a Slice) -> Self {
+  fn new(input: &'a Slice, fuel: usize) -> Self {
     Self {
       inner: Decoder::new(input),
+      fuel,
     }
   }
 }
@@ -111,10 +147,25 @@ impl<'a> Iterator for ChapIter<'a> {
     }
 
     // TODO: This should use the same version as original frame.
-    let Ok(Some(frame)) = self.inner.decode_frame(Version::ID3v24) else {
-      return self.inner.decode_frame(Version::ID3v23).transpose();
+    let frame: DynFrame<'a> = match self.inner.decode_frame(Version::ID3v24) {
+      Ok(Some(frame)) => frame,
+      _ => match self.inner.decode_frame(Version::ID3v23) {
+        Ok(Some(frame)) => frame,
+        other => {
+          // Stop at the first malformed sub-frame.
+          self.inner.remaining();
+          return other.transpose();
+        }
+      },
     };
 
+    if self.fuel == 0 {
+      self.inner.remaining();
+      return Some(Err(Error::new(ErrorKind::FrameLimit)));
+    }
+
+    self.fuel -= 1;
+
     Some(Ok(frame))
   }
 }
@@ -1,4 +1,7 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::decode::Encoding;
 use crate::decode::Language;
@@ -15,3 +18,13 @@ pub struct Comm<'a> {
   text_summary: Cow<'a, str>,
   text_details: Cow<'a, str>,
 }
+
+impl Display for Comm<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "({})[{}]: {}",
+      self.text_summary, self.language, self.text_details
+    )
+  }
+}
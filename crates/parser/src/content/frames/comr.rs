@@ -1,9 +1,14 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::decode::Date;
 use crate::decode::Decode;
 use crate::decode::Decoder;
 use crate::decode::Encoding;
+use crate::encode::Encode;
+use crate::encode::Encoder;
 use crate::error::Error;
 use crate::error::ErrorKind;
 use crate::error::Result;
@@ -27,9 +32,20 @@ pub struct Comr<'a> {
   received_as: ReceivedAs,
   seller_name: Cow<'a, str>,
   description: Cow<'a, str>,
-  #[frame(read = "@latin1")]
-  mime_type: Cow<'a, str>,
-  seller_logo: Cow<'a, Slice>,
+  #[frame(read = "@latin1", optional)]
+  mime_type: Option<Cow<'a, str>>,
+  #[frame(optional)]
+  seller_logo: Option<Cow<'a, Slice>>,
+}
+
+impl Display for Comr<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{}: {} until {}",
+      self.seller_name, self.price_string, self.valid_until
+    )
+  }
 }
 
 // =============================================================================
@@ -77,4 +93,11 @@ impl Decode<'_> for ReceivedAs {
   }
 }
 
+impl Encode for ReceivedAs {
+  #[inline]
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    encoder.encode(&(*self as u8))
+  }
+}
+
 copy_into_owned!(ReceivedAs);
@@ -1,12 +1,20 @@
 use alloc::borrow::Cow;
-use bitflags::bitflags;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 use core::num::NonZeroU8;
 
+use bitflags::bitflags;
+
 use crate::decode::Decode;
 use crate::decode::Decoder;
+use crate::error::Error;
+use crate::error::ErrorKind;
 use crate::error::Result;
 use crate::frame::DynFrame;
+use crate::options::ParseOptions;
 use crate::types::Slice;
+use crate::types::Version;
 
 // =============================================================================
 // Table of Contents
@@ -24,7 +32,26 @@ pub struct Ctoc<'a> {
 impl Ctoc<'_> {
   /// Get an iterator over the elements of the frame.
   pub fn elements(&self) -> CtocIter<'_> {
-    CtocIter::new(self.entry_count.get(), self.binary_data())
+    self.elements_with(ParseOptions::new())
+  }
+
+  /// Get an iterator over the elements of the frame with custom `options`.
+  pub fn elements_with(&self, options: ParseOptions) -> CtocIter<'_> {
+    CtocIter::new(
+      self.entry_count.get(),
+      self.binary_data(),
+      options.sub_frame_limit(),
+    )
+  }
+}
+
+impl Display for Ctoc<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{}: {} entries",
+      self.element_identifier, self.entry_count
+    )
   }
 }
 
@@ -55,6 +82,7 @@ impl Decode<'_> for CtocFlags {
   }
 }
 
+encode_bitflags!(CtocFlags);
 copy_into_owned!(CtocFlags);
 
 // =============================================================================
@@ -80,14 +108,16 @@ pub struct CtocIter<'a> {
   count: u8,
   index: u8,
   inner: Decoder<'a>,
+  fuel: usize,
 }
 
 impl<'a> CtocIter<'a> {
-  fn new(count: u8, input: &'a Slice) -> Self {
+  fn new(count: u8, input: &'a Slice, fuel: usize) -> Self {
     Self {
       count,
       index: 0,
       inner: Decoder::new(input),
+      fuel,
     }
   }
 }
@@ -102,9 +132,29 @@ impl<'a> Iterator for CtocIter<'a> {
 
     if self.index < self.count {
       self.index += 1;
-      Some(self.inner.decode().map(CtocItem::Entry))
-    } else {
-      panic!("TODO: Parse Embedded Frame");
+      return Some(self.inner.decode().map(CtocItem::Entry));
+    }
+
+    // TODO: This should use the same version as original frame.
+    let frame: DynFrame<'a> = match self.inner.decode_frame(Version::ID3v24) {
+      Ok(Some(frame)) => frame,
+      _ => match self.inner.decode_frame(Version::ID3v23) {
+        Ok(Some(frame)) => frame,
+        other => {
+          // Stop at the first malformed sub-frame.
+          self.inner.remaining();
+          return other.transpose().map(|frame| frame.map(CtocItem::Frame));
+        }
+      },
+    };
+
+    if self.fuel == 0 {
+      self.inner.remaining();
+      return Some(Err(Error::new(ErrorKind::FrameLimit)));
     }
+
+    self.fuel -= 1;
+
+    Some(Ok(CtocItem::Frame(frame)))
   }
 }
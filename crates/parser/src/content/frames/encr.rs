@@ -1,6 +1,10 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Encryption Method Registration
@@ -13,3 +17,15 @@ pub struct Encr<'a> {
   method_symbol: u8,
   encryption_data: Cow<'a, Slice>,
 }
+
+impl Display for Encr<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{} ({:#04X}): {}",
+      self.owner_identifier,
+      self.method_symbol,
+      HexPreview(&self.encryption_data)
+    )
+  }
+}
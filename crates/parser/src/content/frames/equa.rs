@@ -1,6 +1,13 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
+use crate::content::frames::rvad;
+use crate::decode::Decoder;
+use crate::error::Result;
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Equalization
@@ -9,5 +16,104 @@ use crate::types::Slice;
 /// Equalization frame content.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Frame)]
 pub struct Equa<'a> {
-  fixme: Cow<'a, Slice>,
+  #[frame(info = "number of bits used for adjustments")]
+  adjustment_bits: u8,
+  #[frame(info = "frequency bands")]
+  adjustments: Cow<'a, Slice>,
+}
+
+impl Equa<'_> {
+  /// Get an iterator over the frequency bands of the frame.
+  #[inline]
+  pub fn bands(&self) -> EquaIter<'_> {
+    EquaIter::new(self.adjustments(), self.adjustment_bits)
+  }
+}
+
+impl Display for Equa<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{} bits: {}",
+      self.adjustment_bits,
+      HexPreview(&self.adjustments)
+    )
+  }
+}
+
+// =============================================================================
+// Frequency Band
+// =============================================================================
+
+/// Parsed frequency band from an [`EQUA`][Equa] frame.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EquaBand {
+  frequency: u16,
+  adjustment: i64,
+}
+
+impl EquaBand {
+  /// Get the frequency (in Hz) of the band.
+  #[inline]
+  pub const fn frequency(&self) -> u16 {
+    self.frequency
+  }
+
+  /// Get the volume adjustment of the band, negative if decremented.
+  #[inline]
+  pub const fn adjustment(&self) -> i64 {
+    self.adjustment
+  }
+
+  fn decode(decoder: &mut Decoder<'_>, width: usize) -> Result<Self> {
+    let frequency: u16 = decoder.decode()?;
+    let increment: bool = (frequency & 0x8000) != 0;
+
+    Ok(Self {
+      frequency: frequency & 0x7FFF,
+      adjustment: rvad::decode_adjustment(decoder, width, increment)?,
+    })
+  }
+}
+
+// =============================================================================
+// Equa Iterator
+// =============================================================================
+
+/// An iterator over the frequency bands of an [`EQUA`][Equa] frame.
+///
+/// Iteration stops after the first error, e.g. if the number of bits is not
+/// in the range 1-32 or a band is cut short.
+#[derive(Clone, Debug)]
+pub struct EquaIter<'a> {
+  inner: Decoder<'a>,
+  bits: u8,
+}
+
+impl<'a> EquaIter<'a> {
+  fn new(input: &'a Slice, bits: u8) -> Self {
+    Self {
+      inner: Decoder::new(input),
+      bits,
+    }
+  }
+}
+
+impl Iterator for EquaIter<'_> {
+  type Item = Result<EquaBand>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.inner.is_empty() {
+      return None;
+    }
+
+    let band: Result<EquaBand> =
+      rvad::value_width(self.bits).and_then(|width| EquaBand::decode(&mut self.inner, width));
+
+    if band.is_err() {
+      self.inner.remaining();
+    }
+
+    Some(band)
+  }
 }
@@ -1,10 +1,15 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::decode::Decode;
+use crate::decode::DecodeIter;
 use crate::decode::Decoder;
 use crate::decode::Timestamp;
 use crate::error::Result;
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Event Timing Codes
@@ -25,6 +30,17 @@ impl Etco<'_> {
   }
 }
 
+impl Display for Etco<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{:?}: {}",
+      self.time_format,
+      HexPreview(&self.event_codes)
+    )
+  }
+}
+
 // =============================================================================
 // Event Type
 // =============================================================================
@@ -180,6 +196,8 @@ impl Decode<'_> for EventType {
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct EventData {
   kind: EventType,
+  extended: Option<u8>,
+  depth: u32,
   time: u32,
 }
 
@@ -190,6 +208,22 @@ impl EventData {
     self.kind
   }
 
+  /// Get the code of an extended event.
+  ///
+  /// Returns the byte following the [`OneMoreByte`][EventType::OneMoreByte]
+  /// marker(s), or `None` for events of a predefined type.
+  #[inline]
+  pub const fn extended_code(&self) -> Option<u8> {
+    self.extended
+  }
+
+  /// Get the number of [`OneMoreByte`][EventType::OneMoreByte] markers
+  /// preceding the extended code, or `0` for events of a predefined type.
+  #[inline]
+  pub const fn extended_depth(&self) -> u32 {
+    self.depth
+  }
+
   /// Get the timestamp of the event.
   #[inline]
   pub const fn time(&self) -> u32 {
@@ -199,8 +233,28 @@ impl EventData {
 
 impl Decode<'_> for EventData {
   fn decode(decoder: &mut Decoder<'_>) -> Result<Self> {
+    let kind: EventType = decoder.decode()?;
+    let mut extended: Option<u8> = None;
+    let mut depth: u32 = 0;
+
+    // All following bytes with the value $FF have the same function.
+    if kind == EventType::OneMoreByte {
+      let mut code: u8 = decoder.decode()?;
+
+      depth = 1;
+
+      while code == 0xFF {
+        code = decoder.decode()?;
+        depth = depth.saturating_add(1);
+      }
+
+      extended = Some(code);
+    }
+
     Ok(Self {
-      kind: decoder.decode()?,
+      kind,
+      extended,
+      depth,
       time: decoder.decode()?,
     })
   }
@@ -213,13 +267,13 @@ impl Decode<'_> for EventData {
 /// An iterator over the events of an [`ETCO`][Etco] frame.
 #[derive(Clone, Debug)]
 pub struct EtcoIter<'a> {
-  inner: Decoder<'a>,
+  inner: DecodeIter<'a, EventData>,
 }
 
 impl<'a> EtcoIter<'a> {
   fn new(input: &'a Slice) -> Self {
     Self {
-      inner: Decoder::new(input),
+      inner: Decoder::new(input).decode_iter(),
     }
   }
 }
@@ -227,11 +281,8 @@ impl<'a> EtcoIter<'a> {
 impl Iterator for EtcoIter<'_> {
   type Item = Result<EventData>;
 
+  #[inline]
   fn next(&mut self) -> Option<Self::Item> {
-    if !self.inner.is_empty() {
-      Some(self.inner.decode())
-    } else {
-      None
-    }
+    self.inner.next()
   }
 }
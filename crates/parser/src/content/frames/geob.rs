@@ -1,6 +1,10 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::decode::Encoding;
+use crate::types::LazySlice;
 use crate::types::Slice;
 
 // =============================================================================
@@ -15,5 +19,68 @@ pub struct Geob<'a> {
   mime_type: Cow<'a, str>,
   filename: Cow<'a, str>,
   content_description: Cow<'a, str>,
-  encapsulated_object: Cow<'a, Slice>,
+  #[frame(defer)]
+  encapsulated_object: LazySlice<'a>,
+}
+
+impl Geob<'_> {
+  /// Get the content description of the object.
+  ///
+  /// Alias of [`content_description`][Self::content_description].
+  #[inline]
+  pub fn description(&self) -> &str {
+    self.content_description()
+  }
+
+  /// Get the data of the object.
+  ///
+  /// Alias of [`encapsulated_object`][Self::encapsulated_object], returns
+  /// `None` if the data was deferred.
+  #[inline]
+  pub fn object_data(&self) -> Option<&Slice> {
+    self.encapsulated_object()
+  }
+
+  /// Get a filename to save the object as.
+  ///
+  /// Directories are stripped from the stored filename, falling back to the
+  /// content description and then `object` if either is empty.
+  pub fn suggested_filename(&self) -> &str {
+    [self.filename(), self.content_description()]
+      .into_iter()
+      .map(basename)
+      .find(|name| !name.is_empty())
+      .unwrap_or("object")
+  }
+
+  /// Get the suggested filename and data of the object, for extraction
+  /// tools.
+  ///
+  /// See [`suggested_filename`][Self::suggested_filename] and
+  /// [`object_data`][Self::object_data], returns `None` if the data was
+  /// deferred.
+  #[inline]
+  pub fn extract(&self) -> Option<(&str, &Slice)> {
+    Some((self.suggested_filename(), self.object_data()?))
+  }
+}
+
+impl Display for Geob<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "({})[{}]: {}, {} bytes",
+      self.content_description,
+      self.filename,
+      self.mime_type,
+      self.encapsulated_object.len()
+    )
+  }
+}
+
+/// Strip directories and leading dots from the filename `name`.
+fn basename(name: &str) -> &str {
+  let name: &str = name.rsplit(['/', '\\']).next().unwrap_or_default();
+
+  name.trim().trim_start_matches('.')
 }
@@ -1,6 +1,10 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Group Identification Registration
@@ -13,3 +17,15 @@ pub struct Grid<'a> {
   group_symbol: u8,
   group_data: Cow<'a, Slice>,
 }
+
+impl Display for Grid<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{} ({:#04X}): {}",
+      self.owner_identifier,
+      self.group_symbol,
+      HexPreview(&self.group_data)
+    )
+  }
+}
@@ -1,5 +1,9 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
+use crate::decode::DecodeIter;
 use crate::decode::Decoder;
 use crate::error::Result;
 use crate::types::FrameId;
@@ -25,6 +29,12 @@ impl Link<'_> {
   }
 }
 
+impl Display for Link<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}: {}", self.frame_identifier, self.url)
+  }
+}
+
 // =============================================================================
 // Link Iterator
 // =============================================================================
@@ -32,13 +42,13 @@ impl Link<'_> {
 /// An iterator over the text entries of a [`LINK`][Link] frame.
 #[derive(Clone, Debug)]
 pub struct LinkIter<'a> {
-  inner: Decoder<'a>,
+  inner: DecodeIter<'a, Cow<'a, str>>,
 }
 
 impl<'a> LinkIter<'a> {
   fn new(input: &'a Slice) -> Self {
     Self {
-      inner: Decoder::new(input),
+      inner: Decoder::new(input).decode_iter(),
     }
   }
 }
@@ -46,11 +56,8 @@ impl<'a> LinkIter<'a> {
 impl<'a> Iterator for LinkIter<'a> {
   type Item = Result<Cow<'a, str>>;
 
+  #[inline]
   fn next(&mut self) -> Option<Self::Item> {
-    if !self.inner.is_empty() {
-      Some(self.inner.decode())
-    } else {
-      None
-    }
+    self.inner.next()
   }
 }
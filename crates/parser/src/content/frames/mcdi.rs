@@ -1,6 +1,10 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Music CD Identifier
@@ -12,3 +16,9 @@ pub struct Mcdi<'a> {
   #[frame(info = "CD table of contents")]
   data: Cow<'a, Slice>,
 }
+
+impl Display for Mcdi<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}", HexPreview(&self.data))
+  }
+}
@@ -1,6 +1,10 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // MPEG Location Lookup Table
@@ -11,3 +15,9 @@ use crate::types::Slice;
 pub struct Mllt<'a> {
   fixme: Cow<'a, Slice>,
 }
+
+impl Display for Mllt<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}", HexPreview(&self.fixme))
+  }
+}
@@ -16,6 +16,7 @@ mod mcdi;
 mod mllt;
 mod owne;
 mod pcnt;
+mod pcst;
 mod popm;
 mod poss;
 mod r#priv;
@@ -52,6 +53,8 @@ pub use self::ctoc::CtocItem;
 pub use self::ctoc::CtocIter;
 pub use self::encr::Encr;
 pub use self::equa::Equa;
+pub use self::equa::EquaBand;
+pub use self::equa::EquaIter;
 pub use self::etco::Etco;
 pub use self::etco::EtcoIter;
 pub use self::etco::EventData;
@@ -64,7 +67,9 @@ pub use self::link::LinkIter;
 pub use self::mcdi::Mcdi;
 pub use self::mllt::Mllt;
 pub use self::owne::Owne;
+pub use self::owne::Price;
 pub use self::pcnt::Pcnt;
+pub use self::pcst::Pcst;
 pub use self::popm::Popm;
 pub use self::poss::Poss;
 pub use self::r#priv::Priv;
@@ -72,12 +77,17 @@ pub use self::rbuf::Rbuf;
 pub use self::rbuf::RbufFlags;
 pub use self::rva2::Rva2;
 pub use self::rvad::Rvad;
+pub use self::rvad::RvadChannel;
+pub use self::rvad::VolumeAdjustment;
 pub use self::rvrb::Rvrb;
 pub use self::sylt::ContentType;
 pub use self::sylt::Lyric;
 pub use self::sylt::Sylt;
 pub use self::sylt::SyltIter;
 pub use self::sytc::Sytc;
+pub use self::sytc::SytcIter;
+pub use self::sytc::TempoEvent;
+pub use self::text::Position;
 pub use self::text::Text;
 pub use self::txxx::Txxx;
 pub use self::ufid::Ufid;
@@ -85,4 +95,6 @@ pub use self::unkn::Unkn;
 pub use self::user::User;
 pub use self::uslt::Uslt;
 pub use self::wurl::Wurl;
+
+pub(crate) use self::text::parse_genre;
 pub use self::wxxx::Wxxx;
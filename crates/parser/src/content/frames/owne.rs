@@ -1,14 +1,27 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::decode::Date;
+use crate::decode::Decode;
+use crate::decode::Decoder;
 use crate::decode::Encoding;
+use crate::error::ErrorKind;
+use crate::error::Result;
+use crate::error::WarningKind;
+use crate::utils::is_ascii_digit;
 
 // =============================================================================
 // Ownership Frame
 // =============================================================================
 
 /// Ownership frame content.
+///
+/// Note: [`purchase_date`][Self::purchase_date] is only validated in strict
+/// mode and may contain characters other than digits.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Frame)]
+#[frame(skip_decoding)]
 pub struct Owne<'a> {
   text_encoding: Encoding,
   #[frame(read = "@latin1")]
@@ -16,3 +29,144 @@ pub struct Owne<'a> {
   purchase_date: Date,
   seller: Cow<'a, str>,
 }
+
+impl Owne<'_> {
+  /// Get the parsed price paid.
+  ///
+  /// Returns `None` if the price is malformed, which is only accepted when
+  /// decoding in lenient mode.
+  #[inline]
+  pub fn price(&self) -> Option<Price<'_>> {
+    Price::parse(&self.price_paid)
+  }
+
+  /// Get the validated purchase date.
+  ///
+  /// Returns `None` if the month or day is out of range, or if the date
+  /// contains characters other than digits, which is only accepted when
+  /// decoding in lenient mode.
+  #[inline]
+  pub const fn date_of_purchase(&self) -> Option<Date> {
+    if self.purchase_date.is_valid() {
+      Some(self.purchase_date)
+    } else {
+      None
+    }
+  }
+}
+
+impl<'a> Decode<'a> for Owne<'a> {
+  fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+    let text_encoding: Encoding = decoder.decode()?;
+
+    let offset: usize = decoder.position();
+    let price_paid: Cow<'a, str> = decoder.decode_latin1()?;
+
+    if Price::parse(&price_paid).is_none() {
+      decoder.recover(
+        WarningKind::InvalidField { offset },
+        ErrorKind::InvalidFrameData,
+      )?;
+    }
+
+    let offset: usize = decoder.position();
+    let purchase_date: [u8; 8] = decoder.decode()?;
+
+    if !is_ascii_digit(&purchase_date) {
+      decoder.recover(
+        WarningKind::InvalidField { offset },
+        ErrorKind::InvalidFrameData,
+      )?;
+    }
+
+    let purchase_date: Date = Date::from_ascii(purchase_date)?;
+
+    Ok(Self {
+      text_encoding,
+      price_paid,
+      purchase_date,
+      seller: decoder.decode()?,
+    })
+  }
+}
+
+impl Display for Owne<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{}: {} on {}",
+      self.seller, self.price_paid, self.purchase_date
+    )
+  }
+}
+
+// =============================================================================
+// Price
+// =============================================================================
+
+/// Parsed price from an [`OWNE`][Owne] frame.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Price<'a> {
+  currency: &'a str,
+  amount: &'a str,
+}
+
+impl<'a> Price<'a> {
+  /// Get the ISO-4217 currency code of the price, e.g. `USD`.
+  #[inline]
+  pub const fn currency(&self) -> &'a str {
+    self.currency
+  }
+
+  /// Get the amount of the price, e.g. `12.99`.
+  #[inline]
+  pub const fn amount(&self) -> &'a str {
+    self.amount
+  }
+
+  /// Get the amount of the price in hundredths of the currency unit.
+  ///
+  /// Returns `None` if the amount has more than two decimals or overflows.
+  pub fn cents(&self) -> Option<u64> {
+    let (units, decimals): (&str, &str) = self.amount.split_once('.').unwrap_or((self.amount, ""));
+
+    let decimals: u64 = match decimals.len() {
+      0 => 0,
+      1 => decimals.parse::<u64>().ok()? * 10,
+      2 => decimals.parse().ok()?,
+      _ => return None,
+    };
+
+    units
+      .parse::<u64>()
+      .ok()?
+      .checked_mul(100)?
+      .checked_add(decimals)
+  }
+
+  /// Parse a three-letter currency code followed by a numeric amount with
+  /// `.` as decimal separator.
+  fn parse(input: &'a str) -> Option<Self> {
+    if input.len() < 4 || !input.is_char_boundary(3) {
+      return None;
+    }
+
+    let (currency, amount): (&str, &str) = input.split_at(3);
+
+    if !currency.bytes().all(|byte| byte.is_ascii_uppercase()) {
+      return None;
+    }
+
+    let (units, decimals): (&str, &str) = amount.split_once('.').unwrap_or((amount, "0"));
+
+    if units.is_empty() || decimals.is_empty() {
+      return None;
+    }
+
+    if !is_ascii_digit(units.as_bytes()) || !is_ascii_digit(decimals.as_bytes()) {
+      return None;
+    }
+
+    Some(Self { currency, amount })
+  }
+}
@@ -1,3 +1,7 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
 // =============================================================================
 // Play Counter
 // =============================================================================
@@ -5,7 +9,25 @@
 /// Play counter frame content.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Frame)]
 pub struct Pcnt {
-  // TODO: Confirm 8-byte value is valid.
+  // Counters are extended by one byte when they overflow (up to 8 bytes).
   #[frame(read = "@u64")]
   counter: u64,
 }
+
+impl Pcnt {
+  /// Get the counter as a 32-bit value, saturating at [`u32::MAX`].
+  #[inline]
+  pub const fn counter_u32(&self) -> u32 {
+    if self.counter > u32::MAX as u64 {
+      u32::MAX
+    } else {
+      self.counter as u32
+    }
+  }
+}
+
+impl Display for Pcnt {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}", self.counter)
+  }
+}
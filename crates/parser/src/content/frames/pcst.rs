@@ -0,0 +1,29 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+// =============================================================================
+// Podcast
+// =============================================================================
+
+/// Podcast flag frame content.
+///
+/// Note: This is an iTunes extension, the flag is stored as a 4-byte integer.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Frame)]
+pub struct Pcst {
+  podcast: u32,
+}
+
+impl Pcst {
+  /// Returns `true` if the file is marked as a podcast episode.
+  #[inline]
+  pub const fn is_podcast(&self) -> bool {
+    self.podcast != 0
+  }
+}
+
+impl Display for Pcst {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}", self.podcast)
+  }
+}
@@ -1,4 +1,7 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 // =============================================================================
 // Popularimeter
@@ -9,7 +12,41 @@ use alloc::borrow::Cow;
 pub struct Popm<'a> {
   user_email: Cow<'a, str>,
   rating: u8,
-  // TODO: Confirm 8-byte value is valid.
   #[frame(read = "@u64")]
   counter: u64,
 }
+
+impl Popm<'_> {
+  /// Get the rating in stars (1-5).
+  ///
+  /// Ratings are mapped to stars as written by Windows Media Player and
+  /// MediaMonkey, which store 1, 64, 128, 196 and 255 respectively. Returns
+  /// `None` if the rating is 0 (unknown).
+  #[inline]
+  pub const fn stars(&self) -> Option<u8> {
+    match self.rating {
+      0 => None,
+      1..=31 => Some(1),
+      32..=95 => Some(2),
+      96..=159 => Some(3),
+      160..=223 => Some(4),
+      224..=255 => Some(5),
+    }
+  }
+
+  /// Get the rating as a fraction of the best rating (0.0-1.0).
+  #[inline]
+  pub fn rating_fraction(&self) -> f32 {
+    f32::from(self.rating) / f32::from(u8::MAX)
+  }
+}
+
+impl Display for Popm<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{}: rating {}, counter {}",
+      self.user_email, self.rating, self.counter
+    )
+  }
+}
@@ -1,6 +1,9 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
-use crate::types::Slice;
+use crate::types::LazySlice;
 
 // =============================================================================
 // Private
@@ -10,5 +13,12 @@ use crate::types::Slice;
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Frame)]
 pub struct Priv<'a> {
   owner_identifier: Cow<'a, str>,
-  private_data: Cow<'a, Slice>,
+  #[frame(defer)]
+  private_data: LazySlice<'a>,
+}
+
+impl Display for Priv<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}: {}", self.owner_identifier, self.private_data)
+  }
 }
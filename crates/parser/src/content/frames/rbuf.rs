@@ -1,3 +1,7 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
 use bitflags::bitflags;
 
 // =============================================================================
@@ -12,6 +16,12 @@ pub struct Rbuf {
   tag_offset: u32,
 }
 
+impl Display for Rbuf {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{} bytes, offset {}", self.buffer_size, self.tag_offset)
+  }
+}
+
 // =============================================================================
 // Recommended Buffer Size Flags
 // =============================================================================
@@ -29,4 +39,5 @@ bitflags! {
 }
 
 decode_bitflags!(RbufFlags);
+encode_bitflags!(RbufFlags);
 copy_into_owned!(RbufFlags);
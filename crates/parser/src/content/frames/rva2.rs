@@ -1,6 +1,10 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Relative Volume Adjustment (2)
@@ -11,3 +15,9 @@ use crate::types::Slice;
 pub struct Rva2<'a> {
   fixme: Cow<'a, Slice>,
 }
+
+impl Display for Rva2<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}", HexPreview(&self.fixme))
+  }
+}
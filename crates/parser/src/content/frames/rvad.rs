@@ -1,6 +1,15 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
+use crate::decode::Decoder;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::error::Result;
 use crate::types::Slice;
+use crate::utils;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Relative Volume Adjustment
@@ -9,5 +18,165 @@ use crate::types::Slice;
 /// Relative volume adjustment frame content.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Frame)]
 pub struct Rvad<'a> {
-  fixme: Cow<'a, Slice>,
+  #[frame(info = "increment/decrement flags")]
+  increment: u8,
+  #[frame(info = "number of bits used for volume descriptions")]
+  volume_bits: u8,
+  #[frame(info = "volume and peak values")]
+  adjustments: Cow<'a, Slice>,
+}
+
+impl Rvad<'_> {
+  /// The channels in the order of the frame, the optional channels follow
+  /// right and left.
+  const CHANNELS: [&'static [RvadChannel]; 4] = [
+    &[RvadChannel::Right, RvadChannel::Left],
+    &[RvadChannel::RightBack, RvadChannel::LeftBack],
+    &[RvadChannel::Center],
+    &[RvadChannel::Bass],
+  ];
+
+  /// Get the volume adjustment of each channel in the frame.
+  ///
+  /// Right and left are always present, followed by right/left back, center
+  /// and bass if stored. Returns an error if the number of bits is not in
+  /// the range 1-32 or the values are cut short.
+  pub fn channels(&self) -> Result<Vec<VolumeAdjustment>> {
+    let width: usize = value_width(self.volume_bits)?;
+    let mut decoder: Decoder<'_> = Decoder::new(self.adjustments());
+    let mut output: Vec<VolumeAdjustment> = Vec::new();
+
+    for (index, channels) in Self::CHANNELS.iter().enumerate() {
+      if index > 0 && decoder.is_empty() {
+        break;
+      }
+
+      let mut volumes: [i64; 2] = [0; 2];
+
+      for (channel, volume) in channels.iter().zip(volumes.iter_mut()) {
+        let increment: bool = (self.increment & (1 << *channel as u8)) != 0;
+        *volume = decode_adjustment(&mut decoder, width, increment)?;
+      }
+
+      for (channel, volume) in channels.iter().zip(volumes) {
+        output.push(VolumeAdjustment {
+          channel: *channel,
+          volume,
+          peak: decode_value(&mut decoder, width)?,
+          bits: self.volume_bits,
+        });
+      }
+    }
+
+    if !decoder.is_empty() {
+      return Err(Error::new(ErrorKind::InvalidFrameData));
+    }
+
+    Ok(output)
+  }
+}
+
+impl Display for Rvad<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{} bits: {}",
+      self.volume_bits,
+      HexPreview(&self.adjustments)
+    )
+  }
+}
+
+// =============================================================================
+// Channel
+// =============================================================================
+
+/// The channel of an [`RVAD`][Rvad] volume adjustment.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum RvadChannel {
+  /// Right channel.
+  Right = 0x00,
+  /// Left channel.
+  Left = 0x01,
+  /// Right back channel.
+  RightBack = 0x02,
+  /// Left back channel.
+  LeftBack = 0x03,
+  /// Center channel.
+  Center = 0x04,
+  /// Bass channel.
+  Bass = 0x05,
+}
+
+// =============================================================================
+// Volume Adjustment
+// =============================================================================
+
+/// Parsed volume adjustment of a channel from an [`RVAD`][Rvad] frame.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VolumeAdjustment {
+  channel: RvadChannel,
+  volume: i64,
+  peak: u64,
+  bits: u8,
+}
+
+impl VolumeAdjustment {
+  /// Get the channel of the adjustment.
+  #[inline]
+  pub const fn channel(&self) -> RvadChannel {
+    self.channel
+  }
+
+  /// Get the relative volume change, negative if decremented.
+  #[inline]
+  pub const fn volume(&self) -> i64 {
+    self.volume
+  }
+
+  /// Get the peak volume.
+  #[inline]
+  pub const fn peak(&self) -> u64 {
+    self.peak
+  }
+
+  /// Get the approximate volume change in decibels.
+  ///
+  /// The volume is taken relative to the full scale of its number of bits,
+  /// i.e. `65535` for 16-bit values.
+  pub fn decibels(&self) -> f32 {
+    let scale: f32 = ((1_u64 << self.bits) - 1) as f32;
+
+    20.0 * (1.0 + self.volume as f32 / scale).log10()
+  }
+}
+
+// =============================================================================
+// Adjustment Values
+// =============================================================================
+
+/// Get the width (in bytes) of values with the given number of `bits`.
+pub(super) fn value_width(bits: u8) -> Result<usize> {
+  match bits {
+    1..=32 => Ok(usize::from(bits).div_ceil(8)),
+    _ => Err(Error::new(ErrorKind::InvalidFrameData)),
+  }
+}
+
+/// Decode an unsigned value of `width` bytes.
+pub(super) fn decode_value(decoder: &mut Decoder<'_>, width: usize) -> Result<u64> {
+  Ok(utils::decode_u64_relaxed(decoder.take(width)?.remaining()))
+}
+
+/// Decode an adjustment of `width` bytes, negated unless `increment` is set.
+pub(super) fn decode_adjustment(
+  decoder: &mut Decoder<'_>,
+  width: usize,
+  increment: bool,
+) -> Result<i64> {
+  // Values are at most 32 bits wide.
+  let value: i64 = decode_value(decoder, width)? as i64;
+
+  Ok(if increment { value } else { -value })
 }
@@ -1,3 +1,7 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
 // =============================================================================
 // Reverb
 // =============================================================================
@@ -16,3 +20,9 @@ pub struct Rvrb {
   premix_ltr: u8,
   premix_rtl: u8,
 }
+
+impl Display for Rvrb {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}/{} ms", self.reverb_lhs, self.reverb_rhs)
+  }
+}
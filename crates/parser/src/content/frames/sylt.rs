@@ -1,15 +1,23 @@
 use alloc::borrow::Borrow;
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::time::Duration;
 
 use crate::decode::Decode;
 use crate::decode::Decoder;
 use crate::decode::Encoding;
 use crate::decode::Language;
 use crate::decode::Timestamp;
+use crate::encode::Encode;
+use crate::encode::Encoder;
 use crate::error::Error;
 use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::options::ParseOptions;
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Synchronised Lyrics
@@ -30,7 +38,34 @@ impl Sylt<'_> {
   /// Get an iterator over the lyrics of the frame.
   #[inline]
   pub fn lyrics(&self) -> SyltIter<'_> {
-    SyltIter::new(self.text_encoding, self.binary_data())
+    self.lyrics_with(ParseOptions::new())
+  }
+
+  /// Get an iterator over the lyrics of the frame with custom `options`.
+  ///
+  /// See [`ParseOptions::trim_lyrics`] to remove the leading newline many
+  /// writers insert before each line.
+  #[inline]
+  pub fn lyrics_with(&self, options: ParseOptions) -> SyltIter<'_> {
+    SyltIter::new(
+      self.binary_data(),
+      self.text_encoding,
+      self.time_format,
+      options,
+    )
+  }
+}
+
+impl Display for Sylt<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "({})[{}]: {:?}, {}",
+      self.content_descriptor,
+      self.language,
+      self.content_type,
+      HexPreview(&self.binary_data)
+    )
   }
 }
 
@@ -73,6 +108,13 @@ impl Decode<'_> for ContentType {
   }
 }
 
+impl Encode for ContentType {
+  #[inline]
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    encoder.encode(&(*self as u8))
+  }
+}
+
 copy_into_owned!(ContentType);
 
 // =============================================================================
@@ -84,6 +126,7 @@ copy_into_owned!(ContentType);
 pub struct Lyric<'a> {
   data: Cow<'a, str>,
   time: u32,
+  time_format: Timestamp,
 }
 
 impl<'a> Lyric<'a> {
@@ -98,31 +141,99 @@ impl<'a> Lyric<'a> {
   pub const fn time(&self) -> u32 {
     self.time
   }
-}
 
-impl<'a> Decode<'a> for Lyric<'a> {
-  fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+  /// Get the unit of the timestamp.
+  #[inline]
+  pub const fn time_format(&self) -> Timestamp {
+    self.time_format
+  }
+
+  /// Get the timestamp in milliseconds.
+  ///
+  /// Returns `None` if the timestamp uses MPEG frames as unit.
+  #[inline]
+  pub const fn milliseconds(&self) -> Option<u32> {
+    match self.time_format {
+      Timestamp::Milliseconds => Some(self.time),
+      Timestamp::MpegFrames => None,
+    }
+  }
+
+  /// Get the timestamp in MPEG frames.
+  ///
+  /// Returns `None` if the timestamp uses milliseconds as unit.
+  #[inline]
+  pub const fn mpeg_frames(&self) -> Option<u32> {
+    match self.time_format {
+      Timestamp::MpegFrames => Some(self.time),
+      Timestamp::Milliseconds => None,
+    }
+  }
+
+  /// Get the time of the lyric from the start of the audio.
+  ///
+  /// The `frame_duration` is the length of a single MPEG frame (e.g. 1152
+  /// samples at 44.1 kHz) and only used if the timestamp uses MPEG frames as
+  /// unit.
+  #[inline]
+  pub fn duration(&self, frame_duration: Duration) -> Duration {
+    match self.time_format {
+      Timestamp::Milliseconds => Duration::from_millis(u64::from(self.time)),
+      Timestamp::MpegFrames => frame_duration.saturating_mul(self.time),
+    }
+  }
+
+  fn decode(decoder: &mut Decoder<'a>, time_format: Timestamp, trim: bool) -> Result<Self> {
+    let data: Cow<'a, str> = decoder.decode()?;
+    let time: u32 = decoder.decode()?;
+
     Ok(Self {
-      data: decoder.decode()?,
-      time: decoder.decode()?,
+      data: if trim { trim_newline(data) } else { data },
+      time,
+      time_format,
     })
   }
 }
 
+/// Remove a single leading newline from `data`.
+fn trim_newline(data: Cow<'_, str>) -> Cow<'_, str> {
+  match data {
+    Cow::Borrowed(data) => Cow::Borrowed(data.strip_prefix('\n').unwrap_or(data)),
+    Cow::Owned(mut data) => {
+      if data.starts_with('\n') {
+        data.remove(0);
+      }
+
+      Cow::Owned(data)
+    }
+  }
+}
+
 // =============================================================================
 // Sylt Iterator
 // =============================================================================
 
 /// An iterator over the lyrics of a [`SYLT`][Sylt] frame.
+///
+/// Iteration stops after the first error.
 #[derive(Clone, Debug)]
 pub struct SyltIter<'a> {
   inner: Decoder<'a>,
+  time_format: Timestamp,
+  trim: bool,
 }
 
 impl<'a> SyltIter<'a> {
-  fn new(format: Encoding, input: &'a Slice) -> Self {
+  fn new(
+    input: &'a Slice,
+    format: Encoding,
+    time_format: Timestamp,
+    options: ParseOptions,
+  ) -> Self {
     Self {
-      inner: Decoder::with_format(input, format),
+      inner: Decoder::with_format(input, format).with_options(options),
+      time_format,
+      trim: options.trims_lyrics(),
     }
   }
 }
@@ -132,9 +243,15 @@ impl<'a> Iterator for SyltIter<'a> {
 
   fn next(&mut self) -> Option<Self::Item> {
     if self.inner.is_empty() {
-      Some(self.inner.decode())
-    } else {
-      None
+      return None;
     }
+
+    let lyric: Result<Lyric<'a>> = Lyric::decode(&mut self.inner, self.time_format, self.trim);
+
+    if lyric.is_err() {
+      self.inner.remaining();
+    }
+
+    Some(lyric)
   }
 }
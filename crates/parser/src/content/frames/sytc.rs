@@ -1,6 +1,15 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
+use crate::decode::Decode;
+use crate::decode::DecodeIter;
+use crate::decode::Decoder;
+use crate::decode::Timestamp;
+use crate::error::Result;
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Synchronized Tempo Codes
@@ -9,5 +18,117 @@ use crate::types::Slice;
 /// Synchronized tempo codes frame content.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Frame)]
 pub struct Sytc<'a> {
-  fixme: Cow<'a, Slice>,
+  time_format: Timestamp,
+  tempo_data: Cow<'a, Slice>,
+}
+
+impl Sytc<'_> {
+  /// Get an iterator over the tempo codes of the frame.
+  #[inline]
+  pub fn tempos(&self) -> SytcIter<'_> {
+    SytcIter::new(self.tempo_data())
+  }
+}
+
+impl Display for Sytc<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{:?}: {}",
+      self.time_format,
+      HexPreview(&self.tempo_data)
+    )
+  }
+}
+
+// =============================================================================
+// Tempo Event
+// =============================================================================
+
+/// Parsed tempo code from a [`SYTC`][Sytc] frame.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TempoEvent {
+  bpm: u16,
+  time: u32,
+}
+
+impl TempoEvent {
+  /// Tempo value for beat-free time.
+  const BEAT_FREE: u16 = 0x00;
+  /// Tempo value for a single beat followed by beat-free time.
+  const SINGLE_BEAT: u16 = 0x01;
+
+  /// Get the tempo in beats per minute.
+  ///
+  /// The values `0` and `1` are reserved, see [`is_beat_free`] and
+  /// [`is_single_beat`].
+  ///
+  /// [`is_beat_free`]: Self::is_beat_free
+  /// [`is_single_beat`]: Self::is_single_beat
+  #[inline]
+  pub const fn bpm(&self) -> u16 {
+    self.bpm
+  }
+
+  /// Returns `true` if the tempo marks beat-free time.
+  #[inline]
+  pub const fn is_beat_free(&self) -> bool {
+    self.bpm == Self::BEAT_FREE
+  }
+
+  /// Returns `true` if the tempo marks a single beat followed by beat-free
+  /// time.
+  #[inline]
+  pub const fn is_single_beat(&self) -> bool {
+    self.bpm == Self::SINGLE_BEAT
+  }
+
+  /// Get the timestamp of the tempo change.
+  #[inline]
+  pub const fn time(&self) -> u32 {
+    self.time
+  }
+}
+
+impl Decode<'_> for TempoEvent {
+  fn decode(decoder: &mut Decoder<'_>) -> Result<Self> {
+    let mut bpm: u16 = u16::from(u8::decode(decoder)?);
+
+    // A first byte of $FF is followed by a byte added to the tempo.
+    if bpm == 0xFF {
+      bpm += u16::from(u8::decode(decoder)?);
+    }
+
+    Ok(Self {
+      bpm,
+      time: decoder.decode()?,
+    })
+  }
+}
+
+// =============================================================================
+// Sytc Iterator
+// =============================================================================
+
+/// An iterator over the tempo codes of a [`SYTC`][Sytc] frame.
+#[derive(Clone, Debug)]
+pub struct SytcIter<'a> {
+  inner: DecodeIter<'a, TempoEvent>,
+}
+
+impl<'a> SytcIter<'a> {
+  fn new(input: &'a Slice) -> Self {
+    Self {
+      inner: Decoder::new(input).decode_iter(),
+    }
+  }
+}
+
+impl Iterator for SytcIter<'_> {
+  type Item = Result<TempoEvent>;
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next()
+  }
 }
@@ -7,8 +7,12 @@ use core::fmt::Result as FmtResult;
 use crate::decode::Decode;
 use crate::decode::Decoder;
 use crate::decode::Encoding;
+use crate::encode::Encode;
+use crate::encode::Encoder;
 use crate::error::Result;
+use crate::error::WarningKind;
 use crate::traits::IntoOwned;
+use crate::types::Genre;
 
 // =============================================================================
 // Text Information
@@ -22,6 +26,122 @@ pub struct Text<'a> {
   text_content: TextContent<'a>,
 }
 
+impl<'a> Text<'a> {
+  /// Create a new single-value text information frame.
+  pub(crate) const fn new(text_encoding: Encoding, text: Cow<'a, str>) -> Self {
+    Self {
+      text_encoding,
+      text_content: TextContent::Text(text),
+    }
+  }
+
+  /// Get the first string of the frame content.
+  pub(crate) fn first(&self) -> Option<&str> {
+    match self.text_content {
+      TextContent::Text(ref inner) => Some(inner),
+      TextContent::List(ref inner) => inner.first().map(|text| &**text),
+    }
+  }
+
+  /// Get an iterator over the strings of the frame content.
+  pub(crate) fn values(&self) -> impl Iterator<Item = &str> + '_ {
+    let (first, rest): (Option<&Cow<'a, str>>, &[Cow<'a, str>]) = match self.text_content {
+      TextContent::Text(ref inner) => (Some(inner), &[]),
+      TextContent::List(ref inner) => (None, inner),
+    };
+
+    first.into_iter().chain(rest).map(|text| &**text)
+  }
+
+  /// Convert the frame content into its strings.
+  pub(crate) fn into_values(self) -> Vec<Cow<'a, str>> {
+    match self.text_content {
+      TextContent::Text(inner) => vec![inner],
+      TextContent::List(inner) => inner,
+    }
+  }
+
+  /// Parse the frame content as a genre of the numeric genre list.
+  ///
+  /// This is the format of the TCON frame, either a numeric reference such as
+  /// `"(17)"` (ID3v2.3) or `"17"` (ID3v2.4), or the name of the genre.
+  ///
+  /// Note: Only the first string of a multi-value frame is considered.
+  pub fn genre(&self) -> Option<Genre> {
+    parse_genre(self.first()?)
+  }
+
+  /// Parse the frame content as a numeric position with an optional total.
+  ///
+  /// This is the `"current/total"` format used by frames such as TRCK, TPOS
+  /// and MVIN, e.g. `"2/4"` or `"2"`.
+  ///
+  /// Note: Only the first string of a multi-value frame is considered.
+  pub fn position(&self) -> Option<Position> {
+    let text: &str = self.first()?;
+
+    let (current, total): (&str, Option<&str>) = match text.split_once('/') {
+      Some((current, total)) => (current, Some(total)),
+      None => (text, None),
+    };
+
+    Some(Position {
+      current: current.trim().parse().ok()?,
+      total: match total {
+        Some(total) => Some(total.trim().parse().ok()?),
+        None => None,
+      },
+    })
+  }
+}
+
+impl Display for Text<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}", self.text_content)
+  }
+}
+
+/// Parse a string of a TCON frame as a genre of the numeric genre list.
+pub(crate) fn parse_genre(text: &str) -> Option<Genre> {
+  let text: &str = text.trim();
+
+  // ID3v2.3 references may be followed by a refinement, e.g. "(4)Eurodisco".
+  let index: &str = text
+    .strip_prefix('(')
+    .and_then(|rest| rest.split_once(')'))
+    .map_or(text, |(index, _)| index);
+
+  match index.parse() {
+    Ok(index) => Genre::new(index),
+    Err(_) => Genre::from_name(text),
+  }
+}
+
+// =============================================================================
+// Position
+// =============================================================================
+
+/// A numeric position parsed from a [`Text`] frame.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+  current: u32,
+  total: Option<u32>,
+}
+
+impl Position {
+  /// Get the current position (e.g. the track or movement number).
+  #[inline]
+  pub const fn current(&self) -> u32 {
+    self.current
+  }
+
+  /// Get the total number of positions (if specified).
+  #[inline]
+  pub const fn total(&self) -> Option<u32> {
+    self.total
+  }
+}
+
 // =============================================================================
 // Text Content
 // =============================================================================
@@ -36,13 +156,21 @@ impl<'a> Decode<'a> for TextContent<'a> {
   fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
     let text: Cow<'a, str> = decoder.decode()?;
 
-    if decoder.is_empty() {
+    if decoder.is_padding() {
+      return Ok(Self::Text(text));
+    }
+
+    if decoder.truncates_text() {
+      let length: usize = decoder.remaining().len();
+
+      decoder.warn(WarningKind::TrailingData { length });
+
       return Ok(Self::Text(text));
     }
 
     let mut list: Vec<Cow<'a, str>> = vec![text];
 
-    while !decoder.is_empty() {
+    while !decoder.is_padding() {
       list.push(decoder.decode()?);
     }
 
@@ -50,6 +178,15 @@ impl<'a> Decode<'a> for TextContent<'a> {
   }
 }
 
+impl Encode for TextContent<'_> {
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    match self {
+      Self::Text(inner) => encoder.encode(inner),
+      Self::List(inner) => encoder.encode(inner),
+    }
+  }
+}
+
 impl IntoOwned for TextContent<'_> {
   type Owned = TextContent<'static>;
 
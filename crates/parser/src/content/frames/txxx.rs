@@ -1,4 +1,7 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::decode::Encoding;
 
@@ -13,3 +16,9 @@ pub struct Txxx<'a> {
   text_summary: Cow<'a, str>,
   text_details: Cow<'a, str>,
 }
+
+impl Display for Txxx<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "({}): {}", self.text_summary, self.text_details)
+  }
+}
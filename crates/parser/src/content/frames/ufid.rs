@@ -1,6 +1,10 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Unique File Identifier
@@ -14,3 +18,14 @@ pub struct Ufid<'a> {
   // TODO: Validate that this is up to 64 bytes
   identifier: Cow<'a, Slice>,
 }
+
+impl Display for Ufid<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{}: {}",
+      self.owner_identifier,
+      HexPreview(&self.identifier)
+    )
+  }
+}
@@ -1,6 +1,13 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
+use crate::encode::Encode;
+use crate::encode::Encoder;
+use crate::error::Result;
 use crate::types::Slice;
+use crate::utils::HexPreview;
 
 // =============================================================================
 // Unknown Frame Content
@@ -8,6 +15,31 @@ use crate::types::Slice;
 
 /// Unknown frame content.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Frame)]
+#[frame(skip_decoding, skip_encoding)]
 pub struct Unkn<'a> {
+  #[frame(info = "frame identifier")]
+  identifier: Cow<'a, str>,
   binary_data: Cow<'a, Slice>,
 }
+
+impl<'a> Unkn<'a> {
+  pub(crate) fn new(identifier: &str, binary_data: &'a Slice) -> Self {
+    Self {
+      identifier: Cow::Owned(identifier.to_owned()),
+      binary_data: Cow::Borrowed(binary_data),
+    }
+  }
+}
+
+impl Encode for Unkn<'_> {
+  #[inline]
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    encoder.encode(&self.binary_data)
+  }
+}
+
+impl Display for Unkn<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}: {}", self.identifier, HexPreview(&self.binary_data))
+  }
+}
@@ -1,4 +1,7 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::decode::Encoding;
 use crate::decode::Language;
@@ -14,3 +17,9 @@ pub struct User<'a> {
   language: Language,
   text_content: Cow<'a, str>,
 }
+
+impl Display for User<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "[{}]: {}", self.language, self.text_content)
+  }
+}
@@ -1,4 +1,7 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::decode::Encoding;
 use crate::decode::Language;
@@ -15,3 +18,13 @@ pub struct Uslt<'a> {
   content_descriptor: Cow<'a, str>,
   lyrics: Cow<'a, str>,
 }
+
+impl Display for Uslt<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "({})[{}]: {}",
+      self.content_descriptor, self.language, self.lyrics
+    )
+  }
+}
@@ -1,4 +1,7 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 // =============================================================================
 // Web URL Link
@@ -9,3 +12,9 @@ use alloc::borrow::Cow;
 pub struct Wurl<'a> {
   url: Cow<'a, str>,
 }
+
+impl Display for Wurl<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}", self.url)
+  }
+}
@@ -1,4 +1,7 @@
 use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 use crate::decode::Encoding;
 
@@ -14,3 +17,9 @@ pub struct Wxxx<'a> {
   #[frame(read = "@latin1")]
   url: Cow<'a, str>,
 }
+
+impl Display for Wxxx<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "({}): {}", self.description, self.url)
+  }
+}
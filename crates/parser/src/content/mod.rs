@@ -1,7 +1,11 @@
 //! ID3v2 Frame Content
 
+#[allow(clippy::module_inception)]
 mod content;
+mod custom;
 mod frames;
 
 pub use self::content::Content;
+pub use self::content::ContentKind;
+pub use self::custom::Custom;
 pub use self::frames::*;
@@ -1,3 +1,6 @@
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::error::Result;
 use crate::utils::is_ascii_digit;
 
 impl_stack_string! {
@@ -6,3 +9,75 @@ impl_stack_string! {
   @bytes = 8;
   @check = is_ascii_digit;
 }
+
+impl Date {
+  /// Get the year of the date.
+  #[inline]
+  pub const fn year(&self) -> u16 {
+    self.digits(0) * 100 + self.digits(2)
+  }
+
+  /// Get the month of the date.
+  ///
+  /// Note: The month is not validated and may be outside the range 1-12.
+  #[inline]
+  pub const fn month(&self) -> u8 {
+    self.digits(4) as u8
+  }
+
+  /// Get the day of the date.
+  ///
+  /// Note: The day is not validated and may be outside the range 1-31.
+  #[inline]
+  pub const fn day(&self) -> u8 {
+    self.digits(6) as u8
+  }
+
+  /// Create a date from ASCII bytes that may contain non-digit characters.
+  ///
+  /// Only used to pass malformed dates through when decoding in lenient mode.
+  pub(crate) fn from_ascii(inner: [u8; 8]) -> Result<Self> {
+    if inner.is_ascii() {
+      Ok(Self { inner })
+    } else {
+      Err(Error::new(ErrorKind::InvalidFrameData))
+    }
+  }
+
+  /// Returns `true` if the date only contains digits and the month and day
+  /// form a calendar date.
+  pub(crate) const fn is_valid(&self) -> bool {
+    if !is_ascii_digit(&self.inner) {
+      return false;
+    }
+
+    let days: u8 = match self.month() {
+      1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+      4 | 6 | 9 | 11 => 30,
+      2 if self.is_leap_year() => 29,
+      2 => 28,
+      _ => return false,
+    };
+
+    self.day() >= 1 && self.day() <= days
+  }
+
+  const fn is_leap_year(&self) -> bool {
+    let year: u16 = self.year();
+
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+  }
+
+  /// Get the value of the two digits at `index`, or `0` if either is not a
+  /// digit.
+  const fn digits(&self, index: usize) -> u16 {
+    let high: u8 = self.inner[index].wrapping_sub(b'0');
+    let low: u8 = self.inner[index + 1].wrapping_sub(b'0');
+
+    if high > 9 || low > 9 {
+      return 0;
+    }
+
+    high as u16 * 10 + low as u16
+  }
+}
@@ -1,15 +1,24 @@
 use alloc::borrow::Cow;
+use core::marker::PhantomData;
 use std::io::Cursor;
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
 
 use crate::decode::Encoding;
 use crate::error::Error;
 use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::error::Warning;
+use crate::error::WarningKind;
 use crate::frame::DynFrame;
+use crate::options::CodepageDecoder;
+use crate::options::ParseOptions;
 use crate::traits::ReadExt;
+use crate::types::DeferredSlice;
 use crate::types::FrameId;
 use crate::types::Slice;
 use crate::types::Version;
+use crate::utils;
 
 // =============================================================================
 // Content Decoder
@@ -20,6 +29,11 @@ use crate::types::Version;
 pub struct Decoder<'a> {
   cursor: Cursor<&'a Slice>,
   format: Encoding,
+  options: ParseOptions,
+  warnings: Vec<Warning>,
+  origin: Option<&'a Slice>,
+  stray: Option<usize>,
+  version: Version,
 }
 
 impl<'a> Decoder<'a> {
@@ -35,9 +49,37 @@ impl<'a> Decoder<'a> {
     Self {
       cursor: Cursor::new(input),
       format,
+      options: ParseOptions::new(),
+      warnings: Vec::new(),
+      origin: None,
+      stray: None,
+      version: Version::ID3v24,
     }
   }
 
+  /// Set the `options` used to handle recoverable problems.
+  #[inline]
+  pub fn with_options(mut self, options: ParseOptions) -> Self {
+    self.options = options;
+    self
+  }
+
+  /// Set the `version` of the tag containing the input.
+  ///
+  /// Defaults to ID3v2.4.
+  #[inline]
+  pub fn with_version(mut self, version: Version) -> Self {
+    self.version = version;
+    self
+  }
+
+  /// Set the tag buffer containing the input, enabling deferred binary data.
+  #[inline]
+  pub(crate) fn with_origin(mut self, origin: Option<&'a Slice>) -> Self {
+    self.origin = origin;
+    self
+  }
+
   /// Decode a `T` value in ID3v2.3 form.
   ///
   /// To decode an ID3v2.2 structure use [`decode_v2`][Self::decode_v2].
@@ -60,18 +102,81 @@ impl<'a> Decoder<'a> {
     T::decode_v2(self)
   }
 
+  /// Decode an optional trailing `T` value in ID3v2.3 form.
+  ///
+  /// Returns `None` if the decoder is empty.
+  #[inline]
+  pub fn decode_option<T>(&mut self) -> Result<Option<T>>
+  where
+    T: Decode<'a>,
+  {
+    if self.is_empty() {
+      return Ok(None);
+    }
+
+    T::decode(self).map(Some)
+  }
+
+  /// Decode `T` values in ID3v2.3 form until the input runs out.
+  ///
+  /// Returns an error if a value cannot be decoded, e.g. a trailing partial
+  /// record, with the number of values decoded so far attached (see
+  /// [`Error::index`]).
+  pub fn decode_all<T>(&mut self) -> Result<Vec<T>>
+  where
+    T: Decode<'a>,
+  {
+    let mut output: Vec<T> = Vec::new();
+
+    while !self.is_empty() {
+      match T::decode(self) {
+        Ok(value) => output.push(value),
+        Err(error) => return Err(error.with_index(output.len())),
+      }
+    }
+
+    Ok(output)
+  }
+
+  /// Get an iterator decoding `T` values in ID3v2.3 form from the remaining
+  /// bytes.
+  ///
+  /// This decoder is advanced past the remaining bytes, see
+  /// [`take`][Self::take].
+  #[inline]
+  pub fn decode_iter<T>(&mut self) -> DecodeIter<'a, T>
+  where
+    T: Decode<'a>,
+  {
+    let input: &'a Slice = self.remaining();
+
+    DecodeIter::new(self.child(input))
+  }
+
   /// Decode a string in `ISO-8859-1` form.
   #[inline]
   pub fn decode_latin1(&mut self) -> Result<Cow<'a, str>> {
     Encoding::decode(Encoding::Latin1, self)
   }
 
+  /// Decode a big-endian counter from the remaining bytes.
+  ///
+  /// Counters are extended by one byte when they overflow, an error is
+  /// returned if more than 8 bytes remain.
+  pub fn decode_u64_relaxed(&mut self) -> Result<u64> {
+    if self.remaining_len() > 8 {
+      return Err(Error::new(ErrorKind::InvalidFrameData));
+    }
+
+    Ok(utils::decode_u64_relaxed(self.remaining()))
+  }
+
   /// Decode an embedded frame.
   pub fn decode_frame(&mut self, version: Version) -> Result<Option<DynFrame<'a>>> {
     let slice: &Slice = self.cursor.get_ref();
-    let index: u64 = self.cursor.position();
+    let index: u64 = self.cursor.position().min(slice.len() as u64);
 
-    match DynFrame::from_slice(version, slice) {
+    match DynFrame::from_slice(version, &slice[(index as usize)..]) {
       Ok(Some(frame)) => {
         self.cursor.set_position(index + frame.total_size() as u64);
         Ok(Some(frame))
@@ -85,6 +190,81 @@ impl<'a> Decoder<'a> {
     }
   }
 
+  /// Get the next byte without advancing the decoder.
+  #[inline]
+  pub fn peek_u8(&self) -> Result<u8> {
+    self.peek_array().map(|[byte]| byte)
+  }
+
+  /// Get the next `S` bytes without advancing the decoder.
+  #[inline]
+  pub fn peek_array<const S: usize>(&self) -> Result<[u8; S]> {
+    let mut output: [u8; S] = [0; S];
+
+    output.copy_from_slice(self.peek_slice(S)?.as_ref());
+
+    Ok(output)
+  }
+
+  /// Get a slice of the next `count` bytes without advancing the decoder.
+  pub fn peek_slice(&self, count: usize) -> Result<&'a Slice> {
+    match self.rest().get(..count) {
+      Some(bytes) => Ok(Slice::new(bytes)),
+      None => Err(IoError::from(IoErrorKind::UnexpectedEof).into()),
+    }
+  }
+
+  /// Split off a decoder over the next `count` bytes.
+  ///
+  /// The child decoder inherits the text format and options, this decoder is
+  /// advanced past the bytes. Returns an error if fewer than `count` bytes
+  /// remain.
+  ///
+  /// Note: Warnings recorded by the child decoder are not propagated.
+  pub fn take(&mut self, count: usize) -> Result<Decoder<'a>> {
+    let bytes: &'a Slice = self.peek_slice(count)?;
+
+    self.cursor.set_position((self.position() + count) as u64);
+
+    Ok(self.child(bytes))
+  }
+
+  /// Create a decoder over `input` with the same format and options.
+  fn child(&self, input: &'a Slice) -> Decoder<'a> {
+    Self {
+      cursor: Cursor::new(input),
+      format: self.format,
+      options: self.options,
+      warnings: Vec::new(),
+      origin: self.origin,
+      stray: None,
+      version: self.version,
+    }
+  }
+
+  /// Save the current state of the decoder.
+  ///
+  /// The decoder can be returned to this state with [`restore`][Self::restore].
+  #[inline]
+  pub fn checkpoint(&self) -> Checkpoint {
+    Checkpoint {
+      position: self.cursor.position(),
+      format: self.format,
+      warnings: self.warnings.len(),
+    }
+  }
+
+  /// Return the decoder to a state saved with [`checkpoint`][Self::checkpoint].
+  ///
+  /// The position and text format are reset and any warnings recorded since
+  /// the checkpoint are discarded.
+  #[inline]
+  pub fn restore(&mut self, checkpoint: Checkpoint) {
+    self.cursor.set_position(checkpoint.position);
+    self.format = checkpoint.format;
+    self.warnings.truncate(checkpoint.warnings);
+  }
+
   /// Returns `true` if the decoder is empty.
   pub fn is_empty(&self) -> bool {
     // TODO: Use cursor.is_empty() when stable
@@ -93,6 +273,24 @@ impl<'a> Decoder<'a> {
     self.cursor.position() >= self.cursor.get_ref().len() as u64
   }
 
+  /// Get the current offset in the input.
+  #[inline]
+  pub fn position(&self) -> usize {
+    (self.cursor.position() as usize).min(self.total_len())
+  }
+
+  /// Get the number of bytes remaining in the decoder.
+  #[inline]
+  pub fn remaining_len(&self) -> usize {
+    self.total_len() - self.position()
+  }
+
+  /// Get the length of the input (in bytes).
+  #[inline]
+  pub fn total_len(&self) -> usize {
+    self.cursor.get_ref().len()
+  }
+
   /// Get a slice of the remaining bytes in the decoder.
   #[inline]
   pub fn remaining(&mut self) -> &'a Slice {
@@ -106,11 +304,215 @@ impl<'a> Decoder<'a> {
   }
 
   /// Get a slice of the remaining bytes up to the first NUL byte pair.
+  ///
+  /// Only pairs at even offsets from the current position are matched, see
+  /// [`Slice::until_nul2`].
   #[inline]
   pub fn until_nul2(&mut self) -> &'a Slice {
     self.step(2, Slice::until_nul2)
   }
 
+  /// Get a slice of the remaining bytes up to the first NUL byte pair of a
+  /// UTF-16 string.
+  ///
+  /// Some writers insert a lone NUL byte before the terminator, which shifts
+  /// every following field by one byte. This is detected when the string ends
+  /// with an unpaired NUL byte, or when a NUL byte follows the terminator and
+  /// leaves an odd number of bytes. The latter is only a stray NUL if another
+  /// UTF-16 string follows, see [`skip_stray_nul`][Self::skip_stray_nul];
+  /// binary fields may legitimately start with a NUL byte.
+  ///
+  /// Any other unpaired byte at the end of the input is a dangling byte,
+  /// which is dropped in lenient mode.
+  pub(crate) fn until_nul2_utf16(&mut self) -> Result<&'a Slice> {
+    let slice: &'a Slice = self.cursor.get_ref();
+    let start: usize = (self.cursor.position() as usize).min(slice.len());
+    let bytes: &'a Slice = self.until_nul2();
+    let end: usize = self.cursor.position() as usize;
+
+    if let Some(rest) = slice.as_ref().get(end..) {
+      if rest.first() == Some(&0x00) && rest.len() % 2 == 1 {
+        self.stray = Some(end);
+      }
+
+      return Ok(bytes);
+    }
+
+    if bytes.len() % 2 == 1 && bytes.as_ref().last() == Some(&0x00) {
+      self.stray_nul(start + bytes.len() - 1)?;
+      return Ok(&bytes[..bytes.len() - 1]);
+    }
+
+    if bytes.len() % 2 == 1 {
+      self.dangling_byte(start + bytes.len() - 1)?;
+      return Ok(&bytes[..bytes.len() - 1]);
+    }
+
+    Ok(bytes)
+  }
+
+  /// Skip a stray NUL byte left after the terminator of the previous UTF-16
+  /// string.
+  ///
+  /// Only called before decoding another UTF-16 string, which is never
+  /// shifted by a NUL byte that is part of the following field.
+  pub(crate) fn skip_stray_nul(&mut self) -> Result<()> {
+    let offset: usize = self.position();
+
+    if self.stray.take() != Some(offset) {
+      return Ok(());
+    }
+
+    self.stray_nul(offset)?;
+    self.cursor.set_position(offset as u64 + 1);
+
+    Ok(())
+  }
+
+  fn stray_nul(&mut self, offset: usize) -> Result<()> {
+    self.recover(
+      WarningKind::StrayNul { offset },
+      ErrorKind::TextEncoding(offset),
+    )
+  }
+
+  fn dangling_byte(&mut self, offset: usize) -> Result<()> {
+    if !self.options.is_lenient() {
+      return Err(Error::new(ErrorKind::InvalidFrameData).with_offset(offset));
+    }
+
+    self.warn(WarningKind::DanglingByte { offset });
+
+    Ok(())
+  }
+
+  /// Record a recoverable problem.
+  ///
+  /// Returns an `error` of the given kind unless decoding in lenient mode, in
+  /// which case a `warning` is recorded instead.
+  pub(crate) fn recover(&mut self, warning: WarningKind, error: ErrorKind) -> Result<()> {
+    if !self.options.is_lenient() {
+      return Err(Error::new(error));
+    }
+
+    self.warnings.push(Warning::new(warning));
+
+    Ok(())
+  }
+
+  /// Record a `warning` regardless of the decoding mode.
+  pub(crate) fn warn(&mut self, warning: WarningKind) {
+    self.warnings.push(Warning::new(warning));
+  }
+
+  /// Skip the remaining bytes if they exceed the defer threshold and can be
+  /// located in the tag buffer.
+  pub(crate) fn defer(&mut self) -> Option<DeferredSlice> {
+    let threshold: usize = self.options.defer_threshold()?;
+    let origin: &[u8] = self.origin?.as_ref();
+    let rest: &[u8] = self.rest();
+
+    if rest.len() <= threshold {
+      return None;
+    }
+
+    let start: usize = (rest.as_ptr() as usize).checked_sub(origin.as_ptr() as usize)?;
+
+    if start + rest.len() > origin.len() {
+      return None;
+    }
+
+    self.remaining();
+
+    Some(DeferredSlice::new(start, rest.len()))
+  }
+
+  /// Get the version of the tag containing the input.
+  #[inline]
+  pub const fn version(&self) -> Version {
+    self.version
+  }
+
+  /// Returns `true` if recoverable problems are tolerated.
+  #[inline]
+  pub(crate) const fn is_lenient(&self) -> bool {
+    self.options.is_lenient()
+  }
+
+  /// Returns `true` if double-encoded text should be repaired.
+  #[inline]
+  pub(crate) const fn repairs_mojibake(&self) -> bool {
+    self.options.repairs_mojibake()
+  }
+
+  /// Returns `true` if the source encoding of each string should be recorded.
+  #[inline]
+  pub(crate) const fn records_encodings(&self) -> bool {
+    self.options.records_encodings()
+  }
+
+  /// Returns `true` if invalid text should be decoded with replacement
+  /// characters.
+  #[inline]
+  pub(crate) const fn replaces_invalid_text(&self) -> bool {
+    self.options.replaces_invalid_text()
+  }
+
+  /// Returns `true` if ISO-8859-1 text should be decoded as Windows-1252.
+  #[inline]
+  pub(crate) const fn decodes_cp1252(&self) -> bool {
+    self.options.decodes_cp1252()
+  }
+
+  /// Get the decoder for ISO-8859-1 text stored in a legacy codepage.
+  #[inline]
+  pub(crate) const fn codepage_decoder(&self) -> Option<CodepageDecoder> {
+    self.options.codepage_decoder()
+  }
+
+  /// Returns `true` if text frames should be cut at the first NUL character.
+  #[inline]
+  pub(crate) const fn truncates_text(&self) -> bool {
+    self.options.truncates_text() && !matches!(self.version, Version::ID3v24)
+  }
+
+  /// Returns `true` if the remaining bytes are all NUL padding.
+  pub(crate) fn is_padding(&self) -> bool {
+    self.rest().iter().all(|byte| *byte == 0x00)
+  }
+
+  /// Check that the entire input was consumed.
+  ///
+  /// Trailing NUL padding is ignored with a warning. Any other trailing data
+  /// is an error unless decoding in lenient mode.
+  pub(crate) fn finish(&mut self) -> Result<()> {
+    let length: usize = self.rest().len();
+
+    if length == 0 {
+      return Ok(());
+    }
+
+    if !self.is_padding() && !self.options.is_lenient() {
+      return Err(Error::new(ErrorKind::TrailingData(length)));
+    }
+
+    self
+      .warnings
+      .push(Warning::new(WarningKind::TrailingData { length }));
+
+    Ok(())
+  }
+
+  fn rest(&self) -> &'a [u8] {
+    let slice: &'a Slice = self.cursor.get_ref();
+
+    &slice.as_ref()[self.position()..]
+  }
+
+  pub(crate) fn take_warnings(&mut self) -> Vec<Warning> {
+    core::mem::take(&mut self.warnings)
+  }
+
   pub(crate) fn set_format(&mut self, encoding: Encoding) {
     self.format = encoding;
   }
@@ -130,6 +532,71 @@ impl<'a> Decoder<'a> {
   }
 }
 
+// =============================================================================
+// Checkpoint
+// =============================================================================
+
+/// A saved [`Decoder`] state.
+///
+/// Checkpoints are created with [`Decoder::checkpoint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+  position: u64,
+  format: Encoding,
+  warnings: usize,
+}
+
+// =============================================================================
+// Decode Iterator
+// =============================================================================
+
+/// An iterator decoding repeated values until the input runs out.
+///
+/// Iteration stops after the first error, which has the number of values
+/// decoded before it attached (see [`Error::index`]).
+#[derive(Clone, Debug)]
+pub struct DecodeIter<'a, T> {
+  inner: Decoder<'a>,
+  index: usize,
+  failed: bool,
+  marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> DecodeIter<'a, T> {
+  pub(crate) const fn new(inner: Decoder<'a>) -> Self {
+    Self {
+      inner,
+      index: 0,
+      failed: false,
+      marker: PhantomData,
+    }
+  }
+}
+
+impl<'a, T> Iterator for DecodeIter<'a, T>
+where
+  T: Decode<'a>,
+{
+  type Item = Result<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.failed || self.inner.is_empty() {
+      return None;
+    }
+
+    match T::decode(&mut self.inner) {
+      Ok(value) => {
+        self.index += 1;
+        Some(Ok(value))
+      }
+      Err(error) => {
+        self.failed = true;
+        Some(Err(error.with_index(self.index)))
+      }
+    }
+  }
+}
+
 // =============================================================================
 // Decode
 // =============================================================================
@@ -153,7 +620,26 @@ pub trait Decode<'a>: Sized {
 impl<const S: usize> Decode<'_> for [u8; S] {
   #[inline]
   fn decode(decoder: &mut Decoder<'_>) -> Result<Self> {
-    decoder.cursor.read_array().map_err(Into::into)
+    let offset: usize = decoder.position();
+    let rest: &[u8] = decoder.rest();
+
+    if rest.len() >= S {
+      return decoder.cursor.read_array().map_err(Into::into);
+    }
+
+    if !decoder.is_lenient() {
+      let error: IoError = IoError::from(IoErrorKind::UnexpectedEof);
+      return Err(Error::from(error).with_offset(offset));
+    }
+
+    decoder.warn(WarningKind::ShortField { offset });
+
+    let mut output: [u8; S] = [0; S];
+
+    output[..rest.len()].copy_from_slice(rest);
+    decoder.cursor.set_position((offset + rest.len()) as u64);
+
+    Ok(output)
   }
 }
 
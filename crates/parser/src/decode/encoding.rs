@@ -4,9 +4,13 @@ use core::str::from_utf8_unchecked;
 
 use crate::decode::Decode;
 use crate::decode::Decoder;
+use crate::encode::Encode;
+use crate::encode::Encoder;
 use crate::error::Error;
 use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::error::WarningKind;
+use crate::text;
 use crate::types::Slice;
 use crate::utils;
 
@@ -33,11 +37,187 @@ pub enum Encoding {
 
 impl Encoding {
   pub(crate) fn decode<'a>(self, decoder: &mut Decoder<'a>) -> Result<Cow<'a, str>> {
+    if matches!(self, Encoding::Utf16 | Encoding::Utf16BE) {
+      decoder.skip_stray_nul()?;
+    }
+
+    let offset: usize = decoder.position();
+
+    let slice: &'a Slice = match self {
+      Encoding::Latin1 | Encoding::Utf8 => decoder.until_nul(),
+      Encoding::Utf16 | Encoding::Utf16BE => decoder.until_nul2_utf16()?,
+    };
+
+    if decoder.records_encodings() {
+      decoder.warn(WarningKind::TextSource {
+        offset,
+        encoding: self.source(slice),
+      });
+    }
+
+    let output: Result<Cow<'a, str>> = match self {
+      Encoding::Latin1 => match decoder.codepage_decoder() {
+        Some(codepage) if !slice.as_ref().is_ascii() => {
+          codepage(slice).map_or_else(|| decode_latin1(slice), Ok)
+        }
+        _ if decoder.decodes_cp1252() => decode_cp1252(slice),
+        _ => decode_latin1(slice),
+      },
+      Encoding::Utf16 => decode_utf16_bom(slice),
+      Encoding::Utf16BE => decode_utf16_be(slice),
+      Encoding::Utf8 => decode_utf8(slice),
+    };
+
+    let output: Cow<'a, str> = match output {
+      Err(_) if decoder.replaces_invalid_text() => {
+        decoder.warn(WarningKind::InvalidText { offset });
+        self.decode_lossy(slice)
+      }
+      Err(_) if decoder.is_lenient() => {
+        decoder.recover(
+          WarningKind::InvalidText { offset },
+          ErrorKind::InvalidFrameData,
+        )?;
+        self.decode_fallback(slice)
+      }
+      output => output?,
+    };
+
+    if !decoder.repairs_mojibake() {
+      return Ok(output);
+    }
+
+    match text::repair_mojibake(&output) {
+      Some(repaired) => {
+        decoder.warn(WarningKind::MojibakeRepaired { offset });
+        Ok(Cow::Owned(repaired))
+      }
+      None => Ok(output),
+    }
+  }
+
+  /// Encode `text` in this encoding, including the BOM for UTF-16.
+  ///
+  /// The terminator of the encoding is appended if `terminated` is `true`.
+  /// Returns an error if the `text` cannot be encoded, see
+  /// [`can_encode`][Self::can_encode].
+  #[inline]
+  pub fn encode(self, text: &str, terminated: bool) -> Result<Vec<u8>> {
+    self.encode_at(text, terminated, 0)
+  }
+
+  /// Returns `true` if `text` can be encoded in this encoding.
+  ///
+  /// `ISO-8859-1` can only encode the characters U+0001..=U+00FF. No
+  /// encoding can encode a NUL character, which would end the string.
+  pub fn can_encode(self, text: &str) -> bool {
     match self {
-      Encoding::Latin1 => Ok(decode_latin1(decoder.until_nul())),
-      Encoding::Utf16 => decode_utf16_bom(decoder.until_nul2()),
-      Encoding::Utf16BE => decode_utf16_be(decoder.until_nul2()),
-      Encoding::Utf8 => decode_utf8(decoder.until_nul()),
+      Encoding::Latin1 => text.chars().all(|ch| matches!(ch, '\u{1}'..='\u{FF}')),
+      Encoding::Utf16 | Encoding::Utf16BE | Encoding::Utf8 => !text.contains('\0'),
+    }
+  }
+
+  /// Get the length of the string terminator (in bytes).
+  #[inline]
+  pub const fn terminator_len(self) -> usize {
+    match self {
+      Encoding::Latin1 | Encoding::Utf8 => 1,
+      Encoding::Utf16 | Encoding::Utf16BE => 2,
+    }
+  }
+
+  /// Write `text` followed by the terminator of the encoding.
+  ///
+  /// Returns an error if `text` contains a NUL character or, for
+  /// `ISO-8859-1`, a character outside of U+0000..=U+00FF.
+  pub(crate) fn write(self, encoder: &mut Encoder, text: &str) -> Result<()> {
+    let bytes: Vec<u8> = self.encode_at(text, true, encoder.len())?;
+
+    encoder.write(&bytes);
+
+    Ok(())
+  }
+
+  /// Encode `text`, reporting errors at the given `offset`.
+  fn encode_at(self, text: &str, terminated: bool, offset: usize) -> Result<Vec<u8>> {
+    if !self.can_encode(text) {
+      return Err(Error::new(ErrorKind::TextEncoding(offset)));
+    }
+
+    let mut output: Vec<u8> = Vec::with_capacity(text.len() + self.terminator_len());
+
+    match self {
+      Encoding::Latin1 => {
+        output.extend(text.chars().map(|ch| ch as u8));
+      }
+      Encoding::Utf16 => {
+        output.extend_from_slice(BOM_LE);
+
+        for unit in text.encode_utf16() {
+          output.extend_from_slice(&unit.to_le_bytes());
+        }
+      }
+      Encoding::Utf16BE => {
+        for unit in text.encode_utf16() {
+          output.extend_from_slice(&unit.to_be_bytes());
+        }
+      }
+      Encoding::Utf8 => {
+        output.extend_from_slice(text.as_bytes());
+      }
+    }
+
+    if terminated {
+      output.resize(output.len() + self.terminator_len(), 0x00);
+    }
+
+    Ok(output)
+  }
+
+  /// Get the concrete form of a string with this encoding.
+  fn source(self, slice: &Slice) -> SourceEncoding {
+    match (self, slice.as_ref()) {
+      (Encoding::Latin1, _) => SourceEncoding::Latin1,
+      (Encoding::Utf8, _) => SourceEncoding::Utf8,
+      (Encoding::Utf16BE, _) => SourceEncoding::Utf16BE,
+      (Encoding::Utf16, [0xFE, 0xFF, ..]) => SourceEncoding::Utf16BEBom,
+      (Encoding::Utf16, [0xFF, 0xFE, ..]) => SourceEncoding::Utf16LEBom,
+      (Encoding::Utf16, _) => SourceEncoding::Utf16LE,
+    }
+  }
+
+  /// Decode a string in this encoding, without its terminator.
+  ///
+  /// Invalid text is replaced with U+FFFD REPLACEMENT CHARACTER: invalid
+  /// UTF-8 sequences, unpaired UTF-16 surrogates and `ISO-8859-1` control
+  /// characters other than newline. UTF-16 text without a BOM is decoded in
+  /// the more likely byte order.
+  pub fn decode_lossy(self, slice: &Slice) -> Cow<'_, str> {
+    match self {
+      Encoding::Latin1 if !utils::is_latin1(slice.as_ref()) => Cow::Owned(
+        slice
+          .iter()
+          .map(|&byte| match byte {
+            0x0A | 0x20..=0xFF => char::from(byte),
+            _ => char::REPLACEMENT_CHARACTER,
+          })
+          .collect(),
+      ),
+      _ => self.decode_fallback(slice),
+    }
+  }
+
+  /// Decode invalid text in lenient mode.
+  ///
+  /// `ISO-8859-1` control characters are mapped as-is.
+  fn decode_fallback(self, slice: &Slice) -> Cow<'_, str> {
+    match (self, slice.as_ref()) {
+      (Encoding::Latin1, _) => decode_latin1_lossy(slice),
+      (Encoding::Utf8, bytes) => String::from_utf8_lossy(bytes),
+      (Encoding::Utf16BE, bytes) => decode_utf16_lossy(bytes, u16::from_be_bytes),
+      (Encoding::Utf16, [0xFE, 0xFF, bytes @ ..]) => decode_utf16_lossy(bytes, u16::from_be_bytes),
+      (Encoding::Utf16, [0xFF, 0xFE, bytes @ ..]) => decode_utf16_lossy(bytes, u16::from_le_bytes),
+      (Encoding::Utf16, bytes) => decode_utf16_lossy(bytes, utf16_order(bytes)),
     }
   }
 }
@@ -49,7 +229,13 @@ impl Decode<'_> for Encoding {
       0x01 => Self::Utf16,
       0x02 => Self::Utf16BE,
       0x03 => Self::Utf8,
-      _ => return Err(Error::new(ErrorKind::InvalidFrameData)),
+      byte => {
+        decoder.recover(
+          WarningKind::TextEncodingByte { byte },
+          ErrorKind::InvalidFrameData,
+        )?;
+        Self::Latin1
+      }
     };
 
     // Change the internal text format if another encoding is encountered.
@@ -59,25 +245,114 @@ impl Decode<'_> for Encoding {
   }
 }
 
+impl Encode for Encoding {
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    // Change the internal text format to match the decoding side.
+    encoder.set_format(*self);
+    encoder.encode(&(*self as u8))
+  }
+}
+
 copy_into_owned!(Encoding);
 
-fn decode_latin1(slice: &Slice) -> Cow<'_, str> {
-  if utils::is_latin1(slice.as_ref()) {
-    // SAFETY: We just checked if the slice was valid LATIN-1
+// =============================================================================
+// Source Encoding
+// =============================================================================
+
+/// The concrete form of a decoded string.
+///
+/// Unlike [`Encoding`], this distinguishes the byte order of UTF-16 strings
+/// and whether a byte order mark was present.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SourceEncoding {
+  /// ISO-8859-1 encoding.
+  Latin1,
+  /// UTF-16 encoding (LE) with BOM.
+  Utf16LEBom,
+  /// UTF-16 encoding (BE) with BOM.
+  Utf16BEBom,
+  /// UTF-16 encoding (LE) assumed for a string with a missing BOM.
+  Utf16LE,
+  /// UTF-16 encoding (BE) without BOM.
+  Utf16BE,
+  /// UTF-8 encoding.
+  Utf8,
+}
+
+impl SourceEncoding {
+  /// Get the text encoding declaring this form.
+  #[inline]
+  pub const fn encoding(self) -> Encoding {
+    match self {
+      Self::Latin1 => Encoding::Latin1,
+      Self::Utf16LEBom | Self::Utf16BEBom | Self::Utf16LE => Encoding::Utf16,
+      Self::Utf16BE => Encoding::Utf16BE,
+      Self::Utf8 => Encoding::Utf8,
+    }
+  }
+
+  /// Returns `true` if the string started with a byte order mark.
+  #[inline]
+  pub const fn has_bom(self) -> bool {
+    matches!(self, Self::Utf16LEBom | Self::Utf16BEBom)
+  }
+}
+
+fn decode_latin1(slice: &Slice) -> Result<Cow<'_, str>> {
+  if !utils::is_latin1(slice.as_ref()) {
+    return Err(Error::new(ErrorKind::InvalidFrameData));
+  }
+
+  Ok(decode_latin1_lossy(slice))
+}
+
+fn decode_latin1_lossy(slice: &Slice) -> Cow<'_, str> {
+  if slice.as_ref().is_ascii() {
+    // SAFETY: We just checked if the slice was valid ASCII
     //         and therefore valid UTF-8.
     Cow::Borrowed(unsafe { from_utf8_unchecked(slice.as_ref()) })
   } else {
-    // TODO: Should probably return error here.
     Cow::Owned(slice.iter().copied().map(char::from).collect())
   }
 }
 
+fn decode_cp1252(slice: &Slice) -> Result<Cow<'_, str>> {
+  if !utils::is_latin1(slice.as_ref()) {
+    return Err(Error::new(ErrorKind::InvalidFrameData));
+  }
+
+  if slice.as_ref().is_ascii() {
+    return Ok(decode_latin1_lossy(slice));
+  }
+
+  Ok(Cow::Owned(slice.iter().copied().map(cp1252_char).collect()))
+}
+
+/// Map a Windows-1252 byte to a character.
+const fn cp1252_char(byte: u8) -> char {
+  // The characters of the bytes `0x80`-`0x9F`, unassigned bytes are mapped
+  // to the C1 control characters.
+  const TABLE: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+  ];
+
+  match byte {
+    0x80..=0x9F => TABLE[(byte - 0x80) as usize],
+    _ => byte as char,
+  }
+}
+
 fn decode_utf16_bom(slice: &Slice) -> Result<Cow<'_, str>> {
-  debug_assert!(slice.len() > 1);
+  if slice.is_empty() {
+    return Ok(Cow::Borrowed(""));
+  }
 
-  match &slice.as_ref()[..2] {
-    BOM_BE => decode_utf16_be(&slice[2..]),
-    BOM_LE => decode_utf16_le(&slice[2..]),
+  match slice.as_ref().get(..2) {
+    Some(BOM_BE) => decode_utf16_be(&slice[2..]),
+    Some(BOM_LE) => decode_utf16_le(&slice[2..]),
     _ => Err(Error::new(ErrorKind::InvalidFrameData)),
   }
 }
@@ -121,6 +396,57 @@ where
   Ok(Cow::Owned(output))
 }
 
+fn decode_utf16_lossy<F>(bytes: &[u8], convert: F) -> Cow<'static, str>
+where
+  F: Fn([u8; 2]) -> u16,
+{
+  let iter = bytes
+    .chunks_exact(2)
+    .map(|chunk| convert([chunk[0], chunk[1]]));
+
+  Cow::Owned(
+    char::decode_utf16(iter)
+      .map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER))
+      .collect(),
+  )
+}
+
+/// Guess the byte order of UTF-16 text without a BOM.
+///
+/// A byte order that yields valid text is preferred, otherwise the order
+/// with the most NUL high bytes is chosen. Little-endian is assumed if both
+/// are equally likely, as used by most Windows software.
+fn utf16_order(bytes: &[u8]) -> fn([u8; 2]) -> u16 {
+  let is_valid = |convert: fn([u8; 2]) -> u16| {
+    char::decode_utf16(
+      bytes
+        .chunks_exact(2)
+        .map(|chunk| convert([chunk[0], chunk[1]])),
+    )
+    .all(|ch| ch.is_ok())
+  };
+
+  match (is_valid(u16::from_le_bytes), is_valid(u16::from_be_bytes)) {
+    (true, false) => return u16::from_le_bytes,
+    (false, true) => return u16::from_be_bytes,
+    _ => {}
+  }
+
+  let mut high_le: usize = 0;
+  let mut high_be: usize = 0;
+
+  for chunk in bytes.chunks_exact(2) {
+    high_le += usize::from(chunk[1] == 0x00);
+    high_be += usize::from(chunk[0] == 0x00);
+  }
+
+  if high_be > high_le {
+    u16::from_be_bytes
+  } else {
+    u16::from_le_bytes
+  }
+}
+
 fn decode_utf8(slice: &Slice) -> Result<Cow<'_, str>> {
   from_utf8(slice.as_ref())
     .map(Cow::Borrowed)
@@ -1,3 +1,7 @@
+use core::str::FromStr;
+
+use crate::error::Result;
+use crate::text::caseless_eq;
 use crate::utils::is_utf8;
 
 impl_stack_string! {
@@ -6,3 +10,112 @@ impl_stack_string! {
   @bytes = 3;
   @check = is_utf8;
 }
+
+// =============================================================================
+// Language Preference
+// =============================================================================
+
+/// An ordered list of preferred languages.
+///
+/// Used to select between frames that differ only by language (e.g. `COMM`).
+/// Frames are ranked by the position of their language in the list, if no
+/// language matches and [`any`][Self::any] is set then the first frame with
+/// any language is selected.
+///
+/// # Example
+///
+/// ```
+/// # use parser::types::LanguagePreference;
+/// # fn main() -> parser::error::Result<()> {
+/// // The UI language, else English, else anything.
+/// let preference = LanguagePreference::from_codes(["deu", "eng"])?.any(true);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LanguagePreference {
+  languages: Vec<Language>,
+  any: bool,
+}
+
+impl LanguagePreference {
+  /// Create a new `LanguagePreference` that accepts any language.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      languages: Vec::new(),
+      any: true,
+    }
+  }
+
+  /// Create a new `LanguagePreference` from an ordered list of 3-byte
+  /// language `codes`.
+  ///
+  /// Note: The fallback to any language is disabled.
+  pub fn from_codes<'a, I>(codes: I) -> Result<Self>
+  where
+    I: IntoIterator<Item = &'a str>,
+  {
+    let languages: Vec<Language> = codes
+      .into_iter()
+      .map(Language::from_str)
+      .collect::<Result<_>>()?;
+
+    Ok(Self {
+      languages,
+      any: false,
+    })
+  }
+
+  /// Append a `language` with lower priority than the existing languages.
+  #[inline]
+  pub fn then(mut self, language: Language) -> Self {
+    self.languages.push(language);
+    self
+  }
+
+  /// Set whether or not any language is accepted when none of the preferred
+  /// languages match.
+  #[inline]
+  pub fn any(mut self, value: bool) -> Self {
+    self.any = value;
+    self
+  }
+
+  /// Get the preferred languages, in priority order.
+  #[inline]
+  pub fn languages(&self) -> &[Language] {
+    &self.languages
+  }
+
+  /// Returns `true` if any language is accepted as a fallback.
+  #[inline]
+  pub const fn is_any(&self) -> bool {
+    self.any
+  }
+
+  /// Get the rank of `language`, lower is better.
+  ///
+  /// Languages are compared with [`caseless_eq`].
+  ///
+  /// Returns `None` if the language is not accepted.
+  pub fn rank(&self, language: &Language) -> Option<usize> {
+    let position: Option<usize> = self
+      .languages
+      .iter()
+      .position(|item| caseless_eq(item, language));
+
+    match position {
+      Some(position) => Some(position),
+      None if self.any => Some(self.languages.len()),
+      None => None,
+    }
+  }
+}
+
+impl Default for LanguagePreference {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
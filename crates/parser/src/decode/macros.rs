@@ -53,6 +53,19 @@ macro_rules! impl_stack_string {
       }
     }
 
+    impl ::core::str::FromStr for $ident {
+      type Err = $crate::error::Error;
+
+      fn from_str(input: &str) -> $crate::error::Result<Self> {
+        match <[u8; $bytes]>::try_from(input.as_bytes()) {
+          Ok(inner) if $check(&inner) => Ok(Self { inner }),
+          _ => Err($crate::error::Error::new(
+            $crate::error::ErrorKind::InvalidFrameData,
+          )),
+        }
+      }
+    }
+
     impl $crate::decode::Decode<'_> for $ident {
       fn decode(decoder: &mut $crate::decode::Decoder<'_>) -> $crate::error::Result<Self> {
         let inner: [u8; $bytes] = decoder.decode()?;
@@ -67,6 +80,13 @@ macro_rules! impl_stack_string {
       }
     }
 
+    impl $crate::encode::Encode for $ident {
+      #[inline]
+      fn encode(&self, encoder: &mut $crate::encode::Encoder) -> $crate::error::Result<()> {
+        encoder.encode(&self.inner)
+      }
+    }
+
     copy_into_owned!($ident);
   };
 }
@@ -10,8 +10,12 @@ mod language;
 mod timestamp;
 
 pub use self::date::Date;
+pub use self::decoder::Checkpoint;
 pub use self::decoder::Decode;
+pub use self::decoder::DecodeIter;
 pub use self::decoder::Decoder;
 pub use self::encoding::Encoding;
+pub use self::encoding::SourceEncoding;
 pub use self::language::Language;
+pub use self::language::LanguagePreference;
 pub use self::timestamp::Timestamp;
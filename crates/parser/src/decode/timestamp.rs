@@ -1,5 +1,7 @@
 use crate::decode::Decode;
 use crate::decode::Decoder;
+use crate::encode::Encode;
+use crate::encode::Encoder;
 use crate::error::Error;
 use crate::error::ErrorKind;
 use crate::error::Result;
@@ -28,4 +30,11 @@ impl Decode<'_> for Timestamp {
   }
 }
 
+impl Encode for Timestamp {
+  #[inline]
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    encoder.encode(&(*self as u8))
+  }
+}
+
 copy_into_owned!(Timestamp);
@@ -0,0 +1,209 @@
+use alloc::borrow::Cow;
+
+use crate::decode::Encoding;
+use crate::error::Result;
+use crate::types::FrameId;
+use crate::types::Slice;
+
+// =============================================================================
+// Content Encoder
+// =============================================================================
+
+/// Frame content encoder.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Encoder {
+  output: Vec<u8>,
+  format: Encoding,
+}
+
+impl Encoder {
+  /// Create a new content `Encoder`.
+  #[inline]
+  pub const fn new() -> Self {
+    Self::with_format(Encoding::Latin1)
+  }
+
+  /// Create a new content `Encoder` with the given text `format`.
+  #[inline]
+  pub const fn with_format(format: Encoding) -> Self {
+    Self {
+      output: Vec::new(),
+      format,
+    }
+  }
+
+  /// Encode a `T` value in ID3v2.3 form.
+  ///
+  /// To encode an ID3v2.2 structure use [`encode_v2`][Self::encode_v2].
+  #[inline]
+  pub fn encode<T>(&mut self, value: &T) -> Result<()>
+  where
+    T: Encode + ?Sized,
+  {
+    value.encode(self)
+  }
+
+  /// Encode a `T` value in ID3v2.2 form.
+  ///
+  /// To encode an ID3v2.3 structure use [`encode`][Self::encode].
+  #[inline]
+  pub fn encode_v2<T>(&mut self, value: &T) -> Result<()>
+  where
+    T: Encode + ?Sized,
+  {
+    value.encode_v2(self)
+  }
+
+  /// Encode a string in `ISO-8859-1` form.
+  #[inline]
+  pub fn encode_latin1(&mut self, value: &str) -> Result<()> {
+    Encoding::write(Encoding::Latin1, self, value)
+  }
+
+  /// Encode a counter in the shortest of 4 or 8 bytes.
+  #[inline]
+  pub fn encode_u64_relaxed(&mut self, value: u64) -> Result<()> {
+    match u32::try_from(value) {
+      Ok(value) => self.encode(&value),
+      Err(_) => self.encode(&value),
+    }
+  }
+
+  /// Append a slice of raw `bytes` to the output.
+  #[inline]
+  pub fn write(&mut self, bytes: &[u8]) {
+    self.output.extend_from_slice(bytes);
+  }
+
+  /// Get the number of bytes written so far.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.output.len()
+  }
+
+  /// Consume the encoder, returning the encoded bytes.
+  #[inline]
+  pub fn finish(self) -> Vec<u8> {
+    self.output
+  }
+
+  pub(crate) fn set_format(&mut self, encoding: Encoding) {
+    self.format = encoding;
+  }
+}
+
+impl Default for Encoder {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// =============================================================================
+// Encode
+// =============================================================================
+
+/// Content encoding behaviour.
+pub trait Encode {
+  /// Encode `self` in ID3v2.3 form.
+  fn encode(&self, encoder: &mut Encoder) -> Result<()>;
+
+  /// Encode `self` in ID3v2.2 form.
+  #[inline]
+  fn encode_v2(&self, encoder: &mut Encoder) -> Result<()> {
+    Encode::encode(self, encoder)
+  }
+}
+
+// =============================================================================
+// Implementations for Rust Types
+// =============================================================================
+
+impl<const S: usize> Encode for [u8; S] {
+  #[inline]
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    encoder.write(self);
+    Ok(())
+  }
+}
+
+impl Encode for Cow<'_, str> {
+  #[inline]
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    encoder.format.write(encoder, self)
+  }
+}
+
+impl Encode for Cow<'_, Slice> {
+  #[inline]
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    let slice: &Slice = self;
+
+    encoder.write(slice.as_ref());
+    Ok(())
+  }
+}
+
+impl<T> Encode for Vec<T>
+where
+  T: Encode,
+{
+  #[inline]
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    for item in self {
+      item.encode(encoder)?;
+    }
+
+    Ok(())
+  }
+}
+
+macro_rules! impl_integer {
+  ($integer:ty) => {
+    impl Encode for $integer {
+      #[inline]
+      fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+        encoder.encode(&self.to_be_bytes())
+      }
+    }
+  };
+  ($($integer:ty),+) => {
+    $(
+      impl_integer!($integer);
+    )+
+  };
+}
+
+impl_integer!(u8, u16, u32, u64);
+impl_integer!(i8, i16, i32, i64);
+
+macro_rules! impl_nonzero {
+  ($nonzero:ident) => {
+    impl Encode for ::core::num::$nonzero {
+      #[inline]
+      fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+        encoder.encode(&self.get())
+      }
+    }
+  };
+  ($($nonzero:ident),+) => {
+    $(
+      impl_nonzero!($nonzero);
+    )+
+  };
+}
+
+impl_nonzero!(NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64);
+impl_nonzero!(NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64);
+
+// =============================================================================
+// Implementations for Crate Types
+// =============================================================================
+
+impl<const S: usize> Encode for FrameId<S> {
+  #[inline]
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    encoder.write(self.as_slice());
+    Ok(())
+  }
+}
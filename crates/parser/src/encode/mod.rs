@@ -0,0 +1,6 @@
+//! ID3v2 Frame Content Encoding
+
+mod encoder;
+
+pub use self::encoder::Encode;
+pub use self::encoder::Encoder;
@@ -7,6 +7,11 @@ use core::num::TryFromIntError;
 use std::error::Error as StdError;
 use std::io::Error as IoError;
 
+use crate::types::DynFrameId;
+use crate::types::Encoding;
+use crate::types::FrameId;
+use crate::types::SourceEncoding;
+
 /// Alias for [`core::result::Result<T, E>`].
 pub type Result<T, E = Error> = ::core::result::Result<T, E>;
 
@@ -15,10 +20,15 @@ pub type Result<T, E = Error> = ::core::result::Result<T, E>;
 // =============================================================================
 
 /// Error returned from ID3 operations.
+///
+/// Errors are `Send + Sync` and may be propagated across threads.
 #[derive(Debug)]
 pub struct Error {
   kind: ErrorKind,
   base: ErrorBase,
+  frame: Option<DynFrameId>,
+  offset: Option<usize>,
+  index: Option<usize>,
 }
 
 impl Error {
@@ -26,13 +36,19 @@ impl Error {
     Self {
       kind,
       base: ErrorBase::Ignore,
+      frame: None,
+      offset: None,
+      index: None,
     }
   }
 
-  pub(crate) fn new_std(kind: ErrorKind, source: impl StdError + 'static) -> Self {
+  pub(crate) fn new_std(kind: ErrorKind, source: impl StdError + Send + Sync + 'static) -> Self {
     Self {
       kind,
       base: ErrorBase::Source(Box::new(source)),
+      frame: None,
+      offset: None,
+      index: None,
     }
   }
 
@@ -40,11 +56,54 @@ impl Error {
     Self::new(ErrorKind::InvalidField(field))
   }
 
+  /// Attach the identifier of the frame that caused the error.
+  pub(crate) const fn with_frame(mut self, frame: DynFrameId) -> Self {
+    self.frame = Some(frame);
+    self
+  }
+
+  /// Attach the `offset` in the frame content at which decoding failed.
+  ///
+  /// The innermost offset is kept if one was already attached.
+  pub(crate) const fn with_offset(mut self, offset: usize) -> Self {
+    if self.offset.is_none() {
+      self.offset = Some(offset);
+    }
+
+    self
+  }
+
+  /// Attach the `index` of the repeated value that failed to decode.
+  pub(crate) const fn with_index(mut self, index: usize) -> Self {
+    self.index = Some(index);
+    self
+  }
+
   /// Get the category of the error.
   #[inline]
   pub const fn kind(&self) -> ErrorKind {
     self.kind
   }
+
+  /// Get the identifier of the frame that caused the error (if known).
+  #[inline]
+  pub const fn frame(&self) -> Option<DynFrameId> {
+    self.frame
+  }
+
+  /// Get the offset in the frame content at which decoding failed (if known).
+  #[inline]
+  pub const fn offset(&self) -> Option<usize> {
+    self.offset
+  }
+
+  /// Get the index of the repeated value that failed to decode (if known).
+  ///
+  /// This is the number of values decoded successfully before the error.
+  #[inline]
+  pub const fn index(&self) -> Option<usize> {
+    self.index
+  }
 }
 
 impl Display for Error {
@@ -98,6 +157,28 @@ pub enum ErrorKind {
   InvalidBitFlag,
   /// Invalid data found in frame.
   InvalidFrameData,
+  /// Invalid text encoding found at the given offset in the frame content.
+  TextEncoding(usize),
+  /// The frame size exceeds the remaining tag data.
+  FrameOverrun,
+  /// Maximum number of frames exceeded.
+  FrameLimit,
+  /// Maximum size exceeded, see [`ParseOptions::no_limits`][crate::options::ParseOptions::no_limits].
+  SizeLimit(SizeLimit),
+  /// Unconsumed data (of the given length) found after the frame content.
+  TrailingData(usize),
+  /// The on-disk bytes of the tag were not retained while parsing.
+  RawUnavailable,
+  /// The frame content cannot be encoded.
+  Unencodable,
+  /// The frame is not permitted in the tag being written.
+  ForbiddenFrame(FrameId),
+  /// The frame is encrypted and no decryptor was registered for the method,
+  /// or decryption failed.
+  EncryptedFrame,
+  /// The tag uses a feature that cannot be decoded, e.g. ID3v2.2 compression
+  /// or a compressed frame without the `zlib` feature.
+  Unsupported,
 }
 
 // =============================================================================
@@ -111,12 +192,282 @@ pub enum TagField {
   Identifier,
   /// Header version.
   Version,
+  /// Header flags not defined for the version.
+  Flags,
   /// Extended header size.
   ExtSize,
   /// Extended header bitflag size.
   ExtFlagSize,
   /// Extended header flag data size.
   ExtFlagData,
+  /// Footer does not match the header.
+  Footer,
+}
+
+// =============================================================================
+// Size Limit
+// =============================================================================
+
+/// Size limits applied while parsing an ID3v2 tag.
+#[derive(Clone, Copy, Debug)]
+pub enum SizeLimit {
+  /// Total size of the tag.
+  Tag,
+  /// Total size of a single frame.
+  Frame,
+  /// Size of decompressed frame content.
+  Decompressed,
+}
+
+// =============================================================================
+// Warning
+// =============================================================================
+
+/// A recoverable problem encountered while parsing in lenient mode.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Warning {
+  kind: WarningKind,
+}
+
+impl Warning {
+  pub(crate) const fn new(kind: WarningKind) -> Self {
+    Self { kind }
+  }
+
+  /// Get the category of the warning.
+  #[inline]
+  pub const fn kind(&self) -> WarningKind {
+    self.kind
+  }
+}
+
+impl Display for Warning {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self.kind {
+      WarningKind::ExtFlagData { flag, size } => {
+        write!(
+          f,
+          "extended header flag {flag:#04X} has {size} bytes of data"
+        )
+      }
+      WarningKind::HeaderFlags { bits } => {
+        write!(f, "undefined header flags {bits:#04X}")
+      }
+      WarningKind::FrameFlags { frame, bits } => {
+        write!(f, "frame {frame} has undefined flags {bits:#06X}")
+      }
+      WarningKind::DataLength {
+        frame,
+        declared,
+        actual,
+      } => {
+        write!(
+          f,
+          "frame {frame} declared {declared} bytes of data but has {actual}"
+        )
+      }
+      WarningKind::ExtHeaderSize { declared, consumed } => {
+        write!(
+          f,
+          "extended header declared {declared} bytes but {consumed} were parsed"
+        )
+      }
+      WarningKind::TextEncRestriction { frame, encoding } => {
+        write!(
+          f,
+          "frame {frame} uses {encoding:?} text despite the text encoding restriction"
+        )
+      }
+      WarningKind::StrayNul { offset } => {
+        write!(f, "stray NUL byte in UTF-16 text at offset {offset}")
+      }
+      WarningKind::DanglingByte { offset } => {
+        write!(f, "dangling byte in UTF-16 text at offset {offset}")
+      }
+      WarningKind::TrailingData { length } => {
+        write!(f, "{length} bytes of trailing data after frame content")
+      }
+      WarningKind::TextEncodingByte { byte } => {
+        write!(f, "invalid text encoding byte {byte:#04X}")
+      }
+      WarningKind::InvalidText { offset } => {
+        write!(f, "invalid text at offset {offset}")
+      }
+      WarningKind::InvalidField { offset } => {
+        write!(f, "malformed field at offset {offset}")
+      }
+      WarningKind::ShortField { offset } => {
+        write!(f, "truncated field at offset {offset}")
+      }
+      WarningKind::PictureType { byte } => {
+        write!(f, "invalid picture type {byte:#04X}")
+      }
+      WarningKind::MojibakeRepaired { offset } => {
+        write!(f, "repaired double-encoded text at offset {offset}")
+      }
+      WarningKind::TextSource { offset, encoding } => {
+        write!(f, "text at offset {offset} decoded as {encoding:?}")
+      }
+      WarningKind::StalePadding { offset, length } => {
+        write!(
+          f,
+          "{length} bytes of stale data in the padding at offset {offset}"
+        )
+      }
+    }
+  }
+}
+
+// =============================================================================
+// Warning Kind
+// =============================================================================
+
+/// The types of warnings that may be recorded.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WarningKind {
+  /// The data length of a known extended header flag did not match the spec.
+  ///
+  /// The flag data was skipped.
+  ExtFlagData {
+    /// The extended header flag bit.
+    flag: u8,
+    /// The declared length of the flag data (in bytes).
+    size: u8,
+  },
+  /// The header flags contained bits not defined for the tag version.
+  ///
+  /// The undefined bits were retained.
+  HeaderFlags {
+    /// The undefined flag bits.
+    bits: u8,
+  },
+  /// The frame flags contained bits not defined for the tag version.
+  ///
+  /// The frame was decoded as if the undefined bits were clear.
+  FrameFlags {
+    /// The frame identifier.
+    frame: FrameId,
+    /// The undefined flag bits.
+    bits: u16,
+  },
+  /// The data length indicator of an unsynchronised frame did not match the
+  /// size of the content once unsynchronisation was reversed.
+  ///
+  /// The frame was decoded from the reversed content.
+  DataLength {
+    /// The frame identifier.
+    frame: FrameId,
+    /// The size declared by the data length indicator (in bytes).
+    declared: u32,
+    /// The size of the reversed content (in bytes).
+    actual: usize,
+  },
+  /// The declared extended header size did not match the parsed fields.
+  ///
+  /// The reader was resynchronised to the declared end of the extended header.
+  ExtHeaderSize {
+    /// The size declared by the extended header (in bytes).
+    declared: u32,
+    /// The size consumed by the known fields (in bytes).
+    consumed: u32,
+  },
+  /// A frame used a text encoding forbidden by the extended header
+  /// restrictions.
+  ///
+  /// The frame was decoded and should be re-encoded with a permitted encoding
+  /// (see [`TextEncRestriction::select`][crate::id3v2::TextEncRestriction::select]).
+  TextEncRestriction {
+    /// The frame identifier.
+    frame: FrameId,
+    /// The text encoding used by the frame.
+    encoding: Encoding,
+  },
+  /// A lone NUL byte preceded the terminator of a UTF-16 string.
+  ///
+  /// The stray byte was skipped.
+  StrayNul {
+    /// The offset of the stray byte in the frame content.
+    offset: usize,
+  },
+  /// A UTF-16 string ended with an odd number of bytes.
+  ///
+  /// The dangling byte was dropped.
+  DanglingByte {
+    /// The offset of the dangling byte in the frame content.
+    offset: usize,
+  },
+  /// Unconsumed bytes followed the frame content.
+  ///
+  /// The bytes were ignored. Trailing NUL padding is always tolerated, other
+  /// data only in lenient mode or after a text frame cut by
+  /// [`ParseOptions::truncate_text`][crate::options::ParseOptions::truncate_text].
+  TrailingData {
+    /// The number of unconsumed bytes.
+    length: usize,
+  },
+  /// An invalid text encoding byte was found.
+  ///
+  /// The text was decoded as ISO-8859-1.
+  TextEncodingByte {
+    /// The invalid encoding byte.
+    byte: u8,
+  },
+  /// A string was not valid in its declared encoding, e.g. ISO-8859-1 text
+  /// with control characters other than newline.
+  ///
+  /// The string was decoded lossily.
+  InvalidText {
+    /// The offset of the string in the frame content.
+    offset: usize,
+  },
+  /// A structured field did not match its format, e.g. an `OWNE` price
+  /// without a currency code.
+  ///
+  /// The raw field was kept.
+  InvalidField {
+    /// The offset of the field in the frame content.
+    offset: usize,
+  },
+  /// A fixed-size field was cut short by the end of the frame content.
+  ///
+  /// The missing bytes were filled with zeros.
+  ShortField {
+    /// The offset of the field in the frame content.
+    offset: usize,
+  },
+  /// An invalid picture type was found.
+  ///
+  /// The picture type was decoded as [`PicType::Other`][crate::content::PicType::Other].
+  PictureType {
+    /// The invalid picture type byte.
+    byte: u8,
+  },
+  /// A string was double-encoded (e.g. UTF-8 decoded as ISO-8859-1).
+  ///
+  /// The original text was restored, see [`ParseOptions::repair_mojibake`][crate::options::ParseOptions::repair_mojibake].
+  MojibakeRepaired {
+    /// The offset of the string in the frame content.
+    offset: usize,
+  },
+  /// The source encoding of a decoded string.
+  ///
+  /// Only recorded if enabled, see [`ParseOptions::record_encodings`][crate::options::ParseOptions::record_encodings].
+  TextSource {
+    /// The offset of the string in the frame content.
+    offset: usize,
+    /// The concrete form of the string.
+    encoding: SourceEncoding,
+  },
+  /// The padding contained non-zero data, e.g. fragments of frames left by
+  /// a tool that shrunk the tag in place.
+  ///
+  /// Frame iteration stops before the stale data.
+  StalePadding {
+    /// The offset of the stale data in the tag buffer.
+    offset: usize,
+    /// The length of the padding, including the stale data.
+    length: usize,
+  },
 }
 
 // =============================================================================
@@ -126,7 +477,7 @@ pub enum TagField {
 #[derive(Debug)]
 enum ErrorBase {
   Ignore,
-  Source(Box<dyn StdError + 'static>),
+  Source(Box<dyn StdError + Send + Sync + 'static>),
 }
 
 impl Display for ErrorBase {
@@ -6,9 +6,13 @@ use crate::content::Content;
 use crate::error::Error;
 use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::error::Warning;
 use crate::frame::FrameV2;
 use crate::frame::FrameV3;
 use crate::frame::FrameV4;
+use crate::options::DecoderConfig;
+use crate::options::ParseOptions;
+use crate::types::DynFrameId;
 use crate::types::Slice;
 use crate::types::Version;
 
@@ -48,6 +52,16 @@ impl<'a> DynFrame<'a> {
     }
   }
 
+  /// Get the frame identifier.
+  #[inline]
+  pub const fn identifier(&self) -> DynFrameId {
+    match self {
+      Self::V2(inner) => DynFrameId::V2(inner.identifier()),
+      Self::V3(inner) => DynFrameId::V3(inner.identifier()),
+      Self::V4(inner) => DynFrameId::V3(inner.identifier()),
+    }
+  }
+
   /// Get the frame identifier as a string slice.
   #[inline]
   pub const fn identifier_str(&self) -> &str {
@@ -88,6 +102,33 @@ impl<'a> DynFrame<'a> {
     }
   }
 
+  /// Returns `true` if the frame sets flag bits that are not defined for its
+  /// version.
+  ///
+  /// Such frames may carry extra data before the content and fail to decode in
+  /// strict mode, see [`ErrorKind::InvalidBitFlag`].
+  #[inline]
+  pub const fn has_unknown_flags(&self) -> bool {
+    match self {
+      Self::V2(_) => false,
+      Self::V3(inner) => inner.flag_bytes().undefined_bits() != 0,
+      Self::V4(inner) => inner.flag_bytes().undefined_bits() != 0,
+    }
+  }
+
+  /// Get the group identifier of the frame (if any).
+  ///
+  /// The group is registered by a `GRID` frame, see
+  /// [`Tag::group_registration`][crate::id3v2::Tag::group_registration].
+  #[inline]
+  pub const fn group_id(&self) -> Option<u8> {
+    match self {
+      Self::V2(_) => None,
+      Self::V3(inner) => inner.extra_data().grid(),
+      Self::V4(inner) => inner.extra_data().grid(),
+    }
+  }
+
   /// Get the raw frame content.
   #[inline]
   pub const fn frame_data(&self) -> &'a Slice {
@@ -109,6 +150,10 @@ impl<'a> DynFrame<'a> {
   }
 
   /// Decode the contents of the frame.
+  ///
+  /// Compressed frames are decompressed first, returning an
+  /// [`Unsupported`][crate::error::ErrorKind::Unsupported] error if the
+  /// `zlib` feature is disabled.
   #[inline]
   pub fn decode(&self) -> Result<Content<'a>> {
     match self {
@@ -118,6 +163,44 @@ impl<'a> DynFrame<'a> {
     }
   }
 
+  /// Decode the contents of the frame with the given `options`.
+  ///
+  /// Problems tolerated in lenient mode are appended to `warnings`.
+  #[inline]
+  pub fn decode_with(
+    &self,
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Content<'a>> {
+    self.decode_with_config(&DecoderConfig::new(options), warnings)
+  }
+
+  /// Decode the contents of the frame with the given `config`.
+  ///
+  /// Parsers registered with the `config` take precedence over the built-in
+  /// formats.
+  #[inline]
+  pub fn decode_with_config(
+    &self,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Content<'a>> {
+    self.decode_in(config, warnings, None)
+  }
+
+  pub(crate) fn decode_in(
+    &self,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+    origin: Option<&'a Slice>,
+  ) -> Result<Content<'a>> {
+    match self {
+      Self::V2(inner) => inner.decode_in(config, warnings, origin),
+      Self::V3(inner) => inner.decode_in(config, warnings, origin),
+      Self::V4(inner) => inner.decode_in(config, warnings, origin),
+    }
+  }
+
   /// Parse an ID3v2 frame from the given `slice`.
   pub fn from_slice(version: Version, slice: &'a Slice) -> Result<Option<Self>> {
     match version {
@@ -0,0 +1,151 @@
+use std::io::Cursor;
+
+use crate::error::Result;
+use crate::traits::ReadExt;
+use crate::types::Version;
+use crate::utils;
+
+// =============================================================================
+// Frame Layout
+// =============================================================================
+
+/// The layout of an ID3v2 frame header.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Layout {
+  id_len: usize,
+  size_len: usize,
+  size_encoding: SizeEncoding,
+  flag_len: usize,
+}
+
+impl Layout {
+  /// ID3v2.2 frame header layout.
+  pub const V2: Self = Self {
+    id_len: 3,
+    size_len: 3,
+    size_encoding: SizeEncoding::U24,
+    flag_len: 0,
+  };
+
+  /// ID3v2.3 frame header layout.
+  pub const V3: Self = Self {
+    id_len: 4,
+    size_len: 4,
+    size_encoding: SizeEncoding::U32,
+    flag_len: 2,
+  };
+
+  /// ID3v2.4 frame header layout.
+  pub const V4: Self = Self {
+    id_len: 4,
+    size_len: 4,
+    size_encoding: SizeEncoding::U28Unsync,
+    flag_len: 2,
+  };
+
+  /// Get the length of the frame identifier (in bytes).
+  #[inline]
+  pub const fn id_len(&self) -> usize {
+    self.id_len
+  }
+
+  /// Get the length of the size descriptor (in bytes).
+  #[inline]
+  pub const fn size_len(&self) -> usize {
+    self.size_len
+  }
+
+  /// Get the encoding of the size descriptor.
+  #[inline]
+  pub const fn size_encoding(&self) -> SizeEncoding {
+    self.size_encoding
+  }
+
+  /// Get the length of the frame flags (in bytes).
+  #[inline]
+  pub const fn flag_len(&self) -> usize {
+    self.flag_len
+  }
+
+  /// Returns `true` if the frame header contains flag bytes.
+  #[inline]
+  pub const fn has_flags(&self) -> bool {
+    self.flag_len != 0
+  }
+
+  /// Get the total length of the frame header (in bytes).
+  #[inline]
+  pub const fn header_len(&self) -> usize {
+    self.id_len + self.size_len + self.flag_len
+  }
+
+  /// Parse the size descriptor from the start of a frame header.
+  ///
+  /// Returns `None` if the `header` is too short.
+  pub fn parse_size(&self, header: &[u8]) -> Option<u32> {
+    let mut reader: Cursor<&[u8]> = Cursor::new(header.get(self.id_len..)?);
+
+    self.read_size(&mut reader).ok()
+  }
+
+  /// Read the size descriptor from the given `reader`.
+  pub(crate) fn read_size<R>(&self, reader: &mut R) -> Result<u32>
+  where
+    R: ReadExt,
+  {
+    match self.size_encoding {
+      SizeEncoding::U24 => reader.read_u24().map_err(Into::into),
+      SizeEncoding::U32 => reader.read_u32().map_err(Into::into),
+      SizeEncoding::U28Unsync => reader.read_u28_unsync().map_err(Into::into),
+    }
+  }
+
+  /// Returns `true` if the frame identifier at the start of `header` begins
+  /// with a NUL byte, which marks the start of padding.
+  ///
+  /// Note: Frame identifiers never contain NUL bytes, so an identifier
+  /// partially overwritten by padding is treated as padding as well.
+  #[inline]
+  pub(crate) fn is_null(&self, header: &[u8]) -> bool {
+    utils::is_null(&header[..header.len().min(1)])
+  }
+}
+
+// =============================================================================
+// Size Encoding
+// =============================================================================
+
+/// The encoding of a frame size descriptor.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SizeEncoding {
+  /// 24-bit big-endian integer.
+  U24,
+  /// 32-bit big-endian integer.
+  U32,
+  /// 28-bit "unsynchronized" integer.
+  U28Unsync,
+}
+
+// =============================================================================
+// Misc. Helpers
+// =============================================================================
+
+/// Get the length of a frame header for the given `version` (in bytes).
+///
+/// Returns `None` if the version does not use ID3v2 frames.
+#[inline]
+pub const fn header_len(version: Version) -> Option<usize> {
+  match version.frame_layout() {
+    Some(layout) => Some(layout.header_len()),
+    None => None,
+  }
+}
+
+/// Parse the size descriptor of a frame header for the given `version`.
+///
+/// Returns `None` if the version does not use ID3v2 frames or the `header` is
+/// too short.
+#[inline]
+pub fn parse_size(header: &[u8], version: Version) -> Option<u32> {
+  version.frame_layout()?.parse_size(header)
+}
@@ -1,11 +1,16 @@
 //! ID3v2 Frames
 
 mod any;
+mod layout;
 mod v22;
 mod v23;
 mod v24;
 
 pub use self::any::DynFrame;
+pub use self::layout::header_len;
+pub use self::layout::parse_size;
+pub use self::layout::Layout;
+pub use self::layout::SizeEncoding;
 pub use self::v22::FrameV2;
 pub use self::v23::FrameV3;
 pub use self::v23::FrameV3Extra;
@@ -2,12 +2,17 @@ use core::num::NonZeroU32;
 use std::io::Cursor;
 
 use crate::content::Content;
+use crate::error::Error;
+use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::error::Warning;
+use crate::frame::Layout;
+use crate::options::DecoderConfig;
+use crate::options::ParseOptions;
 use crate::traits::ReadExt;
 use crate::types::FrameId;
 use crate::types::Slice;
 use crate::types::Version;
-use crate::utils;
 
 // =============================================================================
 // Frame - ID3v2.2
@@ -22,8 +27,11 @@ pub struct FrameV2<'a> {
 }
 
 impl<'a> FrameV2<'a> {
+  /// The layout of the frame header.
+  pub const LAYOUT: Layout = Layout::V2;
+
   /// The size of the frame header (in bytes).
-  pub const SIZE: usize = 6;
+  pub const SIZE: usize = Self::LAYOUT.header_len();
 
   /// The version of the frame.
   pub const VERSION: Version = Version::ID3v22;
@@ -67,20 +75,67 @@ impl<'a> FrameV2<'a> {
   /// Decode the contents of the frame.
   #[inline]
   pub fn decode(&self) -> Result<Content<'a>> {
-    Content::decode(Self::VERSION, self.identifier_str(), self.frame_data())
+    self.decode_with(ParseOptions::new(), &mut Vec::new())
+  }
+
+  /// Decode the contents of the frame with the given `options`.
+  ///
+  /// Problems tolerated in lenient mode are appended to `warnings`.
+  #[inline]
+  pub fn decode_with(
+    &self,
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Content<'a>> {
+    self.decode_with_config(&DecoderConfig::new(options), warnings)
+  }
+
+  /// Decode the contents of the frame with the given `config`.
+  ///
+  /// Parsers registered with the `config` take precedence over the built-in
+  /// formats.
+  #[inline]
+  pub fn decode_with_config(
+    &self,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Content<'a>> {
+    self.decode_in(config, warnings, None)
+  }
+
+  pub(crate) fn decode_in(
+    &self,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+    origin: Option<&'a Slice>,
+  ) -> Result<Content<'a>> {
+    Content::decode_in(
+      Self::VERSION,
+      self.identifier().into(),
+      self.frame_data(),
+      config,
+      warnings,
+      origin,
+    )
   }
 
   /// Parse an ID3v2.2 frame from the given `slice`.
   pub fn from_slice(slice: &'a Slice) -> Result<Option<Self>> {
     // Bail immediately if this is a NULL frame.
-    if utils::is_null(slice.take(3).as_ref()) {
+    if Self::LAYOUT.is_null(slice.as_ref()) {
       return Ok(None);
     }
 
     let mut reader: Cursor<&Slice> = slice.cursor();
 
     let identifier: FrameId<3> = reader.read_array()?.try_into()?;
-    let descriptor: NonZeroU32 = reader.read_u24()?.try_into()?;
+    let descriptor: NonZeroU32 = Self::LAYOUT.read_size(&mut reader)?.try_into()?;
+
+    // The frame must fit in the remaining buffer.
+    if descriptor.get() as usize > slice.len() - Self::SIZE {
+      return Err(Error::new(ErrorKind::FrameOverrun));
+    }
+
     let frame_data: &Slice = reader.get_ref().view(Self::SIZE, descriptor.get() as usize);
 
     Ok(Some(Self {
@@ -1,14 +1,24 @@
 use bitflags::bitflags;
 use core::num::NonZeroU32;
 use std::io::Cursor;
+use std::io::Write;
 
 use crate::content::Content;
+use crate::error::Error;
+use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::error::Warning;
+use crate::error::WarningKind;
+use crate::frame::Layout;
+use crate::options::DecoderConfig;
+use crate::options::ParseOptions;
+use crate::traits::IntoOwned;
 use crate::traits::ReadExt;
+use crate::types::Bytes;
+use crate::types::DynFrameId;
 use crate::types::FrameId;
 use crate::types::Slice;
 use crate::types::Version;
-use crate::utils;
 
 // =============================================================================
 // Frame - ID3v2.3
@@ -25,8 +35,11 @@ pub struct FrameV3<'a> {
 }
 
 impl<'a> FrameV3<'a> {
+  /// The layout of the frame header.
+  pub const LAYOUT: Layout = Layout::V3;
+
   /// The size of the frame header (in bytes).
-  pub const SIZE: usize = 10;
+  pub const SIZE: usize = Self::LAYOUT.header_len();
 
   /// The version of the frame.
   pub const VERSION: Version = Version::ID3v23;
@@ -82,34 +95,146 @@ impl<'a> FrameV3<'a> {
   /// Decode the contents of the frame.
   #[inline]
   pub fn decode(&self) -> Result<Content<'a>> {
-    let name: &str = self.identifier_str();
-    let data: &Slice = self.frame_data();
+    self.decode_with(ParseOptions::new(), &mut Vec::new())
+  }
+
+  /// Decode the contents of the frame with the given `options`.
+  ///
+  /// Problems tolerated in lenient mode are appended to `warnings`.
+  #[inline]
+  pub fn decode_with(
+    &self,
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Content<'a>> {
+    self.decode_with_config(&DecoderConfig::new(options), warnings)
+  }
+
+  /// Decode the contents of the frame with the given `config`.
+  ///
+  /// Parsers registered with the `config` take precedence over the built-in
+  /// formats.
+  #[inline]
+  pub fn decode_with_config(
+    &self,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Content<'a>> {
+    self.decode_in(config, warnings, None)
+  }
+
+  pub(crate) fn decode_in(
+    &self,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+    origin: Option<&'a Slice>,
+  ) -> Result<Content<'a>> {
+    let bits: u16 = self.flag_bytes().undefined_bits();
+
+    // Undefined flags may indicate extra data preceding the content.
+    if bits != 0 {
+      if !config.options().is_lenient() {
+        return Err(Error::new(ErrorKind::InvalidBitFlag));
+      }
+
+      warnings.push(Warning::new(WarningKind::FrameFlags {
+        frame: self.identifier(),
+        bits,
+      }));
+    }
+
+    // Encrypted content is decrypted before it is decompressed.
+    let Some(method) = self.extra_data().encr() else {
+      return self.decode_data(self.frame_data(), config, warnings, origin);
+    };
+
+    let bytes: Bytes = config.decrypt(method, self.frame_data())?;
+
+    self
+      .decode_data(bytes.as_slice(), config, warnings, None)
+      .map(|content| content.into_owned())
+  }
+
+  fn decode_data<'b>(
+    &self,
+    data: &'b Slice,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+    origin: Option<&'b Slice>,
+  ) -> Result<Content<'b>> {
+    let id: DynFrameId = self.identifier().into();
 
     if let Some(size) = self.extra_data().comp() {
-      Content::decode2(Self::VERSION, name, data, size)
+      Content::decode2(Self::VERSION, id, data, size, config, warnings)
     } else {
-      Content::decode(Self::VERSION, name, data)
+      Content::decode_in(Self::VERSION, id, data, config, warnings, origin)
+    }
+  }
+
+  /// Write an ID3v2.3 frame header, followed by the `extra` data, to the
+  /// given `writer`.
+  ///
+  /// The `length` is the size of the frame content (in bytes), excluding the
+  /// extra data. Flags indicating extra data are set from the `extra` data.
+  ///
+  /// Returns the number of bytes written.
+  pub fn write_header<W>(
+    mut writer: W,
+    identifier: FrameId,
+    length: u32,
+    flags: FrameV3Flags,
+    extra: FrameV3Extra,
+  ) -> Result<usize>
+  where
+    W: Write,
+  {
+    if flags.intersects(FrameV3Flags::EXTRA - extra.bitflags()) {
+      return Err(Error::new(ErrorKind::InvalidBitFlag));
     }
+
+    let descriptor: u32 = length
+      .checked_add(extra.size() as u32)
+      .ok_or_else(|| Error::new(ErrorKind::Int))?;
+
+    let descriptor: NonZeroU32 = descriptor.try_into()?;
+    let flags: FrameV3Flags = flags | extra.bitflags();
+
+    writer.write_all(identifier.as_slice())?;
+    writer.write_all(&descriptor.get().to_be_bytes())?;
+    writer.write_all(&flags.bits().to_be_bytes())?;
+    extra.write_to(&mut writer)?;
+
+    Ok(Self::SIZE + extra.size())
   }
 
   /// Parse an ID3v2.3 frame from the given `slice`.
   pub fn from_slice(slice: &'a Slice) -> Result<Option<Self>> {
     // Bail immediately if this is a NULL frame.
-    if utils::is_null(slice.take(4).as_ref()) {
+    if Self::LAYOUT.is_null(slice.as_ref()) {
       return Ok(None);
     }
 
     let mut reader: Cursor<&Slice> = slice.cursor();
 
     let identifier: FrameId = reader.read_array()?.try_into()?;
-    let descriptor: NonZeroU32 = reader.read_u32()?.try_into()?;
+    let descriptor: NonZeroU32 = Self::LAYOUT.read_size(&mut reader)?.try_into()?;
+
+    // The frame must fit in the remaining buffer.
+    if descriptor.get() as usize > slice.len().saturating_sub(Self::SIZE) {
+      return Err(Error::new(ErrorKind::FrameOverrun));
+    }
+
     let flag_bytes: FrameV3Flags = FrameV3Flags::from_reader(&mut reader)?;
     let extra_data: FrameV3Extra = FrameV3Extra::from_reader(flag_bytes, &mut reader)?;
 
+    // The extra data is part of the frame content.
+    let Some(length) = (descriptor.get() as usize).checked_sub(extra_data.size()) else {
+      return Err(Error::new(ErrorKind::InvalidFrameData));
+    };
+
     let frame_data: &Slice = reader
       .get_ref()
-      .skip(Self::SIZE + extra_data.size())
-      .take(descriptor.get() as usize - extra_data.size());
+      .view(Self::SIZE + extra_data.size(), length);
 
     Ok(Some(Self {
       identifier,
@@ -162,6 +287,17 @@ bitflags! {
 }
 
 impl FrameV3Flags {
+  /// Flags indicating extra data in the frame header.
+  pub const EXTRA: Self = Self::COMPRESSION
+    .union(Self::ENCRYPTION)
+    .union(Self::GROUPING_IDENTITY);
+
+  /// Get the bits not defined for ID3v2.3 frames.
+  #[inline]
+  pub const fn undefined_bits(self) -> u16 {
+    self.bits() & !Self::all().bits()
+  }
+
   fn from_reader<R>(reader: &mut R) -> Result<Self>
   where
     R: ReadExt,
@@ -186,6 +322,56 @@ pub struct FrameV3Extra {
 }
 
 impl FrameV3Extra {
+  /// Create a new `FrameV3Extra` without extra data.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      comp: None,
+      encr: None,
+      grid: None,
+    }
+  }
+
+  /// Set the size of the decompressed frame content.
+  #[inline]
+  pub const fn with_comp(mut self, value: u32) -> Self {
+    self.comp = Some(value);
+    self
+  }
+
+  /// Set the encryption method of the frame content.
+  #[inline]
+  pub const fn with_encr(mut self, value: u8) -> Self {
+    self.encr = Some(value);
+    self
+  }
+
+  /// Set the group identifier of the frame content.
+  #[inline]
+  pub const fn with_grid(mut self, value: u8) -> Self {
+    self.grid = Some(value);
+    self
+  }
+
+  /// Get the frame flags indicating the extra data.
+  pub const fn bitflags(&self) -> FrameV3Flags {
+    let mut bitflags: FrameV3Flags = FrameV3Flags::empty();
+
+    if self.comp.is_some() {
+      bitflags = bitflags.union(FrameV3Flags::COMPRESSION);
+    }
+
+    if self.encr.is_some() {
+      bitflags = bitflags.union(FrameV3Flags::ENCRYPTION);
+    }
+
+    if self.grid.is_some() {
+      bitflags = bitflags.union(FrameV3Flags::GROUPING_IDENTITY);
+    }
+
+    bitflags
+  }
+
   /// Get the size of the extra frame data (in bytes).
   #[inline]
   pub const fn size(&self) -> usize {
@@ -218,11 +404,7 @@ impl FrameV3Extra {
   where
     R: ReadExt,
   {
-    let mut this: Self = Self {
-      comp: None,
-      encr: None,
-      grid: None,
-    };
+    let mut this: Self = Self::new();
 
     if bitflags.contains(FrameV3Flags::COMPRESSION) {
       this.comp = Some(reader.read_u32()?);
@@ -238,4 +420,29 @@ impl FrameV3Extra {
 
     Ok(this)
   }
+  fn write_to<W>(&self, writer: &mut W) -> Result<()>
+  where
+    W: Write,
+  {
+    if let Some(comp) = self.comp {
+      writer.write_all(&comp.to_be_bytes())?;
+    }
+
+    if let Some(encr) = self.encr {
+      writer.write_all(&[encr])?;
+    }
+
+    if let Some(grid) = self.grid {
+      writer.write_all(&[grid])?;
+    }
+
+    Ok(())
+  }
+}
+
+impl Default for FrameV3Extra {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
 }
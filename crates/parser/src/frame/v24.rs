@@ -1,15 +1,25 @@
+use alloc::borrow::Cow;
 use bitflags::bitflags;
 use core::num::NonZeroU32;
 use std::io::Cursor;
+use std::io::Write;
 
 use crate::content::Content;
 use crate::error::Error;
 use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::error::Warning;
+use crate::error::WarningKind;
+use crate::frame::Layout;
+use crate::options::DecoderConfig;
+use crate::options::ParseOptions;
+use crate::traits::IntoOwned;
 use crate::traits::ReadExt;
+use crate::types::DynFrameId;
 use crate::types::FrameId;
 use crate::types::Slice;
 use crate::types::Version;
+use crate::unsync::Unsync;
 use crate::utils;
 
 // =============================================================================
@@ -27,8 +37,11 @@ pub struct FrameV4<'a> {
 }
 
 impl<'a> FrameV4<'a> {
+  /// The layout of the frame header.
+  pub const LAYOUT: Layout = Layout::V4;
+
   /// The size of the frame header (in bytes).
-  pub const SIZE: usize = 10;
+  pub const SIZE: usize = Self::LAYOUT.header_len();
 
   /// The version of the frame.
   pub const VERSION: Version = Version::ID3v24;
@@ -84,34 +97,204 @@ impl<'a> FrameV4<'a> {
   /// Decode the contents of the frame.
   #[inline]
   pub fn decode(&self) -> Result<Content<'a>> {
-    let name: &str = self.identifier_str();
-    let data: &Slice = self.frame_data();
+    self.decode_with(ParseOptions::new(), &mut Vec::new())
+  }
+
+  /// Decode the contents of the frame with the given `options`.
+  ///
+  /// Problems tolerated in lenient mode are appended to `warnings`.
+  #[inline]
+  pub fn decode_with(
+    &self,
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Content<'a>> {
+    self.decode_with_config(&DecoderConfig::new(options), warnings)
+  }
+
+  /// Decode the contents of the frame with the given `config`.
+  ///
+  /// Parsers registered with the `config` take precedence over the built-in
+  /// formats.
+  #[inline]
+  pub fn decode_with_config(
+    &self,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Content<'a>> {
+    self.decode_in(config, warnings, None)
+  }
+
+  pub(crate) fn decode_in(
+    &self,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+    origin: Option<&'a Slice>,
+  ) -> Result<Content<'a>> {
+    let bits: u16 = self.flag_bytes().undefined_bits();
+
+    // Undefined flags may indicate extra data preceding the content.
+    if bits != 0 {
+      if !config.options().is_lenient() {
+        return Err(Error::new(ErrorKind::InvalidBitFlag));
+      }
+
+      warnings.push(Warning::new(WarningKind::FrameFlags {
+        frame: self.identifier(),
+        bits,
+      }));
+    }
+
+    let unsync: bool = self.flag_bytes().contains(FrameV4Flags::UNSYNCHRONISATION);
+    let encr: Option<u8> = self.extra_data().encr();
+
+    if !unsync && encr.is_none() {
+      return self.decode_data(self.frame_data(), config, warnings, origin);
+    }
+
+    let mut data: Cow<'_, Slice> = Cow::Borrowed(self.frame_data());
+    let dlen: Option<u32> = self.extra_data().dlen();
+
+    // Reversing unsynchronisation never grows the content, so the untrusted
+    // data length indicator only bounds the allocation.
+    if unsync {
+      let capacity: Option<usize> = dlen.map(|size| (size as usize).min(self.frame_data().len()));
+
+      data = Cow::Owned(Unsync::new(self.frame_data().as_ref()).read_all(capacity)?);
+    }
+
+    // Encrypted content is decrypted before it is decompressed.
+    if let Some(method) = encr {
+      data = Cow::Owned(config.decrypt(method, &data)?);
+    }
+
+    // The data length indicator is the size of the content once all flags are
+    // reversed, it bounds compressed content when decompressed instead.
+    if unsync && !self.flag_bytes().contains(FrameV4Flags::COMPRESSION) {
+      self.check_dlen(dlen, data.len(), config, warnings)?;
+    }
+
+    self
+      .decode_data(&data, config, warnings, None)
+      .map(|content| content.into_owned())
+  }
+
+  fn check_dlen(
+    &self,
+    dlen: Option<u32>,
+    actual: usize,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<()> {
+    let Some(declared) = dlen.filter(|size| *size as usize != actual) else {
+      return Ok(());
+    };
 
-    if let Some(size) = self.extra_data().dlen() {
-      Content::decode2(Self::VERSION, name, data, size)
-    } else {
-      Content::decode(Self::VERSION, name, data)
+    if !config.options().is_lenient() {
+      return Err(Error::new(ErrorKind::InvalidFrameData));
     }
+
+    warnings.push(Warning::new(WarningKind::DataLength {
+      frame: self.identifier(),
+      declared,
+      actual,
+    }));
+
+    Ok(())
+  }
+
+  fn decode_data<'b>(
+    &self,
+    data: &'b Slice,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+    origin: Option<&'b Slice>,
+  ) -> Result<Content<'b>> {
+    let id: DynFrameId = self.identifier().into();
+    let compressed: bool = self.flag_bytes().contains(FrameV4Flags::COMPRESSION);
+
+    match (compressed, self.extra_data().dlen()) {
+      (true, Some(size)) => Content::decode2(Self::VERSION, id, data, size, config, warnings),
+      (_, _) => Content::decode_in(Self::VERSION, id, data, config, warnings, origin),
+    }
+  }
+
+  /// Write an ID3v2.4 frame header, followed by the `extra` data, to the
+  /// given `writer`.
+  ///
+  /// The `length` is the size of the frame content (in bytes), excluding the
+  /// extra data. Flags indicating extra data are set from the `extra` data.
+  ///
+  /// Returns the number of bytes written.
+  pub fn write_header<W>(
+    mut writer: W,
+    identifier: FrameId,
+    length: u32,
+    flags: FrameV4Flags,
+    extra: FrameV4Extra,
+  ) -> Result<usize>
+  where
+    W: Write,
+  {
+    if flags.intersects(FrameV4Flags::EXTRA - extra.bitflags()) {
+      return Err(Error::new(ErrorKind::InvalidBitFlag));
+    }
+
+    let flags: FrameV4Flags = flags | extra.bitflags();
+
+    // Compressed frames require a data length indicator.
+    if flags.contains(FrameV4Flags::COMPRESSION) && extra.dlen.is_none() {
+      return Err(Error::new(ErrorKind::InvalidBitFlag));
+    }
+
+    // The data length indicator is a synchsafe integer.
+    if extra.dlen.is_some_and(|dlen| dlen > utils::MAX_U28_UNSYNC) {
+      return Err(Error::new(ErrorKind::Int));
+    }
+
+    let descriptor: u32 = length
+      .checked_add(extra.size() as u32)
+      .filter(|descriptor| *descriptor <= utils::MAX_U28_UNSYNC)
+      .ok_or_else(|| Error::new(ErrorKind::Int))?;
+
+    let descriptor: NonZeroU32 = descriptor.try_into()?;
+
+    writer.write_all(identifier.as_slice())?;
+    writer.write_all(&utils::encode_u28_unsync(descriptor.get()))?;
+    writer.write_all(&flags.bits().to_be_bytes())?;
+    extra.write_to(&mut writer)?;
+
+    Ok(Self::SIZE + extra.size())
   }
 
   /// Parse an ID3v2.4 frame from the given `slice`.
   pub fn from_slice(slice: &'a Slice) -> Result<Option<Self>> {
     // Bail immediately if this is a NULL frame.
-    if utils::is_null(slice.take(4).as_ref()) {
+    if Self::LAYOUT.is_null(slice.as_ref()) {
       return Ok(None);
     }
 
     let mut reader: Cursor<&Slice> = slice.cursor();
 
     let identifier: FrameId = reader.read_array()?.try_into()?;
-    let descriptor: NonZeroU32 = reader.read_u28_unsync()?.try_into()?;
+    let descriptor: NonZeroU32 = Self::LAYOUT.read_size(&mut reader)?.try_into()?;
+
+    // The frame must fit in the remaining buffer.
+    if descriptor.get() as usize > slice.len().saturating_sub(Self::SIZE) {
+      return Err(Error::new(ErrorKind::FrameOverrun));
+    }
+
     let flag_bytes: FrameV4Flags = FrameV4Flags::from_reader(&mut reader)?;
     let extra_data: FrameV4Extra = FrameV4Extra::from_reader(flag_bytes, &mut reader)?;
 
+    // The extra data is part of the frame content.
+    let Some(length) = (descriptor.get() as usize).checked_sub(extra_data.size()) else {
+      return Err(Error::new(ErrorKind::InvalidFrameData));
+    };
+
     let frame_data: &Slice = reader
       .get_ref()
-      .skip(Self::SIZE + extra_data.size())
-      .take(descriptor.get() as usize - extra_data.size());
+      .view(Self::SIZE + extra_data.size(), length);
 
     Ok(Some(Self {
       identifier,
@@ -172,6 +355,17 @@ bitflags! {
 }
 
 impl FrameV4Flags {
+  /// Flags indicating extra data in the frame header.
+  pub const EXTRA: Self = Self::GROUPING_IDENTITY
+    .union(Self::ENCRYPTION)
+    .union(Self::DATA_LENGTH_INDICATOR);
+
+  /// Get the bits not defined for ID3v2.4 frames.
+  #[inline]
+  pub const fn undefined_bits(self) -> u16 {
+    self.bits() & !Self::all().bits()
+  }
+
   fn from_reader<R>(reader: &mut R) -> Result<Self>
   where
     R: ReadExt,
@@ -196,6 +390,56 @@ pub struct FrameV4Extra {
 }
 
 impl FrameV4Extra {
+  /// Create a new `FrameV4Extra` without extra data.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      grid: None,
+      encr: None,
+      dlen: None,
+    }
+  }
+
+  /// Set the group identifier of the frame content.
+  #[inline]
+  pub const fn with_grid(mut self, value: u8) -> Self {
+    self.grid = Some(value);
+    self
+  }
+
+  /// Set the encryption method of the frame content.
+  #[inline]
+  pub const fn with_encr(mut self, value: u8) -> Self {
+    self.encr = Some(value);
+    self
+  }
+
+  /// Set the data length indicator of the frame content.
+  #[inline]
+  pub const fn with_dlen(mut self, value: u32) -> Self {
+    self.dlen = Some(value);
+    self
+  }
+
+  /// Get the frame flags indicating the extra data.
+  pub const fn bitflags(&self) -> FrameV4Flags {
+    let mut bitflags: FrameV4Flags = FrameV4Flags::empty();
+
+    if self.grid.is_some() {
+      bitflags = bitflags.union(FrameV4Flags::GROUPING_IDENTITY);
+    }
+
+    if self.encr.is_some() {
+      bitflags = bitflags.union(FrameV4Flags::ENCRYPTION);
+    }
+
+    if self.dlen.is_some() {
+      bitflags = bitflags.union(FrameV4Flags::DATA_LENGTH_INDICATOR);
+    }
+
+    bitflags
+  }
+
   /// Get the size of the extra frame data (in bytes).
   #[inline]
   pub const fn size(&self) -> usize {
@@ -228,11 +472,7 @@ impl FrameV4Extra {
   where
     R: ReadExt,
   {
-    let mut this: Self = Self {
-      grid: None,
-      encr: None,
-      dlen: None,
-    };
+    let mut this: Self = Self::new();
 
     // Set to true if the DATA_LENGTH_INDICATOR must be set.
     let mut require_dlen: bool = false;
@@ -251,17 +491,37 @@ impl FrameV4Extra {
     }
 
     // Note: May include `DATA_LENGTH_INDICATOR` but not mandatory.
-    if bitflags.contains(FrameV4Flags::UNSYNCHRONISATION) {
-      panic!("TODO: Handle UNSYNCHRONISATION - V4");
-    }
-
     if bitflags.contains(FrameV4Flags::DATA_LENGTH_INDICATOR) {
-      // TODO: This is a synchsafe integer.
-      this.dlen = Some(reader.read_u32()?);
+      this.dlen = Some(reader.read_u28_unsync()?);
     } else if require_dlen {
       return Err(Error::new(ErrorKind::InvalidBitFlag));
     }
 
     Ok(this)
   }
+  fn write_to<W>(&self, writer: &mut W) -> Result<()>
+  where
+    W: Write,
+  {
+    if let Some(grid) = self.grid {
+      writer.write_all(&[grid])?;
+    }
+
+    if let Some(encr) = self.encr {
+      writer.write_all(&[encr])?;
+    }
+
+    if let Some(dlen) = self.dlen {
+      writer.write_all(&utils::encode_u28_unsync(dlen))?;
+    }
+
+    Ok(())
+  }
+}
+
+impl Default for FrameV4Extra {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
 }
@@ -0,0 +1,7 @@
+//! ID3v1 Support
+
+mod tag;
+
+pub use self::tag::Speed;
+pub use self::tag::TagPlus;
+pub use self::tag::TagV1;
@@ -0,0 +1,318 @@
+use std::fs::File;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::path::Path;
+
+use crate::error::Result;
+use crate::traits::ReadExt;
+use crate::types::Bytes;
+use crate::types::Genre;
+
+// =============================================================================
+// Tag
+// =============================================================================
+
+/// A parsed ID3v1 or ID3v1.1 tag.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TagV1 {
+  title: String,
+  artist: String,
+  album: String,
+  year: String,
+  comment: String,
+  track: Option<u8>,
+  genre: u8,
+  enhanced: Option<TagPlus>,
+}
+
+impl TagV1 {
+  /// ID3v1 tag identifier.
+  pub const IDENTIFIER: [u8; 3] = *b"TAG";
+
+  /// The size of the tag (in bytes).
+  pub const SIZE: usize = 128;
+
+  /// Get the title of the tag.
+  ///
+  /// Note: This includes the extended title of the [`TagPlus`] block.
+  #[inline]
+  pub fn title(&self) -> &str {
+    &self.title
+  }
+
+  /// Get the artist of the tag.
+  ///
+  /// Note: This includes the extended artist of the [`TagPlus`] block.
+  #[inline]
+  pub fn artist(&self) -> &str {
+    &self.artist
+  }
+
+  /// Get the album of the tag.
+  ///
+  /// Note: This includes the extended album of the [`TagPlus`] block.
+  #[inline]
+  pub fn album(&self) -> &str {
+    &self.album
+  }
+
+  /// Get the year of the tag.
+  #[inline]
+  pub fn year(&self) -> &str {
+    &self.year
+  }
+
+  /// Get the comment of the tag.
+  #[inline]
+  pub fn comment(&self) -> &str {
+    &self.comment
+  }
+
+  /// Get the track number of the tag.
+  ///
+  /// Note: Only applicable to ID3v1.1, earlier versions return `None`.
+  #[inline]
+  pub const fn track(&self) -> Option<u8> {
+    self.track
+  }
+
+  /// Get the genre byte of the tag.
+  ///
+  /// Note: `255` indicates that no genre is set.
+  #[inline]
+  pub const fn genre(&self) -> u8 {
+    self.genre
+  }
+
+  /// Get the genre of the tag from the numeric genre list.
+  ///
+  /// Returns `None` if no genre is set.
+  #[inline]
+  pub const fn standard_genre(&self) -> Option<Genre> {
+    Genre::new(self.genre)
+  }
+
+  /// Returns `true` if the tag is an ID3v1.1 tag (with a track number).
+  #[inline]
+  pub const fn is_v11(&self) -> bool {
+    self.track.is_some()
+  }
+
+  /// Get the enhanced `TAG+` block preceding the tag (if any).
+  #[inline]
+  pub const fn enhanced(&self) -> Option<&TagPlus> {
+    self.enhanced.as_ref()
+  }
+
+  /// Parse an ID3v1 tag from the end of the file at the given `path`.
+  pub fn from_path<P>(path: &P) -> Result<Option<Self>>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    Self::from_reader(File::open(path)?)
+  }
+
+  /// Parse an ID3v1 tag from the end of the given `reader`.
+  ///
+  /// Returns `None` if the reader does not end with an ID3v1 tag.
+  ///
+  /// See [`from_slice`][Self::from_slice] for details.
+  ///
+  /// Note: The reader is left at the end of the stream.
+  pub fn from_reader<R>(mut reader: R) -> Result<Option<Self>>
+  where
+    R: Read + Seek,
+  {
+    let length: u64 = reader.seek(SeekFrom::End(0))?;
+    let size: u64 = length.min((TagPlus::SIZE + Self::SIZE) as u64);
+
+    reader.seek(SeekFrom::Start(length - size))?;
+
+    let bytes: Bytes = reader.read_bytes(size as usize)?;
+
+    Ok(Self::from_slice(bytes.as_ref()))
+  }
+
+  /// Parse an ID3v1 tag from the end of the given `slice`.
+  ///
+  /// The tag is merged with the enhanced `TAG+` block immediately preceding
+  /// it (if any), see [`TagPlus`].
+  ///
+  /// Returns `None` if the slice does not end with an ID3v1 tag.
+  pub fn from_slice(slice: &[u8]) -> Option<Self> {
+    let index: usize = slice.len().checked_sub(Self::SIZE)?;
+    let bytes: &[u8; Self::SIZE] = slice[index..].try_into().ok()?;
+
+    let block: Option<&[u8; TagPlus::SIZE]> = index
+      .checked_sub(TagPlus::SIZE)
+      .and_then(|start| slice[start..index].try_into().ok());
+
+    Self::parse(bytes, block.and_then(TagPlus::from_bytes))
+  }
+
+  /// Parse an ID3v1 tag from the given 128-byte block.
+  ///
+  /// Returns `None` if the block does not start with the `"TAG"` identifier.
+  #[inline]
+  pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Option<Self> {
+    Self::parse(bytes, None)
+  }
+
+  fn parse(bytes: &[u8; Self::SIZE], enhanced: Option<(TagPlus, [&[u8]; 3])>) -> Option<Self> {
+    if bytes[..3] != Self::IDENTIFIER {
+      return None;
+    }
+
+    // ID3v1.1 stores the track number in the last byte of the comment,
+    // preceded by a NUL byte.
+    let (comment, track): (&[u8], Option<u8>) = match bytes[125..127] {
+      [0x00, track] if track != 0x00 => (&bytes[97..125], Some(track)),
+      _ => (&bytes[97..127], None),
+    };
+
+    let (enhanced, [title, artist, album]): (Option<TagPlus>, [&[u8]; 3]) = match enhanced {
+      Some((plus, fields)) => (Some(plus), fields),
+      None => (None, [&[], &[], &[]]),
+    };
+
+    Some(Self {
+      title: decode(&[&bytes[3..33], title].concat()),
+      artist: decode(&[&bytes[33..63], artist].concat()),
+      album: decode(&[&bytes[63..93], album].concat()),
+      year: decode(&bytes[93..97]),
+      comment: decode(comment),
+      track,
+      genre: bytes[127],
+      enhanced,
+    })
+  }
+}
+
+// =============================================================================
+// Enhanced Tag
+// =============================================================================
+
+/// An enhanced `TAG+` block, stored immediately before an ID3v1 tag.
+///
+/// The block extends the title, artist and album fields of the ID3v1 tag by
+/// 60 bytes each, the extended fields are merged into the [`TagV1`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TagPlus {
+  speed: Speed,
+  genre: String,
+  start_time: String,
+  end_time: String,
+}
+
+impl TagPlus {
+  /// Enhanced tag identifier.
+  pub const IDENTIFIER: [u8; 4] = *b"TAG+";
+
+  /// The size of the enhanced tag (in bytes).
+  pub const SIZE: usize = 227;
+
+  /// Get the speed of the audio.
+  #[inline]
+  pub const fn speed(&self) -> Speed {
+    self.speed
+  }
+
+  /// Get the free-form genre of the tag.
+  #[inline]
+  pub fn genre(&self) -> &str {
+    &self.genre
+  }
+
+  /// Get the start of the music in the audio (in the format `mmm:ss`).
+  #[inline]
+  pub fn start_time(&self) -> &str {
+    &self.start_time
+  }
+
+  /// Get the end of the music in the audio (in the format `mmm:ss`).
+  #[inline]
+  pub fn end_time(&self) -> &str {
+    &self.end_time
+  }
+
+  /// Parse an enhanced tag, returning the extended title, artist and album
+  /// fields alongside it.
+  ///
+  /// Returns `None` if the block is not a valid enhanced tag.
+  fn from_bytes(bytes: &[u8; Self::SIZE]) -> Option<(Self, [&[u8]; 3])> {
+    if bytes[..4] != Self::IDENTIFIER {
+      return None;
+    }
+
+    // Validate the fixed-format fields to avoid mistaking audio data for an
+    // enhanced tag.
+    let speed: Speed = Speed::from_u8(bytes[184])?;
+    let (start_time, end_time): (&[u8], &[u8]) = (&bytes[215..221], &bytes[221..227]);
+
+    if !is_time(start_time) || !is_time(end_time) {
+      return None;
+    }
+
+    let this: Self = Self {
+      speed,
+      genre: decode(&bytes[185..215]),
+      start_time: decode(start_time),
+      end_time: decode(end_time),
+    };
+
+    Some((this, [&bytes[4..64], &bytes[64..124], &bytes[124..184]]))
+  }
+}
+
+// =============================================================================
+// Speed
+// =============================================================================
+
+/// The speed of the audio, as stored in an enhanced tag.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Speed {
+  /// Not set.
+  Unset = 0x00,
+  /// Slow.
+  Slow = 0x01,
+  /// Medium.
+  Medium = 0x02,
+  /// Fast.
+  Fast = 0x03,
+  /// Hardcore.
+  Hardcore = 0x04,
+}
+
+impl Speed {
+  const fn from_u8(value: u8) -> Option<Self> {
+    match value {
+      0x00 => Some(Self::Unset),
+      0x01 => Some(Self::Slow),
+      0x02 => Some(Self::Medium),
+      0x03 => Some(Self::Fast),
+      0x04 => Some(Self::Hardcore),
+      _ => None,
+    }
+  }
+}
+
+/// Returns `true` if the `field` is an empty or `mmm:ss` time field.
+fn is_time(field: &[u8]) -> bool {
+  field
+    .iter()
+    .all(|byte| matches!(byte, b'0'..=b'9' | b':' | b' ' | 0x00))
+}
+
+/// Decode an ISO-8859-1 field, ignoring everything after the first NUL byte
+/// and trailing spaces.
+fn decode(field: &[u8]) -> String {
+  let field: &[u8] = match field.iter().position(|byte| *byte == 0x00) {
+    Some(index) => &field[..index],
+    None => field,
+  };
+
+  let field: String = field.iter().copied().map(char::from).collect();
+  field.trim_end_matches(' ').to_owned()
+}
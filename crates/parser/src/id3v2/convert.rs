@@ -0,0 +1,336 @@
+use alloc::borrow::Cow;
+use std::io::Write;
+
+use crate::content::Content;
+use crate::content::Ipls;
+use crate::content::Text;
+use crate::decode::Encoding;
+use crate::error::Result;
+use crate::id3v2::write;
+use crate::id3v2::HeaderFlags;
+use crate::options::WriteOptions;
+use crate::traits::IntoOwned;
+use crate::types::DynFrameId;
+use crate::types::FrameId;
+use crate::types::Version;
+
+/// Frame identifiers of ID3v2.2 and their ID3v2.3 equivalents.
+///
+/// The `CRM` frame has no ID3v2.3 equivalent and is not listed.
+pub const UPGRADE_V23: &[(&str, &str)] = &[
+  ("BUF", "RBUF"),
+  ("CNT", "PCNT"),
+  ("COM", "COMM"),
+  ("CRA", "AENC"),
+  ("ETC", "ETCO"),
+  ("EQU", "EQUA"),
+  ("GEO", "GEOB"),
+  ("IPL", "IPLS"),
+  ("LNK", "LINK"),
+  ("MCI", "MCDI"),
+  ("MLL", "MLLT"),
+  ("PIC", "APIC"),
+  ("POP", "POPM"),
+  ("REV", "RVRB"),
+  ("RVA", "RVAD"),
+  ("SLT", "SYLT"),
+  ("STC", "SYTC"),
+  ("TAL", "TALB"),
+  ("TBP", "TBPM"),
+  ("TCM", "TCOM"),
+  ("TCO", "TCON"),
+  ("TCR", "TCOP"),
+  ("TDA", "TDAT"),
+  ("TDY", "TDLY"),
+  ("TEN", "TENC"),
+  ("TFT", "TFLT"),
+  ("TIM", "TIME"),
+  ("TKE", "TKEY"),
+  ("TLA", "TLAN"),
+  ("TLE", "TLEN"),
+  ("TMT", "TMED"),
+  ("TOA", "TOPE"),
+  ("TOF", "TOFN"),
+  ("TOL", "TOLY"),
+  ("TOR", "TORY"),
+  ("TOT", "TOAL"),
+  ("TP1", "TPE1"),
+  ("TP2", "TPE2"),
+  ("TP3", "TPE3"),
+  ("TP4", "TPE4"),
+  ("TPA", "TPOS"),
+  ("TPB", "TPUB"),
+  ("TRC", "TSRC"),
+  ("TRD", "TRDA"),
+  ("TRK", "TRCK"),
+  ("TSI", "TSIZ"),
+  ("TSS", "TSSE"),
+  ("TT1", "TIT1"),
+  ("TT2", "TIT2"),
+  ("TT3", "TIT3"),
+  ("TXT", "TEXT"),
+  ("TXX", "TXXX"),
+  ("TYE", "TYER"),
+  ("UFI", "UFID"),
+  ("ULT", "USLT"),
+  ("WAF", "WOAF"),
+  ("WAR", "WOAR"),
+  ("WAS", "WOAS"),
+  ("WCM", "WCOM"),
+  ("WCP", "WCOP"),
+  ("WPB", "WPUB"),
+  ("WXX", "WXXX"),
+];
+
+/// Frame identifiers replaced when converting a tag from ID3v2.3 to ID3v2.4.
+///
+/// Frames mapped to `None` have no ID3v2.4 equivalent, frames not listed keep
+/// their identifier. The `TYER`, `TDAT` and `TIME` frames are merged into a
+/// single `TDRC` frame.
+pub const UPGRADE_V24: &[(&str, Option<&str>)] = &[
+  ("EQUA", None),
+  ("IPLS", Some("TIPL")),
+  ("RVAD", None),
+  ("TDAT", Some("TDRC")),
+  ("TIME", Some("TDRC")),
+  ("TORY", Some("TDOR")),
+  ("TRDA", None),
+  ("TSIZ", None),
+  ("TYER", Some("TDRC")),
+];
+
+/// Get the ID3v2.4 equivalent of the ID3v2.3 frame `identifier`.
+///
+/// Returns `None` if the frame has no ID3v2.4 equivalent.
+///
+/// See [`UPGRADE_V24`] for the identifiers that are replaced.
+pub fn upgrade_id(identifier: FrameId) -> Option<FrameId> {
+  let Some((_, target)) = UPGRADE_V24
+    .iter()
+    .find(|(source, _)| *source == identifier.as_str())
+  else {
+    return Some(identifier);
+  };
+
+  match DynFrameId::try_from((*target)?) {
+    Ok(DynFrameId::V3(target)) => Some(target),
+    Ok(DynFrameId::V2(_)) | Err(_) => None,
+  }
+}
+
+// =============================================================================
+// Upgrade
+// =============================================================================
+
+/// The frames of an ID3v2.3 tag converted to ID3v2.4.
+///
+/// This struct is created by the [`upgrade`][crate::id3v2::Tag::upgrade]
+/// method on [`tags`][crate::id3v2::Tag].
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Upgrade<'a> {
+  frames: Vec<(FrameId, Content<'a>)>,
+  dropped: Vec<FrameId>,
+}
+
+impl<'a> Upgrade<'a> {
+  /// Convert decoded ID3v2.3 `frames` to ID3v2.4.
+  ///
+  /// The `TYER`, `TDAT` and `TIME` frames are merged into a `TDRC` frame at
+  /// the position of the first of them, the `IPLS` frame is converted into a
+  /// `TIPL` frame and other frames are renamed as listed in [`UPGRADE_V24`].
+  /// Frames that cannot be converted are reported by [`dropped`][Self::dropped].
+  pub fn new<I>(frames: I) -> Self
+  where
+    I: IntoIterator<Item = (FrameId, Content<'a>)>,
+  {
+    let mut this: Self = Self {
+      frames: Vec::new(),
+      dropped: Vec::new(),
+    };
+
+    let mut date: DateFrames = DateFrames::default();
+
+    for (identifier, content) in frames {
+      match (identifier.as_str(), content) {
+        ("TYER" | "TDAT" | "TIME", Content::Text(text)) => {
+          date.insert(identifier, &text, this.frames.len());
+        }
+        ("IPLS", Content::Ipls(ipls)) => match upgrade_ipls(&ipls) {
+          Some(text) => this.push_as(identifier, Content::Text(text)),
+          None => this.dropped.push(identifier),
+        },
+        (_, content) => this.push_as(identifier, content),
+      }
+    }
+
+    if this.frames.iter().any(|(id, _)| id.as_str() == "TDRC") {
+      // Keep the existing TDRC frame.
+      date.drop_all();
+    } else if let Some((index, identifier, timestamp)) = date.timestamp() {
+      let text: Text<'_> = Text::new(Encoding::Latin1, Cow::Owned(timestamp));
+
+      match upgrade_id(identifier) {
+        Some(target) => this.frames.insert(index, (target, Content::Text(text))),
+        None => this.dropped.push(identifier),
+      }
+    }
+
+    this.dropped.extend(date.dropped);
+    this
+  }
+
+  /// Get the converted ID3v2.4 frames.
+  #[inline]
+  pub fn frames(&self) -> &[(FrameId, Content<'a>)] {
+    &self.frames
+  }
+
+  /// Get the identifiers of the frames without an ID3v2.4 equivalent.
+  #[inline]
+  pub fn dropped(&self) -> &[FrameId] {
+    &self.dropped
+  }
+
+  /// Write the converted frames as an ID3v2.4 tag to the given `writer`.
+  ///
+  /// See [`write_to_with`][Self::write_to_with] for details.
+  #[inline]
+  pub fn write_to<W>(&self, writer: W) -> Result<u64>
+  where
+    W: Write,
+  {
+    self.write_to_with(writer, WriteOptions::new())
+  }
+
+  /// Write the converted frames as an ID3v2.4 tag to the given `writer` with
+  /// custom `options`.
+  ///
+  /// Returns an [`Unencodable`][crate::error::ErrorKind::Unencodable] error if
+  /// the content of a frame cannot be encoded.
+  ///
+  /// Returns the number of bytes written.
+  pub fn write_to_with<W>(&self, writer: W, options: WriteOptions) -> Result<u64>
+  where
+    W: Write,
+  {
+    let mut frames: Vec<u8> = Vec::new();
+
+    for (identifier, content) in self.frames.iter() {
+      write::write_frame_v4(&mut frames, *identifier, content)?;
+    }
+
+    write::write_frames(
+      writer,
+      Version::ID3v24,
+      HeaderFlags::empty(),
+      None,
+      &frames,
+      options,
+    )
+  }
+
+  fn push_as(&mut self, identifier: FrameId, content: Content<'a>) {
+    match upgrade_id(identifier) {
+      Some(target) => self.frames.push((target, content)),
+      None => self.dropped.push(identifier),
+    }
+  }
+}
+
+/// Convert the `ipls` frame into a `TIPL` text frame.
+///
+/// The people list shares the layout of an ID3v2.4 multi-value text frame.
+fn upgrade_ipls(ipls: &Ipls<'_>) -> Option<Text<'static>> {
+  let id: FrameId = FrameId::try_from(*b"TIPL").ok()?;
+  let content: Content<'_> = Content::decode(Version::ID3v24, id, ipls.fixme()).ok()?;
+
+  content.into_owned().into_text()
+}
+
+// =============================================================================
+// Date Frames
+// =============================================================================
+
+/// The ID3v2.3 date frames merged into the ID3v2.4 `TDRC` frame.
+#[derive(Default)]
+struct DateFrames {
+  year: Option<(FrameId, String)>,
+  date: Option<(FrameId, String)>,
+  time: Option<(FrameId, String)>,
+  index: Option<usize>,
+  dropped: Vec<FrameId>,
+}
+
+impl DateFrames {
+  fn insert(&mut self, identifier: FrameId, text: &Text<'_>, index: usize) {
+    let slot: &mut Option<(FrameId, String)> = match identifier.as_str() {
+      "TYER" => &mut self.year,
+      "TDAT" => &mut self.date,
+      _ => &mut self.time,
+    };
+
+    // Only the first frame of each kind is merged.
+    if slot.is_some() {
+      self.dropped.push(identifier);
+      return;
+    }
+
+    *slot = Some((identifier, text.first().unwrap_or_default().to_owned()));
+    self.index.get_or_insert(index);
+  }
+
+  /// Build the `TDRC` timestamp (`yyyy-MM-ddTHH:mm`), dropping the frames
+  /// that cannot be represented.
+  fn timestamp(&mut self) -> Option<(usize, FrameId, String)> {
+    let (identifier, mut timestamp): (FrameId, String) = match self.year.take() {
+      Some((identifier, year)) if is_digits(&year) => (identifier, year),
+      year => {
+        self.year = year;
+        self.drop_all();
+        return None;
+      }
+    };
+
+    // TDAT is in the format DDMM.
+    match self.date.take() {
+      Some((_, date)) if is_digits(&date) => {
+        timestamp.push_str(&format!("-{}-{}", &date[2..], &date[..2]));
+      }
+      Some((identifier, _)) => {
+        self.dropped.push(identifier);
+        self.drop_time();
+      }
+      None => self.drop_time(),
+    }
+
+    // TIME is in the format HHMM.
+    match self.time.take() {
+      Some((_, time)) if is_digits(&time) => {
+        timestamp.push_str(&format!("T{}:{}", &time[..2], &time[2..]));
+      }
+      Some((identifier, _)) => self.dropped.push(identifier),
+      None => {}
+    }
+
+    Some((self.index?, identifier, timestamp))
+  }
+
+  fn drop_time(&mut self) {
+    if let Some((identifier, _)) = self.time.take() {
+      self.dropped.push(identifier);
+    }
+  }
+
+  fn drop_all(&mut self) {
+    for slot in [&mut self.year, &mut self.date, &mut self.time] {
+      if let Some((identifier, _)) = slot.take() {
+        self.dropped.push(identifier);
+      }
+    }
+  }
+}
+
+/// Returns `true` if the `text` consists of exactly four ASCII digits.
+fn is_digits(text: &str) -> bool {
+  text.len() == 4 && text.bytes().all(|byte| byte.is_ascii_digit())
+}
@@ -0,0 +1,83 @@
+use core::ops::Range;
+
+use crate::error::Result;
+use crate::id3v2::iter::RawFrameIter;
+use crate::id3v2::Header;
+use crate::id3v2::Tag;
+
+// =============================================================================
+// Digest Scope
+// =============================================================================
+
+/// The region of a tag fed to the hasher by [`Tag::raw_digest`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DigestScope {
+  /// The entire tag: header, extended header, frames, padding and footer.
+  Tag,
+  /// Only the frames, excluding the headers, padding and footer.
+  ///
+  /// Tags with the same frames but a different amount of padding produce the
+  /// same digest.
+  Frames,
+}
+
+// =============================================================================
+// Frame Region
+// =============================================================================
+
+/// Get the range of the frames in the on-disk bytes of the `tag`.
+///
+/// Returns an error if a frame cannot be parsed, see [`frames_len`].
+pub(crate) fn frames_range(tag: &Tag, raw: &[u8]) -> Result<Range<usize>> {
+  let header: &Header = tag.header();
+
+  let data: &[u8] = raw.get(Header::SIZE..).unwrap_or_default();
+
+  // The ID3v2.3 extended header may be unsynchronised.
+  let start: usize = match header.exheader() {
+    Some(exheader) => match header.ext_bytes(data, exheader.total_size() as usize) {
+      Ok(Some((_, consumed))) => Header::SIZE + consumed,
+      Ok(None) | Err(_) => Header::SIZE + exheader.total_size() as usize,
+    },
+    None => Header::SIZE,
+  };
+
+  let body: &[u8] = raw.get(start..).unwrap_or_default();
+  let length: usize = frames_len(tag)?;
+
+  let length: usize = if header.flag_unsynchronisation() {
+    unsync_len(body, length)
+  } else {
+    length.min(body.len())
+  };
+
+  Ok(start..start + length)
+}
+
+/// Get the length of the frames in the decoded tag buffer, up to the first
+/// padding byte.
+///
+/// Returns an error if a frame cannot be parsed before the end of the frames.
+pub(crate) fn frames_len(tag: &Tag) -> Result<usize> {
+  RawFrameIter::new(tag).try_fold(0, |length, item| item.map(|(_, raw)| length + raw.len()))
+}
+
+/// Get the number of unsynchronised bytes that decode to `length` bytes.
+fn unsync_len(input: &[u8], length: usize) -> usize {
+  let mut decoded: usize = 0;
+  let mut previous: u8 = 0x00;
+
+  for (index, byte) in input.iter().copied().enumerate() {
+    if decoded == length {
+      return index;
+    }
+
+    if !(previous == 0xFF && byte == 0x00) {
+      decoded += 1;
+    }
+
+    previous = byte;
+  }
+
+  input.len()
+}
@@ -0,0 +1,79 @@
+use core::ops::Range;
+
+use crate::content::Aenc;
+use crate::content::Encr;
+use crate::types::Slice;
+
+// =============================================================================
+// Audio Encryption
+// =============================================================================
+
+/// An audio encryption frame (`AENC`) and the encryption method registration
+/// (`ENCR`) with the same owner.
+///
+/// This struct is created by the
+/// [`audio_encryption`][crate::id3v2::Tag::audio_encryption] method on
+/// [`tags`][crate::id3v2::Tag].
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AudioEncryption<'a> {
+  aenc: Aenc<'a>,
+  method: Option<Encr<'a>>,
+  audio_length: Option<u32>,
+}
+
+impl<'a> AudioEncryption<'a> {
+  pub(crate) const fn new(
+    aenc: Aenc<'a>,
+    method: Option<Encr<'a>>,
+    audio_length: Option<u32>,
+  ) -> Self {
+    Self {
+      aenc,
+      method,
+      audio_length,
+    }
+  }
+
+  /// Get the owner identifier of the audio encryption frame.
+  #[inline]
+  pub fn owner(&self) -> &str {
+    self.aenc.owner_identifier()
+  }
+
+  /// Get the audio encryption frame.
+  #[inline]
+  pub const fn aenc(&self) -> &Aenc<'a> {
+    &self.aenc
+  }
+
+  /// Get the encryption method registration with a matching owner.
+  #[inline]
+  pub const fn method(&self) -> Option<&Encr<'a>> {
+    self.method.as_ref()
+  }
+
+  /// Get the encryption method data of the matching registration.
+  #[inline]
+  pub fn method_data(&self) -> Option<&Slice> {
+    self.method.as_ref().map(Encr::encryption_data)
+  }
+
+  /// Get the unencrypted preview of the audio (in frames).
+  #[inline]
+  pub fn preview(&self) -> Range<u32> {
+    let start: u32 = self.aenc.preview_start().into();
+    let length: u32 = self.aenc.preview_length().into();
+
+    start..start + length
+  }
+
+  /// Returns `true` if the preview ends within the audio.
+  ///
+  /// Returns `None` if the audio length was not supplied.
+  #[inline]
+  pub fn is_preview_valid(&self) -> Option<bool> {
+    self
+      .audio_length
+      .map(|audio_length| self.preview().end <= audio_length)
+  }
+}
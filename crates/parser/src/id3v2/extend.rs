@@ -1,9 +1,23 @@
 use bitflags::bitflags;
+use core::cmp::Reverse;
+use std::io::Cursor;
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
+use std::io::Write;
 
+use crate::decode::Encoding;
 use crate::error::Error;
 use crate::error::Result;
 use crate::error::TagField;
+use crate::error::Warning;
+use crate::error::WarningKind;
+use crate::options::ParseOptions;
+use crate::text::caseless_eq;
 use crate::traits::ReadExt;
+use crate::types::Bytes;
+use crate::types::Slice;
+use crate::types::Version;
+use crate::utils;
 
 // =============================================================================
 // Extended Header
@@ -17,6 +31,7 @@ pub struct ExtHeader {
   pad_size: u32,
   crc_data: Option<u32>,
   restrict: Option<Restrictions>,
+  flag_data: Vec<ExtFlagData>,
 }
 
 impl ExtHeader {
@@ -50,6 +65,20 @@ impl ExtHeader {
     self.restrict
   }
 
+  /// Get the data attached to each set flag, in the order it was stored.
+  ///
+  /// Note: Only applicable to ID3v2.4, earlier versions return an empty slice.
+  #[inline]
+  pub fn flag_data(&self) -> &[ExtFlagData] {
+    &self.flag_data
+  }
+
+  /// Get an iterator over the data attached to flags not defined by ID3v2.4.
+  #[inline]
+  pub fn unknown_flag_data(&self) -> impl Iterator<Item = &ExtFlagData> + '_ {
+    self.flag_data.iter().filter(|data| !data.is_known())
+  }
+
   /// Returns `true` if the `CRC_DATA_PRESENT` flag is set (and applicable).
   #[inline]
   pub const fn flag_crc(&self) -> bool {
@@ -77,21 +106,274 @@ impl ExtHeader {
     }
   }
 
+  /// Get the total size of the extended header as stored in the tag (in bytes).
+  ///
+  /// Note: The ID3v2.3 size descriptor does not include its own 4 bytes.
+  #[inline]
+  pub const fn total_size(&self) -> u32 {
+    match self.bitflags {
+      ExtHeaderFlags::V3(_) => self.ext_size + 4,
+      ExtHeaderFlags::V4(_) => self.ext_size,
+    }
+  }
+
+  /// Create a new `ExtHeaderBuilder`.
+  #[inline]
+  pub const fn builder() -> ExtHeaderBuilder {
+    ExtHeaderBuilder::new()
+  }
+
+  /// Write the extended header in ID3v2.3 form to the given `writer`.
+  ///
+  /// Only the padding size and CRC data can be represented, other flags are
+  /// not written.
+  ///
+  /// Returns the number of bytes written.
+  pub fn write_v3<W>(&self, mut writer: W) -> Result<usize>
+  where
+    W: Write,
+  {
+    let mut bitflags: ExtHeaderFlagsV3 = ExtHeaderFlagsV3::empty();
+    let mut ext_size: u32 = 0x06;
+
+    if self.crc_data.is_some() {
+      bitflags |= ExtHeaderFlagsV3::CRC_DATA_PRESENT;
+      ext_size += 4;
+    }
+
+    writer.write_all(&ext_size.to_be_bytes())?;
+    writer.write_all(&bitflags.bits().to_be_bytes())?;
+    writer.write_all(&self.pad_size.to_be_bytes())?;
+
+    if let Some(crc) = self.crc_data {
+      writer.write_all(&crc.to_be_bytes())?;
+    }
+
+    Ok(ext_size as usize + 4)
+  }
+
+  /// Write the extended header in ID3v2.4 form to the given `writer`.
+  ///
+  /// The data of each set flag is written in order of significance, including
+  /// the data of flags not defined by ID3v2.4.
+  ///
+  /// Returns the number of bytes written.
+  pub fn write_v4<W>(&self, mut writer: W) -> Result<usize>
+  where
+    W: Write,
+  {
+    let mut flag_data: Vec<(ExtHeaderFlagsV4, Vec<u8>)> = Vec::new();
+
+    if self.flag_update() {
+      flag_data.push((ExtHeaderFlagsV4::TAG_IS_UPDATE, Vec::new()));
+    }
+
+    if let Some(crc) = self.crc_data {
+      flag_data.push((
+        ExtHeaderFlagsV4::CRC_DATA_PRESENT,
+        utils::encode_u35_unsync(crc).to_vec(),
+      ));
+    }
+
+    if let Some(restrict) = self.restrict {
+      flag_data.push((ExtHeaderFlagsV4::TAG_RESTRICTIONS, vec![restrict.to_u8()]));
+    }
+
+    for data in self.unknown_flag_data() {
+      flag_data.push((data.flag, data.data.as_ref().to_vec()));
+    }
+
+    flag_data.sort_by_key(|(flag, _)| Reverse(*flag));
+
+    let mut bitflags: ExtHeaderFlagsV4 = ExtHeaderFlagsV4::empty();
+    let mut ext_size: usize = 0x06;
+
+    for (flag, data) in flag_data.iter() {
+      bitflags |= *flag;
+      ext_size += 1 + data.len();
+    }
+
+    writer.write_all(&utils::encode_u28_unsync(ext_size as u32))?;
+    writer.write_all(&[0x01, bitflags.bits()])?;
+
+    for (_, data) in flag_data.iter() {
+      writer.write_all(&[data.len() as u8])?;
+      writer.write_all(data)?;
+    }
+
+    Ok(ext_size)
+  }
+
+  /// Get a copy of the extended header for a tag rewritten with `pad_size`
+  /// bytes of padding.
+  ///
+  /// The ID3v2.4 CRC data covers the padding and is removed.
+  pub(crate) fn with_padding(&self, pad_size: u32) -> Self {
+    let mut this: Self = self.clone();
+
+    match this.bitflags {
+      ExtHeaderFlags::V3(_) => {
+        this.pad_size = pad_size;
+      }
+      ExtHeaderFlags::V4(_) => {
+        this.crc_data = None;
+        this
+          .flag_data
+          .retain(|data| data.flag != ExtHeaderFlagsV4::CRC_DATA_PRESENT);
+      }
+    }
+
+    this
+  }
+
   /// Parse an ID3v2.3 extended header from the given `reader`.
-  pub fn from_reader_v3<R>(mut reader: R) -> Result<Self>
+  pub fn from_reader_v3<R>(reader: R) -> Result<Self>
   where
     R: ReadExt,
   {
-    // Simple 32-bit unsigned integer
-    let ext_size: u32 = reader.read_u32()?;
+    Self::read(Version::ID3v23, reader)
+  }
 
-    // Must be 6 or 10 bytes
-    if !(ext_size == 0x06 || ext_size == 0x0A) {
-      return Err(Error::tag(TagField::ExtSize));
+  /// Parse an ID3v2.4 extended header from the given `reader`.
+  pub fn from_reader_v4<R>(reader: R) -> Result<Self>
+  where
+    R: ReadExt,
+  {
+    Self::read(Version::ID3v24, reader)
+  }
+
+  /// Parse an ID3v2.3 extended header from the given `slice`.
+  ///
+  /// Returns the extended header and the number of bytes consumed.
+  pub fn from_slice_v3(slice: &[u8]) -> Result<(Self, usize)> {
+    Self::parse_slice(
+      Version::ID3v23,
+      slice,
+      ParseOptions::new(),
+      u32::MAX,
+      &mut Vec::new(),
+    )
+  }
+
+  /// Parse an ID3v2.4 extended header from the given `slice`.
+  ///
+  /// Returns the extended header and the number of bytes consumed.
+  pub fn from_slice_v4(slice: &[u8]) -> Result<(Self, usize)> {
+    Self::parse_slice(
+      Version::ID3v24,
+      slice,
+      ParseOptions::new(),
+      u32::MAX,
+      &mut Vec::new(),
+    )
+  }
+
+  fn read<R>(version: Version, mut reader: R) -> Result<Self>
+  where
+    R: ReadExt,
+  {
+    let size: [u8; 4] = ReadExt::read_array(&mut reader)?;
+    let total: usize = Self::parse_len(version, size, ParseOptions::new(), u32::MAX)?;
+
+    let mut buffer: Vec<u8> = size.to_vec();
+    buffer.extend_from_slice(reader.read_bytes(total - size.len())?.as_ref());
+
+    Self::parse(version, &buffer, ParseOptions::new(), &mut Vec::new())
+  }
+
+  pub(crate) fn parse_slice(
+    version: Version,
+    slice: &[u8],
+    options: ParseOptions,
+    limit: u32,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<(Self, usize)> {
+    let size: [u8; 4] = ReadExt::read_array(&mut Cursor::new(slice))?;
+    let total: usize = Self::parse_len(version, size, options, limit)?;
+
+    let Some(buffer) = slice.get(..total) else {
+      return Err(IoError::from(IoErrorKind::UnexpectedEof).into());
+    };
+
+    Self::parse(version, buffer, options, warnings).map(|this| (this, total))
+  }
+
+  /// Validate the extended header size descriptor and return the total number
+  /// of bytes used by the extended header, including the descriptor itself.
+  pub(crate) fn parse_len(
+    version: Version,
+    bytes: [u8; 4],
+    options: ParseOptions,
+    limit: u32,
+  ) -> Result<usize> {
+    match version {
+      Version::ID3v23 => {
+        // Simple 32-bit unsigned integer
+        let ext_size: u32 = u32::from_be_bytes(bytes);
+
+        // Must be 6 or 10 bytes - lenient mode accepts anything that fits.
+        if options.is_lenient() {
+          if ext_size < 0x06 {
+            return Err(Error::tag(TagField::ExtSize));
+          }
+        } else if !(ext_size == 0x06 || ext_size == 0x0A) {
+          return Err(Error::tag(TagField::ExtSize));
+        }
+
+        // The size descriptor is not included in the declared size.
+        if ext_size > limit.saturating_sub(4) {
+          return Err(Error::tag(TagField::ExtSize));
+        }
+
+        Ok(ext_size as usize + 4)
+      }
+      Version::ID3v24 => {
+        // 28-bit "unsynchronized" integer.
+        let ext_size: u32 = utils::decode_u28_unsync(bytes);
+
+        // Must be at least 6 bytes - the upper bound depends on the set flags.
+        if ext_size < 0x06 {
+          return Err(Error::tag(TagField::ExtSize));
+        }
+
+        // The size descriptor is included in the declared size.
+        if ext_size > limit {
+          return Err(Error::tag(TagField::ExtSize));
+        }
+
+        Ok(ext_size as usize)
+      }
+      _ => Err(Error::tag(TagField::Version)),
     }
+  }
+
+  /// Parse an extended header from a `slice` containing exactly the number of
+  /// bytes returned by [`parse_len`][Self::parse_len].
+  pub(crate) fn parse(
+    version: Version,
+    slice: &[u8],
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Self> {
+    let mut reader: Cursor<&Slice> = Slice::new(slice).cursor();
+
+    match version {
+      Version::ID3v23 => Self::parse_v3(&mut reader, options, warnings),
+      Version::ID3v24 => Self::parse_v4(&mut reader, options, warnings),
+      _ => Err(Error::tag(TagField::Version)),
+    }
+  }
+
+  fn parse_v3(
+    reader: &mut Cursor<&Slice>,
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Self> {
+    // Simple 32-bit unsigned integer
+    let ext_size: u32 = reader.read_u32()?;
 
     // 2 bytes - only 1 valid flag value.
-    let bitflags: ExtHeaderFlags = ExtHeaderFlags::from_reader_v3(&mut reader)?;
+    let bitflags: ExtHeaderFlags = ExtHeaderFlags::from_reader_v3(reader)?;
 
     let mut this: Self = Self {
       ext_size,
@@ -99,6 +381,7 @@ impl ExtHeader {
       pad_size: reader.read_u32()?,
       crc_data: None,
       restrict: None,
+      flag_data: Vec::new(),
     };
 
     // If the CRC flag is set, read the 4-byte value.
@@ -106,29 +389,27 @@ impl ExtHeader {
       this.crc_data = Some(reader.read_u32()?);
     }
 
+    // The size descriptor is not included in the declared size.
+    this.resync(reader.position() as u32 - 4, options, warnings)?;
+
     Ok(this)
   }
 
-  /// Parse an ID3v2.4 extended header from the given `reader`.
-  pub fn from_reader_v4<R>(mut reader: R) -> Result<Self>
-  where
-    R: ReadExt,
-  {
+  fn parse_v4(
+    reader: &mut Cursor<&Slice>,
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Self> {
     // 28-bit "unsynchronized" integer.
     let ext_size: u32 = reader.read_u28_unsync()?;
 
-    // Must be between 6 and 15 bytes.
-    if !(0x06..=0x0F).contains(&ext_size) {
-      return Err(Error::tag(TagField::ExtSize));
-    }
-
     // Number of flag bytes - must be "1"
     if reader.read_u8()? != 0x01 {
       return Err(Error::tag(TagField::ExtFlagSize));
     }
 
     // 1 byte for extended header flags.
-    let bitflags: ExtHeaderFlags = ExtHeaderFlags::from_reader_v4(&mut reader)?;
+    let bitflags: ExtHeaderFlags = ExtHeaderFlags::from_reader_v4(reader)?;
 
     let mut this: Self = Self {
       ext_size,
@@ -136,35 +417,243 @@ impl ExtHeader {
       pad_size: 0,
       crc_data: None,
       restrict: None,
+      flag_data: Vec::new(),
     };
 
-    if this.flag_update() {
-      // Flag data length must be 0x00.
-      if reader.read_u8()? != 0x00 {
-        return Err(Error::tag(TagField::ExtFlagData));
+    let ExtHeaderFlags::V4(inner) = bitflags else {
+      unreachable!();
+    };
+
+    // Every set flag is followed by its own data, starting with the most
+    // significant bit. Unknown flags are kept so their data is not lost.
+    for index in (0..u8::BITS).rev() {
+      let flag: ExtHeaderFlagsV4 = ExtHeaderFlagsV4::from_bits_retain(1 << index);
+
+      if !inner.contains(flag) {
+        continue;
       }
+
+      let size: u8 = reader.read_u8()?;
+      let data: Bytes = reader.read_bytes(size as usize)?;
+
+      this.set_flag_data(flag, data, options, warnings)?;
     }
 
-    if this.flag_crc() {
-      // Flag data length must be 0x05.
-      if reader.read_u8()? != 0x05 {
+    this.resync(reader.position() as u32, options, warnings)?;
+
+    Ok(this)
+  }
+
+  fn set_flag_data(
+    &mut self,
+    flag: ExtHeaderFlagsV4,
+    data: Bytes,
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<()> {
+    let this: ExtFlagData = ExtFlagData { flag, data };
+
+    // Flag data length must match the spec for the flags we understand.
+    if this.len() != this.expected_len().unwrap_or(this.len()) {
+      if !options.is_lenient() {
         return Err(Error::tag(TagField::ExtFlagData));
       }
 
+      warnings.push(Warning::new(WarningKind::ExtFlagData {
+        flag: flag.bits(),
+        size: this.len() as u8,
+      }));
+    } else if flag == ExtHeaderFlagsV4::CRC_DATA_PRESENT {
+      let mut bytes: [u8; 5] = [0; 5];
+      bytes.copy_from_slice(this.data().as_ref());
+
       // 35-bit "unsynchronized" integer.
-      this.crc_data = Some(reader.read_u35_unsync()?);
+      self.crc_data = Some(utils::decode_u35_unsync(bytes));
+    } else if flag == ExtHeaderFlagsV4::TAG_RESTRICTIONS {
+      self.restrict = Some(Restrictions::from_u8(this.data().as_ref()[0]));
     }
 
-    if this.flag_restrictions() {
-      // Flag data length must be 0x01.
-      if reader.read_u8()? != 0x01 {
-        return Err(Error::tag(TagField::ExtFlagData));
-      }
+    self.flag_data.push(this);
 
-      this.restrict = Some(Restrictions::from_reader(&mut reader)?);
+    Ok(())
+  }
+
+  fn resync(
+    &self,
+    consumed: u32,
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<()> {
+    if consumed == self.ext_size {
+      return Ok(());
     }
 
-    Ok(this)
+    if !options.is_lenient() {
+      return Err(Error::tag(TagField::ExtSize));
+    }
+
+    warnings.push(Warning::new(WarningKind::ExtHeaderSize {
+      declared: self.ext_size,
+      consumed,
+    }));
+
+    Ok(())
+  }
+}
+
+// =============================================================================
+// Extended Header Builder
+// =============================================================================
+
+/// A builder for [`ExtHeader`] values.
+///
+/// This struct is created by the [`builder`][ExtHeader::builder] method on
+/// [`ExtHeader`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExtHeaderBuilder {
+  update: bool,
+  pad_size: u32,
+  crc_data: Option<u32>,
+  restrict: Option<Restrictions>,
+}
+
+impl ExtHeaderBuilder {
+  /// Create a new `ExtHeaderBuilder` without any flags set.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      update: false,
+      pad_size: 0,
+      crc_data: None,
+      restrict: None,
+    }
+  }
+
+  /// Set whether or not the tag is an update (ID3v2.4 only).
+  #[inline]
+  pub const fn update(mut self, value: bool) -> Self {
+    self.update = value;
+    self
+  }
+
+  /// Set the padding size (ID3v2.3 only).
+  #[inline]
+  pub const fn padding(mut self, value: u32) -> Self {
+    self.pad_size = value;
+    self
+  }
+
+  /// Set the CRC-32 data.
+  #[inline]
+  pub const fn crc(mut self, value: u32) -> Self {
+    self.crc_data = Some(value);
+    self
+  }
+
+  /// Set the tag restrictions (ID3v2.4 only).
+  #[inline]
+  pub const fn restrictions(mut self, value: Restrictions) -> Self {
+    self.restrict = Some(value);
+    self
+  }
+
+  /// Build an extended header for the given `version`.
+  ///
+  /// Options not applicable to the `version` are ignored.
+  pub fn build(self, version: Version) -> Result<ExtHeader> {
+    let mut this: ExtHeader = match version {
+      Version::ID3v23 => ExtHeader {
+        ext_size: 0,
+        bitflags: ExtHeaderFlags::V3(ExtHeaderFlagsV3::empty()),
+        pad_size: self.pad_size,
+        crc_data: self.crc_data,
+        restrict: None,
+        flag_data: Vec::new(),
+      },
+      Version::ID3v24 => ExtHeader {
+        ext_size: 0,
+        bitflags: ExtHeaderFlags::V4(ExtHeaderFlagsV4::empty()),
+        pad_size: 0,
+        crc_data: self.crc_data,
+        restrict: self.restrict,
+        flag_data: Vec::new(),
+      },
+      _ => return Err(Error::tag(TagField::Version)),
+    };
+
+    if self.update && version == Version::ID3v24 {
+      this.bitflags = ExtHeaderFlags::V4(ExtHeaderFlagsV4::TAG_IS_UPDATE);
+    }
+
+    // Parse the serialized form to fill in the sizes, flags and flag data.
+    let mut buffer: Vec<u8> = Vec::new();
+
+    match version {
+      Version::ID3v23 => this.write_v3(&mut buffer)?,
+      _ => this.write_v4(&mut buffer)?,
+    };
+
+    ExtHeader::parse(version, &buffer, ParseOptions::new(), &mut Vec::new())
+  }
+}
+
+impl Default for ExtHeaderBuilder {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// =============================================================================
+// Extended Header Flag Data
+// =============================================================================
+
+/// The data attached to a single ID3v2.4 extended header flag.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExtFlagData {
+  flag: ExtHeaderFlagsV4,
+  data: Bytes,
+}
+
+impl ExtFlagData {
+  /// Get the flag this data is attached to.
+  #[inline]
+  pub const fn flag(&self) -> ExtHeaderFlagsV4 {
+    self.flag
+  }
+
+  /// Get the raw flag data.
+  #[inline]
+  pub const fn data(&self) -> &Slice {
+    self.data.as_slice()
+  }
+
+  /// Get the length of the flag data (in bytes).
+  #[inline]
+  pub const fn len(&self) -> usize {
+    self.data.as_slice().len()
+  }
+
+  /// Returns `true` if the flag has no data.
+  #[inline]
+  pub const fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Returns `true` if the flag is defined by ID3v2.4.
+  #[inline]
+  pub const fn is_known(&self) -> bool {
+    self.expected_len().is_some()
+  }
+
+  /// Get the length of the flag data required by ID3v2.4 (if known).
+  pub const fn expected_len(&self) -> Option<usize> {
+    match self.flag {
+      ExtHeaderFlagsV4::TAG_IS_UPDATE => Some(0x00),
+      ExtHeaderFlagsV4::CRC_DATA_PRESENT => Some(0x05),
+      ExtHeaderFlagsV4::TAG_RESTRICTIONS => Some(0x01),
+      _ => None,
+    }
   }
 }
 
@@ -278,19 +767,40 @@ impl Restrictions {
     self.image_len
   }
 
-  fn from_reader<R>(reader: &mut R) -> Result<Self>
-  where
-    R: ReadExt,
-  {
-    let byte: u8 = reader.read_u8()?;
+  /// Create a new set of `Restrictions`.
+  #[inline]
+  pub const fn new(
+    tag_size: TagSizeRestriction,
+    text_enc: TextEncRestriction,
+    text_len: TextLenRestriction,
+    image_enc: ImageEncRestriction,
+    image_len: ImageLenRestriction,
+  ) -> Self {
+    Self {
+      tag_size,
+      text_enc,
+      text_len,
+      image_enc,
+      image_len,
+    }
+  }
 
-    Ok(Self {
+  const fn to_u8(self) -> u8 {
+    self.tag_size as u8
+      | self.text_enc as u8
+      | self.text_len as u8
+      | self.image_enc as u8
+      | self.image_len as u8
+  }
+
+  const fn from_u8(byte: u8) -> Self {
+    Self {
       tag_size: TagSizeRestriction::from_u8(byte),
       text_enc: TextEncRestriction::from_u8(byte),
       text_len: TextLenRestriction::from_u8(byte),
       image_enc: ImageEncRestriction::from_u8(byte),
       image_len: ImageLenRestriction::from_u8(byte),
-    })
+    }
   }
 }
 
@@ -312,6 +822,27 @@ impl TagSizeRestriction {
   /// The bitmask applicable to these restrictions.
   pub const MASK: u8 = 0b11000000;
 
+  /// Get the maximum number of frames in the tag.
+  #[inline]
+  pub const fn max_frames(self) -> usize {
+    match self {
+      Self::R1 => 128,
+      Self::R2 => 64,
+      Self::R3 | Self::R4 => 32,
+    }
+  }
+
+  /// Get the maximum total size of the tag (in bytes).
+  #[inline]
+  pub const fn max_size(self) -> u64 {
+    match self {
+      Self::R1 => 1024 * 1024,
+      Self::R2 => 128 * 1024,
+      Self::R3 => 40 * 1024,
+      Self::R4 => 4 * 1024,
+    }
+  }
+
   const fn from_u8(value: u8) -> Self {
     match value & Self::MASK {
       0b00000000 => Self::R1,
@@ -337,6 +868,28 @@ impl TextEncRestriction {
   /// The bitmask applicable to these restrictions.
   pub const MASK: u8 = 0b00100000;
 
+  /// Returns `true` if strings may be stored with the given `encoding`.
+  #[inline]
+  pub const fn permits(self, encoding: Encoding) -> bool {
+    match self {
+      Self::None => true,
+      Self::Some => matches!(encoding, Encoding::Latin1 | Encoding::Utf8),
+    }
+  }
+
+  /// Select a permitted encoding for strings currently stored with the given
+  /// `encoding`.
+  ///
+  /// UTF-16 is replaced with UTF-8, which can represent the same text.
+  #[inline]
+  pub const fn select(self, encoding: Encoding) -> Encoding {
+    if self.permits(encoding) {
+      encoding
+    } else {
+      Encoding::Utf8
+    }
+  }
+
   const fn from_u8(value: u8) -> Self {
     match value & Self::MASK {
       0b00000000 => Self::None,
@@ -364,6 +917,17 @@ impl TextLenRestriction {
   /// The bitmask applicable to these restrictions.
   pub const MASK: u8 = 0b00011000;
 
+  /// Get the maximum length of a string (in characters), if restricted.
+  #[inline]
+  pub const fn max_chars(self) -> Option<usize> {
+    match self {
+      Self::R1 => None,
+      Self::R2 => Some(1024),
+      Self::R3 => Some(128),
+      Self::R4 => Some(30),
+    }
+  }
+
   const fn from_u8(value: u8) -> Self {
     match value & Self::MASK {
       0b00000000 => Self::R1,
@@ -389,6 +953,19 @@ impl ImageEncRestriction {
   /// The bitmask applicable to these restrictions.
   pub const MASK: u8 = 0b00000100;
 
+  /// Returns `true` if images may be stored with the given `mime_type`.
+  ///
+  /// MIME types are compared with [`caseless_eq`].
+  #[inline]
+  pub fn permits(self, mime_type: &str) -> bool {
+    match self {
+      Self::None => true,
+      Self::Some => ["image/png", "image/jpeg", "image/jpg"]
+        .iter()
+        .any(|permitted| caseless_eq(permitted, mime_type)),
+    }
+  }
+
   const fn from_u8(value: u8) -> Self {
     match value & Self::MASK {
       0b00000000 => Self::None,
@@ -416,6 +993,17 @@ impl ImageLenRestriction {
   /// The bitmask applicable to these restrictions.
   pub const MASK: u8 = 0b00000011;
 
+  /// Returns `true` if images may have the given dimensions (in pixels).
+  #[inline]
+  pub const fn permits(self, width: u32, height: u32) -> bool {
+    match self {
+      Self::R1 => true,
+      Self::R2 => width <= 256 && height <= 256,
+      Self::R3 => width <= 64 && height <= 64,
+      Self::R4 => width == 64 && height == 64,
+    }
+  }
+
   const fn from_u8(value: u8) -> Self {
     match value & Self::MASK {
       0b00000000 => Self::R1,
@@ -0,0 +1,382 @@
+use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::ErrorKind as IoErrorKind;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::content::Content;
+use crate::content::ContentKind;
+use crate::content::ImgType;
+use crate::error::Result;
+use crate::id3v2::Tag;
+use crate::text::caseless_eq;
+
+/// The maximum length of a sanitized filename stem (in bytes).
+const MAX_STEM: usize = 64;
+
+/// MIME types of documents and their file extensions, images are recognized
+/// by [`ImgType::from_mime`].
+const MIME: &[(&str, &str)] = &[
+  ("application/pdf", "pdf"),
+  ("application/zip", "zip"),
+  ("text/plain", "txt"),
+];
+
+// =============================================================================
+// Extract Options
+// =============================================================================
+
+/// Options controlling which embedded binary objects are extracted.
+///
+/// By default every `APIC`, `COMR`, `GEOB`, `MCDI` and `PRIV` payload is
+/// extracted without any size limits.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExtractOptions {
+  kinds: Vec<ContentKind>,
+  max_file_size: Option<usize>,
+  max_total_size: Option<usize>,
+}
+
+impl ExtractOptions {
+  /// Create a new set of default `ExtractOptions`.
+  pub fn new() -> Self {
+    Self {
+      kinds: vec![
+        ContentKind::Apic,
+        ContentKind::Comr,
+        ContentKind::Geob,
+        ContentKind::Mcdi,
+        ContentKind::Priv,
+      ],
+      max_file_size: None,
+      max_total_size: None,
+    }
+  }
+
+  /// Set the kinds of frame content to extract.
+  ///
+  /// Kinds without a binary payload are ignored.
+  pub fn kinds<I>(mut self, kinds: I) -> Self
+  where
+    I: IntoIterator<Item = ContentKind>,
+  {
+    self.kinds = kinds.into_iter().collect();
+    self
+  }
+
+  /// Set the maximum size of a single extracted file (in bytes).
+  ///
+  /// Larger payloads are skipped.
+  #[inline]
+  pub const fn max_file_size(mut self, value: usize) -> Self {
+    self.max_file_size = Some(value);
+    self
+  }
+
+  /// Set the maximum size of all extracted files combined (in bytes).
+  ///
+  /// Payloads that would exceed the remaining budget are skipped.
+  #[inline]
+  pub const fn max_total_size(mut self, value: usize) -> Self {
+    self.max_total_size = Some(value);
+    self
+  }
+
+  /// Returns `true` if content of the given `kind` is extracted.
+  #[inline]
+  pub fn includes(&self, kind: ContentKind) -> bool {
+    self.kinds.contains(&kind)
+  }
+
+  /// Get the maximum size of a single extracted file (in bytes).
+  #[inline]
+  pub const fn file_size_limit(&self) -> Option<usize> {
+    self.max_file_size
+  }
+
+  /// Get the maximum size of all extracted files combined (in bytes).
+  #[inline]
+  pub const fn total_size_limit(&self) -> Option<usize> {
+    self.max_total_size
+  }
+}
+
+impl Default for ExtractOptions {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// =============================================================================
+// Extracted File
+// =============================================================================
+
+/// A binary object written by [`Tag::extract_binaries`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExtractedFile {
+  identifier: String,
+  path: PathBuf,
+  size: usize,
+}
+
+impl ExtractedFile {
+  /// Get the identifier of the frame containing the object.
+  #[inline]
+  pub fn identifier(&self) -> &str {
+    &self.identifier
+  }
+
+  /// Get the path the object was written to.
+  #[inline]
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  /// Get the size of the object (in bytes).
+  #[inline]
+  pub const fn size(&self) -> usize {
+    self.size
+  }
+}
+
+// =============================================================================
+// Extraction
+// =============================================================================
+
+/// A binary payload and the hints used to name it.
+struct Payload<'a> {
+  data: &'a [u8],
+  name: &'a str,
+  mime: &'a str,
+  extension: &'a str,
+}
+
+pub(crate) fn extract(
+  tag: &Tag,
+  dir: &Path,
+  options: &ExtractOptions,
+) -> Result<Vec<ExtractedFile>> {
+  let mut output: Vec<ExtractedFile> = Vec::new();
+  let mut budget: Option<usize> = options.max_total_size;
+
+  fs::create_dir_all(dir)?;
+
+  // Frames that cannot be parsed, decoded or loaded are skipped so the files
+  // already written are always returned in the manifest.
+  for frame in tag.frames_recovering().filter_map(Result::ok) {
+    let Ok(content) = tag.decode_frame(&frame, &mut Vec::new()) else {
+      continue;
+    };
+
+    if !options.includes(content.kind()) {
+      continue;
+    }
+
+    let Ok(Some(payload)) = payload(tag, &content) else {
+      continue;
+    };
+
+    let size: usize = payload.data.len();
+
+    if size == 0 || options.max_file_size.is_some_and(|limit| size > limit) {
+      continue;
+    }
+
+    if budget.is_some_and(|remaining| size > remaining) {
+      continue;
+    }
+
+    let identifier: &str = frame.identifier_str();
+    let stem: String = filename_stem(identifier, payload.name);
+    let extension: &str = extension(&payload);
+    let (path, mut file): (PathBuf, File) = create_unique(dir, &stem, extension)?;
+
+    // Don't leave a partial file behind that is missing from the manifest.
+    if let Err(error) = file.write_all(payload.data) {
+      let _ = fs::remove_file(&path);
+      return Err(error.into());
+    }
+
+    budget = budget.map(|remaining| remaining - size);
+
+    output.push(ExtractedFile {
+      identifier: identifier.to_owned(),
+      path,
+      size,
+    });
+  }
+
+  Ok(output)
+}
+
+fn payload<'a>(tag: &'a Tag, content: &'a Content<'_>) -> Result<Option<Payload<'a>>> {
+  let payload: Payload<'a> = match content {
+    // Linked pictures are not stored in the tag.
+    Content::Apic(inner) if inner.image_format() == ImgType::Link => {
+      return Ok(None);
+    }
+    Content::Apic(inner) => Payload {
+      data: inner.load_picture_data(tag)?.as_ref(),
+      name: inner.description(),
+      mime: inner.image_format().mime_type(),
+      extension: "",
+    },
+    Content::Comr(inner) => {
+      let Some(logo) = inner.seller_logo() else {
+        return Ok(None);
+      };
+
+      Payload {
+        data: logo.as_ref(),
+        name: inner.seller_name(),
+        mime: inner.mime_type().unwrap_or_default(),
+        extension: "",
+      }
+    }
+    Content::Geob(inner) => {
+      let (stem, extension): (&str, &str) = split_extension(inner.filename());
+
+      Payload {
+        data: inner.load_encapsulated_object(tag)?.as_ref(),
+        name: if stem.is_empty() {
+          inner.content_description()
+        } else {
+          stem
+        },
+        mime: inner.mime_type(),
+        extension,
+      }
+    }
+    Content::Mcdi(inner) => Payload {
+      data: inner.data().as_ref(),
+      name: "",
+      mime: "",
+      extension: "",
+    },
+    Content::Priv(inner) => Payload {
+      data: inner.load_private_data(tag)?.as_ref(),
+      name: inner.owner_identifier(),
+      mime: "",
+      extension: "",
+    },
+    _ => return Ok(None),
+  };
+
+  Ok(Some(payload))
+}
+
+/// Create a new file in `dir`, appending a counter to `stem` until the name
+/// does not collide with an existing file.
+fn create_unique(dir: &Path, stem: &str, extension: &str) -> Result<(PathBuf, File)> {
+  let mut index: usize = 1;
+
+  loop {
+    let name: String = if index == 1 {
+      format!("{stem}.{extension}")
+    } else {
+      format!("{stem}-{index}.{extension}")
+    };
+
+    let path: PathBuf = dir.join(name);
+
+    match OpenOptions::new().write(true).create_new(true).open(&path) {
+      Ok(file) => return Ok((path, file)),
+      Err(error) if error.kind() == IoErrorKind::AlreadyExists => index += 1,
+      Err(error) => return Err(error.into()),
+    }
+  }
+}
+
+/// Build a filename stem that is safe to join to the output directory.
+///
+/// Only ASCII alphanumerics, `-` and `_` are kept, which rules out path
+/// separators, parent directory components and hidden files.
+fn filename_stem(identifier: &str, name: &str) -> String {
+  let mut output: String = sanitize(identifier);
+  let name: String = sanitize(name);
+
+  if !name.is_empty() {
+    output.push('-');
+    output.push_str(&name);
+  }
+
+  output.truncate(MAX_STEM);
+  output
+}
+
+fn sanitize(input: &str) -> String {
+  let mut output: String = String::with_capacity(input.len());
+
+  for ch in input.chars() {
+    let ch: char = if ch.is_ascii_alphanumeric() || ch == '-' {
+      ch
+    } else {
+      '_'
+    };
+
+    // Collapse runs of replaced characters.
+    if ch == '_' && output.ends_with('_') {
+      continue;
+    }
+
+    output.push(ch);
+  }
+
+  output.trim_matches('_').to_owned()
+}
+
+fn split_extension(filename: &str) -> (&str, &str) {
+  match filename.rsplit_once('.') {
+    Some((stem, extension)) => (stem, extension),
+    None => (filename, ""),
+  }
+}
+
+/// Choose a file extension from the payload contents, MIME type or the
+/// original filename.
+fn extension<'a>(payload: &Payload<'a>) -> &'a str {
+  if let Some(extension) = ImgType::sniff(payload.data).and_then(|format| format.extension()) {
+    return extension;
+  }
+
+  if let Some(extension) = sniff(payload.data) {
+    return extension;
+  }
+
+  if let Some(extension) = ImgType::from_mime(payload.mime).and_then(|format| format.extension()) {
+    return extension;
+  }
+
+  let document: Option<&(&str, &str)> = MIME
+    .iter()
+    .find(|(mime, _)| caseless_eq(mime, payload.mime));
+
+  if let Some((_, extension)) = document {
+    return extension;
+  }
+
+  if !payload.extension.is_empty()
+    && payload.extension.len() <= 8
+    && payload
+      .extension
+      .bytes()
+      .all(|byte| byte.is_ascii_alphanumeric())
+  {
+    return payload.extension;
+  }
+
+  "bin"
+}
+
+/// Get the file extension of a document from its magic bytes, images are
+/// recognized by [`ImgType::sniff`].
+fn sniff(data: &[u8]) -> Option<&'static str> {
+  match data {
+    [b'%', b'P', b'D', b'F', ..] => Some("pdf"),
+    [b'P', b'K', 0x03, 0x04, ..] => Some("zip"),
+    _ => None,
+  }
+}
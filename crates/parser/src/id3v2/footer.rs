@@ -0,0 +1,144 @@
+use std::io::Cursor;
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::error::TagField;
+use crate::id3v2::Header;
+use crate::id3v2::HeaderFlags;
+use crate::traits::ReadExt;
+use crate::types::Version;
+
+// =============================================================================
+// Footer
+// =============================================================================
+
+/// A parsed ID3v2.4 footer.
+///
+/// The footer is a copy of the header with a different identifier, appended
+/// to the tag to allow locating it when scanning from the end of a file.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Footer {
+  version: Version,
+  bitflags: HeaderFlags,
+  data_len: u32,
+}
+
+impl Footer {
+  /// ID3 tag footer identifier.
+  pub const IDENTIFIER: [u8; 3] = Header::FOOTER_IDENTIFIER;
+
+  /// The size of the footer (in bytes).
+  pub const SIZE: usize = 10;
+
+  /// Get the ID3 tag version.
+  #[inline]
+  pub const fn version(&self) -> Version {
+    self.version
+  }
+
+  /// Get the ID3 tag bitflags.
+  #[inline]
+  pub const fn bitflags(&self) -> HeaderFlags {
+    self.bitflags
+  }
+
+  /// Get the ID3 tag size (in bytes).
+  ///
+  /// Note: This includes the extended header (if any), but not the header or
+  /// the footer.
+  #[inline]
+  pub const fn data_len(&self) -> u32 {
+    self.data_len
+  }
+
+  /// Get the total size of the tag (in bytes), including the header and the
+  /// footer.
+  #[inline]
+  pub const fn tag_len(&self) -> u64 {
+    (Header::SIZE + Self::SIZE) as u64 + self.data_len as u64
+  }
+
+  /// Returns `true` if the footer agrees with the given `header`.
+  pub fn matches(&self, header: &Header) -> bool {
+    self.version == header.version()
+      && self.bitflags == header.bitflags()
+      && self.data_len == header.raw_data_len()
+  }
+
+  /// Parse an ID3v2 tag footer from the given `reader`.
+  pub fn from_reader<R>(mut reader: R) -> Result<Self>
+  where
+    R: ReadExt,
+  {
+    Self::from_bytes(ReadExt::read_array(&mut reader)?)
+  }
+
+  /// Parse an ID3v2 tag footer from the 10 bytes preceding the current
+  /// position of the given `reader`.
+  ///
+  /// Returns `None` if the bytes are not a footer, in which case the reader is
+  /// returned to its original position. Otherwise the reader is left at the
+  /// start of the tag described by the footer.
+  pub fn from_reader_backwards<R>(reader: &mut R) -> Result<Option<Self>>
+  where
+    R: Read + Seek + ?Sized,
+  {
+    let position: u64 = reader.stream_position()?;
+
+    let Some(start) = position.checked_sub(Self::SIZE as u64) else {
+      return Ok(None);
+    };
+
+    reader.seek(SeekFrom::Start(start))?;
+
+    let bytes: [u8; Self::SIZE] = ReadExt::read_array(reader)?;
+
+    if bytes[..3] != Self::IDENTIFIER {
+      reader.seek(SeekFrom::Start(position))?;
+      return Ok(None);
+    }
+
+    let this: Self = Self::from_bytes(bytes)?;
+
+    let Some(offset) = position.checked_sub(this.tag_len()) else {
+      return Err(IoError::from(IoErrorKind::UnexpectedEof).into());
+    };
+
+    reader.seek(SeekFrom::Start(offset))?;
+
+    Ok(Some(this))
+  }
+
+  /// Parse an ID3v2 tag footer from the given `bytes`.
+  pub fn from_bytes(bytes: [u8; Self::SIZE]) -> Result<Self> {
+    let mut reader: Cursor<&[u8]> = Cursor::new(&bytes);
+
+    // Always "3DI" to indicate that this is an ID3 tag footer.
+    if ReadExt::read_array(&mut reader)? != Self::IDENTIFIER {
+      return Err(Error::tag(TagField::Identifier));
+    }
+
+    // 2 bytes - [major, revision]. Footers were introduced in ID3v2.4.
+    let version: Version = match ReadExt::read_array(&mut reader)? {
+      [0x04, _] => Version::ID3v24,
+      [_, _] => return Err(Error::tag(TagField::Version)),
+    };
+
+    // 1 byte - identical to the header flags.
+    let bitflags: HeaderFlags = HeaderFlags::from_reader(&mut reader)?;
+
+    // 28-bit "unsynchronized" integer.
+    let data_len: u32 = reader.read_u28_unsync()?;
+
+    Ok(Self {
+      version,
+      bitflags,
+      data_len,
+    })
+  }
+}
@@ -1,11 +1,23 @@
+use alloc::borrow::Cow;
 use bitflags::bitflags;
+use std::io::Cursor;
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
 
 use crate::error::Error;
+use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::error::SizeLimit;
 use crate::error::TagField;
+use crate::error::Warning;
+use crate::error::WarningKind;
 use crate::id3v2::ExtHeader;
+use crate::id3v2::Footer;
+use crate::id3v2::TagParser;
+use crate::options::ParseOptions;
 use crate::traits::ReadExt;
 use crate::types::Version;
+use crate::unsync;
 
 // =============================================================================
 // Header
@@ -24,6 +36,12 @@ impl Header {
   /// ID3 tag identifier.
   pub const IDENTIFIER: [u8; 3] = *b"ID3";
 
+  /// ID3 tag footer identifier.
+  pub const FOOTER_IDENTIFIER: [u8; 3] = *b"3DI";
+
+  /// The size of the header (in bytes), excluding the extended header.
+  pub const SIZE: usize = 10;
+
   /// Get the ID3 tag version.
   #[inline]
   pub const fn version(&self) -> Version {
@@ -36,17 +54,37 @@ impl Header {
     self.bitflags
   }
 
-  /// Get the ID3 tag size (in bytes).
+  /// Get the size of the frames and padding (in bytes).
   ///
-  /// Note: This is offset the the extended header size (if included).
+  /// Note: This is the tag size declared by the header, less the extended
+  /// header (if included). It is the same as [`frames_len`][Self::frames_len].
   #[inline]
   pub const fn data_len(&self) -> u32 {
     match self.exheader() {
-      Some(header) => self.data_len - header.ext_size(),
+      Some(header) => self.data_len - header.total_size(),
       None => self.data_len,
     }
   }
 
+  /// Get the size of the frames and padding (in bytes).
+  ///
+  /// This excludes the header, extended header and footer.
+  #[inline]
+  pub const fn frames_len(&self) -> u32 {
+    self.data_len()
+  }
+
+  /// Get the total size of the tag (in bytes).
+  ///
+  /// This includes the header, extended header, frames, padding and footer,
+  /// i.e. the number of bytes to skip to reach the data after the tag.
+  #[inline]
+  pub const fn total_size(&self) -> u64 {
+    let footer: usize = if self.flag_footer() { Footer::SIZE } else { 0 };
+
+    (Self::SIZE + footer) as u64 + self.data_len as u64
+  }
+
   /// Get a shared reference to the extended header (if included).
   #[inline]
   pub const fn exheader(&self) -> Option<&ExtHeader> {
@@ -99,18 +137,78 @@ impl Header {
     self.bitflags.contains(HeaderFlags::FOOTER_PRESENT)
   }
 
+  /// Create a header for a tag of `data_len` bytes without an extended header.
+  pub(crate) const fn new(version: Version, bitflags: HeaderFlags, data_len: u32) -> Self {
+    Self {
+      version,
+      bitflags,
+      data_len,
+      exheader: None,
+    }
+  }
+
   /// Parse an ID3v2 tag header from the given `reader`.
-  pub fn from_reader<R>(mut reader: R) -> Result<Self>
+  pub fn from_reader<R>(reader: R) -> Result<Self>
   where
     R: ReadExt,
   {
+    let mut parser: TagParser = TagParser::new(ParseOptions::new());
+
+    parser.read_header(reader)?;
+    parser.into_header()
+  }
+
+  /// Parse an ID3v2 tag header from the given `slice`.
+  ///
+  /// Returns the header and the number of bytes consumed, which includes the
+  /// extended header (if present).
+  pub fn from_slice(slice: &[u8]) -> Result<(Self, usize)> {
+    Self::parse_slice(slice, ParseOptions::new(), &mut Vec::new())
+  }
+
+  pub(crate) fn parse_slice(
+    slice: &[u8],
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<(Self, usize)> {
+    let mut reader: Cursor<&[u8]> = Cursor::new(slice);
+    let mut this: Self = Self::parse_base(ReadExt::read_array(&mut reader)?)?;
+
+    this.check_flags(options, warnings)?;
+    this.check_size(options)?;
+
+    if !this.flag_extended_header() {
+      return Ok((this, Self::SIZE));
+    }
+
+    let data: &[u8] = &slice[Self::SIZE..];
+
+    let Some((bytes, _)) = this.ext_bytes(data, 4)? else {
+      return Err(IoError::from(IoErrorKind::UnexpectedEof).into());
+    };
+
+    let size: usize = this.parse_ext_len(bytes.as_ref(), options)?;
+
+    let Some((buffer, consumed)) = this.ext_bytes(data, size)? else {
+      return Err(IoError::from(IoErrorKind::UnexpectedEof).into());
+    };
+
+    this.parse_ext(buffer.as_ref(), options, warnings)?;
+
+    Ok((this, Self::SIZE + consumed))
+  }
+
+  /// Parse the fixed-size portion of the header, without the extended header.
+  pub(crate) fn parse_base(bytes: [u8; Self::SIZE]) -> Result<Self> {
+    let mut reader: Cursor<&[u8]> = Cursor::new(&bytes);
+
     // Always "ID3" to indicate that this is an ID3 tag.
-    if reader.read_array()? != Header::IDENTIFIER {
+    if ReadExt::read_array(&mut reader)? != Header::IDENTIFIER {
       return Err(Error::tag(TagField::Identifier));
     }
 
     // 2 bytes - [major, revision].
-    let version: Version = match reader.read_array()? {
+    let version: Version = match ReadExt::read_array(&mut reader)? {
       [0x02, _] => Version::ID3v22,
       [0x03, _] => Version::ID3v23,
       [0x04, _] => Version::ID3v24,
@@ -123,30 +221,97 @@ impl Header {
     // 28-bit "unsynchronized" integer.
     let data_len: u32 = reader.read_u28_unsync()?;
 
-    let mut this: Self = Self {
+    Ok(Self {
       version,
       bitflags,
       data_len,
       exheader: None,
-    };
+    })
+  }
+
+  /// Check the header flags for bits not defined for the tag version.
+  ///
+  /// Returns an error, or records a warning in lenient mode.
+  pub(crate) fn check_flags(
+    &self,
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<()> {
+    let bits: u8 = self.bitflags.undefined_bits(self.version);
+
+    if bits == 0 {
+      return Ok(());
+    }
 
-    if this.flag_extended_header() {
-      if this.flag_unsynchronisation() {
-        panic!("TODO: Handle UNSYNCHRONISATION");
-      }
+    if !options.is_lenient() {
+      return Err(Error::tag(TagField::Flags));
+    }
+
+    warnings.push(Warning::new(WarningKind::HeaderFlags { bits }));
 
-      let exheader: ExtHeader = match this.version() {
-        Version::ID3v11 => unreachable!(),
-        Version::ID3v12 => unreachable!(),
-        Version::ID3v22 => return Err(Error::tag(TagField::Version)),
-        Version::ID3v23 => ExtHeader::from_reader_v3(&mut reader)?,
-        Version::ID3v24 => ExtHeader::from_reader_v4(&mut reader)?,
-      };
+    Ok(())
+  }
 
-      this.exheader = Some(exheader);
+  /// Check the total size of the tag against the [limit][ParseOptions::max_tag_size].
+  pub(crate) fn check_size(&self, options: ParseOptions) -> Result<()> {
+    if self.total_size() > options.tag_size_limit() as u64 {
+      return Err(Error::new(ErrorKind::SizeLimit(SizeLimit::Tag)));
     }
 
-    Ok(this)
+    Ok(())
+  }
+
+  /// Get the declared tag size (in bytes), including the extended header.
+  pub(crate) const fn raw_data_len(&self) -> u32 {
+    self.data_len
+  }
+
+  /// Get the first `length` bytes of the extended header from the `data`
+  /// following the header.
+  ///
+  /// In ID3v2.3 the extended header is covered by unsynchronisation and is
+  /// decoded here, in ID3v2.4 it is always stored as-is.
+  ///
+  /// Returns the bytes and the number of bytes of `data` they occupy, or
+  /// `None` if more data is required.
+  pub(crate) fn ext_bytes<'a>(
+    &self,
+    data: &'a [u8],
+    length: usize,
+  ) -> Result<Option<(Cow<'a, [u8]>, usize)>> {
+    if !(self.version == Version::ID3v23 && self.flag_unsynchronisation()) {
+      let bytes: Option<&[u8]> = data.get(..length);
+      return Ok(bytes.map(|bytes| (Cow::Borrowed(bytes), length)));
+    }
+
+    let limit: usize = self.data_len as usize;
+    let input: &[u8] = &data[..data.len().min(limit)];
+
+    match unsync::decode_prefix(input, length) {
+      Some((bytes, consumed)) => Ok(Some((Cow::Owned(bytes), consumed))),
+      None if input.len() == limit => Err(Error::tag(TagField::ExtSize)),
+      None => Ok(None),
+    }
+  }
+
+  /// Get the total size of the extended header from its size descriptor.
+  pub(crate) fn parse_ext_len(&self, bytes: &[u8], options: ParseOptions) -> Result<usize> {
+    let Ok(bytes) = <[u8; 4]>::try_from(bytes) else {
+      return Err(Error::tag(TagField::ExtSize));
+    };
+
+    ExtHeader::parse_len(self.version, bytes, options, self.data_len)
+  }
+
+  /// Parse the extended header from a `slice` sized by [`parse_ext_len`][Self::parse_ext_len].
+  pub(crate) fn parse_ext(
+    &mut self,
+    slice: &[u8],
+    options: ParseOptions,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<()> {
+    self.exheader = Some(ExtHeader::parse(self.version, slice, options, warnings)?);
+    Ok(())
   }
 }
 
@@ -190,7 +355,30 @@ bitflags! {
 }
 
 impl HeaderFlags {
-  fn from_reader<R>(reader: &mut R) -> Result<Self>
+  /// Get the flags defined for the given ID3v2 `version`.
+  pub const fn defined(version: Version) -> Self {
+    match version {
+      Version::ID3v11 | Version::ID3v12 => Self::empty(),
+      Version::ID3v22 => Self::UNSYNCHRONISATION.union(Self::COMPRESSION),
+      Version::ID3v23 => Self::UNSYNCHRONISATION
+        .union(Self::EXTENDED_HEADER)
+        .union(Self::EXPERIMENTAL),
+      Version::ID3v24 => Self::UNSYNCHRONISATION
+        .union(Self::EXTENDED_HEADER)
+        .union(Self::EXPERIMENTAL)
+        .union(Self::FOOTER_PRESENT),
+    }
+  }
+
+  /// Get the bits not defined for the given ID3v2 `version`.
+  ///
+  /// Note: The specification declares a tag with undefined flags unreadable.
+  #[inline]
+  pub const fn undefined_bits(self, version: Version) -> u8 {
+    self.bits() & !Self::defined(version).bits()
+  }
+
+  pub(crate) fn from_reader<R>(reader: &mut R) -> Result<Self>
   where
     R: ReadExt,
   {
@@ -1,8 +1,23 @@
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
+
+use crate::content::Content;
+use crate::error::Error;
+use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::error::SizeLimit;
+use crate::error::Warning;
+use crate::error::WarningKind;
 use crate::frame::DynFrame;
+use crate::frame::Layout;
+use crate::id3v2::ExtHeader;
 use crate::id3v2::Header;
 use crate::id3v2::Tag;
+use crate::text::caseless_eq;
+use crate::types::Language;
 use crate::types::Slice;
+use crate::types::Version;
+use crate::utils;
 
 // =============================================================================
 // DynFrame Iterator
@@ -10,26 +25,97 @@ use crate::types::Slice;
 
 /// An iterator over the frames on an ID3v2 tag.
 ///
-/// This struct is created by the [`frames`][Tag::frames] method on [`tags`][Tag].
+/// This struct is created by the [`frames`][Tag::frames] and
+/// [`frames_recovering`][Tag::frames_recovering] methods on [`tags`][Tag], and
+/// used by [`frames_lossy`][Tag::frames_lossy].
 #[derive(Clone)]
 pub struct FrameIter<'tag> {
   header: &'tag Header,
   buffer: &'tag Slice,
+  fuel: usize,
+  max_size: usize,
+  truncated: bool,
+  recover: bool,
+  lossy: bool,
+  offset: usize,
+  skipped: usize,
+  padding: &'tag Slice,
+  tail: &'tag Slice,
+  failed: bool,
 }
 
 impl<'tag> FrameIter<'tag> {
   pub(crate) const fn new(tag: &'tag Tag) -> Self {
     Self {
       header: tag.header(),
+      buffer: tag.buffer().take(tag.frames_end()),
+      fuel: tag.options().frame_limit(),
+      max_size: tag.options().frame_size_limit(),
+      truncated: tag.is_truncated(),
+      recover: false,
+      lossy: false,
+      offset: 0,
+      skipped: 0,
+      padding: Slice::empty(),
+      tail: tag.buffer().skip(tag.frames_end()),
+      failed: false,
+    }
+  }
+
+  pub(crate) const fn recovering(tag: &'tag Tag) -> Self {
+    // Lenient tags end their frames at the first invalid one, which is where
+    // recovery needs to start.
+    Self {
       buffer: tag.buffer(),
+      tail: Slice::empty(),
+      recover: true,
+      ..Self::new(tag)
     }
   }
-}
 
-impl<'tag> Iterator for FrameIter<'tag> {
-  type Item = Result<DynFrame<'tag>>;
+  pub(crate) const fn lossy(tag: &'tag Tag) -> Self {
+    Self {
+      lossy: true,
+      ..Self::recovering(tag)
+    }
+  }
 
-  fn next(&mut self) -> Option<Self::Item> {
+  /// Get the offset of the next frame from the start of the tag buffer.
+  #[inline]
+  pub const fn offset(&self) -> usize {
+    self.offset
+  }
+
+  /// Get the number of bytes skipped so far while recovering from invalid
+  /// frame headers.
+  #[inline]
+  pub const fn skipped(&self) -> usize {
+    self.skipped
+  }
+
+  /// Consume the remaining frames and get how iteration ended.
+  pub fn finish(mut self) -> IterOutcome {
+    while self.next().is_some() {}
+
+    if self.failed {
+      return IterOutcome::Error;
+    }
+
+    let length: usize = self.padding.len() + self.tail.len();
+
+    if utils::is_null(self.padding.as_ref()) && utils::is_null(self.tail.as_ref()) {
+      IterOutcome::CleanPadding(length)
+    } else {
+      IterOutcome::TrailingGarbage(length)
+    }
+  }
+
+  fn advance(&mut self, count: usize) {
+    self.offset += count.min(self.buffer.len());
+    self.buffer = self.buffer.skip(count);
+  }
+
+  fn next_frame(&mut self) -> Option<Result<DynFrame<'tag>>> {
     // Exit early if the buffer is empty.
     if self.buffer.is_empty() {
       return None;
@@ -38,20 +124,53 @@ impl<'tag> Iterator for FrameIter<'tag> {
     // Read the next frame from the ID3 tag buffer.
     match DynFrame::from_slice(self.header.version(), self.buffer) {
       Ok(None) => {
-        // The frame ID was NULL and we don't know how far ahead to skip
-        // so we'll just skip to the end of the buffer and stop iterating.
+        // The frame ID was NULL so the rest of the buffer should be padding,
+        // keep it for `finish` and stop iterating.
+        self.padding = self.buffer;
         self.buffer = Slice::empty();
 
         // Return `None` since this wasn't even a valid frame.
         None
       }
+      Ok(Some(_)) if self.fuel == 0 => {
+        // The frame limit was reached so stop iterating.
+        self.buffer = Slice::empty();
+
+        // Return an error so the limit is not mistaken for the end of the tag.
+        Some(Err(Error::new(ErrorKind::FrameLimit)))
+      }
+      Ok(Some(frame)) if frame.total_size() > self.max_size => {
+        // The frame is too large so stop iterating.
+        self.buffer = Slice::empty();
+
+        // Return an error so the limit is not mistaken for the end of the tag.
+        Some(Err(Error::new(ErrorKind::SizeLimit(SizeLimit::Frame))))
+      }
       Ok(Some(frame)) => {
         // The frame was valid so advance the buffer.
-        self.buffer = self.buffer.skip(frame.total_size());
+        self.fuel -= 1;
+        self.advance(frame.total_size());
 
         // Return the parsed frame.
         Some(Ok(frame))
       }
+      Err(error) if self.truncated && matches!(error.kind(), ErrorKind::FrameOverrun) => {
+        // The frame was cut short by the end of the input so stop iterating.
+        self.buffer = Slice::empty();
+
+        // Return an error so the partial frame is not mistaken for corruption.
+        Some(Err(IoError::from(IoErrorKind::UnexpectedEof).into()))
+      }
+      Err(error) if self.lossy || self.recover && is_corrupt(&error) => {
+        // The frame header was corrupted so skip to the next plausible frame.
+        let skip: usize = resync(self.header.version().frame_layout(), self.buffer);
+
+        self.skipped += skip;
+        self.advance(skip);
+
+        // Return the error so the corruption is still reported.
+        Some(Err(error))
+      }
       Err(error) => {
         // The frame was invalid and we don't know how far ahead to skip
         // so we'll just skip to the end of the buffer and stop iterating.
@@ -63,3 +182,271 @@ impl<'tag> Iterator for FrameIter<'tag> {
     }
   }
 }
+
+impl<'tag> Iterator for FrameIter<'tag> {
+  type Item = Result<DynFrame<'tag>>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let item: Option<Self::Item> = self.next_frame();
+
+    if matches!(item, Some(Err(_))) {
+      self.failed = true;
+    }
+
+    item
+  }
+}
+
+// =============================================================================
+// Raw Frame Iterator
+// =============================================================================
+
+/// An iterator over the frames of an ID3v2 tag with their bytes in the tag
+/// buffer.
+///
+/// Iteration ends at the padding, or after the first frame that cannot be
+/// parsed. The error is returned so callers never mistake an invalid frame for
+/// the end of the tag.
+pub(crate) struct RawFrameIter<'tag> {
+  version: Version,
+  buffer: &'tag Slice,
+}
+
+impl<'tag> RawFrameIter<'tag> {
+  pub(crate) const fn new(tag: &'tag Tag) -> Self {
+    Self {
+      version: tag.header().version(),
+      buffer: tag.buffer().take(tag.frames_end()),
+    }
+  }
+}
+
+impl<'tag> Iterator for RawFrameIter<'tag> {
+  type Item = Result<(DynFrame<'tag>, &'tag Slice)>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.buffer.is_empty() {
+      return None;
+    }
+
+    match DynFrame::from_slice(self.version, self.buffer) {
+      Ok(Some(frame)) => {
+        let size: usize = frame.total_size().min(self.buffer.len());
+        let raw: &'tag Slice = self.buffer.take(size);
+
+        self.buffer = self.buffer.skip(size);
+
+        Some(Ok((frame, raw)))
+      }
+      Ok(None) => {
+        self.buffer = Slice::empty();
+        None
+      }
+      Err(error) => {
+        self.buffer = Slice::empty();
+        Some(Err(error))
+      }
+    }
+  }
+}
+
+// =============================================================================
+// Language Frame Iterator
+// =============================================================================
+
+/// An iterator over the decoded frames of an ID3v2 tag that are distinguished
+/// by language and description, such as comments and lyrics.
+///
+/// This struct is created by the [`comments`][Tag::comments] and
+/// [`lyrics_all`][Tag::lyrics_all] methods on [`tags`][Tag].
+#[derive(Clone)]
+pub struct LangFrameIter<'tag, T> {
+  tag: &'tag Tag,
+  frames: FrameIter<'tag>,
+  identifier: &'static str,
+  select: fn(Content<'tag>) -> Option<T>,
+  key: fn(&T) -> (Language, &str),
+  language: Option<String>,
+  description: Option<String>,
+  skip_itunes: bool,
+}
+
+impl<'tag, T> LangFrameIter<'tag, T> {
+  pub(crate) const fn new(
+    tag: &'tag Tag,
+    identifier: &'static str,
+    select: fn(Content<'tag>) -> Option<T>,
+    key: fn(&T) -> (Language, &str),
+  ) -> Self {
+    Self {
+      tag,
+      frames: FrameIter::new(tag),
+      identifier,
+      select,
+      key,
+      language: None,
+      description: None,
+      skip_itunes: false,
+    }
+  }
+
+  /// Only yield frames in the given `language`.
+  ///
+  /// Languages are compared with [`caseless_eq`].
+  #[inline]
+  pub fn with_language(mut self, language: &str) -> Self {
+    self.language = Some(language.to_owned());
+    self
+  }
+
+  /// Only yield frames with the given `description`.
+  #[inline]
+  pub fn with_description(mut self, description: &str) -> Self {
+    self.description = Some(description.to_owned());
+    self
+  }
+
+  /// Skip frames with a description used by iTunes for application data
+  /// (e.g. `iTunNORM` or `iTunSMPB`).
+  #[inline]
+  pub fn skip_itunes(mut self) -> Self {
+    self.skip_itunes = true;
+    self
+  }
+
+  fn accepts(&self, item: &T) -> bool {
+    let (language, description): (Language, &str) = (self.key)(item);
+
+    if let Some(ref expected) = self.language {
+      if !caseless_eq(&language, expected) {
+        return false;
+      }
+    }
+
+    if let Some(ref expected) = self.description {
+      if description != expected {
+        return false;
+      }
+    }
+
+    !(self.skip_itunes && description.starts_with("iTun"))
+  }
+}
+
+impl<'tag, T> Iterator for LangFrameIter<'tag, T> {
+  type Item = Result<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      let frame: DynFrame<'tag> = match self.frames.next()? {
+        Ok(frame) if frame.identifier_str() == self.identifier => frame,
+        Ok(_) => continue,
+        Err(error) => return Some(Err(error)),
+      };
+
+      let content: Content<'tag> = match self.tag.decode_frame(&frame, &mut Vec::new()) {
+        Ok(content) => content,
+        Err(error) => return Some(Err(error)),
+      };
+
+      match (self.select)(content) {
+        Some(item) if self.accepts(&item) => return Some(Ok(item)),
+        Some(_) | None => continue,
+      }
+    }
+  }
+}
+
+// =============================================================================
+// Iteration Outcome
+// =============================================================================
+
+/// How iteration over the frames of an ID3v2 tag ended.
+///
+/// The outcome is returned by [`FrameIter::finish`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IterOutcome {
+  /// All frames were read, followed by the given number of NUL padding bytes.
+  CleanPadding(usize),
+  /// All frames were read, followed by the given number of bytes that are
+  /// neither frames nor padding.
+  TrailingGarbage(usize),
+  /// An error was encountered, including truncation of the tag.
+  Error,
+}
+
+/// Returns `true` if the `error` indicates a corrupted frame header.
+const fn is_corrupt(error: &Error) -> bool {
+  matches!(
+    error.kind(),
+    ErrorKind::InvalidFrameId | ErrorKind::InvalidBitFlag
+  )
+}
+
+/// Get the offset of the next plausible frame header in the `buffer`, after
+/// the first byte.
+///
+/// A frame header is plausible if it starts with a valid identifier and its
+/// size is non-zero and fits in the rest of the buffer. Returns the length of
+/// the buffer if there is none.
+fn resync(layout: Option<Layout>, buffer: &Slice) -> usize {
+  let Some(layout) = layout else {
+    return buffer.len();
+  };
+
+  let plausible = |offset: &usize| -> bool {
+    let rest: &[u8] = &buffer.as_ref()[*offset..];
+
+    let Some(header) = rest.get(..layout.header_len()) else {
+      return false;
+    };
+
+    let Some(size) = layout.parse_size(header) else {
+      return false;
+    };
+
+    utils::is_frame_id(&header[..layout.id_len()])
+      && size != 0
+      && size as usize <= rest.len() - layout.header_len()
+  };
+
+  (1..buffer.len()).find(plausible).unwrap_or(buffer.len())
+}
+
+// =============================================================================
+// Stale Padding
+// =============================================================================
+
+/// Get the end of the frames in the tag `buffer`, before any stale data left
+/// in the padding.
+///
+/// Frames end at the declared padding size (if any), at the first NUL frame
+/// identifier, or before the first frame that is invalid or extends past the
+/// end of the buffer. A warning is recorded if the remaining bytes are not all
+/// NUL padding.
+pub(crate) fn frames_end(header: &Header, buffer: &Slice, warnings: &mut Vec<Warning>) -> usize {
+  let padding: usize = header.exheader().map_or(0, ExtHeader::pad_size) as usize;
+  let limit: usize = buffer.len().saturating_sub(padding);
+  let mut offset: usize = 0;
+
+  while offset < limit {
+    let rest: &Slice = buffer.skip(offset).take(limit - offset);
+
+    match DynFrame::from_slice(header.version(), rest) {
+      Ok(Some(frame)) if frame.total_size() <= rest.len() => {
+        offset += frame.total_size();
+      }
+      Ok(_) | Err(_) => {
+        break;
+      }
+    }
+  }
+
+  let length: usize = buffer.len() - offset;
+
+  if !buffer.skip(offset).iter().all(|byte| *byte == 0x00) {
+    warnings.push(Warning::new(WarningKind::StalePadding { offset, length }));
+  }
+
+  offset
+}
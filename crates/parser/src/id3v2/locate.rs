@@ -0,0 +1,135 @@
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+use crate::error::Result;
+use crate::id3v1::TagPlus;
+use crate::id3v1::TagV1;
+use crate::id3v2::Footer;
+use crate::id3v2::Header;
+use crate::traits::ReadExt;
+
+// =============================================================================
+// Tag Location
+// =============================================================================
+
+/// The position of an ID3v2 tag in a file.
+///
+/// Locations are found with [`Tag::locate`][crate::id3v2::Tag::locate].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TagLocation {
+  offset: u64,
+  size: u64,
+  placement: Placement,
+}
+
+impl TagLocation {
+  /// Get the offset of the tag header from the start of the file.
+  #[inline]
+  pub const fn offset(&self) -> u64 {
+    self.offset
+  }
+
+  /// Get the total size of the tag (in bytes), including the header and
+  /// footer.
+  #[inline]
+  pub const fn size(&self) -> u64 {
+    self.size
+  }
+
+  /// Get where the tag is placed in the file.
+  #[inline]
+  pub const fn placement(&self) -> Placement {
+    self.placement
+  }
+}
+
+// =============================================================================
+// Placement
+// =============================================================================
+
+/// Where an ID3v2 tag is placed in a file.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Placement {
+  /// The tag is at the start of the file.
+  Prepended,
+  /// The tag is at the end of the file, before the ID3v1 tag (if any), and
+  /// is located by its footer.
+  Appended,
+}
+
+// =============================================================================
+// Locate
+// =============================================================================
+
+pub(crate) fn locate<R>(reader: &mut R) -> Result<Vec<TagLocation>>
+where
+  R: Read + Seek + ?Sized,
+{
+  let mut output: Vec<TagLocation> = Vec::new();
+  let length: u64 = reader.seek(SeekFrom::End(0))?;
+
+  if length >= Header::SIZE as u64 {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let bytes: [u8; Header::SIZE] = ReadExt::read_array(reader)?;
+
+    if bytes[..3] == Header::IDENTIFIER {
+      let header: Header = Header::parse_base(bytes)?;
+
+      output.push(TagLocation {
+        offset: 0,
+        size: header.total_size(),
+        placement: Placement::Prepended,
+      });
+    }
+  }
+
+  let end: u64 = appended_end(reader, length)?;
+
+  reader.seek(SeekFrom::Start(end))?;
+
+  if let Some(footer) = Footer::from_reader_backwards(reader)? {
+    let offset: u64 = reader.stream_position()?;
+
+    // A prepended tag with a footer in a file without audio.
+    if !output.iter().any(|location| location.offset == offset) {
+      output.push(TagLocation {
+        offset,
+        size: footer.tag_len(),
+        placement: Placement::Appended,
+      });
+    }
+  }
+
+  Ok(output)
+}
+
+/// Get the end of an appended ID3v2 tag, which precedes the ID3v1 tag and its
+/// enhanced `TAG+` block (if any).
+fn appended_end<R>(reader: &mut R, length: u64) -> Result<u64>
+where
+  R: Read + Seek + ?Sized,
+{
+  let Some(end) = length.checked_sub(TagV1::SIZE as u64) else {
+    return Ok(length);
+  };
+
+  reader.seek(SeekFrom::Start(end))?;
+
+  if ReadExt::read_array(reader)? != TagV1::IDENTIFIER {
+    return Ok(length);
+  }
+
+  let Some(start) = end.checked_sub(TagPlus::SIZE as u64) else {
+    return Ok(end);
+  };
+
+  reader.seek(SeekFrom::Start(start))?;
+
+  if ReadExt::read_array(reader)? != TagPlus::IDENTIFIER {
+    return Ok(end);
+  }
+
+  Ok(start)
+}
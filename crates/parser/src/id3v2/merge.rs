@@ -0,0 +1,123 @@
+use crate::content::Content;
+use crate::decode::Language;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::error::Result;
+use crate::frame::DynFrame;
+use crate::id3v2::iter::RawFrameIter;
+use crate::id3v2::Header;
+use crate::id3v2::HeaderFlags;
+use crate::id3v2::Tag;
+use crate::types::Bytes;
+use crate::types::Slice;
+use crate::types::Version;
+
+/// Merge the frames of the ID3v2.4 `update` tag over the `base` tag.
+pub(crate) fn apply_update(base: &Tag, update: &Tag) -> Result<Tag> {
+  if base.header().version() != Version::ID3v24 || update.header().version() != Version::ID3v24 {
+    return Err(Error::new(ErrorKind::InvalidVersion));
+  }
+
+  let changes: Vec<(DynFrame<'_>, &Slice)> = RawFrameIter::new(update).collect::<Result<_>>()?;
+  let mut buffer: Vec<u8> = Vec::new();
+
+  for item in RawFrameIter::new(base) {
+    let (frame, raw): (DynFrame<'_>, &Slice) = item?;
+    let mut keys: Option<Key> = None;
+
+    let replaced: bool = changes
+      .iter()
+      .filter(|(change, _)| change.identifier_str() == frame.identifier_str())
+      .any(|(change, _)| {
+        let key: &Key = keys.get_or_insert_with(|| Key::new(&frame));
+        Key::new(change).replaces(key)
+      });
+
+    if !replaced {
+      buffer.extend_from_slice(raw.as_ref());
+    }
+  }
+
+  for (_, raw) in changes.iter() {
+    buffer.extend_from_slice(raw.as_ref());
+  }
+
+  // The frames are stored as-is, without unsynchronisation, extended header
+  // or footer.
+  let bitflags: HeaderFlags = base.header().bitflags().difference(
+    HeaderFlags::UNSYNCHRONISATION | HeaderFlags::EXTENDED_HEADER | HeaderFlags::FOOTER_PRESENT,
+  );
+
+  let header: Header = Header::new(Version::ID3v24, bitflags, u32::try_from(buffer.len())?);
+  let buffer: Bytes = Bytes::new(buffer.into_boxed_slice());
+
+  Ok(Tag::new(
+    header,
+    None,
+    buffer,
+    None,
+    0,
+    Vec::new(),
+    base.options(),
+  ))
+}
+
+// =============================================================================
+// Frame Key
+// =============================================================================
+
+/// The fields that distinguish frames permitted to appear more than once.
+#[derive(PartialEq, Eq)]
+enum Key {
+  /// Only one frame with the identifier is permitted.
+  Single,
+  /// One frame per description or owner.
+  Name(String),
+  /// One frame per language and description.
+  Lang(Language, String),
+  /// One frame per distinct content.
+  Data(Vec<u8>),
+}
+
+impl Key {
+  fn new(frame: &DynFrame<'_>) -> Self {
+    let data: &Slice = frame.frame_data();
+
+    // Frames that cannot be decoded are only replaced by identical frames.
+    let Ok(content) = frame.decode() else {
+      return Self::Data(data.as_ref().to_vec());
+    };
+
+    match content {
+      Content::Aenc(inner) => Self::Name(inner.owner_identifier().to_owned()),
+      Content::Apic(inner) => Self::Name(inner.description().to_owned()),
+      Content::Chap(inner) => Self::Name(inner.element_identifier().to_owned()),
+      Content::Comm(inner) => Self::Lang(inner.language(), inner.text_summary().to_owned()),
+      Content::Ctoc(inner) => Self::Name(inner.element_identifier().to_owned()),
+      Content::Encr(inner) => Self::Name(inner.owner_identifier().to_owned()),
+      Content::Geob(inner) => Self::Name(inner.content_description().to_owned()),
+      Content::Grid(inner) => Self::Name(inner.owner_identifier().to_owned()),
+      Content::Popm(inner) => Self::Name(inner.user_email().to_owned()),
+      Content::Sylt(inner) => Self::Lang(inner.language(), inner.content_descriptor().to_owned()),
+      Content::Txxx(inner) => Self::Name(inner.text_summary().to_owned()),
+      Content::Ufid(inner) => Self::Name(inner.owner_identifier().to_owned()),
+      Content::User(inner) => Self::Lang(inner.language(), String::new()),
+      Content::Uslt(inner) => Self::Lang(inner.language(), inner.content_descriptor().to_owned()),
+      Content::Wxxx(inner) => Self::Name(inner.description().to_owned()),
+      // The identification string precedes the adjustments.
+      Content::Rva2(_) => Self::Data(data.until_nul().as_ref().to_vec()),
+      Content::Comr(_)
+      | Content::Link(_)
+      | Content::Priv(_)
+      | Content::Wcom(_)
+      | Content::Woar(_) => Self::Data(data.as_ref().to_vec()),
+      _ => Self::Single,
+    }
+  }
+
+  /// Returns `true` if a frame with this key replaces a frame with the
+  /// `other` key of the same identifier.
+  fn replaces(&self, other: &Self) -> bool {
+    matches!(self, Self::Single) || self == other
+  }
+}
@@ -1,11 +1,30 @@
 //! ID3v2 Support
 
+mod convert;
+mod digest;
+mod encryption;
 mod extend;
+mod extract;
+mod footer;
 mod header;
 mod iter;
+mod locate;
+mod merge;
+mod parser;
+mod restrict;
+mod stats;
 mod tag;
+mod write;
 
+pub use self::convert::upgrade_id;
+pub use self::convert::Upgrade;
+pub use self::convert::UPGRADE_V23;
+pub use self::convert::UPGRADE_V24;
+pub use self::digest::DigestScope;
+pub use self::encryption::AudioEncryption;
+pub use self::extend::ExtFlagData;
 pub use self::extend::ExtHeader;
+pub use self::extend::ExtHeaderBuilder;
 pub use self::extend::ExtHeaderFlags;
 pub use self::extend::ExtHeaderFlagsV3;
 pub use self::extend::ExtHeaderFlagsV4;
@@ -15,7 +34,19 @@ pub use self::extend::Restrictions;
 pub use self::extend::TagSizeRestriction;
 pub use self::extend::TextEncRestriction;
 pub use self::extend::TextLenRestriction;
+pub use self::extract::ExtractOptions;
+pub use self::extract::ExtractedFile;
+pub use self::footer::Footer;
 pub use self::header::Header;
 pub use self::header::HeaderFlags;
 pub use self::iter::FrameIter;
+pub use self::iter::IterOutcome;
+pub use self::iter::LangFrameIter;
+pub use self::locate::Placement;
+pub use self::locate::TagLocation;
+pub use self::parser::ParseEvent;
+pub use self::parser::TagParser;
+pub use self::restrict::RestrictionViolation;
+pub use self::restrict::ViolationKind;
+pub use self::stats::TagStats;
 pub use self::tag::Tag;
@@ -0,0 +1,547 @@
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
+use std::io::Read;
+
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::error::Result;
+use crate::error::SizeLimit;
+use crate::error::TagField;
+use crate::error::Warning;
+use crate::frame::DynFrame;
+use crate::frame::Layout;
+use crate::id3v2::Footer;
+use crate::id3v2::Header;
+use crate::id3v2::Tag;
+use crate::options::ParseOptions;
+use crate::traits::ReadExt;
+use crate::types::Bytes;
+use crate::types::Slice;
+use crate::types::Version;
+use crate::unsync;
+
+// =============================================================================
+// Tag Parser
+// =============================================================================
+
+/// A resumable, I/O-agnostic ID3v2 tag parser.
+///
+/// Input is provided in chunks of any size with [`feed`][Self::feed] and
+/// progress is made with [`poll`][Self::poll], which reports how many more
+/// bytes are required or yields the parsed header and frames as soon as they
+/// are available.
+///
+/// # Example
+///
+/// ```
+/// # use parser::id3v2::ParseEvent;
+/// # use parser::id3v2::TagParser;
+/// # use parser::options::ParseOptions;
+/// # fn main() -> parser::error::Result<()> {
+/// # let mut input: &[u8] = &[b'I', b'D', b'3', 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+/// let mut parser: TagParser = TagParser::new(ParseOptions::new());
+///
+/// loop {
+///   match parser.poll()? {
+///     ParseEvent::NeedMore(size) => {
+///       let (chunk, rest) = input.split_at(size);
+///       parser.feed(chunk);
+///       input = rest;
+///     }
+///     ParseEvent::Header(header) => println!("{:?}", header.version()),
+///     ParseEvent::Frame(frame) => println!("{:?}", frame?.identifier_str()),
+///     ParseEvent::Done => break,
+///   }
+/// }
+///
+/// let tag = parser.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TagParser {
+  options: ParseOptions,
+  warnings: Vec<Warning>,
+  buffer: Vec<u8>,
+  header: Option<Header>,
+  footer: Option<Footer>,
+  decoded: Option<Bytes>,
+  frames: bool,
+  fuel: usize,
+  state: State,
+  consumed: usize,
+}
+
+impl TagParser {
+  /// Create a new `TagParser` with the given `options`.
+  pub const fn new(options: ParseOptions) -> Self {
+    Self {
+      options,
+      warnings: Vec::new(),
+      buffer: Vec::new(),
+      header: None,
+      footer: None,
+      decoded: None,
+      frames: true,
+      fuel: options.frame_limit(),
+      state: State::Header,
+      consumed: 0,
+    }
+  }
+
+  /// Get the parsed header (if available).
+  #[inline]
+  pub const fn header(&self) -> Option<&Header> {
+    self.header.as_ref()
+  }
+
+  /// Get the warnings recorded so far.
+  #[inline]
+  pub fn warnings(&self) -> &[Warning] {
+    &self.warnings
+  }
+
+  /// Get the number of input bytes consumed so far.
+  ///
+  /// Once parsing is complete this is the total size of the tag, any input
+  /// fed beyond that point is ignored.
+  #[inline]
+  pub const fn consumed(&self) -> usize {
+    self.consumed
+  }
+
+  /// Returns `true` if the complete tag has been parsed.
+  #[inline]
+  pub const fn is_done(&self) -> bool {
+    matches!(self.state, State::Done)
+  }
+
+  /// Provide the parser with more input.
+  #[inline]
+  pub fn feed(&mut self, chunk: &[u8]) {
+    self.buffer.extend_from_slice(chunk);
+  }
+
+  /// Advance the parser as far as the current input allows.
+  ///
+  /// Errors in the header are fatal and returned directly. Errors in a frame,
+  /// including exceeding the [frame limit][ParseOptions::max_frames], are
+  /// yielded as a [`ParseEvent::Frame`], after which the rest of the tag is
+  /// consumed without further frame parsing.
+  ///
+  /// A compressed ID3v2.2 tag yields its header, after which an
+  /// [`Unsupported`][ErrorKind::Unsupported] error is returned. The header
+  /// can be used to skip the tag.
+  pub fn poll(&mut self) -> Result<ParseEvent<'_>> {
+    loop {
+      match self.state {
+        State::Header => {
+          let Some(bytes) = self.buffer.first_chunk::<{ Header::SIZE }>() else {
+            return Ok(self.need(Header::SIZE));
+          };
+
+          let header: Header = Header::parse_base(*bytes)?;
+
+          header.check_flags(self.options, &mut self.warnings)?;
+          header.check_size(self.options)?;
+
+          self.state = if header.flag_extended_header() {
+            State::ExtSize
+          } else {
+            State::Ready
+          };
+
+          self.header = Some(header);
+          self.consumed = Header::SIZE;
+        }
+        State::ExtSize => {
+          let data: &[u8] = &self.buffer[Header::SIZE..];
+
+          let Some((bytes, _)) = self.header_ref().ext_bytes(data, 4)? else {
+            return Ok(self.need_ext(4));
+          };
+
+          self.state = State::ExtData(self.header_ref().parse_ext_len(&bytes, self.options)?);
+        }
+        State::ExtData(size) => {
+          let header: &mut Header = self.header.as_mut().expect("header");
+          let data: &[u8] = &self.buffer[Header::SIZE..];
+
+          let Some((buffer, consumed)) = header.ext_bytes(data, size)? else {
+            return Ok(self.need_ext(size));
+          };
+
+          header.parse_ext(&buffer, self.options, &mut self.warnings)?;
+
+          self.state = State::Ready;
+          self.consumed = Header::SIZE + consumed;
+        }
+        State::Ready => {
+          // Unsynchronisation has to be reversed before frames can be found,
+          // so the entire tag is buffered up front.
+          self.state = if self.header_ref().flag_compression() {
+            State::Unsupported
+          } else if self.frames && !self.header_ref().flag_unsynchronisation() {
+            State::Frames(0)
+          } else {
+            State::Body
+          };
+
+          return Ok(ParseEvent::Header(self.header_ref()));
+        }
+        State::Body => {
+          let start: usize = self.consumed;
+          let end: usize = self.body_end();
+
+          let Some(buffer) = self.buffer.get(start..end) else {
+            return Ok(self.need(end));
+          };
+
+          if self.header_ref().flag_unsynchronisation() {
+            self.decoded = Some(unsync::decode(buffer)?);
+          }
+
+          self.state = if self.frames {
+            State::Frames(0)
+          } else {
+            self.after_body()
+          };
+
+          self.consumed = end;
+        }
+        State::Frames(offset) => {
+          let version: Version = self.header_ref().version();
+          let layout: Layout = version.frame_layout().expect("ID3v2 version");
+          let (data, total): (&[u8], usize) = self.body();
+          let remaining: usize = total - offset;
+
+          if remaining == 0 {
+            self.state = State::Padding;
+            continue;
+          }
+
+          // Wait for the frame header to find out how large the frame is.
+          let header: usize = remaining.min(layout.header_len());
+
+          let Some(bytes) = data.get(offset..offset + header) else {
+            return Ok(self.need_body(offset + header));
+          };
+
+          let data_len: usize = layout.parse_size(bytes).unwrap_or(0) as usize;
+          let length: usize = remaining.min(header + data_len);
+
+          let Some(bytes) = data.get(offset..offset + length) else {
+            return Ok(self.need_body(offset + length));
+          };
+
+          let size: Result<Option<usize>> = DynFrame::from_slice(version, Slice::new(bytes))
+            .map(|frame| frame.as_ref().map(DynFrame::total_size));
+
+          match size {
+            Ok(None) => {
+              self.state = State::Padding;
+            }
+            Ok(Some(_)) if self.fuel == 0 => {
+              self.state = State::Padding;
+              return Ok(ParseEvent::Frame(Err(Error::new(ErrorKind::FrameLimit))));
+            }
+            Ok(Some(size)) if size > self.options.frame_size_limit() => {
+              self.state = State::Padding;
+              let error: Error = Error::new(ErrorKind::SizeLimit(SizeLimit::Frame));
+              return Ok(ParseEvent::Frame(Err(error)));
+            }
+            Ok(Some(size)) => {
+              self.fuel -= 1;
+
+              let next: usize = total.min(offset + size);
+
+              self.state = State::Frames(next);
+
+              if self.decoded.is_none() {
+                self.consumed = self.body_start() + next;
+              }
+
+              return Ok(ParseEvent::Frame(self.frame(offset, length)));
+            }
+            Err(error) => {
+              self.state = State::Padding;
+              return Ok(ParseEvent::Frame(Err(error)));
+            }
+          }
+        }
+        State::Padding => {
+          let end: usize = self.body_end();
+
+          if self.buffer.len() < end {
+            return Ok(self.need(end));
+          }
+
+          self.state = self.after_body();
+          self.consumed = end;
+        }
+        State::Footer => {
+          let end: usize = self.consumed + Footer::SIZE;
+
+          let Some(bytes) = self.buffer[self.consumed..].first_chunk::<{ Footer::SIZE }>() else {
+            return Ok(self.need(end));
+          };
+
+          let footer: Footer = Footer::from_bytes(*bytes)?;
+
+          if !footer.matches(self.header_ref()) {
+            return Err(Error::tag(TagField::Footer));
+          }
+
+          self.footer = Some(footer);
+          self.state = State::Done;
+          self.consumed = end;
+        }
+        State::Unsupported => {
+          // The ID3v2.2 compression scheme was never defined.
+          return Err(Error::new(ErrorKind::Unsupported));
+        }
+        State::Done => {
+          return Ok(ParseEvent::Done);
+        }
+      }
+    }
+  }
+
+  /// Consume the parser and return the parsed tag.
+  ///
+  /// Returns an error if the tag is not complete, unless
+  /// [truncated tags][ParseOptions::allow_truncated] are allowed and the
+  /// header (including the extended header) has been parsed.
+  pub fn finish(self) -> Result<Tag> {
+    let length: usize = if self.is_done() {
+      self.consumed
+    } else if self.options.allows_truncated() && self.in_body() {
+      self.buffer.len().min(self.tag_end())
+    } else {
+      return Err(IoError::from(IoErrorKind::UnexpectedEof).into());
+    };
+
+    let start: usize = self.body_start();
+    let missing: usize = self.tag_end() - length;
+    let header: Header = self.header.expect("header");
+
+    let raw: Option<Bytes> = if self.options.retains_raw() {
+      let raw: &[u8] = &self.buffer[..length];
+      Some(Bytes::new(raw.into()))
+    } else {
+      None
+    };
+
+    let buffer: Bytes = match self.decoded {
+      Some(decoded) => decoded,
+      None if header.flag_unsynchronisation() => {
+        // The tag was cut short before the body could be decoded.
+        let end: usize = length.min(Header::SIZE + header.raw_data_len() as usize);
+        unsync::decode(&self.buffer[self.consumed..end])?
+      }
+      None => {
+        let mut buffer: Vec<u8> = self.buffer;
+        buffer.truncate(length.min(start + header.data_len() as usize));
+        buffer.drain(..start);
+        Bytes::new(buffer.into_boxed_slice())
+      }
+    };
+
+    Ok(Tag::new(
+      header,
+      self.footer,
+      buffer,
+      raw,
+      missing,
+      self.warnings,
+      self.options,
+    ))
+  }
+
+  // ===========================================================================
+  // Reader Adapters
+  // ===========================================================================
+
+  /// Drive the parser with exact-sized reads from `reader` until the header
+  /// has been parsed.
+  pub(crate) fn read_header<R>(&mut self, reader: R) -> Result<()>
+  where
+    R: ReadExt,
+  {
+    self.frames = false;
+    self.read_until(reader, true)
+  }
+
+  /// Drive the parser with exact-sized reads from `reader` until the tag is
+  /// complete.
+  pub(crate) fn read_tag<R>(&mut self, reader: R) -> Result<()>
+  where
+    R: ReadExt,
+  {
+    self.frames = false;
+    self.read_until(reader, false)
+  }
+
+  pub(crate) fn into_header(self) -> Result<Header> {
+    self
+      .header
+      .ok_or_else(|| IoError::from(IoErrorKind::UnexpectedEof).into())
+  }
+
+  fn read_until<R>(&mut self, mut reader: R, header: bool) -> Result<()>
+  where
+    R: ReadExt,
+  {
+    let truncated: bool = self.options.allows_truncated();
+
+    loop {
+      match self.poll()? {
+        ParseEvent::NeedMore(size) if truncated => {
+          let mut data: Vec<u8> = Vec::with_capacity(size);
+
+          // A short read means the input ended, the tag is built from what
+          // has been read so far.
+          (&mut reader).take(size as u64).read_to_end(&mut data)?;
+          self.feed(&data);
+
+          if data.len() < size {
+            return Ok(());
+          }
+        }
+        ParseEvent::NeedMore(size) => {
+          let data: Bytes = reader.read_bytes(size)?;
+          self.feed(data.as_ref());
+        }
+        ParseEvent::Header(_) if header => return Ok(()),
+        ParseEvent::Header(_) | ParseEvent::Frame(_) => {}
+        ParseEvent::Done => return Ok(()),
+      }
+    }
+  }
+
+  // ===========================================================================
+  // Misc. Helpers
+  // ===========================================================================
+
+  fn header_ref(&self) -> &Header {
+    self.header.as_ref().expect("header")
+  }
+
+  fn body_start(&self) -> usize {
+    let Some(header) = self.header() else {
+      return 0;
+    };
+
+    match header.exheader() {
+      Some(exheader) => Header::SIZE + exheader.total_size() as usize,
+      None => Header::SIZE,
+    }
+  }
+
+  /// Get the end of the tag data, before the footer (if any).
+  ///
+  /// Note: The body does not start at [`body_start`][Self::body_start] if the
+  /// extended header is unsynchronised.
+  fn body_end(&self) -> usize {
+    Header::SIZE + self.header_ref().raw_data_len() as usize
+  }
+
+  /// Get the end of the tag, including the footer (if any).
+  fn tag_end(&self) -> usize {
+    self.header_ref().total_size() as usize
+  }
+
+  /// Returns `true` if the header has been parsed and the parser has moved on
+  /// to the tag data.
+  const fn in_body(&self) -> bool {
+    matches!(
+      self.state,
+      State::Body | State::Frames(_) | State::Padding | State::Footer
+    )
+  }
+
+  /// Get the frame data buffered so far and the total size of the frame data.
+  fn body(&self) -> (&[u8], usize) {
+    if let Some(ref decoded) = self.decoded {
+      return (decoded.as_ref(), decoded.len());
+    }
+
+    let start: usize = self.body_start();
+    let total: usize = self.header_ref().data_len() as usize;
+    let data: &[u8] = &self.buffer[start..];
+
+    (&data[..data.len().min(total)], total)
+  }
+
+  /// Re-parse a frame that is known to be valid.
+  fn frame(&self, offset: usize, length: usize) -> Result<DynFrame<'_>> {
+    let version: Version = self.header_ref().version();
+    let (data, _): (&[u8], usize) = self.body();
+    let slice: &Slice = Slice::new(&data[offset..offset + length]);
+
+    DynFrame::from_slice(version, slice).map(|frame| frame.expect("frame"))
+  }
+
+  fn after_body(&self) -> State {
+    if self.header_ref().flag_footer() {
+      State::Footer
+    } else {
+      State::Done
+    }
+  }
+
+  fn need(&self, total: usize) -> ParseEvent<'static> {
+    ParseEvent::NeedMore(total - self.buffer.len())
+  }
+
+  fn need_ext(&self, length: usize) -> ParseEvent<'static> {
+    // Unsynchronisation may stretch the extended header by an unknown amount.
+    self.need((Header::SIZE + length).max(self.buffer.len() + 1))
+  }
+
+  fn need_body(&self, offset: usize) -> ParseEvent<'static> {
+    self.need(self.body_start() + offset)
+  }
+}
+
+impl Default for TagParser {
+  #[inline]
+  fn default() -> Self {
+    Self::new(ParseOptions::new())
+  }
+}
+
+// =============================================================================
+// Parse Event
+// =============================================================================
+
+/// An event produced by [`TagParser::poll`].
+#[derive(Debug)]
+pub enum ParseEvent<'a> {
+  /// At least this many more bytes must be fed before progress can be made.
+  NeedMore(usize),
+  /// The header, including the extended header, has been parsed.
+  Header(&'a Header),
+  /// A frame has been parsed.
+  Frame(Result<DynFrame<'a>>),
+  /// The complete tag has been parsed.
+  Done,
+}
+
+// =============================================================================
+// Parser State
+// =============================================================================
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+  Header,
+  ExtSize,
+  ExtData(usize),
+  Ready,
+  Body,
+  Frames(usize),
+  Padding,
+  Footer,
+  Unsupported,
+  Done,
+}
@@ -0,0 +1,165 @@
+use memchr::memchr;
+
+use crate::content::Apic;
+use crate::content::Content;
+use crate::frame::DynFrame;
+use crate::id3v2::ImageEncRestriction;
+use crate::id3v2::ImageLenRestriction;
+use crate::id3v2::Restrictions;
+use crate::id3v2::Tag;
+use crate::id3v2::TagSizeRestriction;
+use crate::id3v2::TextEncRestriction;
+use crate::id3v2::TextLenRestriction;
+use crate::types::DynFrameId;
+
+// =============================================================================
+// Restriction Violation
+// =============================================================================
+
+/// A violation of the extended header [`Restrictions`].
+///
+/// Violations are found with [`Restrictions::check`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RestrictionViolation {
+  frame: Option<DynFrameId>,
+  kind: ViolationKind,
+}
+
+impl RestrictionViolation {
+  const fn new(frame: Option<DynFrameId>, kind: ViolationKind) -> Self {
+    Self { frame, kind }
+  }
+
+  /// Get the identifier of the offending frame.
+  ///
+  /// Returns `None` if the violation applies to the whole tag.
+  #[inline]
+  pub const fn frame(&self) -> Option<DynFrameId> {
+    self.frame
+  }
+
+  /// Get the restriction that was violated.
+  #[inline]
+  pub const fn kind(&self) -> ViolationKind {
+    self.kind
+  }
+}
+
+// =============================================================================
+// Violation Kind
+// =============================================================================
+
+/// The restrictions that may be violated.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ViolationKind {
+  /// The tag contains more frames than permitted.
+  FrameCount(TagSizeRestriction),
+  /// The tag is larger than permitted.
+  TagSize(TagSizeRestriction),
+  /// A frame uses a forbidden text encoding.
+  TextEnc(TextEncRestriction),
+  /// A string is longer than permitted.
+  TextLen(TextLenRestriction),
+  /// A picture uses a forbidden image format.
+  ImageEnc(ImageEncRestriction),
+  /// A picture is larger than permitted.
+  ImageLen(ImageLenRestriction),
+}
+
+// =============================================================================
+// Check
+// =============================================================================
+
+impl Restrictions {
+  /// Check whether the given `tag` complies with the restrictions.
+  ///
+  /// Violations of the whole tag are reported first, followed by those of
+  /// each frame in the order of the frames. The text length is checked for
+  /// text information frames and the image size for `APIC` frames in PNG or
+  /// JPEG format.
+  ///
+  /// Note: Frames that cannot be decoded are only checked for the image
+  /// format, iteration stops at the first invalid frame.
+  pub fn check(&self, tag: &Tag) -> Vec<RestrictionViolation> {
+    let mut output: Vec<RestrictionViolation> = Vec::new();
+    let mut frames: Vec<RestrictionViolation> = Vec::new();
+    let mut count: usize = 0;
+
+    for frame in tag.frames() {
+      let Ok(frame) = frame else {
+        break;
+      };
+
+      self.check_frame(tag, &frame, &mut frames);
+      count += 1;
+    }
+
+    if count > self.tag_size().max_frames() {
+      let kind: ViolationKind = ViolationKind::FrameCount(self.tag_size());
+      output.push(RestrictionViolation::new(None, kind));
+    }
+
+    if tag.header().total_size() > self.tag_size().max_size() {
+      let kind: ViolationKind = ViolationKind::TagSize(self.tag_size());
+      output.push(RestrictionViolation::new(None, kind));
+    }
+
+    output.extend(frames);
+    output
+  }
+
+  fn check_frame(&self, tag: &Tag, frame: &DynFrame<'_>, output: &mut Vec<RestrictionViolation>) {
+    let identifier: Option<DynFrameId> = Some(frame.identifier());
+
+    // Unsupported image formats cannot be decoded, so the MIME type is read
+    // from the frame data.
+    if frame.identifier_str() == "APIC" {
+      if let Some(mime_type) = mime_type(frame.frame_data().as_ref()) {
+        if !self.image_enc().permits(mime_type) {
+          let kind: ViolationKind = ViolationKind::ImageEnc(self.image_enc());
+          output.push(RestrictionViolation::new(identifier, kind));
+        }
+      }
+    }
+
+    let Ok(content) = tag.decode_frame(frame, &mut Vec::new()) else {
+      return;
+    };
+
+    if let Some(encoding) = content.text_encoding() {
+      if !self.text_enc().permits(encoding) {
+        let kind: ViolationKind = ViolationKind::TextEnc(self.text_enc());
+        output.push(RestrictionViolation::new(identifier, kind));
+      }
+    }
+
+    if let (Some(limit), Content::Text(text)) = (self.text_len().max_chars(), &content) {
+      if text.values().any(|value| value.chars().count() > limit) {
+        let kind: ViolationKind = ViolationKind::TextLen(self.text_len());
+        output.push(RestrictionViolation::new(identifier, kind));
+      }
+    }
+
+    if let Content::Apic(apic) = &content {
+      let size: Option<(u32, u32)> = apic
+        .load_picture_data(tag)
+        .ok()
+        .and_then(|data| Apic::image_size(data.as_ref()));
+
+      if let Some((width, height)) = size {
+        if !self.image_len().permits(width, height) {
+          let kind: ViolationKind = ViolationKind::ImageLen(self.image_len());
+          output.push(RestrictionViolation::new(identifier, kind));
+        }
+      }
+    }
+  }
+}
+
+/// Get the MIME type of the `APIC` frame `data`.
+fn mime_type(data: &[u8]) -> Option<&str> {
+  let data: &[u8] = data.get(1..)?;
+  let index: usize = memchr(0x00, data)?;
+
+  core::str::from_utf8(&data[..index]).ok()
+}
@@ -0,0 +1,50 @@
+// =============================================================================
+// Tag Statistics
+// =============================================================================
+
+/// Statistics about the frames of an ID3v2 tag.
+///
+/// This struct is created by the [`stats`][crate::id3v2::Tag::stats] method on
+/// [`tags`][crate::id3v2::Tag].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TagStats {
+  pub(crate) frame_count: usize,
+  pub(crate) frame_limit: usize,
+  pub(crate) limit_reached: bool,
+  pub(crate) invalid: bool,
+}
+
+impl TagStats {
+  pub(crate) const fn new(frame_limit: usize) -> Self {
+    Self {
+      frame_count: 0,
+      frame_limit,
+      limit_reached: false,
+      invalid: false,
+    }
+  }
+
+  /// Get the number of frames yielded by the frame iterator.
+  #[inline]
+  pub const fn frame_count(&self) -> usize {
+    self.frame_count
+  }
+
+  /// Get the configured maximum number of frames.
+  #[inline]
+  pub const fn frame_limit(&self) -> usize {
+    self.frame_limit
+  }
+
+  /// Returns `true` if the tag contains more frames than the frame limit.
+  #[inline]
+  pub const fn is_limit_reached(&self) -> bool {
+    self.limit_reached
+  }
+
+  /// Returns `true` if frame iteration stopped at an invalid frame.
+  #[inline]
+  pub const fn is_invalid(&self) -> bool {
+    self.invalid
+  }
+}
@@ -1,25 +1,85 @@
+use alloc::borrow::Cow;
+use core::hash::Hasher;
+use core::time::Duration;
 use std::fs::File;
 use std::io::BufReader;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
 use std::path::Path;
 
+use crate::content::parse_genre;
+use crate::content::Aenc;
+use crate::content::Apic;
+use crate::content::Comm;
+use crate::content::Content;
+use crate::content::Encr;
+use crate::content::Geob;
+use crate::content::Grid;
+use crate::content::PicType;
+use crate::content::Position;
+use crate::content::Text;
+use crate::content::User;
+use crate::content::Uslt;
+use crate::error::Error;
+use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::error::Warning;
+use crate::error::WarningKind;
 use crate::frame::DynFrame;
+use crate::id3v2::digest;
+use crate::id3v2::extract;
+use crate::id3v2::iter;
+use crate::id3v2::locate;
+use crate::id3v2::merge;
+use crate::id3v2::write;
+use crate::id3v2::AudioEncryption;
+use crate::id3v2::DigestScope;
+use crate::id3v2::ExtHeader;
+use crate::id3v2::ExtractOptions;
+use crate::id3v2::ExtractedFile;
+use crate::id3v2::Footer;
 use crate::id3v2::FrameIter;
 use crate::id3v2::Header;
+use crate::id3v2::LangFrameIter;
+use crate::id3v2::Restrictions;
+use crate::id3v2::TagLocation;
+use crate::id3v2::TagParser;
+use crate::id3v2::TagStats;
+use crate::id3v2::Upgrade;
+use crate::id3v2::UPGRADE_V23;
+use crate::options::DecoderConfig;
+use crate::options::ParseOptions;
+use crate::options::WriteOptions;
+use crate::text::caseless_eq;
 use crate::traits::ReadExt;
 use crate::types::Bytes;
+use crate::types::DynFrameId;
+use crate::types::FrameId;
+use crate::types::Language;
+use crate::types::LanguagePreference;
 use crate::types::Slice;
-use crate::unsync::Unsync;
+use crate::types::Version;
 
 // =============================================================================
 // Tag
 // =============================================================================
 
 /// A parsed ID3v2 tag.
+///
+/// Tags are `Send + Sync` and may be parsed on one thread and shared with or
+/// moved to others.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Tag {
   header: Header,
+  footer: Option<Footer>,
   buffer: Bytes,
+  raw: Option<Bytes>,
+  missing: usize,
+  warnings: Vec<Warning>,
+  options: ParseOptions,
+  frames_end: usize,
 }
 
 impl Tag {
@@ -29,45 +89,1200 @@ impl Tag {
     &self.header
   }
 
+  /// Get a shared reference to the tag footer (if included).
+  #[inline]
+  pub const fn footer(&self) -> Option<&Footer> {
+    self.footer.as_ref()
+  }
+
   /// Get a shared reference to the tag content.
   #[inline]
   pub const fn buffer(&self) -> &Slice {
     self.buffer.as_slice()
   }
 
+  /// Get the on-disk bytes of the tag, including the header and footer.
+  ///
+  /// Note: Only available when parsed with [`ParseOptions::retain_raw`].
+  #[inline]
+  pub fn raw(&self) -> Option<&Slice> {
+    self.raw.as_deref()
+  }
+
+  /// Returns `true` if the input ended before the end of the tag.
+  ///
+  /// A truncated tag contains the frames that were read completely, a frame
+  /// cut short by the end of the input is yielded as an
+  /// [`UnexpectedEof`][std::io::ErrorKind::UnexpectedEof] I/O error.
+  ///
+  /// Note: Truncated tags are only parsed with
+  /// [`ParseOptions::allow_truncated`].
+  #[inline]
+  pub const fn is_truncated(&self) -> bool {
+    self.missing != 0
+  }
+
+  /// Get the number of bytes of the tag missing from the input.
+  #[inline]
+  pub const fn missing_len(&self) -> usize {
+    self.missing
+  }
+
+  /// Get the warnings recorded while parsing the tag.
+  ///
+  /// Note: Warnings are only recorded when parsing in lenient mode.
+  #[inline]
+  pub fn warnings(&self) -> &[Warning] {
+    &self.warnings
+  }
+
+  /// Get the options used to parse the tag.
+  #[inline]
+  pub const fn options(&self) -> ParseOptions {
+    self.options
+  }
+
+  /// Get statistics about the frames of the tag.
+  pub fn stats(&self) -> TagStats {
+    let mut stats: TagStats = TagStats::new(self.options.frame_limit());
+
+    for frame in self.frames() {
+      match frame {
+        Ok(_) => stats.frame_count += 1,
+        Err(error) if matches!(error.kind(), ErrorKind::FrameLimit) => stats.limit_reached = true,
+        Err(_) => stats.invalid = true,
+      }
+    }
+
+    stats
+  }
+
   /// Get an iterator over the frames of the tag.
   #[inline]
   pub const fn frames(&self) -> FrameIter<'_> {
     FrameIter::new(self)
   }
 
+  /// Get an iterator over the frames of the tag that recovers from corrupted
+  /// frame headers.
+  ///
+  /// When a frame has an invalid identifier or flags, the error is yielded and
+  /// iteration resumes at the next plausible frame header. The number of bytes
+  /// skipped is reported by [`FrameIter::skipped`].
+  #[inline]
+  pub const fn frames_recovering(&self) -> FrameIter<'_> {
+    FrameIter::recovering(self)
+  }
+
+  /// Get an iterator over the frames of the tag that continues after errors.
+  ///
+  /// Each frame or error is yielded with its offset from the start of the tag
+  /// buffer. After an invalid frame, iteration resumes at the next plausible
+  /// frame header, see [`frames_recovering`][Self::frames_recovering].
+  pub fn frames_lossy(&self) -> impl Iterator<Item = (usize, Result<DynFrame<'_>>)> + '_ {
+    let mut iter: FrameIter<'_> = FrameIter::lossy(self);
+
+    core::iter::from_fn(move || {
+      let offset: usize = iter.offset();
+      iter.next().map(|frame| (offset, frame))
+    })
+  }
+
+  /// Get an iterator over the frames of the tag belonging to the group with
+  /// the given identifier.
+  ///
+  /// Errors encountered while iterating are yielded as well.
+  pub fn frames_in_group(&self, id: u8) -> impl Iterator<Item = Result<DynFrame<'_>>> + '_ {
+    self.frames().filter(move |frame| match frame {
+      Ok(frame) => frame.group_id() == Some(id),
+      Err(_) => true,
+    })
+  }
+
+  /// Get the first frame of the tag with the given identifier.
+  ///
+  /// Identifiers are matched case-sensitively. Both ID3v2.2 and ID3v2.3/ID3v2.4
+  /// identifiers are accepted and mapped to the version of the tag, see
+  /// [`UPGRADE_V23`]. Frames that fail to parse are ignored.
+  pub fn get(&self, id: &str) -> Option<DynFrame<'_>> {
+    self.frames_by_id(id).next()
+  }
+
+  /// Get an iterator over the frames of the tag with the given identifier.
+  ///
+  /// Identifiers are mapped to the version of the tag like in
+  /// [`get`][Self::get]. Frames that fail to parse are skipped.
+  pub fn frames_by_id<'a>(&'a self, id: &str) -> impl Iterator<Item = DynFrame<'a>> + 'a {
+    let id: Option<DynFrameId> = DynFrameId::try_from(self.frame_id(id, |a, b| a == b)).ok();
+
+    self
+      .frames()
+      .filter_map(Result::ok)
+      .filter(move |frame| Some(frame.identifier()) == id)
+  }
+
+  /// Get the first frame of the tag with the given identifier, ignoring case.
+  ///
+  /// Identifiers are compared with [`caseless_eq`], see [`get`][Self::get]
+  /// for details.
+  pub fn get_caseless(&self, id: &str) -> Option<DynFrame<'_>> {
+    let id: &str = self.frame_id(id, caseless_eq);
+
+    self
+      .frames()
+      .filter_map(Result::ok)
+      .find(|frame| caseless_eq(frame.identifier_str(), id))
+  }
+
+  /// Get the decoded contents of the first frame of the tag with the given
+  /// identifier.
+  ///
+  /// The frame is decoded with the options used to parse the tag, see
+  /// [`get`][Self::get] for details.
+  pub fn get_content(&self, id: &str) -> Option<Result<Content<'_>>> {
+    let frame: DynFrame<'_> = self.get(id)?;
+
+    Some(self.decode_frame(&frame, &mut Vec::new()))
+  }
+
+  /// Get an iterator over the frames of the tag with their decoded contents.
+  ///
+  /// Frames are decoded with the options used to parse the tag. Errors are
+  /// yielded as well, decoding errors carry the [identifier][Error::frame] of
+  /// the frame that failed.
+  pub fn contents(&self) -> impl Iterator<Item = Result<(DynFrame<'_>, Content<'_>)>> + '_ {
+    self.frames().map(move |frame| {
+      let frame: DynFrame<'_> = frame?;
+
+      match self.decode_frame(&frame, &mut Vec::new()) {
+        Ok(content) => Ok((frame, content)),
+        Err(error) => Err(error.with_frame(frame.identifier())),
+      }
+    })
+  }
+
+  /// Decode the contents of a `frame` with the options used to parse the tag.
+  ///
+  /// See [`decode_frame_with_config`][Self::decode_frame_with_config] for details.
+  #[inline]
+  pub fn decode_frame<'a>(
+    &'a self,
+    frame: &DynFrame<'a>,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Content<'a>> {
+    self.decode_frame_with_config(frame, &DecoderConfig::new(self.options), warnings)
+  }
+
+  /// Decode the contents of a `frame` with the given `config`.
+  ///
+  /// Binary data larger than the [defer threshold][ParseOptions::defer_binary]
+  /// is left in the tag buffer, see [`DeferredSlice::load`][crate::types::DeferredSlice::load].
+  ///
+  /// Problems tolerated in lenient mode and, if enabled, violations of the
+  /// extended header [restrictions][ParseOptions::check_restrictions] are
+  /// appended to `warnings`.
+  pub fn decode_frame_with_config<'a>(
+    &'a self,
+    frame: &DynFrame<'a>,
+    config: &DecoderConfig,
+    warnings: &mut Vec<Warning>,
+  ) -> Result<Content<'a>> {
+    let content: Content<'a> = frame.decode_in(config, warnings, Some(self.buffer()))?;
+
+    if !config.options().checks_restrictions() {
+      return Ok(content);
+    }
+
+    let (DynFrame::V4(inner), Some(restrictions)) = (frame, self.restrictions()) else {
+      return Ok(content);
+    };
+
+    if let Some(encoding) = content.text_encoding() {
+      if !restrictions.text_enc().permits(encoding) {
+        warnings.push(Warning::new(WarningKind::TextEncRestriction {
+          frame: inner.identifier(),
+          encoding,
+        }));
+      }
+    }
+
+    Ok(content)
+  }
+
+  /// Feed the on-disk bytes of the tag `scope` into the given `hasher`.
+  ///
+  /// The bytes are hashed as stored, before unsynchronisation is reversed.
+  /// Returns an error if the tag was not parsed with
+  /// [`ParseOptions::retain_raw`].
+  pub fn raw_digest<H>(&self, hasher: &mut H, scope: DigestScope) -> Result<()>
+  where
+    H: Hasher,
+  {
+    let Some(raw) = self.raw() else {
+      return Err(Error::new(ErrorKind::RawUnavailable));
+    };
+
+    let raw: &[u8] = raw.as_ref();
+
+    match scope {
+      DigestScope::Tag => hasher.write(raw),
+      DigestScope::Frames => hasher.write(&raw[digest::frames_range(self, raw)?]),
+    }
+
+    Ok(())
+  }
+
+  /// Write the tag to the given `writer`.
+  ///
+  /// See [`write_to_with`][Self::write_to_with] for details.
+  #[inline]
+  pub fn write_to<W>(&self, writer: W) -> Result<u64>
+  where
+    W: Write,
+  {
+    self.write_to_with(writer, WriteOptions::new())
+  }
+
+  /// Write the tag to the given `writer` with custom `options`.
+  ///
+  /// The frames are written byte-for-byte as parsed, followed by the
+  /// configured amount of padding or a footer. The tag size and the ID3v2.3
+  /// extended header padding size are recomputed, the ID3v2.4 extended header
+  /// CRC data is removed.
+  ///
+  /// Returns an [`InvalidVersion`][ErrorKind::InvalidVersion] error if a
+  /// footer is requested for a tag other than ID3v2.4.
+  ///
+  /// Note: The tag is written without unsynchronisation, and the
+  /// corresponding header flag is cleared.
+  ///
+  /// Returns the number of bytes written.
+  pub fn write_to_with<W>(&self, writer: W, options: WriteOptions) -> Result<u64>
+  where
+    W: Write,
+  {
+    write::write_tag(self, writer, options)
+  }
+
+  /// Save the tag to the file at the given `path`.
+  ///
+  /// See [`save_to_path_with`][Self::save_to_path_with] for details.
+  #[inline]
+  pub fn save_to_path<P>(&self, path: &P) -> Result<u64>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    self.save_to_path_with(path, WriteOptions::new())
+  }
+
+  /// Save the tag to the file at the given `path` with custom `options`,
+  /// replacing the ID3v2 tag at the start of the file (if any).
+  ///
+  /// If the tag fits within the existing tag and its padding, it is
+  /// overwritten in place and any reclaimed space is written as padding. The
+  /// padding option is ignored in this case. Otherwise the tag is written
+  /// with the configured padding, followed by the audio data, to a temporary
+  /// file that replaces the original file.
+  ///
+  /// See [`write_to_with`][Self::write_to_with] for how the tag is written.
+  ///
+  /// Returns the number of bytes written for the tag.
+  pub fn save_to_path_with<P>(&self, path: &P, options: WriteOptions) -> Result<u64>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    write::save_tag(self, path.as_ref(), options)
+  }
+
+  /// Write an ID3v2.4 update tag for the `base` tag to the given `writer`.
+  ///
+  /// Only the `changes` that differ from the frames of the `base` tag are
+  /// written, the extended header `TAG_IS_UPDATE` flag is set and no padding
+  /// is added. The update tag is usually appended to the file and linked from
+  /// the `base` tag with a `SEEK` frame, see [`seek_target`][Self::seek_target].
+  ///
+  /// Returns an error if the `base` tag is not an ID3v2.4 tag, if a change is
+  /// not permitted in an update tag (`ASPI` and `SEEK`) or if the content of a
+  /// change cannot be encoded.
+  ///
+  /// Returns the number of bytes written.
+  pub fn write_update<W>(writer: W, base: &Tag, changes: &[(FrameId, Content<'_>)]) -> Result<u64>
+  where
+    W: Write,
+  {
+    write::write_update(writer, base, changes)
+  }
+
+  /// Merge the frames of an ID3v2.4 `update` tag over this tag.
+  ///
+  /// Frames of the update replace all frames with the same identifier, except
+  /// frames permitted to appear more than once (e.g. `APIC`, `COMM`, `TXXX`
+  /// and `UFID`), which only replace frames with the same description,
+  /// language or owner. Replaced frames are removed and the frames of the
+  /// update are appended.
+  ///
+  /// The update is usually flagged with
+  /// [`TAG_IS_UPDATE`][crate::id3v2::ExtHeaderFlagsV4::TAG_IS_UPDATE], see
+  /// [`ExtHeader::flag_update`]. The merged tag has no extended header or
+  /// footer.
+  ///
+  /// Returns an [`InvalidVersion`][ErrorKind::InvalidVersion] error if either
+  /// tag is not an ID3v2.4 tag.
+  pub fn apply_update(&self, update: &Tag) -> Result<Tag> {
+    merge::apply_update(self, update)
+  }
+
+  /// Convert the frames of an ID3v2.3 tag to ID3v2.4.
+  ///
+  /// Returns an [`InvalidVersion`][ErrorKind::InvalidVersion] error if the
+  /// tag is not an ID3v2.3 tag, or the first error encountered while decoding
+  /// the frames.
+  ///
+  /// See [`Upgrade::new`] for details.
+  pub fn upgrade(&self) -> Result<Upgrade<'_>> {
+    if self.header.version() != Version::ID3v23 {
+      return Err(Error::new(ErrorKind::InvalidVersion));
+    }
+
+    let mut frames: Vec<(FrameId, Content<'_>)> = Vec::new();
+
+    for frame in self.frames() {
+      let frame: DynFrame<'_> = frame?;
+
+      let DynFrameId::V3(identifier) = frame.identifier() else {
+        return Err(Error::new(ErrorKind::InvalidFrameId));
+      };
+
+      frames.push((identifier, frame.decode()?));
+    }
+
+    Ok(Upgrade::new(frames))
+  }
+
+  /// Get the offset declared by the `SEEK` frame of the tag (if any).
+  ///
+  /// This is the minimum offset from the end of the tag to the next tag.
+  pub fn seek_offset(&self) -> Result<Option<u32>> {
+    for frame in self.frames() {
+      let frame: DynFrame<'_> = frame?;
+
+      if frame.identifier_str() != "SEEK" {
+        continue;
+      }
+
+      let Ok(bytes) = <[u8; 4]>::try_from(frame.frame_data().as_ref()) else {
+        return Err(Error::new(ErrorKind::InvalidFrameData));
+      };
+
+      return Ok(Some(u32::from_be_bytes(bytes)));
+    }
+
+    Ok(None)
+  }
+
+  /// Get the position of the tag linked by the `SEEK` frame (if any), given
+  /// the `position` of this tag in the file.
+  ///
+  /// Compare the result with the position of an appended update tag to verify
+  /// the link.
+  pub fn seek_target(&self, position: u64) -> Result<Option<u64>> {
+    let Some(offset) = self.seek_offset()? else {
+      return Ok(None);
+    };
+
+    let size: u64 = self.header.total_size();
+
+    Ok(Some(position + size + u64::from(offset)))
+  }
+
+  /// Write every embedded binary object (e.g. pictures and `GEOB` objects)
+  /// to a file in `dir`, which is created if missing.
+  ///
+  /// Filenames are derived from the frame identifier and a sanitized
+  /// description or owner, with an extension sniffed from the payload. Names
+  /// never contain path separators and existing files are never overwritten,
+  /// a counter is appended instead.
+  ///
+  /// Frames that cannot be parsed or decoded are skipped. Returns a manifest
+  /// of the files written, or an error if writing a file fails.
+  pub fn extract_binaries(
+    &self,
+    dir: &Path,
+    options: &ExtractOptions,
+  ) -> Result<Vec<ExtractedFile>> {
+    extract::extract(self, dir, options)
+  }
+
+  /// Get the title of the tag.
+  ///
+  /// Returns `None` if the frame is missing or cannot be decoded, see
+  /// [`title_all`][Self::title_all] for multi-value frames.
+  #[inline]
+  pub fn title(&self) -> Option<Cow<'_, str>> {
+    self.first_value(self.frame_name("TT2", "TIT2"))
+  }
+
+  /// Get all titles of the tag.
+  #[inline]
+  pub fn title_all(&self) -> Vec<Cow<'_, str>> {
+    self.all_values(self.frame_name("TT2", "TIT2"))
+  }
+
+  /// Get the artist of the tag.
+  #[inline]
+  pub fn artist(&self) -> Option<Cow<'_, str>> {
+    self.first_value(self.frame_name("TP1", "TPE1"))
+  }
+
+  /// Get all artists of the tag.
+  #[inline]
+  pub fn artist_all(&self) -> Vec<Cow<'_, str>> {
+    self.all_values(self.frame_name("TP1", "TPE1"))
+  }
+
+  /// Get the album of the tag.
+  #[inline]
+  pub fn album(&self) -> Option<Cow<'_, str>> {
+    self.first_value(self.frame_name("TAL", "TALB"))
+  }
+
+  /// Get all albums of the tag.
+  #[inline]
+  pub fn album_all(&self) -> Vec<Cow<'_, str>> {
+    self.all_values(self.frame_name("TAL", "TALB"))
+  }
+
+  /// Get the album artist of the tag.
+  #[inline]
+  pub fn album_artist(&self) -> Option<Cow<'_, str>> {
+    self.first_value(self.frame_name("TP2", "TPE2"))
+  }
+
+  /// Get all album artists of the tag.
+  #[inline]
+  pub fn album_artist_all(&self) -> Vec<Cow<'_, str>> {
+    self.all_values(self.frame_name("TP2", "TPE2"))
+  }
+
+  /// Get the genre of the tag.
+  ///
+  /// Numeric references to the genre list are resolved to the genre name.
+  pub fn genre(&self) -> Option<Cow<'_, str>> {
+    self.genre_all().into_iter().next()
+  }
+
+  /// Get all genres of the tag.
+  ///
+  /// Numeric references to the genre list are resolved to the genre name.
+  pub fn genre_all(&self) -> Vec<Cow<'_, str>> {
+    let mut output: Vec<Cow<'_, str>> = self.all_values(self.frame_name("TCO", "TCON"));
+
+    for value in output.iter_mut() {
+      if let Some(genre) = parse_genre(value) {
+        *value = Cow::Borrowed(genre.name());
+      }
+    }
+
+    output
+  }
+
+  /// Get the track number of the tag.
+  #[inline]
+  pub fn track(&self) -> Option<Position> {
+    self
+      .find_text(self.frame_name("TRK", "TRCK"))
+      .ok()??
+      .position()
+  }
+
+  /// Get the disc number of the tag.
+  #[inline]
+  pub fn disc(&self) -> Option<Position> {
+    self
+      .find_text(self.frame_name("TPA", "TPOS"))
+      .ok()??
+      .position()
+  }
+
+  /// Get the year of the tag.
+  ///
+  /// The year is read from the TYER (ID3v2.3), TYE (ID3v2.2) or the recording
+  /// time TDRC (ID3v2.4) frame.
+  pub fn year(&self) -> Option<u32> {
+    let date: Cow<'_, str> = self.date_recorded()?;
+    let year: &str = date.get(..4)?;
+
+    if !year.bytes().all(|byte| byte.is_ascii_digit()) {
+      return None;
+    }
+
+    year.parse().ok()
+  }
+
+  /// Get the recording time of the tag.
+  ///
+  /// Note: Only the year is available in ID3v2.2 and ID3v2.3 tags.
+  pub fn date_recorded(&self) -> Option<Cow<'_, str>> {
+    match self.header.version() {
+      Version::ID3v22 => self.first_value("TYE"),
+      Version::ID3v23 => self.first_value("TYER"),
+      _ => self.first_value("TDRC"),
+    }
+  }
+
+  /// Get the duration of the audio from the TLEN (or TLE in ID3v2.2) frame.
+  pub fn duration(&self) -> Option<Duration> {
+    let length: Cow<'_, str> = self.first_value(self.frame_name("TLE", "TLEN"))?;
+    let millis: u64 = length.trim().parse().ok()?;
+
+    Some(Duration::from_millis(millis))
+  }
+
+  /// Get an iterator over the attached pictures of the tag.
+  ///
+  /// Pictures are decoded with the options used to parse the tag, errors are
+  /// yielded as well.
+  pub fn pictures(&self) -> impl Iterator<Item = Result<Apic<'_>>> + '_ {
+    let identifier: &str = self.frame_name("PIC", "APIC");
+
+    self
+      .decode_all(identifier)
+      .filter_map(|content| match content {
+        Ok(Content::Apic(inner)) => Some(Ok(inner)),
+        Ok(_) => None,
+        Err(error) => Some(Err(error)),
+      })
+  }
+
+  /// Get the front cover of the tag.
+  ///
+  /// Falls back to the first picture if none is marked as the front cover.
+  /// Pictures that cannot be decoded are ignored.
+  pub fn front_cover(&self) -> Option<Apic<'_>> {
+    let mut output: Option<Apic<'_>> = None;
+
+    for picture in self.pictures().filter_map(Result::ok) {
+      if picture.picture_type() == PicType::CoverFront {
+        return Some(picture);
+      }
+
+      output.get_or_insert(picture);
+    }
+
+    output
+  }
+
+  /// Get an iterator over the general encapsulated objects of the tag.
+  ///
+  /// Objects are decoded with the options used to parse the tag, errors are
+  /// yielded as well.
+  pub fn geobs(&self) -> impl Iterator<Item = Result<Geob<'_>>> + '_ {
+    let identifier: &str = self.frame_name("GEO", "GEOB");
+
+    self
+      .decode_all(identifier)
+      .filter_map(|content| match content {
+        Ok(Content::Geob(inner)) => Some(Ok(inner)),
+        Ok(_) => None,
+        Err(error) => Some(Err(error)),
+      })
+  }
+
+  /// Get the general encapsulated object with the given content
+  /// `description`.
+  pub fn geob_by_description(&self, description: &str) -> Result<Option<Geob<'_>>> {
+    for geob in self.geobs() {
+      let geob: Geob<'_> = geob?;
+
+      if geob.content_description() == description {
+        return Ok(Some(geob));
+      }
+    }
+
+    Ok(None)
+  }
+
+  /// Get the grouping of the tag.
+  ///
+  /// iTunes 12.5 moved the grouping from TIT1 to the GRP1 frame and now uses
+  /// TIT1 for the classical "work". GRP1 is preferred when present, otherwise
+  /// the legacy TIT1 (or TT1 in ID3v2.2) frame is returned.
+  pub fn grouping(&self) -> Result<Option<Text<'_>>> {
+    if let Some(text) = self.find_text("GRP1")? {
+      return Ok(Some(text));
+    }
+
+    match self.header.version() {
+      Version::ID3v22 => self.find_text("TT1"),
+      _ => self.find_text("TIT1"),
+    }
+  }
+
+  /// Get the work of the tag.
+  ///
+  /// Note: TIT1 is only treated as the work when a GRP1 frame is present,
+  /// otherwise it is assumed to hold the legacy [`grouping`][Self::grouping].
+  pub fn work(&self) -> Result<Option<Text<'_>>> {
+    if self.find_text("GRP1")?.is_none() {
+      return Ok(None);
+    }
+
+    self.find_text("TIT1")
+  }
+
+  /// Get the album sort order of the tag.
+  ///
+  /// Note: Falls back to the legacy MusicBrainz XSOA frame if TSOA is missing.
+  #[inline]
+  pub fn album_sort(&self) -> Result<Option<Text<'_>>> {
+    self.find_sort("TSOA", "XSOA")
+  }
+
+  /// Get the performer sort order of the tag.
+  ///
+  /// Note: Falls back to the legacy MusicBrainz XSOP frame if TSOP is missing.
+  #[inline]
+  pub fn performer_sort(&self) -> Result<Option<Text<'_>>> {
+    self.find_sort("TSOP", "XSOP")
+  }
+
+  /// Get the title sort order of the tag.
+  ///
+  /// Note: Falls back to the legacy MusicBrainz XSOT frame if TSOT is missing.
+  #[inline]
+  pub fn title_sort(&self) -> Result<Option<Text<'_>>> {
+    self.find_sort("TSOT", "XSOT")
+  }
+
+  /// Get the comment of the tag.
+  ///
+  /// See [`comment_with`][Self::comment_with] for details.
+  #[inline]
+  pub fn comment(&self) -> Result<Option<Comm<'_>>> {
+    self.comment_with(&LanguagePreference::new())
+  }
+
+  /// Get the comment of the tag in the preferred language.
+  ///
+  /// Within each level of the `preference`, a comment with an empty
+  /// description is preferred over comments with a description, which are
+  /// conventionally used for application data (e.g. `iTunNORM`).
+  pub fn comment_with(&self, preference: &LanguagePreference) -> Result<Option<Comm<'_>>> {
+    self.find_language(
+      preference,
+      self.frame_name("COM", "COMM"),
+      |content| match content {
+        Content::Comm(inner) => Some((inner.language(), !inner.text_summary().is_empty(), inner)),
+        _ => None,
+      },
+    )
+  }
+
+  /// Get an iterator over the comments of the tag.
+  ///
+  /// Comments are decoded with the options used to parse the tag, errors are
+  /// yielded as well.
+  pub fn comments(&self) -> LangFrameIter<'_, Comm<'_>> {
+    LangFrameIter::new(
+      self,
+      self.frame_name("COM", "COMM"),
+      |content| match content {
+        Content::Comm(inner) => Some(inner),
+        _ => None,
+      },
+      |inner| (inner.language(), inner.text_summary()),
+    )
+  }
+
+  /// Get the unsynchronised lyrics of the tag.
+  ///
+  /// See [`lyrics_with`][Self::lyrics_with] for details.
+  #[inline]
+  pub fn lyrics(&self) -> Result<Option<Uslt<'_>>> {
+    self.lyrics_with(&LanguagePreference::new())
+  }
+
+  /// Get the unsynchronised lyrics of the tag in the preferred language.
+  pub fn lyrics_with(&self, preference: &LanguagePreference) -> Result<Option<Uslt<'_>>> {
+    self.find_language(
+      preference,
+      self.frame_name("ULT", "USLT"),
+      |content| match content {
+        Content::Uslt(inner) => Some((inner.language(), false, inner)),
+        _ => None,
+      },
+    )
+  }
+
+  /// Get an iterator over the unsynchronised lyrics of the tag.
+  ///
+  /// Lyrics are decoded with the options used to parse the tag, errors are
+  /// yielded as well.
+  pub fn lyrics_all(&self) -> LangFrameIter<'_, Uslt<'_>> {
+    LangFrameIter::new(
+      self,
+      self.frame_name("ULT", "USLT"),
+      |content| match content {
+        Content::Uslt(inner) => Some(inner),
+        _ => None,
+      },
+      |inner| (inner.language(), inner.content_descriptor()),
+    )
+  }
+
+  /// Get the terms of use of the tag.
+  ///
+  /// See [`terms_of_use_with`][Self::terms_of_use_with] for details.
+  #[inline]
+  pub fn terms_of_use(&self) -> Result<Option<User<'_>>> {
+    self.terms_of_use_with(&LanguagePreference::new())
+  }
+
+  /// Get the terms of use of the tag in the preferred language.
+  pub fn terms_of_use_with(&self, preference: &LanguagePreference) -> Result<Option<User<'_>>> {
+    self.find_language(preference, "USER", |content| match content {
+      Content::User(inner) => Some((inner.language(), false, inner)),
+      _ => None,
+    })
+  }
+
+  /// Get the audio encryption frames of the tag.
+  ///
+  /// Each `AENC` frame is paired with the first `ENCR` frame with the same
+  /// owner identifier. If the `audio_length` (in frames) is given, the
+  /// preview of each frame is checked against it, see
+  /// [`AudioEncryption::is_preview_valid`].
+  pub fn audio_encryption(&self, audio_length: Option<u32>) -> Result<Vec<AudioEncryption<'_>>> {
+    let mut aencs: Vec<Aenc<'_>> = Vec::new();
+    let mut encrs: Vec<Encr<'_>> = Vec::new();
+
+    for frame in self.frames() {
+      let frame: DynFrame<'_> = frame?;
+
+      match frame.identifier_str() {
+        "CRA" | "AENC" | "ENCR" => {}
+        _ => continue,
+      }
+
+      match frame.decode()? {
+        Content::Aenc(inner) => aencs.push(inner),
+        Content::Encr(inner) => encrs.push(inner),
+        _ => {}
+      }
+    }
+
+    let output: Vec<AudioEncryption<'_>> = aencs
+      .into_iter()
+      .map(|aenc| {
+        let method: Option<Encr<'_>> = encrs
+          .iter()
+          .find(|encr| encr.owner_identifier() == aenc.owner_identifier())
+          .cloned();
+
+        AudioEncryption::new(aenc, method, audio_length)
+      })
+      .collect();
+
+    Ok(output)
+  }
+
+  /// Get the encryption method registrations (`ENCR`) of the tag.
+  ///
+  /// Each registration maps an owner identifier to the method symbol used by
+  /// frames encrypted with that method, see
+  /// [`DecoderConfig::decryptor`] to decrypt them.
+  pub fn encryption_methods(&self) -> Result<Vec<Encr<'_>>> {
+    let mut output: Vec<Encr<'_>> = Vec::new();
+
+    for frame in self.frames() {
+      let frame: DynFrame<'_> = frame?;
+
+      if frame.identifier_str() != "ENCR" {
+        continue;
+      }
+
+      if let Content::Encr(inner) = frame.decode()? {
+        output.push(inner);
+      }
+    }
+
+    Ok(output)
+  }
+
+  /// Get the method symbol registered by the given encryption `owner`.
+  pub fn encryption_method(&self, owner: &str) -> Result<Option<u8>> {
+    let methods: Vec<Encr<'_>> = self.encryption_methods()?;
+
+    let method: Option<u8> = methods
+      .iter()
+      .find(|encr| encr.owner_identifier() == owner)
+      .map(Encr::method_symbol);
+
+    Ok(method)
+  }
+
+  /// Get the group identification registration (`GRID`) for the group with
+  /// the given identifier.
+  pub fn group_registration(&self, id: u8) -> Result<Option<Grid<'_>>> {
+    for frame in self.frames() {
+      let frame: DynFrame<'_> = frame?;
+
+      if frame.identifier_str() != "GRID" {
+        continue;
+      }
+
+      if let Content::Grid(inner) = frame.decode()? {
+        if inner.group_symbol() == id {
+          return Ok(Some(inner));
+        }
+      }
+    }
+
+    Ok(None)
+  }
+
+  /// Decode the frames with the given `identifier` using the options used to
+  /// parse the tag.
+  fn decode_all<'a>(
+    &'a self,
+    identifier: &'a str,
+  ) -> impl Iterator<Item = Result<Content<'a>>> + 'a {
+    self.frames().filter_map(move |frame| {
+      let frame: DynFrame<'_> = match frame {
+        Ok(frame) if frame.identifier_str() == identifier => frame,
+        Ok(_) => return None,
+        Err(error) => return Some(Err(error)),
+      };
+
+      Some(self.decode_frame(&frame, &mut Vec::new()))
+    })
+  }
+
+  /// Find the frame that best matches the language `preference`.
+  ///
+  /// Frames are decoded with the options used to parse the tag and ranked by
+  /// language and then by the `secondary` flag returned from `select`, the
+  /// first of equally ranked frames is returned.
+  fn find_language<'a, T, F>(
+    &'a self,
+    preference: &LanguagePreference,
+    identifier: &str,
+    select: F,
+  ) -> Result<Option<T>>
+  where
+    F: Fn(Content<'a>) -> Option<(Language, bool, T)>,
+  {
+    let mut output: Option<((usize, bool), T)> = None;
+
+    for frame in self.frames() {
+      let frame: DynFrame<'_> = frame?;
+
+      if frame.identifier_str() != identifier {
+        continue;
+      }
+
+      let content: Content<'a> = self.decode_frame(&frame, &mut Vec::new())?;
+
+      let Some((language, secondary, item)) = select(content) else {
+        continue;
+      };
+
+      let Some(rank) = preference.rank(&language) else {
+        continue;
+      };
+
+      if output
+        .as_ref()
+        .is_none_or(|(best, _)| (rank, secondary) < *best)
+      {
+        output = Some(((rank, secondary), item));
+      }
+    }
+
+    Ok(output.map(|(_, item)| item))
+  }
+
+  /// Select the ID3v2.2 or ID3v2.3/ID3v2.4 frame identifier.
+  const fn frame_name(&self, v2: &'static str, v3: &'static str) -> &'static str {
+    match self.header.version() {
+      Version::ID3v22 => v2,
+      _ => v3,
+    }
+  }
+
+  /// Map the frame identifier `id` between ID3v2.2 and ID3v2.3/ID3v2.4 to
+  /// match the version of the tag, comparing identifiers with `eq`.
+  fn frame_id<'a>(&self, id: &'a str, eq: fn(&str, &str) -> bool) -> &'a str {
+    let found: Option<&(&str, &str)> = match (self.header.version(), id.len()) {
+      (Version::ID3v22, 4) => UPGRADE_V23.iter().find(|(_, v3)| eq(v3, id)),
+      (Version::ID3v23 | Version::ID3v24, 3) => UPGRADE_V23.iter().find(|(v2, _)| eq(v2, id)),
+      _ => None,
+    };
+
+    match (found, self.header.version()) {
+      (Some((v2, _)), Version::ID3v22) => v2,
+      (Some((_, v3)), _) => v3,
+      (None, _) => id,
+    }
+  }
+
+  fn restrictions(&self) -> Option<Restrictions> {
+    self.header.exheader().and_then(ExtHeader::restrictions)
+  }
+
+  fn find_sort(&self, identifier: &str, legacy: &str) -> Result<Option<Text<'_>>> {
+    match self.find_text(identifier)? {
+      Some(text) => Ok(Some(text)),
+      None => self.find_text(legacy),
+    }
+  }
+
+  /// Get the first string of the first text frame with the given
+  /// `identifier`, ignoring errors.
+  fn first_value(&self, identifier: &str) -> Option<Cow<'_, str>> {
+    self.all_values(identifier).into_iter().next()
+  }
+
+  /// Get the strings of the first text frame with the given `identifier`,
+  /// ignoring errors.
+  fn all_values(&self, identifier: &str) -> Vec<Cow<'_, str>> {
+    match self.find_text(identifier) {
+      Ok(Some(text)) => text.into_values(),
+      Ok(None) | Err(_) => Vec::new(),
+    }
+  }
+
+  /// Find the first text frame with the given `identifier`.
+  ///
+  /// Frames are decoded with the options used to parse the tag. Frames that
+  /// fail to parse are skipped, only an error decoding a matching frame is
+  /// returned.
+  pub(crate) fn find_text(&self, identifier: &str) -> Result<Option<Text<'_>>> {
+    for frame in self.frames().filter_map(Result::ok) {
+      if frame.identifier_str() != identifier {
+        continue;
+      }
+
+      if let Content::Text(text) = self.decode_frame(&frame, &mut Vec::new())? {
+        return Ok(Some(text));
+      }
+    }
+
+    Ok(None)
+  }
+
   /// Parse an ID3v2 tag from the file at the given `path`.
+  #[inline]
   pub fn from_path<P>(path: &P) -> Result<Self>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    Self::from_path_with(path, ParseOptions::new())
+  }
+
+  /// Parse an ID3v2 tag from the file at the given `path` with custom `options`.
+  pub fn from_path_with<P>(path: &P, options: ParseOptions) -> Result<Self>
   where
     P: AsRef<Path> + ?Sized,
   {
     let file: File = File::open(path)?;
     let read: BufReader<File> = BufReader::new(file);
 
-    Self::from_reader(read)
+    Self::from_reader_with(read, options)
   }
 
   /// Parse an ID3v2 tag from the given `reader`.
-  pub fn from_reader<R>(mut reader: R) -> Result<Self>
+  #[inline]
+  pub fn from_reader<R>(reader: R) -> Result<Self>
   where
     R: ReadExt,
   {
-    let header: Header = Header::from_reader(&mut reader)?;
-    let length: usize = header.data_len() as usize;
+    Self::from_reader_with(reader, ParseOptions::new())
+  }
+
+  /// Parse an ID3v2 tag from the given `reader` with custom `options`.
+  ///
+  /// Returns an [`Unsupported`][ErrorKind::Unsupported] error if the tag is
+  /// a compressed ID3v2.2 tag, use [`Header::from_reader`] to find the size
+  /// of the tag and skip it.
+  pub fn from_reader_with<R>(reader: R, options: ParseOptions) -> Result<Self>
+  where
+    R: ReadExt,
+  {
+    let mut parser: TagParser = TagParser::new(options);
+
+    parser.read_tag(reader)?;
+    parser.finish()
+  }
+
+  /// Parse an ID3v2 tag starting at `offset` in the given `reader`.
+  ///
+  /// See [`from_reader_at_mut_with`][Self::from_reader_at_mut_with] for details.
+  #[inline]
+  pub fn from_reader_at<R>(reader: R, offset: u64) -> Result<Self>
+  where
+    R: Read + Seek,
+  {
+    Self::from_reader_at_with(reader, offset, ParseOptions::new())
+  }
+
+  /// Parse an ID3v2 tag starting at `offset` in the given `reader` with custom
+  /// `options`.
+  ///
+  /// See [`from_reader_at_mut_with`][Self::from_reader_at_mut_with] for details.
+  #[inline]
+  pub fn from_reader_at_with<R>(mut reader: R, offset: u64, options: ParseOptions) -> Result<Self>
+  where
+    R: Read + Seek,
+  {
+    Self::from_reader_at_mut_with(&mut reader, offset, options)
+  }
 
-    // Read the entire set of frames, which is sized according to the header.
-    let buffer: Bytes = if header.flag_unsynchronisation() {
-      Unsync::new(reader).read_bytes(length)?
+  /// Parse an ID3v2 tag starting at `offset` in a borrowed `reader`.
+  ///
+  /// See [`from_reader_at_mut_with`][Self::from_reader_at_mut_with] for details.
+  #[inline]
+  pub fn from_reader_at_mut<R>(reader: &mut R, offset: u64) -> Result<Self>
+  where
+    R: Read + Seek + ?Sized,
+  {
+    Self::from_reader_at_mut_with(reader, offset, ParseOptions::new())
+  }
+
+  /// Parse an ID3v2 tag starting at `offset` in a borrowed `reader` with
+  /// custom `options`.
+  ///
+  /// The reader is returned to the position it had before the call, whether
+  /// or not the tag was parsed successfully. If restoring the position fails,
+  /// that I/O error is returned instead of the parse result.
+  pub fn from_reader_at_mut_with<R>(
+    reader: &mut R,
+    offset: u64,
+    options: ParseOptions,
+  ) -> Result<Self>
+  where
+    R: Read + Seek + ?Sized,
+  {
+    let position: u64 = reader.stream_position()?;
+
+    let output: Result<Self> = match reader.seek(SeekFrom::Start(offset)) {
+      Ok(_) => Self::from_reader_with(&mut *reader, options),
+      Err(error) => Err(error.into()),
+    };
+
+    reader.seek(SeekFrom::Start(position))?;
+
+    output
+  }
+
+  /// Find the ID3v2 tags in the given seekable `reader`.
+  ///
+  /// The tag at the start of the stream is found by its header and a tag
+  /// appended to the end of the stream, before the ID3v1 tag (if any), by its
+  /// footer. Tags linked with a `SEEK` frame can be found with
+  /// [`seek_target`][Self::seek_target].
+  ///
+  /// The reader is returned to the position it had before the call.
+  pub fn locate<R>(reader: &mut R) -> Result<Vec<TagLocation>>
+  where
+    R: Read + Seek + ?Sized,
+  {
+    let position: u64 = reader.stream_position()?;
+    let output: Result<Vec<TagLocation>> = locate::locate(reader);
+
+    reader.seek(SeekFrom::Start(position))?;
+
+    output
+  }
+
+  /// Parse every ID3v2 tag in the file at the given `path`.
+  ///
+  /// See [`from_reader_all_with`][Self::from_reader_all_with] for details.
+  #[inline]
+  pub fn from_path_all<P>(path: &P) -> Result<Vec<(TagLocation, Self)>>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    Self::from_path_all_with(path, ParseOptions::new())
+  }
+
+  /// Parse every ID3v2 tag in the file at the given `path` with custom
+  /// `options`.
+  ///
+  /// See [`from_reader_all_with`][Self::from_reader_all_with] for details.
+  pub fn from_path_all_with<P>(path: &P, options: ParseOptions) -> Result<Vec<(TagLocation, Self)>>
+  where
+    P: AsRef<Path> + ?Sized,
+  {
+    let file: File = File::open(path)?;
+    let mut read: BufReader<File> = BufReader::new(file);
+
+    Self::from_reader_all_with(&mut read, options)
+  }
+
+  /// Parse every ID3v2 tag in the given seekable `reader`.
+  ///
+  /// See [`from_reader_all_with`][Self::from_reader_all_with] for details.
+  #[inline]
+  pub fn from_reader_all<R>(reader: &mut R) -> Result<Vec<(TagLocation, Self)>>
+  where
+    R: Read + Seek + ?Sized,
+  {
+    Self::from_reader_all_with(reader, ParseOptions::new())
+  }
+
+  /// Parse every ID3v2 tag in the given seekable `reader` with custom
+  /// `options`.
+  ///
+  /// The tags are found with [`locate`][Self::locate] and returned in the
+  /// order they appear in the stream.
+  pub fn from_reader_all_with<R>(
+    reader: &mut R,
+    options: ParseOptions,
+  ) -> Result<Vec<(TagLocation, Self)>>
+  where
+    R: Read + Seek + ?Sized,
+  {
+    Self::locate(reader)?
+      .into_iter()
+      .map(|location| {
+        Self::from_reader_at_mut_with(reader, location.offset(), options).map(|tag| (location, tag))
+      })
+      .collect()
+  }
+
+  pub(crate) fn new(
+    header: Header,
+    footer: Option<Footer>,
+    buffer: Bytes,
+    raw: Option<Bytes>,
+    missing: usize,
+    mut warnings: Vec<Warning>,
+    options: ParseOptions,
+  ) -> Self {
+    // Stale data in the padding is only skipped in lenient mode, the padding
+    // of a truncated tag is missing.
+    let frames_end: usize = if options.is_lenient() && missing == 0 {
+      iter::frames_end(&header, buffer.as_slice(), &mut warnings)
     } else {
-      reader.read_bytes(length)?
+      buffer.len()
     };
 
-    Ok(Self { header, buffer })
+    Self {
+      header,
+      footer,
+      buffer,
+      raw,
+      missing,
+      warnings,
+      options,
+      frames_end,
+    }
+  }
+
+  /// Get the end of the frames in the tag buffer.
+  pub(crate) const fn frames_end(&self) -> usize {
+    self.frames_end
   }
 }
 
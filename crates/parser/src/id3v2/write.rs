@@ -0,0 +1,356 @@
+use std::ffi::OsString;
+use std::fs;
+use std::fs::File;
+use std::io::copy;
+use std::io::repeat;
+use std::io::BufWriter;
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering;
+
+use crate::content::Content;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::error::Result;
+use crate::frame::DynFrame;
+use crate::frame::FrameV4;
+use crate::frame::FrameV4Extra;
+use crate::frame::FrameV4Flags;
+use crate::id3v2::digest;
+use crate::id3v2::ExtHeader;
+use crate::id3v2::Header;
+use crate::id3v2::HeaderFlags;
+use crate::id3v2::Tag;
+use crate::options::Padding;
+use crate::options::WriteOptions;
+use crate::types::FrameId;
+use crate::types::Version;
+use crate::utils;
+
+/// Frames that are not permitted in an update tag.
+const UPDATE_FORBIDDEN: &[&str] = &["ASPI", "SEEK"];
+
+// =============================================================================
+// Tag Writer
+// =============================================================================
+
+/// Write the header, extended header, frames and padding or footer of the
+/// `tag` to the `writer`.
+///
+/// Returns the number of bytes written.
+pub(crate) fn write_tag<W>(tag: &Tag, writer: W, options: WriteOptions) -> Result<u64>
+where
+  W: Write,
+{
+  let header: &Header = tag.header();
+  let frames: &[u8] = &tag.buffer().as_ref()[..digest::frames_len(tag)?];
+
+  write_frames(
+    writer,
+    header.version(),
+    header.bitflags(),
+    header.exheader(),
+    frames,
+    options,
+  )
+}
+
+/// Write a tag of the given `version` containing the encoded `frames` to the
+/// `writer`.
+///
+/// Returns the number of bytes written.
+pub(crate) fn write_frames<W>(
+  mut writer: W,
+  version: Version,
+  bitflags: HeaderFlags,
+  exheader: Option<&ExtHeader>,
+  frames: &[u8],
+  options: WriteOptions,
+) -> Result<u64>
+where
+  W: Write,
+{
+  let padding: usize = options.padding_len(frames.len());
+  let mut ext_data: Vec<u8> = Vec::new();
+
+  if let Some(ext) = exheader {
+    let ext: ExtHeader = ext.with_padding(u32::try_from(padding)?);
+
+    match version {
+      Version::ID3v23 => ext.write_v3(&mut ext_data)?,
+      _ => ext.write_v4(&mut ext_data)?,
+    };
+  }
+
+  let size: u32 = u32::try_from(ext_data.len() + frames.len() + padding)?;
+
+  if size > utils::MAX_U28_UNSYNC {
+    return Err(Error::new(ErrorKind::Int));
+  }
+
+  let major: u8 = match version {
+    Version::ID3v11 | Version::ID3v12 => return Err(Error::new(ErrorKind::InvalidVersion)),
+    Version::ID3v22 => 0x02,
+    Version::ID3v23 => 0x03,
+    Version::ID3v24 => 0x04,
+  };
+
+  if options.writes_footer() && major != 0x04 {
+    return Err(Error::new(ErrorKind::InvalidVersion));
+  }
+
+  let mut bitflags: HeaderFlags = bitflags - HeaderFlags::UNSYNCHRONISATION;
+
+  bitflags.set(HeaderFlags::FOOTER_PRESENT, options.writes_footer());
+
+  let descriptor: [u8; 7] = {
+    let [a, b, c, d] = utils::encode_u28_unsync(size);
+    [major, 0x00, bitflags.bits(), a, b, c, d]
+  };
+
+  writer.write_all(&Header::IDENTIFIER)?;
+  writer.write_all(&descriptor)?;
+  writer.write_all(&ext_data)?;
+  writer.write_all(frames)?;
+  copy(&mut repeat(0x00).take(padding as u64), &mut writer)?;
+
+  if !options.writes_footer() {
+    return Ok((Header::SIZE + size as usize) as u64);
+  }
+
+  // The footer is a copy of the header with a different identifier.
+  writer.write_all(&Header::FOOTER_IDENTIFIER)?;
+  writer.write_all(&descriptor)?;
+
+  Ok((Header::SIZE * 2 + size as usize) as u64)
+}
+
+// =============================================================================
+// Tag Saver
+// =============================================================================
+
+/// Write the `tag` to the file at `path`, replacing the existing ID3v2 tag at
+/// the start of the file (if any).
+///
+/// The tag is overwritten in place if it fits within the existing tag region,
+/// with the remaining space written as padding. Otherwise the file is
+/// rewritten through a temporary file in the same directory.
+///
+/// Returns the number of bytes written for the tag.
+pub(crate) fn save_tag(tag: &Tag, path: &Path, options: WriteOptions) -> Result<u64> {
+  let mut file: File = File::options().read(true).write(true).open(path)?;
+  let region: u64 = region_len(&mut file)?;
+
+  let mut buffer: Vec<u8> = Vec::new();
+  let length: u64 = write_tag(tag, &mut buffer, options.padding(Padding::None))?;
+
+  let fits: bool = if options.writes_footer() {
+    length == region
+  } else {
+    length <= region
+  };
+
+  if fits {
+    let padding: usize = usize::try_from(region - length)?;
+
+    buffer.clear();
+    write_tag(tag, &mut buffer, options.padding(Padding::Fixed(padding)))?;
+
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(&buffer)?;
+    file.sync_all()?;
+
+    return Ok(region);
+  }
+
+  let (temp, output): (PathBuf, File) = create_temp(path)?;
+  let result: Result<u64> = rewrite(tag, &mut file, region, output, options);
+
+  // The file must be closed before it is replaced on Windows.
+  drop(file);
+
+  let result: Result<u64> = result.and_then(|length| {
+    fs::rename(&temp, path)?;
+    Ok(length)
+  });
+
+  if result.is_err() {
+    let _ = fs::remove_file(&temp);
+  }
+
+  result
+}
+
+/// Get the size of the ID3v2 tag region at the start of the `file`, including
+/// the header and footer.
+///
+/// Returns `0` if the file does not start with an ID3v2 tag.
+fn region_len(file: &mut File) -> Result<u64> {
+  let mut bytes: [u8; Header::SIZE] = [0; Header::SIZE];
+  let mut length: usize = 0;
+
+  while length < Header::SIZE {
+    match file.read(&mut bytes[length..])? {
+      0 => return Ok(0),
+      read => length += read,
+    }
+  }
+
+  if bytes[..3] != Header::IDENTIFIER {
+    return Ok(0);
+  }
+
+  Ok(Header::parse_base(bytes)?.total_size())
+}
+
+/// Write the `tag` followed by the data after the tag `region` of the `file`
+/// to the `output` file, with the permissions of the original file.
+fn rewrite(
+  tag: &Tag,
+  file: &mut File,
+  region: u64,
+  output: File,
+  options: WriteOptions,
+) -> Result<u64> {
+  let mut writer: BufWriter<File> = BufWriter::new(output);
+  let length: u64 = write_tag(tag, &mut writer, options)?;
+
+  file.seek(SeekFrom::Start(region))?;
+  copy(file, &mut writer)?;
+
+  let output: File = writer.into_inner().map_err(|error| error.into_error())?;
+
+  output.set_permissions(file.metadata()?.permissions())?;
+  output.sync_all()?;
+
+  Ok(length)
+}
+
+/// Create a new temporary file next to the file at `path`.
+///
+/// Existing files are never opened, a different name is tried instead.
+fn create_temp(path: &Path) -> Result<(PathBuf, File)> {
+  const ATTEMPTS: usize = 64;
+  static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+  for _ in 0..ATTEMPTS {
+    let temp: PathBuf = temp_path(path, COUNTER.fetch_add(1, Ordering::Relaxed));
+
+    match File::options().write(true).create_new(true).open(&temp) {
+      Ok(file) => return Ok((temp, file)),
+      Err(error) if error.kind() == IoErrorKind::AlreadyExists => continue,
+      Err(error) => return Err(error.into()),
+    }
+  }
+
+  Err(IoError::from(IoErrorKind::AlreadyExists).into())
+}
+
+/// Get the path of the `count`-th temporary file used to rewrite the file at
+/// `path`.
+fn temp_path(path: &Path, count: u32) -> PathBuf {
+  let mut name: OsString = OsString::from(".");
+
+  if let Some(file_name) = path.file_name() {
+    name.push(file_name);
+  }
+
+  name.push(format!(".{}-{count}.errai", process::id()));
+  path.with_file_name(name)
+}
+
+/// Write an ID3v2.4 update tag containing the `changes` that differ from the
+/// frames of the `base` tag to the `writer`.
+///
+/// Returns the number of bytes written.
+pub(crate) fn write_update<W>(
+  mut writer: W,
+  base: &Tag,
+  changes: &[(FrameId, Content<'_>)],
+) -> Result<u64>
+where
+  W: Write,
+{
+  if base.header().version() != Version::ID3v24 {
+    return Err(Error::new(ErrorKind::InvalidVersion));
+  }
+
+  let mut frames: Vec<u8> = Vec::new();
+
+  for (identifier, content) in changes {
+    if UPDATE_FORBIDDEN.contains(&identifier.as_str()) {
+      return Err(Error::new(ErrorKind::ForbiddenFrame(*identifier)));
+    }
+
+    if contains(base, *identifier, content)? {
+      continue;
+    }
+
+    write_frame_v4(&mut frames, *identifier, content)?;
+  }
+
+  let mut exheader: Vec<u8> = Vec::new();
+
+  ExtHeader::builder()
+    .update(true)
+    .build(Version::ID3v24)?
+    .write_v4(&mut exheader)?;
+
+  let size: u32 = u32::try_from(exheader.len() + frames.len())?;
+
+  if size > utils::MAX_U28_UNSYNC {
+    return Err(Error::new(ErrorKind::Int));
+  }
+
+  writer.write_all(&Header::IDENTIFIER)?;
+  writer.write_all(&[0x04, 0x00, HeaderFlags::EXTENDED_HEADER.bits()])?;
+  writer.write_all(&utils::encode_u28_unsync(size))?;
+  writer.write_all(&exheader)?;
+  writer.write_all(&frames)?;
+
+  Ok((Header::SIZE + size as usize) as u64)
+}
+
+/// Append an ID3v2.4 frame with the given `identifier` and `content` to the
+/// `output`.
+pub(crate) fn write_frame_v4(
+  output: &mut Vec<u8>,
+  identifier: FrameId,
+  content: &Content<'_>,
+) -> Result<()> {
+  let data: Vec<u8> = content.encode(Version::ID3v24)?;
+  let length: u32 = u32::try_from(data.len())?;
+
+  FrameV4::write_header(
+    &mut *output,
+    identifier,
+    length,
+    FrameV4Flags::empty(),
+    FrameV4Extra::new(),
+  )?;
+
+  output.extend_from_slice(&data);
+  Ok(())
+}
+
+/// Returns `true` if the `tag` contains a frame with the given `identifier`
+/// and `content`.
+fn contains(tag: &Tag, identifier: FrameId, content: &Content<'_>) -> Result<bool> {
+  for frame in tag.frames() {
+    let frame: DynFrame<'_> = frame?;
+
+    if frame.identifier_str() == identifier.as_str() && frame.decode()? == *content {
+      return Ok(true);
+    }
+  }
+
+  Ok(false)
+}
@@ -10,13 +10,18 @@ extern crate derive;
 #[macro_use]
 mod macros;
 
-mod decode;
+mod encode;
 mod traits;
 mod utils;
 
 pub mod content;
+pub mod decode;
 pub mod error;
 pub mod frame;
+pub mod id3v1;
 pub mod id3v2;
+pub mod meta;
+pub mod options;
+pub mod text;
 pub mod types;
 pub mod unsync;
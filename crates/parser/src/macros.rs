@@ -9,6 +9,17 @@ macro_rules! decode_bitflags {
   };
 }
 
+macro_rules! encode_bitflags {
+  ($type:ty) => {
+    impl $crate::encode::Encode for $type {
+      #[inline]
+      fn encode(&self, encoder: &mut $crate::encode::Encoder) -> $crate::error::Result<()> {
+        encoder.encode(&self.bits())
+      }
+    }
+  };
+}
+
 macro_rules! copy_into_owned {
   ($type:ty) => {
     impl $crate::traits::IntoOwned for $type {
@@ -51,6 +62,14 @@ macro_rules! impl_content {
       }
     }
 
+    impl ::core::fmt::Display for Content<'_> {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+          $(Self::$variant(inner) => ::core::fmt::Display::fmt(inner, f)),+
+        }
+      }
+    }
+
     impl $crate::traits::IntoOwned for Content<'_> {
       type Owned = Content<'static>;
 
@@ -61,5 +80,36 @@ macro_rules! impl_content {
         }
       }
     }
+
+    impl Content<'_> {
+      /// Get the kind of frame content.
+      #[inline]
+      pub const fn kind(&self) -> ContentKind {
+        match self {
+          $(Self::$variant(_) => ContentKind::$variant),+
+        }
+      }
+    }
+
+    /// The kind of decoded frame content.
+    ///
+    /// This mirrors the variants of [`Content`] without any data.
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum ContentKind {
+      $(
+        $(#[$vmeta])*
+        $variant
+      ),+
+    }
+
+    impl ContentKind {
+      /// Get the name of the content kind (e.g. `"Apic"`).
+      #[inline]
+      pub const fn name(self) -> &'static str {
+        match self {
+          $(Self::$variant => stringify!($variant)),+
+        }
+      }
+    }
   };
 }
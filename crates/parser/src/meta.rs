@@ -0,0 +1,242 @@
+//! Unified ID3v1 and ID3v2 Metadata
+
+use std::fs::File;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Seek;
+use std::path::Path;
+
+use crate::content::Text;
+use crate::error::Result;
+use crate::id3v1::TagV1;
+use crate::id3v2::Header;
+use crate::id3v2::Tag;
+use crate::options::ParseOptions;
+use crate::types::Genre;
+use crate::types::Version;
+
+/// Read the ID3v2 and ID3v1 tags of the file at the given `path`.
+///
+/// See [`read_path_with`] for details.
+#[inline]
+pub fn read_path<P>(path: &P) -> Result<FileMeta>
+where
+  P: AsRef<Path> + ?Sized,
+{
+  read_path_with(path, ParseOptions::new())
+}
+
+/// Read the ID3v2 and ID3v1 tags of the file at the given `path` with custom
+/// ID3v2 parsing `options`.
+///
+/// The ID3v2 tag is read from the start of the file and the ID3v1 tag from
+/// the end of the file, either may be missing.
+pub fn read_path_with<P>(path: &P, options: ParseOptions) -> Result<FileMeta>
+where
+  P: AsRef<Path> + ?Sized,
+{
+  let mut file: File = File::open(path)?;
+  let mut identifier: [u8; 3] = [0; 3];
+
+  let v2: Option<Tag> =
+    if file.read_exact(&mut identifier).is_ok() && identifier == Header::IDENTIFIER {
+      file.rewind()?;
+      Some(Tag::from_reader_with(BufReader::new(&mut file), options)?)
+    } else {
+      None
+    };
+
+  let v1: Option<TagV1> = TagV1::from_reader(&mut file)?;
+
+  Ok(FileMeta::new(v2, v1))
+}
+
+// =============================================================================
+// File Metadata
+// =============================================================================
+
+/// The ID3v2 and ID3v1 tags of a file.
+///
+/// Common fields are resolved from both tags, in the order defined by the
+/// [`Precedence`].
+#[derive(Debug)]
+pub struct FileMeta {
+  v2: Option<Tag>,
+  v1: Option<TagV1>,
+  precedence: Precedence,
+}
+
+impl FileMeta {
+  /// Create a new `FileMeta` from the given ID3v2 and ID3v1 tags.
+  #[inline]
+  pub const fn new(v2: Option<Tag>, v1: Option<TagV1>) -> Self {
+    Self {
+      v2,
+      v1,
+      precedence: Precedence::PreferV2,
+    }
+  }
+
+  /// Set the order in which the tags are consulted.
+  #[inline]
+  pub fn with_precedence(mut self, precedence: Precedence) -> Self {
+    self.precedence = precedence;
+    self
+  }
+
+  /// Get the order in which the tags are consulted.
+  #[inline]
+  pub const fn precedence(&self) -> Precedence {
+    self.precedence
+  }
+
+  /// Get the ID3v2 tag (if any).
+  #[inline]
+  pub const fn tag_v2(&self) -> Option<&Tag> {
+    self.v2.as_ref()
+  }
+
+  /// Get the ID3v1 tag (if any).
+  #[inline]
+  pub const fn tag_v1(&self) -> Option<&TagV1> {
+    self.v1.as_ref()
+  }
+
+  /// Get the resolved title.
+  pub fn title(&self) -> Result<Option<String>> {
+    self.resolve(
+      |tag| text(tag, ["TT2", "TIT2", "TIT2"]),
+      |tag| owned(tag.title()),
+    )
+  }
+
+  /// Get the resolved artist.
+  pub fn artist(&self) -> Result<Option<String>> {
+    self.resolve(
+      |tag| text(tag, ["TP1", "TPE1", "TPE1"]),
+      |tag| owned(tag.artist()),
+    )
+  }
+
+  /// Get the resolved album.
+  pub fn album(&self) -> Result<Option<String>> {
+    self.resolve(
+      |tag| text(tag, ["TAL", "TALB", "TALB"]),
+      |tag| owned(tag.album()),
+    )
+  }
+
+  /// Get the resolved year.
+  ///
+  /// Note: Only the year of the ID3v2.4 recording time is returned.
+  pub fn year(&self) -> Result<Option<String>> {
+    self.resolve(
+      |tag| {
+        let year: Option<String> = text(tag, ["TYE", "TYER", "TDRC"])?;
+        Ok(year.and_then(|year| owned(year.split('-').next()?)))
+      },
+      |tag| owned(tag.year()),
+    )
+  }
+
+  /// Get the resolved track number.
+  pub fn track(&self) -> Result<Option<u32>> {
+    self.resolve(
+      |tag| {
+        let text: Option<Text<'_>> = find(tag, ["TRK", "TRCK", "TRCK"])?;
+        Ok(
+          text
+            .and_then(|text| text.position())
+            .map(|position| position.current()),
+        )
+      },
+      |tag| tag.track().map(u32::from),
+    )
+  }
+
+  /// Get the resolved genre.
+  ///
+  /// Numeric references to the genre list are resolved to the genre name.
+  pub fn genre(&self) -> Result<Option<String>> {
+    self.resolve(
+      |tag| {
+        let Some(text) = find(tag, ["TCO", "TCON", "TCON"])? else {
+          return Ok(None);
+        };
+
+        match text.genre() {
+          Some(genre) => Ok(Some(genre.name().to_owned())),
+          None => Ok(text.first().and_then(owned)),
+        }
+      },
+      |tag| tag.standard_genre().map(Genre::name).map(str::to_owned),
+    )
+  }
+
+  /// Get the resolved comment.
+  pub fn comment(&self) -> Result<Option<String>> {
+    self.resolve(
+      |tag| Ok(tag.comment()?.and_then(|comm| owned(comm.text_details()))),
+      |tag| owned(tag.comment()),
+    )
+  }
+
+  fn resolve<T, F, G>(&self, v2: F, v1: G) -> Result<Option<T>>
+  where
+    F: FnOnce(&Tag) -> Result<Option<T>>,
+    G: FnOnce(&TagV1) -> Option<T>,
+  {
+    let v2: Option<T> = match self.v2 {
+      Some(ref tag) => v2(tag)?,
+      None => None,
+    };
+
+    let v1: Option<T> = self.v1.as_ref().and_then(v1);
+
+    match self.precedence {
+      Precedence::PreferV2 => Ok(v2.or(v1)),
+      Precedence::PreferV1 => Ok(v1.or(v2)),
+    }
+  }
+}
+
+// =============================================================================
+// Precedence
+// =============================================================================
+
+/// The order in which the tags of a file are consulted.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precedence {
+  /// ID3v2 frames take precedence over ID3v1 fields.
+  #[default]
+  PreferV2,
+  /// ID3v1 fields take precedence over ID3v2 frames.
+  PreferV1,
+}
+
+// =============================================================================
+// Misc. Utilities
+// =============================================================================
+
+/// Find the first text frame with the ID3v2.2, ID3v2.3 or ID3v2.4 identifier.
+fn find<'a>(tag: &'a Tag, [v22, v23, v24]: [&str; 3]) -> Result<Option<Text<'a>>> {
+  match tag.header().version() {
+    Version::ID3v22 => tag.find_text(v22),
+    Version::ID3v23 => tag.find_text(v23),
+    _ => tag.find_text(v24),
+  }
+}
+
+/// Get the first string of a text frame (if not empty).
+fn text(tag: &Tag, identifiers: [&str; 3]) -> Result<Option<String>> {
+  Ok(find(tag, identifiers)?.and_then(|text| text.first().and_then(owned)))
+}
+
+/// Copy the `value` unless it is empty.
+fn owned(value: &str) -> Option<String> {
+  if value.is_empty() {
+    None
+  } else {
+    Some(value.to_owned())
+  }
+}
@@ -0,0 +1,694 @@
+//! Parsing and Writing Options
+
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use core::any::Any;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::hash::Hash;
+use core::hash::Hasher;
+use std::error::Error as StdError;
+
+use crate::content::Custom;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::error::Result;
+use crate::types::Bytes;
+use crate::types::DynFrameId;
+use crate::types::Slice;
+use crate::types::Version;
+
+// =============================================================================
+// Parse Options
+// =============================================================================
+
+/// Decoder for text in a legacy codepage, see
+/// [`ParseOptions::legacy_codepage`].
+pub type CodepageDecoder = for<'a> fn(&'a Slice) -> Option<Cow<'a, str>>;
+
+/// Options controlling how an ID3v2 tag is parsed.
+///
+/// The default options are strict: any deviation from the specification is
+/// reported as an [`Error`][crate::error::Error]. Lenient parsing recovers
+/// from some common encoder mistakes and records a
+/// [`Warning`][crate::error::Warning] instead.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ParseOptions {
+  lenient: bool,
+  restrictions: bool,
+  max_frames: usize,
+  max_sub_frames: usize,
+  max_tag_size: usize,
+  max_frame_size: usize,
+  max_decompressed_size: usize,
+  defer_threshold: Option<usize>,
+  repair_mojibake: bool,
+  retain_raw: bool,
+  record_encodings: bool,
+  truncated: bool,
+  truncate_text: bool,
+  codepage: Option<Codepage>,
+  replace_invalid_text: bool,
+  cp1252: bool,
+  trim_lyrics: bool,
+}
+
+impl ParseOptions {
+  /// The default maximum number of frames yielded from a tag.
+  pub const DEFAULT_MAX_FRAMES: usize = 10_000;
+
+  /// The default maximum number of sub-frames yielded from an embedding frame.
+  pub const DEFAULT_MAX_SUB_FRAMES: usize = 256;
+
+  /// The default maximum size of a tag (in bytes).
+  pub const DEFAULT_MAX_TAG_SIZE: usize = 64 << 20;
+
+  /// The default maximum size of a single frame (in bytes).
+  pub const DEFAULT_MAX_FRAME_SIZE: usize = 16 << 20;
+
+  /// The default maximum size of decompressed frame content (in bytes).
+  pub const DEFAULT_MAX_DECOMPRESSED_SIZE: usize = 16 << 20;
+
+  /// Create a new set of strict `ParseOptions`.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      lenient: false,
+      restrictions: false,
+      max_frames: Self::DEFAULT_MAX_FRAMES,
+      max_sub_frames: Self::DEFAULT_MAX_SUB_FRAMES,
+      max_tag_size: Self::DEFAULT_MAX_TAG_SIZE,
+      max_frame_size: Self::DEFAULT_MAX_FRAME_SIZE,
+      max_decompressed_size: Self::DEFAULT_MAX_DECOMPRESSED_SIZE,
+      defer_threshold: None,
+      repair_mojibake: false,
+      retain_raw: false,
+      record_encodings: false,
+      truncated: false,
+      truncate_text: false,
+      codepage: None,
+      replace_invalid_text: false,
+      cp1252: false,
+      trim_lyrics: false,
+    }
+  }
+
+  /// Set whether or not recoverable problems should be tolerated.
+  ///
+  /// When decoding frame content in lenient mode:
+  ///
+  /// - an invalid text encoding byte is treated as ISO-8859-1
+  /// - invalid UTF-8/UTF-16 text is decoded lossily
+  /// - UTF-16 text without a byte order mark is decoded in the more likely
+  ///   byte order
+  /// - fixed-size fields cut short by the end of the frame are zero-filled
+  /// - unknown picture types are mapped to [`PicType::Other`][crate::content::PicType::Other]
+  /// - trailing data after the frame content is ignored
+  #[inline]
+  pub const fn lenient(mut self, value: bool) -> Self {
+    self.lenient = value;
+    self
+  }
+
+  /// Set whether or not decoded frames are checked against the restrictions
+  /// declared in the extended header.
+  ///
+  /// Violations do not prevent decoding, they are recorded as a
+  /// [`Warning`][crate::error::Warning] so the frame can be normalized when
+  /// the tag is written.
+  #[inline]
+  pub const fn check_restrictions(mut self, value: bool) -> Self {
+    self.restrictions = value;
+    self
+  }
+
+  /// Set the maximum number of frames yielded from a tag.
+  ///
+  /// Frame iteration stops with a [`FrameLimit`][crate::error::ErrorKind::FrameLimit]
+  /// error once the limit is exceeded.
+  #[inline]
+  pub const fn max_frames(mut self, value: usize) -> Self {
+    self.max_frames = value;
+    self
+  }
+
+  /// Set the maximum number of sub-frames yielded from an embedding frame
+  /// (e.g. `CHAP` or `CTOC`).
+  #[inline]
+  pub const fn max_sub_frames(mut self, value: usize) -> Self {
+    self.max_sub_frames = value;
+    self
+  }
+
+  /// Set the maximum size of a tag (in bytes), including the header and
+  /// footer.
+  ///
+  /// Larger tags are rejected with a [`SizeLimit`][crate::error::ErrorKind::SizeLimit]
+  /// error before the tag data is read.
+  #[inline]
+  pub const fn max_tag_size(mut self, value: usize) -> Self {
+    self.max_tag_size = value;
+    self
+  }
+
+  /// Set the maximum size of a single frame (in bytes), including the frame
+  /// header.
+  ///
+  /// Frame iteration stops with a [`SizeLimit`][crate::error::ErrorKind::SizeLimit]
+  /// error at the first larger frame.
+  #[inline]
+  pub const fn max_frame_size(mut self, value: usize) -> Self {
+    self.max_frame_size = value;
+    self
+  }
+
+  /// Set the maximum size of decompressed frame content (in bytes).
+  ///
+  /// Decompression stops with a [`SizeLimit`][crate::error::ErrorKind::SizeLimit]
+  /// error once the limit is exceeded.
+  #[inline]
+  pub const fn max_decompressed_size(mut self, value: usize) -> Self {
+    self.max_decompressed_size = value;
+    self
+  }
+
+  /// Remove all size and count limits.
+  ///
+  /// Note: Only use this for trusted input, a malicious tag may declare sizes
+  /// that exhaust the available memory.
+  #[inline]
+  pub const fn no_limits(self) -> Self {
+    self
+      .max_frames(usize::MAX)
+      .max_sub_frames(usize::MAX)
+      .max_tag_size(usize::MAX)
+      .max_frame_size(usize::MAX)
+      .max_decompressed_size(usize::MAX)
+  }
+
+  /// Defer loading binary frame data (e.g. `APIC` pictures) larger than
+  /// `threshold` bytes.
+  ///
+  /// Deferred data is represented by a [`DeferredSlice`] and may be loaded
+  /// from the tag buffer later on.
+  ///
+  /// Note: Only applies to uncompressed frames decoded with
+  /// [`Tag::decode_frame`].
+  ///
+  /// [`DeferredSlice`]: crate::types::DeferredSlice
+  /// [`Tag::decode_frame`]: crate::id3v2::Tag::decode_frame
+  #[inline]
+  pub const fn defer_binary(mut self, threshold: usize) -> Self {
+    self.defer_threshold = Some(threshold);
+    self
+  }
+
+  /// Set whether or not double-encoded text is repaired when decoded.
+  ///
+  /// Repaired strings are recorded as a [`Warning`][crate::error::Warning],
+  /// see [`repair_mojibake`][crate::text::repair_mojibake] for details.
+  #[inline]
+  pub const fn repair_mojibake(mut self, value: bool) -> Self {
+    self.repair_mojibake = value;
+    self
+  }
+
+  /// Set whether or not the on-disk bytes of the tag are retained.
+  ///
+  /// The bytes are kept as stored, before unsynchronisation is reversed, and
+  /// are required by [`Tag::raw_digest`][crate::id3v2::Tag::raw_digest].
+  #[inline]
+  pub const fn retain_raw(mut self, value: bool) -> Self {
+    self.retain_raw = value;
+    self
+  }
+
+  /// Set whether or not the source encoding of each decoded string is
+  /// recorded.
+  ///
+  /// The encoding is recorded as a [`Warning`][crate::error::Warning] with
+  /// the offset of the string in the frame content, see
+  /// [`SourceEncoding`][crate::types::SourceEncoding] for details.
+  #[inline]
+  pub const fn record_encodings(mut self, value: bool) -> Self {
+    self.record_encodings = value;
+    self
+  }
+
+  /// Set whether or not a tag cut short by the end of the input is parsed.
+  ///
+  /// The frames that were read completely are available from the tag, see
+  /// [`Tag::is_truncated`][crate::id3v2::Tag::is_truncated] for details.
+  #[inline]
+  pub const fn allow_truncated(mut self, value: bool) -> Self {
+    self.truncated = value;
+    self
+  }
+
+  /// Set whether or not ID3v2.2 and ID3v2.3 text frames are cut at the first
+  /// NUL character.
+  ///
+  /// Only ID3v2.4 defines NUL-separated lists of strings, older versions
+  /// separate multiple values with `/`. Any data after the first string is
+  /// ignored and recorded as a [`Warning`][crate::error::Warning]. ID3v2.4
+  /// text frames are always decoded as lists.
+  #[inline]
+  pub const fn truncate_text(mut self, value: bool) -> Self {
+    self.truncate_text = value;
+    self
+  }
+
+  /// Set a decoder for ISO-8859-1 text stored in a legacy codepage.
+  ///
+  /// Many older tags contain e.g. Windows-1251 or Shift JIS text marked as
+  /// ISO-8859-1. The `decoder` receives the raw bytes of each ISO-8859-1
+  /// string containing non-ASCII bytes, the string is decoded as ISO-8859-1
+  /// if it returns `None`.
+  #[inline]
+  pub const fn legacy_codepage(mut self, decoder: CodepageDecoder) -> Self {
+    self.codepage = Some(Codepage(decoder));
+    self
+  }
+
+  /// Set whether or not invalid text is decoded with replacement characters.
+  ///
+  /// Invalid strings are decoded with [`Encoding::decode_lossy`] and recorded
+  /// as a [`Warning`][crate::error::Warning] instead of returning an error,
+  /// independent of lenient mode.
+  ///
+  /// [`Encoding::decode_lossy`]: crate::types::Encoding::decode_lossy
+  #[inline]
+  pub const fn replace_invalid_text(mut self, value: bool) -> Self {
+    self.replace_invalid_text = value;
+    self
+  }
+
+  /// Set whether or not ISO-8859-1 text is decoded as Windows-1252.
+  ///
+  /// Most taggers write Windows-1252, where the bytes `0x80`-`0x9F` are
+  /// punctuation (e.g. smart quotes) instead of C1 control characters. A
+  /// [`legacy_codepage`][Self::legacy_codepage] decoder takes precedence.
+  #[inline]
+  pub const fn latin1_as_cp1252(mut self, value: bool) -> Self {
+    self.cp1252 = value;
+    self
+  }
+
+  /// Set whether or not a leading newline is removed from each lyric of a
+  /// `SYLT` frame.
+  ///
+  /// Many writers start every line with a newline, which is unwanted when
+  /// displaying one line at a time, see
+  /// [`Sylt::lyrics_with`][crate::content::Sylt::lyrics_with].
+  #[inline]
+  pub const fn trim_lyrics(mut self, value: bool) -> Self {
+    self.trim_lyrics = value;
+    self
+  }
+
+  /// Returns `true` if recoverable problems are tolerated.
+  #[inline]
+  pub const fn is_lenient(&self) -> bool {
+    self.lenient
+  }
+
+  /// Returns `true` if decoded frames are checked against the restrictions
+  /// declared in the extended header.
+  #[inline]
+  pub const fn checks_restrictions(&self) -> bool {
+    self.restrictions
+  }
+
+  /// Get the maximum number of frames yielded from a tag.
+  #[inline]
+  pub const fn frame_limit(&self) -> usize {
+    self.max_frames
+  }
+
+  /// Get the maximum number of sub-frames yielded from an embedding frame.
+  #[inline]
+  pub const fn sub_frame_limit(&self) -> usize {
+    self.max_sub_frames
+  }
+
+  /// Get the maximum size of a tag (in bytes).
+  #[inline]
+  pub const fn tag_size_limit(&self) -> usize {
+    self.max_tag_size
+  }
+
+  /// Get the maximum size of a single frame (in bytes).
+  #[inline]
+  pub const fn frame_size_limit(&self) -> usize {
+    self.max_frame_size
+  }
+
+  /// Get the maximum size of decompressed frame content (in bytes).
+  #[inline]
+  pub const fn decompressed_size_limit(&self) -> usize {
+    self.max_decompressed_size
+  }
+
+  /// Get the size above which binary frame data is deferred (in bytes).
+  #[inline]
+  pub const fn defer_threshold(&self) -> Option<usize> {
+    self.defer_threshold
+  }
+
+  /// Returns `true` if double-encoded text is repaired when decoded.
+  #[inline]
+  pub const fn repairs_mojibake(&self) -> bool {
+    self.repair_mojibake
+  }
+
+  /// Returns `true` if the on-disk bytes of the tag are retained.
+  #[inline]
+  pub const fn retains_raw(&self) -> bool {
+    self.retain_raw
+  }
+
+  /// Returns `true` if the source encoding of each decoded string is
+  /// recorded.
+  #[inline]
+  pub const fn records_encodings(&self) -> bool {
+    self.record_encodings
+  }
+
+  /// Returns `true` if a tag cut short by the end of the input is parsed.
+  #[inline]
+  pub const fn allows_truncated(&self) -> bool {
+    self.truncated
+  }
+
+  /// Returns `true` if ID3v2.2 and ID3v2.3 text frames are cut at the first
+  /// NUL character.
+  #[inline]
+  pub const fn truncates_text(&self) -> bool {
+    self.truncate_text
+  }
+
+  /// Get the decoder for ISO-8859-1 text stored in a legacy codepage.
+  #[inline]
+  pub const fn codepage_decoder(&self) -> Option<CodepageDecoder> {
+    match self.codepage {
+      Some(Codepage(decoder)) => Some(decoder),
+      None => None,
+    }
+  }
+
+  /// Returns `true` if invalid text is decoded with replacement characters.
+  #[inline]
+  pub const fn replaces_invalid_text(&self) -> bool {
+    self.replace_invalid_text
+  }
+
+  /// Returns `true` if ISO-8859-1 text is decoded as Windows-1252.
+  #[inline]
+  pub const fn decodes_cp1252(&self) -> bool {
+    self.cp1252
+  }
+
+  /// Returns `true` if a leading newline is removed from each lyric of a
+  /// `SYLT` frame.
+  #[inline]
+  pub const fn trims_lyrics(&self) -> bool {
+    self.trim_lyrics
+  }
+}
+
+impl Default for ParseOptions {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// A [`CodepageDecoder`] compared by address.
+#[derive(Clone, Copy)]
+struct Codepage(CodepageDecoder);
+
+impl Codepage {
+  fn address(&self) -> usize {
+    self.0 as usize
+  }
+}
+
+impl Debug for Codepage {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "Codepage({:#x})", self.address())
+  }
+}
+
+impl Hash for Codepage {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.address().hash(state);
+  }
+}
+
+impl PartialEq for Codepage {
+  fn eq(&self, other: &Self) -> bool {
+    self.address() == other.address()
+  }
+}
+
+impl Eq for Codepage {}
+
+impl PartialOrd for Codepage {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Codepage {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.address().cmp(&other.address())
+  }
+}
+
+// =============================================================================
+// Write Options
+// =============================================================================
+
+/// Options controlling how an ID3v2 tag is written.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WriteOptions {
+  padding: Padding,
+  footer: bool,
+}
+
+impl WriteOptions {
+  /// Create a new set of `WriteOptions` without padding or footer.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      padding: Padding::None,
+      footer: false,
+    }
+  }
+
+  /// Set the amount of padding written after the frames.
+  ///
+  /// Note: Padding is not written if the tag has a footer.
+  #[inline]
+  pub const fn padding(mut self, value: Padding) -> Self {
+    self.padding = value;
+    self
+  }
+
+  /// Set whether or not a footer is written after the frames (ID3v2.4 only).
+  ///
+  /// A tag with a footer cannot contain padding, any configured padding is
+  /// ignored.
+  #[inline]
+  pub const fn footer(mut self, value: bool) -> Self {
+    self.footer = value;
+    self
+  }
+
+  /// Get the amount of padding written after the frames.
+  #[inline]
+  pub const fn padding_policy(&self) -> Padding {
+    self.padding
+  }
+
+  /// Get the number of padding bytes written after `size` bytes of frames.
+  #[inline]
+  pub const fn padding_len(&self, size: usize) -> usize {
+    if self.footer {
+      0
+    } else {
+      self.padding.resolve(size)
+    }
+  }
+
+  /// Returns `true` if a footer is written after the frames.
+  #[inline]
+  pub const fn writes_footer(&self) -> bool {
+    self.footer
+  }
+}
+
+impl Default for WriteOptions {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// =============================================================================
+// Padding
+// =============================================================================
+
+/// The amount of padding written after the frames of a tag.
+///
+/// Padding allows the tag to grow in place, without rewriting the audio data
+/// that follows it.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Padding {
+  /// No padding.
+  None,
+  /// A fixed number of bytes.
+  Fixed(usize),
+  /// A percentage of the size of the frames (rounded up).
+  Percent(u8),
+}
+
+impl Padding {
+  /// Get the number of padding bytes written after `size` bytes of frames.
+  #[inline]
+  pub const fn resolve(self, size: usize) -> usize {
+    match self {
+      Self::None => 0,
+      Self::Fixed(value) => value,
+      Self::Percent(value) => (size * value as usize).div_ceil(100),
+    }
+  }
+}
+
+impl Default for Padding {
+  #[inline]
+  fn default() -> Self {
+    Self::None
+  }
+}
+
+// =============================================================================
+// Decoder Config
+// =============================================================================
+
+type CustomParser = dyn Fn(&Slice, Version) -> Result<Custom> + Send + Sync;
+type Decryptor = dyn Fn(&Slice) -> Result<Bytes> + Send + Sync;
+
+/// Options controlling how frame content is decoded, including parsers for
+/// proprietary frames and decryptors for encrypted frames.
+///
+/// Registered parsers are consulted before the built-in frame table and their
+/// output is returned as [`Content::Custom`][crate::content::Content::Custom].
+/// A config is passed to each decode call, so independent registries can be
+/// used concurrently.
+#[derive(Clone, Default)]
+pub struct DecoderConfig {
+  options: ParseOptions,
+  parsers: BTreeMap<DynFrameId, Arc<CustomParser>>,
+  decryptors: BTreeMap<u8, Arc<Decryptor>>,
+}
+
+impl DecoderConfig {
+  /// Create a new `DecoderConfig` with the given `options` and no parsers.
+  #[inline]
+  pub const fn new(options: ParseOptions) -> Self {
+    Self {
+      options,
+      parsers: BTreeMap::new(),
+      decryptors: BTreeMap::new(),
+    }
+  }
+
+  /// Register a `parser` for frames with the given identifier.
+  ///
+  /// The parser receives the frame content (decompressed if necessary) and
+  /// the version of the frame. Parser errors are returned as
+  /// [`InvalidFrameData`][ErrorKind::InvalidFrameData] with the original error
+  /// as the source. Any parser previously registered for the identifier is
+  /// replaced.
+  pub fn register<I, T, E, F>(mut self, id: I, parser: F) -> Self
+  where
+    I: Into<DynFrameId>,
+    T: Any + Send + Sync,
+    E: StdError + Send + Sync + 'static,
+    F: Fn(&Slice, Version) -> Result<T, E> + Send + Sync + 'static,
+  {
+    let id: DynFrameId = id.into();
+
+    self.parsers.insert(
+      id,
+      Arc::new(move |slice, version| match parser(slice, version) {
+        Ok(value) => Ok(Custom::new(id, value)),
+        Err(error) => Err(Error::new_std(ErrorKind::InvalidFrameData, error)),
+      }),
+    );
+
+    self
+  }
+
+  /// Register a `decryptor` for frames encrypted with the given `method`.
+  ///
+  /// The method symbol refers to an `ENCR` frame of the tag, see
+  /// [`Tag::encryption_method`][crate::id3v2::Tag::encryption_method] to find
+  /// the symbol registered by an owner. The decryptor receives the encrypted
+  /// frame content and returns the plaintext, which is decompressed (if
+  /// necessary) and decoded as usual. Decryptor errors are returned as
+  /// [`EncryptedFrame`][ErrorKind::EncryptedFrame] with the original error as
+  /// the source. Any decryptor previously registered for the method is
+  /// replaced.
+  pub fn decryptor<E, F>(mut self, method: u8, decryptor: F) -> Self
+  where
+    E: StdError + Send + Sync + 'static,
+    F: Fn(&Slice) -> Result<Vec<u8>, E> + Send + Sync + 'static,
+  {
+    self.decryptors.insert(
+      method,
+      Arc::new(move |slice| match decryptor(slice) {
+        Ok(value) => Ok(Bytes::new(value.into_boxed_slice())),
+        Err(error) => Err(Error::new_std(ErrorKind::EncryptedFrame, error)),
+      }),
+    );
+
+    self
+  }
+
+  /// Get the options used to decode frame content.
+  #[inline]
+  pub const fn options(&self) -> ParseOptions {
+    self.options
+  }
+
+  /// Get the parser registered for the given identifier (if any).
+  pub(crate) fn parser(&self, id: DynFrameId) -> Option<&CustomParser> {
+    self.parsers.get(&id).map(|parser| &**parser)
+  }
+
+  /// Decrypt frame content encrypted with the given `method`.
+  ///
+  /// Returns an [`EncryptedFrame`][ErrorKind::EncryptedFrame] error if no
+  /// decryptor is registered for the method.
+  pub(crate) fn decrypt(&self, method: u8, data: &Slice) -> Result<Bytes> {
+    match self.decryptors.get(&method) {
+      Some(decryptor) => decryptor(data),
+      None => Err(Error::new(ErrorKind::EncryptedFrame)),
+    }
+  }
+}
+
+impl Debug for DecoderConfig {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.debug_struct("DecoderConfig")
+      .field("options", &self.options)
+      .field("parsers", &self.parsers.keys())
+      .field("decryptors", &self.decryptors.keys())
+      .finish()
+  }
+}
@@ -0,0 +1,128 @@
+//! Text Utilities
+
+use core::str::from_utf8;
+
+// =============================================================================
+// Mojibake Repair
+// =============================================================================
+
+/// Windows-1252 characters in the range `0x80-0x9F`, `None` if undefined.
+const CP1252: [Option<char>; 32] = [
+  Some('\u{20AC}'),
+  None,
+  Some('\u{201A}'),
+  Some('\u{0192}'),
+  Some('\u{201E}'),
+  Some('\u{2026}'),
+  Some('\u{2020}'),
+  Some('\u{2021}'),
+  Some('\u{02C6}'),
+  Some('\u{2030}'),
+  Some('\u{0160}'),
+  Some('\u{2039}'),
+  Some('\u{0152}'),
+  None,
+  Some('\u{017D}'),
+  None,
+  None,
+  Some('\u{2018}'),
+  Some('\u{2019}'),
+  Some('\u{201C}'),
+  Some('\u{201D}'),
+  Some('\u{2022}'),
+  Some('\u{2013}'),
+  Some('\u{2014}'),
+  Some('\u{02DC}'),
+  Some('\u{2122}'),
+  Some('\u{0161}'),
+  Some('\u{203A}'),
+  Some('\u{0153}'),
+  None,
+  Some('\u{017E}'),
+  Some('\u{0178}'),
+];
+
+/// Reverse UTF-8 text that was mistakenly decoded as ISO-8859-1 (or
+/// Windows-1252) and encoded again, e.g. `"CafÃ©"` to `"Café"`.
+///
+/// Returns `None` if the `input` does not look double-encoded. The repair is
+/// conservative: every character must map back to a single byte, the bytes
+/// must form valid UTF-8 with at least one multi-byte sequence, and the
+/// result must not contain C1 control characters. Legitimate text (e.g.
+/// `"SÃO PAULO"`) is therefore left untouched.
+pub fn repair_mojibake(input: &str) -> Option<String> {
+  if input.is_ascii() {
+    return None;
+  }
+
+  let bytes: Vec<u8> = input.chars().map(to_byte).collect::<Option<_>>()?;
+  let output: &str = from_utf8(&bytes).ok()?;
+
+  if output.chars().any(|ch| ch.is_control() && !ch.is_ascii()) {
+    return None;
+  }
+
+  Some(output.to_owned())
+}
+
+/// Map a character back to the byte it was decoded from.
+fn to_byte(ch: char) -> Option<u8> {
+  match ch {
+    '\u{0000}'..='\u{00FF}' => Some(ch as u8),
+    _ => CP1252
+      .iter()
+      .position(|item| *item == Some(ch))
+      .map(|index| 0x80 + index as u8),
+  }
+}
+
+// =============================================================================
+// Caseless Matching
+// =============================================================================
+
+/// Compare two strings, ignoring case.
+///
+/// By default only ASCII letters are folded and non-ASCII characters must
+/// match exactly. With the `unicode-case` feature enabled every character is
+/// compared by its simple (single character) case folding, e.g. `"ÄRGER"`
+/// matches `"ärger"`.
+///
+/// All case-insensitive matching in this crate is performed by this function.
+#[inline]
+pub fn caseless_eq(a: &str, b: &str) -> bool {
+  #[cfg(feature = "unicode-case")]
+  {
+    a.chars().map(fold).eq(b.chars().map(fold))
+  }
+
+  #[cfg(not(feature = "unicode-case"))]
+  {
+    a.eq_ignore_ascii_case(b)
+  }
+}
+
+/// Get the simple case folding of a character.
+///
+/// Characters without a single character mapping (e.g. `'ß'`) are unchanged.
+#[cfg(feature = "unicode-case")]
+fn fold(ch: char) -> char {
+  fn single<I>(mut iter: I) -> Option<char>
+  where
+    I: Iterator<Item = char>,
+  {
+    match (iter.next(), iter.next()) {
+      (Some(ch), None) => Some(ch),
+      _ => None,
+    }
+  }
+
+  if ch.is_ascii() {
+    return ch.to_ascii_lowercase();
+  }
+
+  // Round-trip through uppercase so variants such as the final sigma fold to
+  // the same character as their regular form.
+  let upper: char = single(ch.to_uppercase()).unwrap_or(ch);
+
+  single(upper.to_lowercase()).unwrap_or(ch)
+}
@@ -32,6 +32,15 @@ impl<const S: usize> IntoOwned for FrameId<S> {
 // Implementations for Rust Types
 // =============================================================================
 
+impl<const S: usize> IntoOwned for [u8; S] {
+  type Owned = Self;
+
+  #[inline]
+  fn into_owned(self) -> Self::Owned {
+    self
+  }
+}
+
 impl<T> IntoOwned for Option<T>
 where
   T: IntoOwned,
@@ -0,0 +1,171 @@
+use alloc::borrow::Cow;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+use crate::decode::Decode;
+use crate::decode::Decoder;
+use crate::encode::Encode;
+use crate::encode::Encoder;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::error::Result;
+use crate::id3v2::Tag;
+use crate::traits::IntoOwned;
+use crate::types::Slice;
+use crate::utils::HexPreview;
+
+// =============================================================================
+// Deferred Slice
+// =============================================================================
+
+/// The location of binary frame data that was not loaded while decoding.
+///
+/// See [`ParseOptions::defer_binary`][crate::options::ParseOptions::defer_binary].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DeferredSlice {
+  offset: usize,
+  len: usize,
+}
+
+impl DeferredSlice {
+  pub(crate) const fn new(offset: usize, len: usize) -> Self {
+    Self { offset, len }
+  }
+
+  /// Get the offset of the data in the tag buffer.
+  #[inline]
+  pub const fn offset(&self) -> usize {
+    self.offset
+  }
+
+  /// Get the length of the data (in bytes).
+  #[inline]
+  pub const fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Returns `true` if the data has a length of 0.
+  #[inline]
+  pub const fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Load the data from the buffer of the `tag` it was decoded from.
+  pub fn load<'a>(&self, tag: &'a Tag) -> Result<&'a Slice> {
+    let range: core::ops::Range<usize> = self.offset..self.offset.saturating_add(self.len);
+
+    match tag.buffer().as_ref().get(range) {
+      Some(data) => Ok(Slice::new(data)),
+      None => Err(Error::new(ErrorKind::InvalidFrameData)),
+    }
+  }
+}
+
+copy_into_owned!(DeferredSlice);
+
+// =============================================================================
+// Lazy Slice
+// =============================================================================
+
+/// Binary frame data that may be deferred until explicitly loaded.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LazySlice<'a> {
+  /// Data decoded along with the rest of the frame.
+  Loaded(Cow<'a, Slice>),
+  /// Data left in the tag buffer.
+  Deferred(DeferredSlice),
+}
+
+impl LazySlice<'_> {
+  /// Get the loaded data.
+  ///
+  /// Returns `None` if the data was deferred, see [`load`][Self::load].
+  #[inline]
+  pub fn as_slice(&self) -> Option<&Slice> {
+    match self {
+      Self::Loaded(inner) => Some(inner),
+      Self::Deferred(_) => None,
+    }
+  }
+
+  /// Get the location of the data if it was deferred.
+  #[inline]
+  pub const fn deferred(&self) -> Option<DeferredSlice> {
+    match self {
+      Self::Loaded(_) => None,
+      Self::Deferred(inner) => Some(*inner),
+    }
+  }
+
+  /// Get the length of the data (in bytes), whether loaded or deferred.
+  #[inline]
+  pub fn len(&self) -> usize {
+    match self {
+      Self::Loaded(inner) => inner.len(),
+      Self::Deferred(inner) => inner.len(),
+    }
+  }
+
+  /// Returns `true` if the data has a length of 0.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Returns `true` if the data was deferred.
+  #[inline]
+  pub const fn is_deferred(&self) -> bool {
+    matches!(self, Self::Deferred(_))
+  }
+
+  /// Get the data, loading it from the buffer of the `tag` if deferred.
+  pub fn load<'b>(&'b self, tag: &'b Tag) -> Result<&'b Slice> {
+    match self {
+      Self::Loaded(inner) => Ok(inner),
+      Self::Deferred(inner) => inner.load(tag),
+    }
+  }
+}
+
+impl Display for LazySlice<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::Loaded(inner) => Display::fmt(&HexPreview(inner), f),
+      Self::Deferred(inner) => write!(f, "{} bytes (deferred)", inner.len()),
+    }
+  }
+}
+
+impl<'a> Decode<'a> for LazySlice<'a> {
+  #[inline]
+  fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+    match decoder.defer() {
+      Some(deferred) => Ok(Self::Deferred(deferred)),
+      None => decoder.decode().map(Self::Loaded),
+    }
+  }
+}
+
+impl Encode for LazySlice<'_> {
+  #[inline]
+  fn encode(&self, encoder: &mut Encoder) -> Result<()> {
+    match self {
+      Self::Loaded(inner) => encoder.encode(inner),
+      // Deferred data must be loaded from the tag buffer first.
+      Self::Deferred(_) => Err(Error::new(ErrorKind::Unencodable)),
+    }
+  }
+}
+
+impl IntoOwned for LazySlice<'_> {
+  type Owned = LazySlice<'static>;
+
+  #[inline]
+  fn into_owned(self) -> Self::Owned {
+    match self {
+      Self::Loaded(inner) => LazySlice::Loaded(IntoOwned::into_owned(inner)),
+      Self::Deferred(inner) => LazySlice::Deferred(inner),
+    }
+  }
+}
@@ -84,3 +84,83 @@ impl<const S: usize> TryFrom<[u8; S]> for FrameId<S> {
     }
   }
 }
+
+// =============================================================================
+// Dynamic Frame Identifier
+// =============================================================================
+
+/// An ID3v2 frame identifier of any version.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DynFrameId {
+  /// ID3v2.2 frame identifier.
+  V2(FrameId<3>),
+  /// ID3v2.3/ID3v2.4 frame identifier.
+  V3(FrameId<4>),
+}
+
+impl DynFrameId {
+  /// Get a string representation of the frame ID.
+  #[inline]
+  pub const fn as_str(&self) -> &str {
+    match self {
+      Self::V2(inner) => inner.as_str(),
+      Self::V3(inner) => inner.as_str(),
+    }
+  }
+
+  /// Get a shared reference to the underlying slice of bytes.
+  #[inline]
+  pub const fn as_slice(&self) -> &[u8] {
+    match self {
+      Self::V2(inner) => inner.as_slice(),
+      Self::V3(inner) => inner.as_slice(),
+    }
+  }
+}
+
+impl Debug for DynFrameId {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    Debug::fmt(self.as_str(), f)
+  }
+}
+
+impl Display for DynFrameId {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    Display::fmt(self.as_str(), f)
+  }
+}
+
+impl Deref for DynFrameId {
+  type Target = str;
+
+  #[inline]
+  fn deref(&self) -> &Self::Target {
+    self.as_str()
+  }
+}
+
+impl From<FrameId<3>> for DynFrameId {
+  #[inline]
+  fn from(other: FrameId<3>) -> Self {
+    Self::V2(other)
+  }
+}
+
+impl From<FrameId<4>> for DynFrameId {
+  #[inline]
+  fn from(other: FrameId<4>) -> Self {
+    Self::V3(other)
+  }
+}
+
+impl TryFrom<&str> for DynFrameId {
+  type Error = Error;
+
+  fn try_from(other: &str) -> Result<Self, Self::Error> {
+    match *other.as_bytes() {
+      [a, b, c] => FrameId::try_from([a, b, c]).map(Self::V2),
+      [a, b, c, d] => FrameId::try_from([a, b, c, d]).map(Self::V3),
+      _ => Err(Error::new(ErrorKind::InvalidFrameId)),
+    }
+  }
+}
@@ -0,0 +1,259 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+use crate::text::caseless_eq;
+
+/// Genre names, indexed by their numeric identifier.
+const GENRES: [&str; Genre::COUNT] = [
+  // ID3v1
+  "Blues",
+  "Classic Rock",
+  "Country",
+  "Dance",
+  "Disco",
+  "Funk",
+  "Grunge",
+  "Hip-Hop",
+  "Jazz",
+  "Metal",
+  "New Age",
+  "Oldies",
+  "Other",
+  "Pop",
+  "R&B",
+  "Rap",
+  "Reggae",
+  "Rock",
+  "Techno",
+  "Industrial",
+  "Alternative",
+  "Ska",
+  "Death Metal",
+  "Pranks",
+  "Soundtrack",
+  "Euro-Techno",
+  "Ambient",
+  "Trip-Hop",
+  "Vocal",
+  "Jazz+Funk",
+  "Fusion",
+  "Trance",
+  "Classical",
+  "Instrumental",
+  "Acid",
+  "House",
+  "Game",
+  "Sound Clip",
+  "Gospel",
+  "Noise",
+  "Alternative Rock",
+  "Bass",
+  "Soul",
+  "Punk",
+  "Space",
+  "Meditative",
+  "Instrumental Pop",
+  "Instrumental Rock",
+  "Ethnic",
+  "Gothic",
+  "Darkwave",
+  "Techno-Industrial",
+  "Electronic",
+  "Pop-Folk",
+  "Eurodance",
+  "Dream",
+  "Southern Rock",
+  "Comedy",
+  "Cult",
+  "Gangsta",
+  "Top 40",
+  "Christian Rap",
+  "Pop/Funk",
+  "Jungle",
+  "Native American",
+  "Cabaret",
+  "New Wave",
+  "Psychedelic",
+  "Rave",
+  "Showtunes",
+  "Trailer",
+  "Lo-Fi",
+  "Tribal",
+  "Acid Punk",
+  "Acid Jazz",
+  "Polka",
+  "Retro",
+  "Musical",
+  "Rock & Roll",
+  "Hard Rock",
+  // Winamp extensions
+  "Folk",
+  "Folk-Rock",
+  "National Folk",
+  "Swing",
+  "Fast Fusion",
+  "Bebop",
+  "Latin",
+  "Revival",
+  "Celtic",
+  "Bluegrass",
+  "Avantgarde",
+  "Gothic Rock",
+  "Progressive Rock",
+  "Psychedelic Rock",
+  "Symphonic Rock",
+  "Slow Rock",
+  "Big Band",
+  "Chorus",
+  "Easy Listening",
+  "Acoustic",
+  "Humour",
+  "Speech",
+  "Chanson",
+  "Opera",
+  "Chamber Music",
+  "Sonata",
+  "Symphony",
+  "Booty Bass",
+  "Primus",
+  "Porn Groove",
+  "Satire",
+  "Slow Jam",
+  "Club",
+  "Tango",
+  "Samba",
+  "Folklore",
+  "Ballad",
+  "Power Ballad",
+  "Rhythmic Soul",
+  "Freestyle",
+  "Duet",
+  "Punk Rock",
+  "Drum Solo",
+  "A Cappella",
+  "Euro-House",
+  "Dance Hall",
+  "Goa",
+  "Drum & Bass",
+  "Club-House",
+  "Hardcore Techno",
+  "Terror",
+  "Indie",
+  "BritPop",
+  "Afro-Punk",
+  "Polsk Punk",
+  "Beat",
+  "Christian Gangsta Rap",
+  "Heavy Metal",
+  "Black Metal",
+  "Crossover",
+  "Contemporary Christian",
+  "Christian Rock",
+  "Merengue",
+  "Salsa",
+  "Thrash Metal",
+  "Anime",
+  "Jpop",
+  "Synthpop",
+  "Abstract",
+  "Art Rock",
+  "Baroque",
+  "Bhangra",
+  "Big Beat",
+  "Breakbeat",
+  "Chillout",
+  "Downtempo",
+  "Dub",
+  "EBM",
+  "Eclectic",
+  "Electro",
+  "Electroclash",
+  "Emo",
+  "Experimental",
+  "Garage",
+  "Global",
+  "IDM",
+  "Illbient",
+  "Industro-Goth",
+  "Jam Band",
+  "Krautrock",
+  "Leftfield",
+  "Lounge",
+  "Math Rock",
+  "New Romantic",
+  "Nu-Breakz",
+  "Post-Punk",
+  "Post-Rock",
+  "Psytrance",
+  "Shoegaze",
+  "Space Rock",
+  "Trop Rock",
+  "World Music",
+  "Neoclassical",
+  "Audiobook",
+  "Audio Theatre",
+  "Neue Deutsche Welle",
+  "Podcast",
+  "Indie Rock",
+  "G-Funk",
+  "Dubstep",
+  "Garage Rock",
+  "Psybient",
+];
+
+// =============================================================================
+// Genre
+// =============================================================================
+
+/// A genre of the numeric genre list shared by ID3v1 tags and ID3v2 `TCON`
+/// frames, including the Winamp extensions.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Genre {
+  index: u8,
+}
+
+impl Genre {
+  /// The number of genres in the list.
+  pub const COUNT: usize = 192;
+
+  /// Get the genre with the given numeric `index`.
+  ///
+  /// Returns `None` if the index is out of range.
+  #[inline]
+  pub const fn new(index: u8) -> Option<Self> {
+    if (index as usize) < Self::COUNT {
+      Some(Self { index })
+    } else {
+      None
+    }
+  }
+
+  /// Get the genre with the given `name`, compared case-insensitively.
+  ///
+  /// See [`caseless_eq`][crate::text::caseless_eq] for details.
+  pub fn from_name(name: &str) -> Option<Self> {
+    let index: usize = GENRES.iter().position(|genre| caseless_eq(genre, name))?;
+
+    Self::new(index as u8)
+  }
+
+  /// Get the numeric index of the genre.
+  #[inline]
+  pub const fn index(self) -> u8 {
+    self.index
+  }
+
+  /// Get the name of the genre.
+  #[inline]
+  pub const fn name(self) -> &'static str {
+    GENRES[self.index as usize]
+  }
+}
+
+impl Display for Genre {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(self.name())
+  }
+}
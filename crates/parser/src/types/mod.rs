@@ -1,11 +1,22 @@
 //! Common Utility Types
 
 mod bytes;
+mod deferred;
 mod frame;
+mod genre;
 mod slice;
 mod version;
 
+pub use crate::decode::Encoding;
+pub use crate::decode::Language;
+pub use crate::decode::LanguagePreference;
+pub use crate::decode::SourceEncoding;
+
 pub use self::bytes::Bytes;
+pub use self::deferred::DeferredSlice;
+pub use self::deferred::LazySlice;
+pub use self::frame::DynFrameId;
 pub use self::frame::FrameId;
+pub use self::genre::Genre;
 pub use self::slice::Slice;
 pub use self::version::Version;
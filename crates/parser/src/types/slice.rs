@@ -52,6 +52,11 @@ impl Slice {
   }
 
   /// Returns a sublice without doing bounds checking.
+  ///
+  /// # Safety
+  ///
+  /// Caller is responsible for ensuring `index` is within the bounds of the
+  /// slice.
   pub unsafe fn get_unchecked<I>(&self, index: I) -> &Self
   where
     I: SliceIndex<[u8], Output = [u8]>,
@@ -67,16 +72,22 @@ impl Slice {
 
   /// Returns a subslice advanced by `count` bytes.
   #[inline]
-  pub fn skip(&self, count: usize) -> &Self {
-    // SAFETY: index is constrained within the bounds of the slice.
-    unsafe { self.get_unchecked(count.min(self.len())..) }
+  pub const fn skip(&self, count: usize) -> &Self {
+    if count >= self.len() {
+      Self::new(self.inner.split_at(self.len()).1)
+    } else {
+      Self::new(self.inner.split_at(count).1)
+    }
   }
 
   /// Returns a subslice of up to `count` bytes.
   #[inline]
-  pub fn take(&self, count: usize) -> &Self {
-    // SAFETY: index is constrained within the bounds of the slice.
-    unsafe { self.get_unchecked(..count.min(self.len())) }
+  pub const fn take(&self, count: usize) -> &Self {
+    if count >= self.len() {
+      self
+    } else {
+      Self::new(self.inner.split_at(count).0)
+    }
   }
 
   /// Returns a subslice of `count` bytes offset by `start`.
@@ -98,6 +109,9 @@ impl Slice {
 
   /// Returns a subslice of self up to the first NUL byte pair.
   ///
+  /// Only pairs at even offsets are matched, so a NUL byte pair spanning two
+  /// UTF-16 code units (e.g. `"a\u{100}"`) is not mistaken for a terminator.
+  ///
   /// Note: Returns the original slice if no NUL byte pair is found.
   pub fn until_nul2(&self) -> &Self {
     let mut index: usize = 0;
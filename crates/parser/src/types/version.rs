@@ -1,3 +1,5 @@
+use crate::frame::Layout;
+
 // =============================================================================
 // ID3 Version
 // =============================================================================
@@ -16,3 +18,18 @@ pub enum Version {
   /// ID3v2.4
   ID3v24,
 }
+
+impl Version {
+  /// Get the frame header layout used by this version.
+  ///
+  /// Returns `None` for ID3v1 versions, which do not use frames.
+  #[inline]
+  pub const fn frame_layout(self) -> Option<Layout> {
+    match self {
+      Self::ID3v11 | Self::ID3v12 => None,
+      Self::ID3v22 => Some(Layout::V2),
+      Self::ID3v23 => Some(Layout::V3),
+      Self::ID3v24 => Some(Layout::V4),
+    }
+  }
+}
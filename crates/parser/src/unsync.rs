@@ -1,10 +1,13 @@
 //! ID3 Unsynchronisation Scheme
 
+use memchr::memchr;
 use std::io::ErrorKind;
 use std::io::Read;
 use std::io::Result;
+use std::io::Write;
 
 use crate::traits::ReadExt;
+use crate::types::Bytes;
 
 // =============================================================================
 // Unsync Reader
@@ -15,7 +18,6 @@ use crate::traits::ReadExt;
 pub struct Unsync<R> {
   reader: R,     // The actual reader implementation.
   bcache: u8,    // The last byte we read.
-  bstale: bool,  // Whether we wrote the last byte to the output buffer.
   cursor: usize, // Current position in the output buffer.
 }
 
@@ -25,7 +27,6 @@ impl<R> Unsync<R> {
     Self {
       reader,
       bcache: 0,
-      bstale: false,
       cursor: 0,
     }
   }
@@ -59,8 +60,8 @@ where
         // Write this byte to the output buffer.
         buffer[self.cursor] = byte;
 
-        // Clear the "stale" flag and increment the cursor position.
-        self.bstale = false;
+        // Store the current byte for next comparison.
+        self.bcache = byte;
         self.cursor += 1;
 
         // Break the loop if we've reached the end of the buffer.
@@ -68,20 +69,110 @@ where
           break;
         }
       } else {
-        // Set the "stale" flag since we didn't write this byte.
-        self.bstale = true;
+        // Drop the padding byte, the next byte is compared against it.
+        self.bcache = byte;
       }
+    }
+
+    Ok(self.cursor)
+  }
+}
+
+// =============================================================================
+// Unsync Writer
+// =============================================================================
+
+/// Implementation of [`Write`] for ID3 unsynchronisation scheme.
+///
+/// A `0x00` byte is inserted after every `0xFF` byte, including a trailing
+/// `0xFF` and one written at the end of a `write` call.
+#[derive(Debug)]
+pub struct UnsyncWriter<W> {
+  writer: W,
+  written: u64,
+}
+
+impl<W> UnsyncWriter<W> {
+  /// Create a new `UnsyncWriter`.
+  pub const fn new(writer: W) -> Self {
+    Self { writer, written: 0 }
+  }
+
+  /// Get the number of bytes emitted to the underlying writer.
+  #[inline]
+  pub const fn written(&self) -> u64 {
+    self.written
+  }
+
+  /// Get a reference to the underlying writer.
+  #[inline]
+  pub const fn get_ref(&self) -> &W {
+    &self.writer
+  }
+
+  /// Unwrap this `UnsyncWriter`, returning the underlying writer.
+  #[inline]
+  pub fn into_inner(self) -> W {
+    self.writer
+  }
+}
+
+impl<W> Write for UnsyncWriter<W>
+where
+  W: Write,
+{
+  fn write(&mut self, buffer: &[u8]) -> Result<usize> {
+    let mut input: &[u8] = buffer;
+
+    while let Some(index) = memchr(0xFF, input) {
+      self.writer.write_all(&input[..=index])?;
+      self.writer.write_all(&[0x00])?;
+      self.written += index as u64 + 2;
 
-      // Store the current byte for next comparison
-      self.bcache = byte;
+      input = &input[index + 1..];
     }
 
-    // Add trailing byte if applicable.
-    if self.bstale {
-      buffer[self.cursor] = self.bcache;
+    self.writer.write_all(input)?;
+    self.written += input.len() as u64;
+
+    Ok(buffer.len())
+  }
+
+  fn flush(&mut self) -> Result<()> {
+    self.writer.flush()
+  }
+}
+
+/// Reverse the ID3 unsynchronisation scheme applied to `input`.
+pub(crate) fn decode(input: &[u8]) -> Result<Bytes> {
+  Unsync::new(input).read_all(Some(input.len()))
+}
+
+/// Reverse the ID3 unsynchronisation scheme applied to the first `length`
+/// bytes of `input`.
+///
+/// Returns the decoded bytes and the number of input bytes they occupy,
+/// including the `0x00` byte inserted after a trailing `0xFF`. Returns `None`
+/// if `input` is too short.
+pub(crate) fn decode_prefix(input: &[u8], length: usize) -> Option<(Vec<u8>, usize)> {
+  let mut output: Vec<u8> = Vec::with_capacity(length);
+  let mut bcache: u8 = 0;
+  let mut index: usize = 0;
+
+  while output.len() < length {
+    let byte: u8 = *input.get(index)?;
+
+    if !(bcache == 0xFF && byte == 0x00) {
+      output.push(byte);
     }
 
-    // Return the cursor position +1 for trailing byte.
-    Ok(self.cursor + usize::from(self.bstale))
+    bcache = byte;
+    index += 1;
+  }
+
+  if bcache == 0xFF && *input.get(index)? == 0x00 {
+    index += 1;
   }
+
+  Some((output, index))
 }
@@ -1,9 +1,17 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 use core::str::from_utf8;
 
 #[cfg(feature = "zlib")]
 use flate2::read::ZlibDecoder;
+#[cfg(feature = "zlib")]
+use std::io::Read;
 
+use crate::error::Error;
+use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::error::SizeLimit;
 use crate::types::Bytes;
 use crate::types::Slice;
 
@@ -33,6 +41,17 @@ pub const fn decode_u35_unsync(bytes: [u8; 5]) -> u32 {
   output
 }
 
+/// Encode an unsigned 35-bit "unsynchronized" integer as an array of bytes.
+pub const fn encode_u35_unsync(value: u32) -> [u8; 5] {
+  [
+    (value >> 28) as u8 & 0x7F,
+    (value >> 21) as u8 & 0x7F,
+    (value >> 14) as u8 & 0x7F,
+    (value >> 7) as u8 & 0x7F,
+    value as u8 & 0x7F,
+  ]
+}
+
 /// Decode an unsigned 28-bit "unsynchronized" integer from an array of bytes.
 pub const fn decode_u28_unsync(bytes: [u8; 4]) -> u32 {
   debug_assert!(bytes[0] & MASK == bytes[0]);
@@ -48,6 +67,21 @@ pub const fn decode_u28_unsync(bytes: [u8; 4]) -> u32 {
   output
 }
 
+/// The maximum value of an unsigned 28-bit "unsynchronized" integer.
+pub const MAX_U28_UNSYNC: u32 = (1 << 28) - 1;
+
+/// Encode an unsigned 28-bit "unsynchronized" integer as an array of bytes.
+pub const fn encode_u28_unsync(value: u32) -> [u8; 4] {
+  debug_assert!(value <= MAX_U28_UNSYNC);
+
+  [
+    (value >> 21) as u8 & 0x7F,
+    (value >> 14) as u8 & 0x7F,
+    (value >> 7) as u8 & 0x7F,
+    value as u8 & 0x7F,
+  ]
+}
+
 // =============================================================================
 // Misc. Integers
 // =============================================================================
@@ -89,9 +123,10 @@ pub const fn is_frame_id(mut input: &[u8]) -> bool {
 
 /// Returns `true` if the input bytes are a valid ISO-8859-1 string.
 ///
-/// Note: All characters must be in the range of `0x20-0xFF`.
+/// Note: All characters must be in the range of `0x20-0xFF`, except for the
+/// newline character (`0x0A`).
 pub const fn is_latin1(mut input: &[u8]) -> bool {
-  while let [0x20..=0xFF, tail @ ..] = input {
+  while let [0x0A | 0x20..=0xFF, tail @ ..] = input {
     input = tail;
   }
 
@@ -121,18 +156,73 @@ pub const fn is_null(mut input: &[u8]) -> bool {
   input.is_empty()
 }
 
+// =============================================================================
+// Formatting
+// =============================================================================
+
+/// The maximum number of bytes shown by [`HexPreview`].
+const PREVIEW: usize = 8;
+
+/// Displays the length and first few bytes of binary data.
+pub struct HexPreview<'a>(pub &'a Slice);
+
+impl Display for HexPreview<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{} bytes", self.0.len())?;
+
+    if self.0.is_empty() {
+      return Ok(());
+    }
+
+    write!(f, ":")?;
+
+    for byte in self.0.iter().take(PREVIEW) {
+      write!(f, " {byte:02X}")?;
+    }
+
+    if self.0.len() > PREVIEW {
+      write!(f, " ...")?;
+    }
+
+    Ok(())
+  }
+}
+
 // =============================================================================
 // Compression
 // =============================================================================
 
+/// Decompress the ZLIB `input`, expected to be `size` bytes, up to `limit`
+/// bytes.
 #[cfg(feature = "zlib")]
-pub fn decompress(input: &Slice, size: Option<usize>) -> Result<Bytes> {
-  ZlibDecoder::new(input.cursor())
-    .read_all(size)
-    .map_err(Into::into)
+pub fn decompress(input: &Slice, size: Option<usize>, limit: usize) -> Result<Bytes> {
+  check_decompressed(size, limit)?;
+
+  // Read one byte past the limit to detect content that exceeds it.
+  let bytes: Bytes = ZlibDecoder::new(input.cursor())
+    .take((limit as u64).saturating_add(1))
+    .read_all(size)?;
+
+  check_decompressed(Some(bytes.len()), limit)?;
+
+  Ok(bytes)
 }
 
+/// Returns an [`Unsupported`][ErrorKind::Unsupported] error, enable the
+/// `zlib` feature to use ZLIB decompression.
 #[cfg(not(feature = "zlib"))]
-pub fn decompress(_input: &Slice, _size: Option<usize>) -> Result<Bytes> {
-  panic!("Enable `zlib` feature to use ZLIB decompression.");
+pub fn decompress(_input: &Slice, size: Option<usize>, limit: usize) -> Result<Bytes> {
+  check_decompressed(size, limit)?;
+
+  Err(Error::new(ErrorKind::Unsupported))
+}
+
+/// Returns a [`SizeLimit`][ErrorKind::SizeLimit] error if the expected
+/// `size` exceeds the `limit`.
+pub(crate) fn check_decompressed(size: Option<usize>, limit: usize) -> Result<()> {
+  if size.is_some_and(|size| size > limit) {
+    return Err(Error::new(ErrorKind::SizeLimit(SizeLimit::Decompressed)));
+  }
+
+  Ok(())
 }
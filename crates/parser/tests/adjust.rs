@@ -0,0 +1,165 @@
+use parser::content::Content;
+use parser::content::Equa;
+use parser::content::EquaBand;
+use parser::content::Rvad;
+use parser::content::RvadChannel;
+use parser::content::VolumeAdjustment;
+use parser::types::DynFrameId;
+use parser::types::Slice;
+use parser::types::Version;
+
+fn decode<'a>(id: &str, data: &'a [u8]) -> Content<'a> {
+  let id: DynFrameId = DynFrameId::try_from(id).unwrap();
+
+  Content::decode(Version::ID3v23, id, Slice::new(data)).unwrap()
+}
+
+fn rvad(data: &[u8]) -> Rvad<'_> {
+  let Content::Rvad(rvad) = decode("RVAD", data) else {
+    panic!("expected RVAD");
+  };
+
+  rvad
+}
+
+fn channels(adjustments: &[VolumeAdjustment]) -> Vec<(RvadChannel, i64, u64)> {
+  adjustments
+    .iter()
+    .map(|adjustment| (adjustment.channel(), adjustment.volume(), adjustment.peak()))
+    .collect()
+}
+
+#[test]
+fn test_rvad_two_channels() {
+  let data: &[u8] = &[
+    0b0000_0001,
+    0x10,
+    0x0F,
+    0xFF,
+    0x20,
+    0x00,
+    0x7F,
+    0xFF,
+    0x80,
+    0x00,
+  ];
+  let rvad: Rvad<'_> = rvad(data);
+  let adjustments: Vec<VolumeAdjustment> = rvad.channels().unwrap();
+
+  assert_eq!(rvad.increment(), 0x01);
+  assert_eq!(rvad.volume_bits(), 16);
+  assert_eq!(
+    channels(&adjustments),
+    [
+      (RvadChannel::Right, 0x0FFF, 0x7FFF),
+      (RvadChannel::Left, -0x2000, 0x8000),
+    ]
+  );
+
+  assert!((adjustments[0].decibels() - 0.526).abs() < 0.001);
+  assert!((adjustments[1].decibels() + 1.160).abs() < 0.001);
+
+  let Content::Rvad(rvad) = decode("RVAD", data) else {
+    panic!("expected RVAD");
+  };
+
+  assert_eq!(Content::Rvad(rvad).encode(Version::ID3v23).unwrap(), data);
+}
+
+#[test]
+fn test_rvad_six_channels() {
+  let mut data: Vec<u8> = vec![0b0010_1010, 0x08];
+  data.extend([0x01, 0x02, 0x11, 0x12]);
+  data.extend([0x03, 0x04, 0x13, 0x14]);
+  data.extend([0x05, 0x15]);
+  data.extend([0x06, 0x16]);
+
+  let adjustments: Vec<VolumeAdjustment> = rvad(&data).channels().unwrap();
+
+  assert_eq!(
+    channels(&adjustments),
+    [
+      (RvadChannel::Right, -0x01, 0x11),
+      (RvadChannel::Left, 0x02, 0x12),
+      (RvadChannel::RightBack, -0x03, 0x13),
+      (RvadChannel::LeftBack, 0x04, 0x14),
+      (RvadChannel::Center, -0x05, 0x15),
+      (RvadChannel::Bass, 0x06, 0x16),
+    ]
+  );
+
+  // Frames may stop after any group of channels.
+  assert_eq!(rvad(&data[..10]).channels().unwrap().len(), 4);
+  assert_eq!(rvad(&data[..12]).channels().unwrap().len(), 5);
+}
+
+#[test]
+fn test_rvad_widths() {
+  let data: &[u8] = &[
+    0x03, 0x18, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x01,
+  ];
+  let adjustments: Vec<VolumeAdjustment> = rvad(data).channels().unwrap();
+
+  assert_eq!(
+    channels(&adjustments),
+    [
+      (RvadChannel::Right, 0x010000, 0xFFFFFF),
+      (RvadChannel::Left, 0x000002, 0x000001),
+    ]
+  );
+
+  // Values are cut short or the number of bits is invalid.
+  assert!(rvad(&data[..13]).channels().is_err());
+  assert!(rvad(&data[..3]).channels().is_err());
+  assert!(rvad(&[0x03, 0x00]).channels().is_err());
+  assert!(rvad(&[0x03, 0x21]).channels().is_err());
+}
+
+fn equa(data: &[u8]) -> Equa<'_> {
+  let Content::Equa(equa) = decode("EQUA", data) else {
+    panic!("expected EQUA");
+  };
+
+  equa
+}
+
+fn bands(equa: &Equa<'_>) -> Vec<(u16, i64)> {
+  equa
+    .bands()
+    .map(|band| band.unwrap())
+    .map(|band: EquaBand| (band.frequency(), band.adjustment()))
+    .collect()
+}
+
+#[test]
+fn test_equa_two_bands() {
+  let data: &[u8] = &[0x10, 0x80, 0x64, 0x01, 0x00, 0x27, 0x10, 0x00, 0x80];
+  let frame: Equa<'_> = equa(data);
+
+  assert_eq!(frame.adjustment_bits(), 16);
+  assert_eq!(bands(&frame), [(100, 0x0100), (10000, -0x0080)]);
+  assert_eq!(Content::Equa(frame).encode(Version::ID3v23).unwrap(), data);
+
+  let data: &[u8] = &[0x08, 0x80, 0x64, 0x10, 0x27, 0x10, 0x20];
+  let frame: Equa<'_> = equa(data);
+
+  assert_eq!(bands(&frame), [(100, 0x10), (10000, -0x20)]);
+  assert_eq!(Content::Equa(frame).encode(Version::ID3v23).unwrap(), data);
+}
+
+#[test]
+fn test_equa_invalid_width() {
+  // The 16-bit adjustment of the second band is cut short.
+  let frame: Equa<'_> = equa(&[0x10, 0x80, 0x64, 0x01, 0x00, 0x27, 0x10, 0x00]);
+  let mut iter = frame.bands();
+
+  assert_eq!(iter.next().unwrap().unwrap().frequency(), 100);
+  assert!(iter.next().unwrap().is_err());
+  assert!(iter.next().is_none());
+
+  let frame: Equa<'_> = equa(&[0x00, 0x80, 0x64, 0x01]);
+  let mut iter = frame.bands();
+
+  assert!(iter.next().unwrap().is_err());
+  assert!(iter.next().is_none());
+}
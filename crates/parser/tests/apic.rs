@@ -0,0 +1,98 @@
+mod common;
+
+use parser::content::Apic;
+use parser::content::ImgType;
+
+use self::common::RawFrame;
+
+/// A 2x3 pixel PNG image.
+const PNG: &[u8] = &[
+  0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52,
+  0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x08, 0x02, 0x00, 0x00, 0x00, 0x12, 0x16, 0xF1,
+  0x4D, 0x00, 0x00, 0x00, 0x0C, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0x60, 0xA0, 0x0C, 0x00,
+  0x00, 0x00, 0x12, 0x00, 0x01, 0xA8, 0xC4, 0x37, 0x9B, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E,
+  0x44, 0xAE, 0x42, 0x60, 0x82,
+];
+
+/// A 640x480 pixel progressive JPEG image header (APP0, DQT and SOF2).
+const JPG: &[u8] = &[
+  0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10, 0x4A, 0x46, 0x49, 0x46, 0x00, 0x01, 0x01, 0x00, 0x00, 0x01,
+  0x00, 0x01, 0x00, 0x00, 0xFF, 0xDB, 0x00, 0x04, 0x00, 0x01, 0xFF, 0xC2, 0x00, 0x0B, 0x08, 0x01,
+  0xE0, 0x02, 0x80, 0x01, 0x01, 0x11, 0x00, 0xFF, 0xD9,
+];
+
+fn apic_frame(mime_type: &str, image: &[u8]) -> RawFrame {
+  RawFrame::v3(
+    b"APIC",
+    &[b"\x00", mime_type.as_bytes(), b"\x00\x03\x00", image].concat(),
+  )
+}
+
+fn apic(raw: &RawFrame) -> Apic<'_> {
+  raw.frame().decode().unwrap().into_apic().unwrap()
+}
+
+#[test]
+fn test_apic_sniff_format() {
+  let cases: [(&[u8], Option<ImgType>); 7] = [
+    (PNG, Some(ImgType::Png)),
+    (JPG, Some(ImgType::Jpg)),
+    (b"GIF87a\x01\x00", Some(ImgType::Gif)),
+    (b"GIF89a\x01\x00", Some(ImgType::Gif)),
+    (b"BM\x3A\x00", Some(ImgType::Bmp)),
+    (b"RIFF\x24\x00\x00\x00WEBPVP8 ", Some(ImgType::Webp)),
+    (b"\x00\x01\x02\x03", None),
+  ];
+
+  for (image, format) in cases {
+    let raw: RawFrame = apic_frame("image/png", image);
+    let picture: Apic<'_> = apic(&raw);
+
+    assert_eq!(picture.sniff_format(), format);
+    assert_eq!(
+      picture.is_mislabeled(),
+      format.is_some_and(|format| format != ImgType::Png)
+    );
+  }
+
+  let raw: RawFrame = apic_frame("image/jpeg", JPG);
+  let picture: Apic<'_> = apic(&raw);
+
+  assert!(!picture.is_mislabeled());
+}
+
+#[test]
+fn test_apic_dimensions() {
+  let raw: RawFrame = apic_frame("image/png", PNG);
+  let picture: Apic<'_> = apic(&raw);
+
+  assert_eq!(picture.dimensions(), Some((2, 3)));
+
+  let raw: RawFrame = apic_frame("image/jpeg", JPG);
+  let picture: Apic<'_> = apic(&raw);
+
+  assert_eq!(picture.dimensions(), Some((640, 480)));
+
+  let raw: RawFrame = apic_frame("image/gif", b"GIF89a\x01\x00\x01\x00");
+  let picture: Apic<'_> = apic(&raw);
+
+  assert_eq!(picture.dimensions(), None);
+}
+
+#[test]
+fn test_apic_dimensions_truncated() {
+  for length in 0..24 {
+    let raw: RawFrame = apic_frame("image/png", &PNG[..length]);
+    let picture: Apic<'_> = apic(&raw);
+
+    assert_eq!(picture.dimensions(), None, "{length}");
+  }
+
+  // Truncated within the DQT segment and the SOF2 marker.
+  for length in [3, 24, 30, 34] {
+    let raw: RawFrame = apic_frame("image/jpeg", &JPG[..length]);
+    let picture: Apic<'_> = apic(&raw);
+
+    assert_eq!(picture.dimensions(), None, "{length}");
+  }
+}
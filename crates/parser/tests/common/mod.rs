@@ -0,0 +1,184 @@
+#![allow(dead_code)]
+
+//! Helpers for building raw ID3v2 tags in tests.
+
+use parser::content::Content;
+use parser::frame::DynFrame;
+use parser::id3v2::Tag;
+use parser::types::Slice;
+use parser::types::Version;
+
+/// Encode a 28-bit "unsynchronized" integer.
+pub fn synchsafe(value: u32) -> [u8; 4] {
+  [
+    ((value >> 21) & 0x7F) as u8,
+    ((value >> 14) & 0x7F) as u8,
+    ((value >> 7) & 0x7F) as u8,
+    (value & 0x7F) as u8,
+  ]
+}
+
+/// Build a raw ID3v2.2 frame.
+pub fn frame_v2(id: &[u8; 3], data: &[u8]) -> Vec<u8> {
+  let size: [u8; 4] = (data.len() as u32).to_be_bytes();
+  let mut output: Vec<u8> = id.to_vec();
+  output.extend_from_slice(&size[1..]);
+  output.extend_from_slice(data);
+  output
+}
+
+/// Build a raw ID3v2.3 frame.
+pub fn frame_v3(id: &[u8; 4], flags: u16, data: &[u8]) -> Vec<u8> {
+  let mut output: Vec<u8> = id.to_vec();
+  output.extend_from_slice(&(data.len() as u32).to_be_bytes());
+  output.extend_from_slice(&flags.to_be_bytes());
+  output.extend_from_slice(data);
+  output
+}
+
+/// Build a raw ID3v2.4 frame.
+pub fn frame_v4(id: &[u8; 4], flags: u16, data: &[u8]) -> Vec<u8> {
+  let mut output: Vec<u8> = id.to_vec();
+  output.extend_from_slice(&synchsafe(data.len() as u32));
+  output.extend_from_slice(&flags.to_be_bytes());
+  output.extend_from_slice(data);
+  output
+}
+
+/// An owned raw frame with no flags, parsed from its buffer on demand.
+pub struct RawFrame {
+  version: Version,
+  data: Vec<u8>,
+}
+
+impl RawFrame {
+  /// Build a raw ID3v2.3 frame.
+  pub fn v3(id: &[u8; 4], data: &[u8]) -> Self {
+    Self {
+      version: Version::ID3v23,
+      data: frame_v3(id, 0x0000, data),
+    }
+  }
+
+  /// Build a raw ID3v2.4 frame.
+  pub fn v4(id: &[u8; 4], data: &[u8]) -> Self {
+    Self {
+      version: Version::ID3v24,
+      data: frame_v4(id, 0x0000, data),
+    }
+  }
+
+  /// Parse the frame header, borrowing the buffer.
+  pub fn frame(&self) -> DynFrame<'_> {
+    DynFrame::from_slice(self.version, Slice::new(&self.data))
+      .unwrap()
+      .unwrap()
+  }
+}
+
+/// Build a raw ID3v2 tag with the given `major` version, header `flags` and `body`.
+pub fn tag(major: u8, flags: u8, body: &[u8]) -> Vec<u8> {
+  let mut output: Vec<u8> = vec![b'I', b'D', b'3', major, 0x00, flags];
+  output.extend_from_slice(&synchsafe(body.len() as u32));
+  output.extend_from_slice(body);
+  output
+}
+
+/// Build a raw ID3v2.4 tag with a footer.
+pub fn tag_footer(body: &[u8]) -> Vec<u8> {
+  let mut data: Vec<u8> = tag(0x04, 0x10, body);
+  let mut footer: Vec<u8> = data[..10].to_vec();
+
+  footer[..3].copy_from_slice(b"3DI");
+  data.extend_from_slice(&footer);
+  data
+}
+
+/// Decode the `TIT2` frame of `tag` as a string.
+pub fn title(tag: &Tag) -> Option<String> {
+  tag
+    .frames()
+    .map(Result::unwrap)
+    .find(|frame: &DynFrame<'_>| frame.identifier_str() == "TIT2")
+    .map(|frame| frame.decode().unwrap().to_string())
+}
+
+/// Decode all frames of `tag` with their identifiers.
+pub fn contents(tag: &Tag) -> Vec<(String, Content<'_>)> {
+  tag
+    .frames()
+    .map(|frame| {
+      let frame: DynFrame<'_> = frame.unwrap();
+      (frame.identifier_str().to_owned(), frame.decode().unwrap())
+    })
+    .collect()
+}
+
+/// Build the content of a Latin-1 text frame.
+pub fn text_latin1(text: &str) -> Vec<u8> {
+  let mut output: Vec<u8> = vec![0x00];
+  output.extend_from_slice(text.as_bytes());
+  output
+}
+
+/// Build the content of a UTF-16 (with BOM) text frame.
+pub fn text_utf16(text: &str) -> Vec<u8> {
+  let mut output: Vec<u8> = vec![0x01, 0xFF, 0xFE];
+  output.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+  output
+}
+
+/// Build the content of a UTF-8 text frame.
+pub fn text_utf8(text: &str) -> Vec<u8> {
+  let mut output: Vec<u8> = vec![0x03];
+  output.extend_from_slice(text.as_bytes());
+  output
+}
+
+/// Build the content of a COMM or USLT frame with Latin-1 text.
+pub fn comm(language: &[u8; 3], description: &str, text: &str) -> Vec<u8> {
+  let mut output: Vec<u8> = vec![0x00];
+  output.extend_from_slice(language);
+  output.extend_from_slice(description.as_bytes());
+  output.push(0x00);
+  output.extend_from_slice(text.as_bytes());
+  output
+}
+
+/// Build the content of a front cover APIC frame with a Latin-1 description.
+pub fn apic(mime_type: &str, description: &str, data: &[u8]) -> Vec<u8> {
+  let mut output: Vec<u8> = vec![0x00];
+  output.extend_from_slice(mime_type.as_bytes());
+  output.extend_from_slice(&[0x00, 0x03]);
+  output.extend_from_slice(description.as_bytes());
+  output.push(0x00);
+  output.extend_from_slice(data);
+  output
+}
+
+/// Collect the identifiers of all frames in `tag`.
+pub fn frame_ids(tag: &Tag) -> Vec<String> {
+  tag
+    .frames()
+    .map(|frame| frame.map(|frame: DynFrame<'_>| frame.identifier_str().to_owned()))
+    .collect::<Result<_, _>>()
+    .unwrap()
+}
+
+/// Wrap `data` in a zlib stream of stored (uncompressed) deflate blocks.
+pub fn zlib_stored(data: &[u8]) -> Vec<u8> {
+  let mut output: Vec<u8> = vec![0x78, 0x01, 0x01];
+  let length: u16 = data.len() as u16;
+
+  output.extend(length.to_le_bytes());
+  output.extend((!length).to_le_bytes());
+  output.extend(data);
+
+  let (a, b): (u32, u32) = data.iter().fold((1, 0), |(a, b), byte| {
+    let a: u32 = (a + u32::from(*byte)) % 65521;
+    (a, (b + a) % 65521)
+  });
+
+  output.extend(((b << 16) | a).to_be_bytes());
+  output
+}
@@ -0,0 +1,729 @@
+mod common;
+
+use parser::content::Content;
+use parser::content::ContentKind;
+use parser::content::ImgType;
+use parser::content::Position;
+use parser::error::Error;
+use parser::error::ErrorKind;
+use parser::frame::DynFrame;
+use parser::id3v2::Tag;
+use parser::options::ParseOptions;
+use parser::types::DynFrameId;
+use parser::types::FrameId;
+use parser::types::LanguagePreference;
+use parser::types::Slice;
+use parser::types::Version;
+
+use self::common::comm;
+use self::common::frame_v3;
+use self::common::tag;
+use self::common::text_latin1;
+use self::common::text_utf16;
+use self::common::text_utf8;
+use self::common::RawFrame;
+
+#[test]
+fn test_decode_unknown_frame() {
+  let payload: &[u8] = &[0xDE, 0xAD, 0xBE, 0xEF, 0x00, 0x01];
+  let data: Vec<u8> = frame_v3(b"ZZZZ", 0x0000, payload);
+  let frame: DynFrame<'_> = DynFrame::from_slice(Version::ID3v23, Slice::new(&data))
+    .unwrap()
+    .unwrap();
+
+  let Content::Unkn(content) = frame.decode().unwrap() else {
+    panic!("expected unknown content");
+  };
+
+  assert_eq!(content.identifier(), "ZZZZ");
+  assert_eq!(content.binary_data().as_ref(), payload);
+}
+
+#[test]
+fn test_decode_id3v1_version() {
+  let slice: &Slice = Slice::new(b"\x00Title");
+  let id: FrameId = FrameId::try_from(*b"TIT2").unwrap();
+
+  for version in [Version::ID3v11, Version::ID3v12] {
+    let error: Error = Content::decode(version, id, slice).unwrap_err();
+
+    assert!(matches!(error.kind(), ErrorKind::InvalidVersion));
+  }
+}
+
+#[test]
+fn test_decode_frame_id() {
+  let slice: &Slice = Slice::new(b"\x00Title");
+  let v2: FrameId<3> = FrameId::try_from(*b"TT2").unwrap();
+  let v3: FrameId<4> = FrameId::try_from(*b"TIT2").unwrap();
+
+  let content: Content<'_> = Content::decode(Version::ID3v22, v2, slice).unwrap();
+
+  assert_eq!(content.to_string(), "Title");
+
+  let content: Content<'_> = Content::decode(Version::ID3v24, v3, slice).unwrap();
+
+  assert_eq!(content.to_string(), "Title");
+
+  // Identifiers of the wrong length are unknown content.
+  let content: Content<'_> = Content::decode(Version::ID3v24, v2, slice).unwrap();
+
+  assert!(matches!(content, Content::Unkn(_)));
+
+  assert_eq!(DynFrameId::try_from("TT2").unwrap(), DynFrameId::V2(v2));
+  assert_eq!(DynFrameId::try_from("TIT2").unwrap(), DynFrameId::V3(v3));
+  assert!(DynFrameId::try_from("TIT").is_ok());
+  assert!(DynFrameId::try_from("TI").is_err());
+  assert!(DynFrameId::try_from("tit2").is_err());
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_decode_str() {
+  let slice: &Slice = Slice::new(b"\x00Title");
+
+  let content: Content<'_> = Content::decode_str(Version::ID3v23, "TIT2", slice).unwrap();
+
+  assert_eq!(content.to_string(), "Title");
+
+  let content: Content<'_> = Content::decode_str(Version::ID3v23, "not an id", slice).unwrap();
+
+  assert!(matches!(content, Content::Unkn(_)));
+}
+
+#[test]
+fn test_decode_musicmatch_frames() {
+  // An NCON payload in the MusicMatch Jukebox style: opaque binary data with
+  // embedded NUL and 0xFF bytes.
+  let ncon: &[u8] = &[
+    0x4D, 0x4D, 0x4A, 0x42, 0x00, 0x00, 0x02, 0x00, 0xFF, 0xFE, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+    0x01, 0x00, 0x00, 0x00, 0x3C, 0x2A, 0x00, 0xFF,
+  ];
+  let xdor: &[u8] = &[0x00, 0x00, 0x01, 0x07, 0xCF, 0x00];
+
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(frame_v3(b"NCON", 0x0000, ncon));
+  body.extend(frame_v3(b"XDOR", 0x0000, xdor));
+
+  let tag: Tag = Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap();
+  let mut unknown: Vec<(String, Vec<u8>)> = Vec::new();
+
+  for frame in tag.frames() {
+    if let Content::Unkn(content) = frame.unwrap().decode().unwrap() {
+      unknown.push((
+        content.identifier().to_string(),
+        content.binary_data().as_ref().to_vec(),
+      ));
+    }
+  }
+
+  assert_eq!(
+    unknown,
+    [
+      ("NCON".to_string(), ncon.to_vec()),
+      ("XDOR".to_string(), xdor.to_vec())
+    ]
+  );
+}
+
+fn decode(raw: &RawFrame) -> Content<'_> {
+  raw.frame().decode().unwrap()
+}
+
+#[test]
+fn test_decode_comr_without_logo() {
+  // Written by a 2002 tagger that stops after the description.
+  let payload: &[u8] =
+    b"\x00USD0.99\x0020021231http://www.example.com/buy\x00\x03Indie Label\x00Full album\x00";
+
+  let raw: RawFrame = RawFrame::v3(b"COMR", payload);
+  let Content::Comr(comr) = decode(&raw) else {
+    panic!("expected COMR");
+  };
+
+  assert_eq!(comr.price_string(), "USD0.99");
+  assert_eq!(comr.seller_name(), "Indie Label");
+  assert_eq!(comr.description(), "Full album");
+  assert_eq!(comr.mime_type(), None);
+  assert_eq!(comr.seller_logo(), None);
+
+  let payload: Vec<u8> = [payload, b"image/png\x00\x89PNG"].concat();
+
+  let raw: RawFrame = RawFrame::v3(b"COMR", &payload);
+  let Content::Comr(comr) = decode(&raw) else {
+    panic!("expected COMR");
+  };
+
+  assert_eq!(comr.mime_type(), Some("image/png"));
+  assert_eq!(comr.seller_logo().map(AsRef::as_ref), Some(&b"\x89PNG"[..]));
+}
+
+#[test]
+fn test_decode_apic_formats() {
+  let gif: &[u8] = b"\x00image/GIF\x00\x03Cover\x00GIF89a\x01\x00\x01\x00";
+
+  let raw: RawFrame = RawFrame::v3(b"APIC", gif);
+  let Content::Apic(apic) = decode(&raw) else {
+    panic!("expected APIC");
+  };
+
+  assert_eq!(apic.image_format(), ImgType::Gif);
+  assert_eq!(apic.image_format().mime_type(), "image/gif");
+  assert_eq!(
+    apic.picture_data().unwrap().as_ref(),
+    b"GIF89a\x01\x00\x01\x00"
+  );
+  assert_eq!(apic.picture_url(), None);
+
+  for (mime_type, format) in [
+    ("image/bmp", ImgType::Bmp),
+    ("image/tiff", ImgType::Tiff),
+    ("image/webp", ImgType::Webp),
+    ("image/JPEG", ImgType::Jpg),
+  ] {
+    let data: Vec<u8> = [b"\x00", mime_type.as_bytes(), b"\x00\x03\x00\x00"].concat();
+
+    let raw: RawFrame = RawFrame::v3(b"APIC", &data);
+    let Content::Apic(apic) = decode(&raw) else {
+      panic!("expected APIC");
+    };
+
+    assert_eq!(apic.image_format(), format);
+  }
+
+  for (code, format) in [
+    (b"GIF", ImgType::Gif),
+    (b"bmp", ImgType::Bmp),
+    (b"TIF", ImgType::Tiff),
+  ] {
+    let data: Vec<u8> = [b"\x00", &code[..], b"\x03\x00\x00"].concat();
+    let id: FrameId<3> = FrameId::try_from(*b"PIC").unwrap();
+
+    let Content::Apic(apic) = Content::decode(Version::ID3v22, id, Slice::new(&data)).unwrap()
+    else {
+      panic!("expected PIC");
+    };
+
+    assert_eq!(apic.image_format(), format);
+  }
+}
+
+#[test]
+fn test_decode_apic_link() {
+  let link: &[u8] = b"\x00-->\x00\x03Cover\x00https://example.com/cover.jpg";
+
+  let raw: RawFrame = RawFrame::v3(b"APIC", link);
+  let Content::Apic(apic) = decode(&raw) else {
+    panic!("expected APIC");
+  };
+
+  assert_eq!(apic.image_format(), ImgType::Link);
+  assert_eq!(apic.picture_url().unwrap(), "https://example.com/cover.jpg");
+  assert_eq!(Content::Apic(apic).encode(Version::ID3v23).unwrap(), link);
+
+  let id: FrameId<3> = FrameId::try_from(*b"PIC").unwrap();
+  let content: Content<'_> = Content::decode(
+    Version::ID3v22,
+    id,
+    Slice::new(b"\x00-->\x03\x00http://a.b/c"),
+  )
+  .unwrap();
+
+  let Content::Apic(apic) = content else {
+    panic!("expected PIC");
+  };
+
+  assert_eq!(apic.picture_url().unwrap(), "http://a.b/c");
+}
+
+#[test]
+fn test_decode_apic_unknown_mime() {
+  let png: &[u8] = b"\x89PNG\r\n\x1A\n";
+
+  for (mime_type, data, format) in [
+    ("image/x-ms-bmp", &b"BM\x00\x00"[..], ImgType::Bmp),
+    ("", png, ImgType::Png),
+    ("image/x-unknown", b"\x00\x01\x02", ImgType::Unknown),
+  ] {
+    let payload: Vec<u8> = [b"\x00", mime_type.as_bytes(), b"\x00\x03\x00", data].concat();
+
+    let raw: RawFrame = RawFrame::v3(b"APIC", &payload);
+    let Content::Apic(apic) = decode(&raw) else {
+      panic!("expected APIC");
+    };
+
+    assert_eq!(apic.mime_type(), mime_type);
+    assert_eq!(apic.image_format(), format);
+    assert_eq!(apic.image_format().is_png(), format == ImgType::Png);
+    assert!(!apic.image_format().is_jpeg());
+    assert_eq!(
+      Content::Apic(apic).encode(Version::ID3v23).unwrap(),
+      payload
+    );
+  }
+
+  // The declared format takes precedence over the magic bytes.
+  let payload: &[u8] = b"\x00image/JPEG\x00\x03\x00\x89PNG\r\n\x1A\n";
+
+  let raw: RawFrame = RawFrame::v3(b"APIC", payload);
+  let Content::Apic(apic) = decode(&raw) else {
+    panic!("expected APIC");
+  };
+
+  assert_eq!(apic.mime_type(), "image/JPEG");
+  assert!(apic.image_format().is_jpeg());
+
+  // ID3v2.2 image formats are kept as stored.
+  let id: FrameId<3> = FrameId::try_from(*b"PIC").unwrap();
+  let data: &[u8] = b"\x00XYZ\x03\x00\xFF\xD8\xFF\xE0";
+  let content: Content<'_> = Content::decode(Version::ID3v22, id, Slice::new(data)).unwrap();
+
+  assert_eq!(content.encode(Version::ID3v22).unwrap(), data);
+
+  let Content::Apic(apic) = content else {
+    panic!("expected PIC");
+  };
+
+  assert_eq!(apic.mime_type(), "XYZ");
+  assert_eq!(apic.image_format(), ImgType::Jpg);
+}
+
+#[test]
+fn test_decode_pcnt() {
+  let raw: RawFrame = RawFrame::v3(b"PCNT", b"\x00\x00\x00\x2A");
+  let Content::Pcnt(pcnt) = decode(&raw) else {
+    panic!("expected PCNT");
+  };
+
+  assert_eq!(pcnt.counter(), 42);
+  assert_eq!(pcnt.counter_u32(), 42);
+
+  let raw: RawFrame = RawFrame::v3(b"PCNT", b"\x01\x00\x00\x00\x00");
+  let Content::Pcnt(pcnt) = decode(&raw) else {
+    panic!("expected PCNT");
+  };
+
+  assert_eq!(pcnt.counter(), 4294967296);
+  assert_eq!(pcnt.counter_u32(), u32::MAX);
+
+  let data: Vec<u8> = frame_v3(b"PCNT", 0x0000, &[0x01; 9]);
+  let frame: DynFrame<'_> = DynFrame::from_slice(Version::ID3v23, Slice::new(&data))
+    .unwrap()
+    .unwrap();
+
+  assert!(frame.decode().is_err());
+}
+
+#[test]
+fn test_decode_popm() {
+  let raw: RawFrame = RawFrame::v3(b"POPM", b"a@b.c\x00\xC4\x00\x01\x00\x00\x00\x00");
+  let Content::Popm(popm) = decode(&raw) else {
+    panic!("expected POPM");
+  };
+
+  assert_eq!(popm.counter(), 1 << 32);
+  assert_eq!(popm.rating(), 0xC4);
+
+  for (rating, stars) in [
+    (0, None),
+    (1, Some(1)),
+    (64, Some(2)),
+    (128, Some(3)),
+    (196, Some(4)),
+    (255, Some(5)),
+  ] {
+    let raw: RawFrame = RawFrame::v3(b"POPM", &[b'a', 0x00, rating]);
+    let Content::Popm(popm) = decode(&raw) else {
+      panic!("expected POPM");
+    };
+
+    assert_eq!(popm.counter(), 0);
+    assert_eq!(popm.stars(), stars, "{rating}");
+    assert_eq!(popm.rating_fraction(), f32::from(rating) / 255.0);
+  }
+
+  let data: Vec<u8> = frame_v3(
+    b"POPM",
+    0x0000,
+    b"a\x00\xFF\x01\x00\x00\x00\x00\x00\x00\x00\x00",
+  );
+  let frame: DynFrame<'_> = DynFrame::from_slice(Version::ID3v23, Slice::new(&data))
+    .unwrap()
+    .unwrap();
+
+  assert!(matches!(
+    frame.decode().unwrap_err().kind(),
+    ErrorKind::InvalidFrameData
+  ));
+}
+
+#[test]
+fn test_decode_movement_name() {
+  let raw: RawFrame = RawFrame::v3(b"MVNM", &text_latin1("Allegro"));
+  let Content::Text(content) = decode(&raw) else {
+    panic!("expected text content");
+  };
+
+  assert_eq!(content.text_content().to_string(), "Allegro");
+}
+
+#[test]
+fn test_decode_movement_number() {
+  let raw: RawFrame = RawFrame::v3(b"MVIN", &text_latin1("2/4"));
+  let Content::Text(content) = decode(&raw) else {
+    panic!("expected text content");
+  };
+
+  let position: Position = content.position().unwrap();
+
+  assert_eq!(position.current(), 2);
+  assert_eq!(position.total(), Some(4));
+
+  let raw: RawFrame = RawFrame::v3(b"MVIN", &text_latin1("3"));
+  let Content::Text(content) = decode(&raw) else {
+    panic!("expected text content");
+  };
+
+  let position: Position = content.position().unwrap();
+
+  assert_eq!(position.current(), 3);
+  assert_eq!(position.total(), None);
+}
+
+#[test]
+fn test_decode_grouping() {
+  let raw: RawFrame = RawFrame::v4(b"GRP1", &text_utf8("Café Sessions"));
+  let Content::Text(content) = decode(&raw) else {
+    panic!("expected text content");
+  };
+
+  assert_eq!(content.text_content().to_string(), "Café Sessions");
+
+  let raw: RawFrame = RawFrame::v3(b"GRP1", &text_utf16("Café Sessions"));
+  let Content::Text(content) = decode(&raw) else {
+    panic!("expected text content");
+  };
+
+  assert_eq!(content.text_content().to_string(), "Café Sessions");
+}
+
+#[test]
+fn test_tag_grouping_and_work() {
+  let mut body: Vec<u8> = frame_v3(b"TIT1", 0x0000, &text_latin1("Symphony No. 5"));
+  body.extend(frame_v3(b"GRP1", 0x0000, &text_utf16("Favourites")));
+
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  let grouping: String = tag.grouping().unwrap().unwrap().text_content().to_string();
+  let work: String = tag.work().unwrap().unwrap().text_content().to_string();
+
+  assert_eq!(grouping, "Favourites");
+  assert_eq!(work, "Symphony No. 5");
+}
+
+#[test]
+fn test_tag_legacy_grouping() {
+  let body: Vec<u8> = frame_v3(b"TIT1", 0x0000, &text_latin1("Favourites"));
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  let grouping: String = tag.grouping().unwrap().unwrap().text_content().to_string();
+
+  assert_eq!(grouping, "Favourites");
+  assert!(tag.work().unwrap().is_none());
+}
+
+#[test]
+fn test_decode_podcast_frames() {
+  let mut body: Vec<u8> = frame_v3(b"PCST", 0x0000, &[0x00, 0x00, 0x00, 0x01]);
+  body.extend(frame_v3(b"TGID", 0x0000, &text_latin1("urn:uuid:0f1e2d3c")));
+  body.extend(frame_v3(b"TDES", 0x0000, &text_utf16("Episode notes")));
+  body.extend(frame_v3(b"TKWD", 0x0000, &text_latin1("rust,audio")));
+  body.extend(frame_v3(
+    b"WFED",
+    0x0000,
+    &text_latin1("https://example.com/feed.xml"),
+  ));
+
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  let contents: Vec<Content<'_>> = tag
+    .frames()
+    .map(|frame| frame.unwrap().decode().unwrap())
+    .collect();
+
+  let [Content::Pcst(pcst), Content::Text(tgid), Content::Text(tdes), Content::Text(tkwd), Content::Text(wfed)] =
+    contents.as_slice()
+  else {
+    panic!("unexpected content: {contents:?}");
+  };
+
+  assert!(pcst.is_podcast());
+  assert_eq!(tgid.text_content().to_string(), "urn:uuid:0f1e2d3c");
+  assert_eq!(tdes.text_content().to_string(), "Episode notes");
+  assert_eq!(tkwd.text_content().to_string(), "rust,audio");
+  assert_eq!(
+    wfed.text_content().to_string(),
+    "https://example.com/feed.xml"
+  );
+}
+
+#[test]
+fn test_decode_legacy_sort_order() {
+  let mut body: Vec<u8> = frame_v3(b"XSOP", 0x0000, &text_latin1("Beatles, The"));
+  body.extend(frame_v3(b"XSOA", 0x0000, &text_latin1("White Album")));
+  body.extend(frame_v3(
+    b"TSOA",
+    0x0000,
+    &text_latin1("Beatles (White Album)"),
+  ));
+
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  let performer: String = tag
+    .performer_sort()
+    .unwrap()
+    .unwrap()
+    .text_content()
+    .to_string();
+  let album: String = tag
+    .album_sort()
+    .unwrap()
+    .unwrap()
+    .text_content()
+    .to_string();
+
+  assert_eq!(performer, "Beatles, The");
+  assert_eq!(album, "Beatles (White Album)");
+  assert!(tag.title_sort().unwrap().is_none());
+
+  let raw: RawFrame = RawFrame::v4(b"XSOT", &text_utf8("Revolution 9"));
+  let Content::Text(content) = decode(&raw) else {
+    panic!("expected text content");
+  };
+
+  assert_eq!(content.text_content().to_string(), "Revolution 9");
+}
+
+#[test]
+fn test_content_kind() {
+  let text_frame: RawFrame = RawFrame::v3(b"TIT2", &text_latin1("Title"));
+  let text: Content<'_> = decode(&text_frame);
+  let url_frame: RawFrame = RawFrame::v3(b"WCOM", b"https://example.com");
+  let url: Content<'_> = decode(&url_frame);
+  let apic_frame: RawFrame = RawFrame::v3(b"APIC", b"\x00image/png\x00\x03\x00\x89PNG");
+  let apic: Content<'_> = decode(&apic_frame);
+  let unkn_frame: RawFrame = RawFrame::v3(b"ZZZZ", &[0x01, 0x02]);
+  let unkn: Content<'_> = decode(&unkn_frame);
+
+  assert_eq!(text.kind(), ContentKind::Text);
+  assert_eq!(url.kind(), ContentKind::Wcom);
+  assert_eq!(apic.kind(), ContentKind::Apic);
+  assert_eq!(unkn.kind(), ContentKind::Unkn);
+
+  assert!(text.is_text() && !text.is_url() && !text.is_binary());
+  assert!(url.is_url() && !url.is_text() && !url.is_binary());
+  assert!(apic.is_binary() && !apic.is_text() && !apic.is_url());
+  assert!(unkn.is_binary());
+
+  assert_eq!(ContentKind::Apic.frame_id(), Some("APIC"));
+  assert_eq!(ContentKind::Rva2.frame_id(), Some("RVA2"));
+  assert_eq!(ContentKind::Wxxx.frame_id(), Some("WXXX"));
+  assert_eq!(ContentKind::Text.frame_id(), None);
+  assert_eq!(ContentKind::Unkn.frame_id(), None);
+
+  assert_eq!(ContentKind::Apic.to_string(), "Apic");
+  assert_eq!(ContentKind::Txxx.name(), "Txxx");
+}
+
+fn lang(language: &[u8; 3], text: &str) -> Vec<u8> {
+  let mut data: Vec<u8> = vec![0x00];
+  data.extend(language);
+  data.extend(text.as_bytes());
+  data
+}
+
+fn language_tag() -> Tag {
+  let mut body: Vec<u8> = frame_v3(b"COMM", 0x0000, &comm(b"eng", "iTunNORM", " 00000001"));
+  body.extend(frame_v3(b"COMM", 0x0000, &comm(b"eng", "", "English")));
+  body.extend(frame_v3(b"COMM", 0x0000, &comm(b"deu", "", "Deutsch")));
+  body.extend(frame_v3(b"COMM", 0x0000, &comm(b"jpn", "", "Japanese")));
+  body.extend(frame_v3(
+    b"COMM",
+    0x0000,
+    &comm(b"jpn", "Notes", "Japanese notes"),
+  ));
+  body.extend(frame_v3(b"USLT", 0x0000, &lang(b"deu", "\x00Lied")));
+  body.extend(frame_v3(b"USLT", 0x0000, &lang(b"eng", "\x00Song")));
+  body.extend(frame_v3(b"USER", 0x0000, &lang(b"eng", "Terms")));
+  body.extend(frame_v3(b"USER", 0x0000, &lang(b"jpn", "Kiyaku")));
+
+  Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap()
+}
+
+fn preference(codes: &[&str]) -> LanguagePreference {
+  LanguagePreference::from_codes(codes.iter().copied()).unwrap()
+}
+
+#[test]
+fn test_comment_language_preference() {
+  let tag: Tag = language_tag();
+
+  let comment = |preference: &LanguagePreference| -> Option<String> {
+    let comm = tag.comment_with(preference).unwrap()?;
+    Some(comm.text_details().to_owned())
+  };
+
+  assert_eq!(tag.comment().unwrap().unwrap().text_details(), "English");
+  assert_eq!(
+    comment(&preference(&["deu", "eng"])).as_deref(),
+    Some("Deutsch")
+  );
+  assert_eq!(
+    comment(&preference(&["eng", "deu"])).as_deref(),
+    Some("English")
+  );
+  assert_eq!(comment(&preference(&["jpn"])).as_deref(), Some("Japanese"));
+  assert_eq!(
+    comment(&preference(&["fra", "eng"])).as_deref(),
+    Some("English")
+  );
+  assert_eq!(comment(&preference(&["fra"])), None);
+  assert_eq!(
+    comment(&preference(&["fra"]).any(true)).as_deref(),
+    Some("English")
+  );
+}
+
+#[test]
+fn test_lyrics_and_terms_language_preference() {
+  let tag: Tag = language_tag();
+
+  let lyrics = tag
+    .lyrics_with(&preference(&["eng", "deu"]))
+    .unwrap()
+    .unwrap();
+  assert_eq!(lyrics.lyrics(), "Song");
+
+  let lyrics = tag.lyrics().unwrap().unwrap();
+  assert_eq!(lyrics.lyrics(), "Lied");
+
+  let terms = tag
+    .terms_of_use_with(&preference(&["jpn"]))
+    .unwrap()
+    .unwrap();
+  assert_eq!(terms.text_content(), "Kiyaku");
+
+  assert!(tag
+    .terms_of_use_with(&preference(&["deu"]))
+    .unwrap()
+    .is_none());
+}
+
+#[test]
+fn test_language_preference_lenient() {
+  let body: Vec<u8> = frame_v3(b"COMM", 0x0000, b"\x03eng\x00Ti\xFFle");
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let strict: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let lenient: Tag =
+    Tag::from_reader_with(data.as_slice(), ParseOptions::new().lenient(true)).unwrap();
+
+  // Comments are decoded with the options used to parse the tag.
+  assert!(strict.comment().is_err());
+  assert_eq!(
+    lenient.comment().unwrap().unwrap().text_details(),
+    "Ti\u{FFFD}le"
+  );
+}
+
+#[test]
+fn test_language_preference_codes() {
+  let preference: LanguagePreference = preference(&["deu", "eng"]);
+
+  assert_eq!(preference.languages().len(), 2);
+  assert!(!preference.is_any());
+  assert!(LanguagePreference::new().is_any());
+  assert!(LanguagePreference::from_codes(["en"]).is_err());
+  assert!(LanguagePreference::from_codes(["engl"]).is_err());
+}
+
+#[test]
+fn test_content_accessors() {
+  let raw: RawFrame = RawFrame::v3(b"TIT2", &text_latin1("Title"));
+  let text: Content<'_> = decode(&raw);
+
+  assert_eq!(text.as_text().unwrap().text_content().to_string(), "Title");
+  assert!(text.as_apic().is_none());
+  assert!(text.as_url().is_none());
+
+  let raw: RawFrame = RawFrame::v3(b"APIC", b"\x00image/png\x00\x03Cover\x00\x89PNG");
+  let apic: Content<'_> = decode(&raw);
+
+  assert_eq!(apic.as_apic().unwrap().description(), "Cover");
+  assert!(apic.as_text().is_none());
+  assert_eq!(
+    apic.into_apic().unwrap().picture_data().unwrap().as_ref(),
+    b"\x89PNG"
+  );
+
+  for id in [
+    b"WCOM", b"WCOP", b"WOAF", b"WOAR", b"WOAS", b"WORS", b"WPAY", b"WPUB",
+  ] {
+    let raw: RawFrame = RawFrame::v3(id, b"https://example.com");
+    let url: Content<'_> = decode(&raw);
+
+    assert_eq!(url.as_url().unwrap().url(), "https://example.com");
+    assert_eq!(url.into_url().unwrap().url(), "https://example.com");
+  }
+
+  let raw: RawFrame = RawFrame::v3(b"WXXX", b"\x00desc\x00https://example.com");
+  let wxxx: Content<'_> = decode(&raw);
+
+  assert!(wxxx.as_url().is_none());
+}
+
+#[test]
+fn test_content_into_text_one_liner() {
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(frame_v3(b"WCOM", 0x0000, b"https://example.com"));
+  body.extend(frame_v3(b"TALB", 0x0000, &text_utf16("Album")));
+
+  let tag: Tag = Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap();
+
+  let texts: Vec<String> = tag
+    .frames()
+    .filter_map(|frame| frame.ok()?.decode().ok()?.into_text())
+    .map(|text| text.text_content().to_string())
+    .collect();
+
+  assert_eq!(texts, ["Title", "Album"]);
+}
+
+#[test]
+fn test_content_display() {
+  let text_frame: RawFrame = RawFrame::v3(b"TIT2", &text_latin1("Title"));
+  let text: Content<'_> = decode(&text_frame);
+  let comm_frame: RawFrame = RawFrame::v3(b"COMM", b"\x00engdesc\x00Comment");
+  let comm: Content<'_> = decode(&comm_frame);
+  let popm_frame: RawFrame = RawFrame::v3(b"POPM", b"user@example.com\x00\xFF\x00\x00\x00\x2A");
+  let popm: Content<'_> = decode(&popm_frame);
+  let apic_frame: RawFrame = RawFrame::v3(b"APIC", b"\x00image/png\x00\x03Cover\x00\x89PNG");
+  let apic: Content<'_> = decode(&apic_frame);
+  let unkn_frame: RawFrame = RawFrame::v3(
+    b"ZZZZ",
+    &[0xDE, 0xAD, 0xBE, 0xEF, 0x00, 0x01, 0x02, 0x03, 0x04],
+  );
+  let unkn: Content<'_> = decode(&unkn_frame);
+
+  assert_eq!(text.to_string(), "Title");
+  assert_eq!(comm.to_string(), "(desc)[eng]: Comment");
+  assert_eq!(popm.to_string(), "user@example.com: rating 255, counter 42");
+  assert_eq!(apic.to_string(), "(Cover)[CoverFront]: image/png, 4 bytes");
+  assert_eq!(
+    unkn.to_string(),
+    "ZZZZ: 9 bytes: DE AD BE EF 00 01 02 03 ..."
+  );
+}
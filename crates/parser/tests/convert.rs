@@ -0,0 +1,114 @@
+mod common;
+
+use parser::content::Content;
+use parser::error::ErrorKind;
+use parser::id3v2::upgrade_id;
+use parser::id3v2::Tag;
+use parser::id3v2::Upgrade;
+use parser::types::FrameId;
+use parser::types::Version;
+
+use self::common::frame_v3;
+use self::common::tag;
+use self::common::text_latin1;
+use self::common::text_utf16;
+
+fn id(name: &[u8; 4]) -> FrameId {
+  FrameId::try_from(*name).unwrap()
+}
+
+fn upgrade(frames: &[Vec<u8>]) -> (Tag, Vec<String>) {
+  let data: Vec<u8> = tag(0x03, 0x00, &frames.concat());
+  let original: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let upgrade: Upgrade<'_> = original.upgrade().unwrap();
+
+  let dropped: Vec<String> = upgrade
+    .dropped()
+    .iter()
+    .map(|identifier| identifier.to_string())
+    .collect();
+
+  let mut output: Vec<u8> = Vec::new();
+  upgrade.write_to(&mut output).unwrap();
+
+  (Tag::from_reader(output.as_slice()).unwrap(), dropped)
+}
+
+fn texts(tag: &Tag) -> Vec<(String, String)> {
+  tag
+    .frames()
+    .map(|frame| {
+      let frame = frame.unwrap();
+      let content: Content<'_> = frame.decode().unwrap();
+      (frame.identifier_str().to_owned(), content.to_string())
+    })
+    .collect()
+}
+
+#[test]
+fn test_upgrade_v24() {
+  let (tag, dropped) = upgrade(&[
+    frame_v3(b"TIT2", 0x0000, &text_utf16("Title")),
+    frame_v3(b"TYER", 0x0000, &text_latin1("2004")),
+    frame_v3(b"TDAT", 0x0000, &text_latin1("1503")),
+    frame_v3(b"TIME", 0x0000, &text_latin1("1230")),
+    frame_v3(b"TORY", 0x0000, &text_latin1("1999")),
+    frame_v3(b"IPLS", 0x0000, b"\x00producer\x00Alice\x00"),
+    frame_v3(b"RVAD", 0x0000, &[0x03, 0x10, 0x00, 0x20, 0x00, 0x20]),
+    frame_v3(b"TSIZ", 0x0000, &text_latin1("1024")),
+  ]);
+
+  assert_eq!(tag.header().version(), Version::ID3v24);
+  assert_eq!(dropped, ["RVAD", "TSIZ"]);
+  assert_eq!(
+    texts(&tag),
+    [
+      ("TIT2".to_owned(), "Title".to_owned()),
+      ("TDRC".to_owned(), "2004-03-15T12:30".to_owned()),
+      ("TDOR".to_owned(), "1999".to_owned()),
+      ("TIPL".to_owned(), "producer:Alice".to_owned()),
+    ]
+  );
+}
+
+#[test]
+fn test_upgrade_partial_date() {
+  // TIME is only meaningful with a date.
+  let (tag, dropped) = upgrade(&[
+    frame_v3(b"TIME", 0x0000, &text_latin1("1230")),
+    frame_v3(b"TYER", 0x0000, &text_latin1("2004")),
+  ]);
+
+  assert_eq!(dropped, ["TIME"]);
+  assert_eq!(texts(&tag), [("TDRC".to_owned(), "2004".to_owned())]);
+
+  // Dates without a valid year are dropped.
+  let (tag, dropped) = upgrade(&[
+    frame_v3(b"TDAT", 0x0000, &text_latin1("1503")),
+    frame_v3(b"TYER", 0x0000, &text_latin1("04")),
+    frame_v3(b"TIT2", 0x0000, &text_latin1("Title")),
+  ]);
+
+  assert_eq!(dropped, ["TYER", "TDAT"]);
+  assert_eq!(texts(&tag), [("TIT2".to_owned(), "Title".to_owned())]);
+}
+
+#[test]
+fn test_upgrade_id() {
+  assert_eq!(upgrade_id(id(b"TIT2")), Some(id(b"TIT2")));
+  assert_eq!(upgrade_id(id(b"TORY")), Some(id(b"TDOR")));
+  assert_eq!(upgrade_id(id(b"IPLS")), Some(id(b"TIPL")));
+  assert_eq!(upgrade_id(id(b"EQUA")), None);
+  assert_eq!(upgrade_id(id(b"RVAD")), None);
+}
+
+#[test]
+fn test_upgrade_invalid_version() {
+  let data: Vec<u8> = tag(0x04, 0x00, &[]);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  assert!(matches!(
+    tag.upgrade().unwrap_err().kind(),
+    ErrorKind::InvalidVersion
+  ));
+}
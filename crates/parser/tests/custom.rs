@@ -0,0 +1,189 @@
+mod common;
+
+use parser::content::Content;
+use parser::content::ContentKind;
+use parser::content::Custom;
+use parser::error::Error;
+use parser::error::ErrorKind;
+use parser::error::Result;
+use parser::frame::DynFrame;
+use parser::id3v2::Tag;
+use parser::options::DecoderConfig;
+use parser::options::ParseOptions;
+use parser::types::FrameId;
+use parser::types::Slice;
+use parser::types::Version;
+use std::convert::Infallible;
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::thread;
+
+use self::common::frame_v3;
+use self::common::tag;
+use self::common::text_latin1;
+use self::common::RawFrame;
+
+/// The content of the proprietary `XMYA` frame.
+#[derive(Debug, PartialEq)]
+struct MyApp {
+  revision: u8,
+  score: u32,
+}
+
+/// The error returned by the `XMYA` parser.
+#[derive(Debug)]
+struct MyAppError;
+
+impl Display for MyAppError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str("invalid XMYA frame")
+  }
+}
+
+impl StdError for MyAppError {}
+
+fn parse_myapp(slice: &Slice, _version: Version) -> Result<MyApp, MyAppError> {
+  let [revision, a, b, c, d] = *slice.as_ref() else {
+    return Err(MyAppError);
+  };
+
+  Ok(MyApp {
+    revision,
+    score: u32::from_be_bytes([a, b, c, d]),
+  })
+}
+
+fn xmya() -> FrameId {
+  FrameId::try_from(*b"XMYA").unwrap()
+}
+
+#[test]
+fn test_custom_parser() {
+  let config: DecoderConfig = DecoderConfig::new(ParseOptions::new()).register(xmya(), parse_myapp);
+  let raw: RawFrame = RawFrame::v3(b"XMYA", &[0x02, 0x00, 0x00, 0x01, 0x00]);
+  let frame: DynFrame<'_> = raw.frame();
+
+  let Content::Custom(custom) = frame.decode_with_config(&config, &mut Vec::new()).unwrap() else {
+    panic!("expected custom content");
+  };
+
+  assert_eq!(custom.identifier().as_str(), "XMYA");
+  assert!(custom.is::<MyApp>());
+  assert_eq!(
+    custom.downcast_ref::<MyApp>(),
+    Some(&MyApp {
+      revision: 2,
+      score: 256
+    })
+  );
+  assert_eq!(custom.downcast_ref::<String>(), None);
+
+  // Without the parser the frame is unknown.
+  assert_eq!(frame.decode().unwrap().kind(), ContentKind::Unkn);
+
+  // Parser errors are the source of an invalid frame data error.
+  let raw: RawFrame = RawFrame::v3(b"XMYA", &[0x02]);
+  let error: Error = raw
+    .frame()
+    .decode_with_config(&config, &mut Vec::new())
+    .unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::InvalidFrameData));
+  assert!(error.source().unwrap().is::<MyAppError>());
+}
+
+#[test]
+fn test_custom_parser_precedence() {
+  let title: FrameId = FrameId::try_from(*b"TIT2").unwrap();
+  let config: DecoderConfig = DecoderConfig::new(ParseOptions::new())
+    .register(title, |slice: &Slice, _: Version| {
+      Ok::<_, Infallible>(slice.len())
+    });
+
+  let raw: RawFrame = RawFrame::v3(b"TIT2", &text_latin1("Title"));
+  let content: Content<'_> = raw
+    .frame()
+    .decode_with_config(&config, &mut Vec::new())
+    .unwrap();
+
+  let custom: &Custom = match content {
+    Content::Custom(ref inner) => inner,
+    _ => panic!("expected custom content"),
+  };
+
+  assert_eq!(custom.downcast_ref::<usize>(), Some(&6));
+}
+
+#[test]
+fn test_custom_parser_id3v1_version() {
+  let config: DecoderConfig = DecoderConfig::new(ParseOptions::new()).register(xmya(), parse_myapp);
+  let slice: &Slice = Slice::new(&[0x02, 0x00, 0x00, 0x01, 0x00]);
+
+  for version in [Version::ID3v11, Version::ID3v12] {
+    let error: Error =
+      Content::decode_with_config(version, xmya(), slice, &config, &mut Vec::new()).unwrap_err();
+
+    assert!(matches!(error.kind(), ErrorKind::InvalidVersion));
+  }
+}
+
+#[test]
+fn test_custom_parser_tag() {
+  let body: Vec<u8> = [
+    frame_v3(b"TIT2", 0x0000, &text_latin1("Title")),
+    frame_v3(b"XMYA", 0x0000, &[0x01, 0x00, 0x00, 0x00, 0x2A]),
+  ]
+  .concat();
+
+  let tag: Tag = Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap();
+  let config: DecoderConfig = DecoderConfig::new(tag.options()).register(xmya(), parse_myapp);
+
+  let content: Vec<Content<'_>> = tag
+    .frames()
+    .map(|frame| {
+      tag
+        .decode_frame_with_config(&frame.unwrap(), &config, &mut Vec::new())
+        .unwrap()
+    })
+    .collect();
+
+  assert_eq!(content[0].to_string(), "Title");
+  assert_eq!(content[1].to_string(), "XMYA: custom content");
+}
+
+#[test]
+fn test_custom_parser_concurrent() {
+  let raw: RawFrame = RawFrame::v3(b"XMYA", &[0x01, 0x00, 0x00, 0x00, 0x2A]);
+  let frame: DynFrame<'_> = raw.frame();
+
+  thread::scope(|scope| {
+    let first = scope.spawn(|| {
+      let config: DecoderConfig =
+        DecoderConfig::new(ParseOptions::new()).register(xmya(), parse_myapp);
+
+      frame.decode_with_config(&config, &mut Vec::new()).unwrap()
+    });
+
+    let second = scope.spawn(|| {
+      let config: DecoderConfig = DecoderConfig::new(ParseOptions::new())
+        .register(xmya(), |_: &Slice, _: Version| {
+          Ok::<_, Infallible>("second")
+        });
+
+      frame.decode_with_config(&config, &mut Vec::new()).unwrap()
+    });
+
+    let Content::Custom(first) = first.join().unwrap() else {
+      panic!("expected custom content");
+    };
+
+    let Content::Custom(second) = second.join().unwrap() else {
+      panic!("expected custom content");
+    };
+
+    assert_eq!(first.downcast_ref::<MyApp>().unwrap().score, 42);
+    assert_eq!(second.downcast_ref::<&str>(), Some(&"second"));
+  });
+}
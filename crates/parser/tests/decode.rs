@@ -0,0 +1,836 @@
+mod common;
+
+use parser::content::Content;
+use parser::content::EtcoIter;
+use parser::content::EventData;
+use parser::content::EventType;
+use parser::content::PicType;
+use parser::content::Price;
+use parser::content::SytcIter;
+use parser::content::TempoEvent;
+use parser::decode::Checkpoint;
+use parser::decode::Date;
+use parser::decode::DecodeIter;
+use parser::decode::Decoder;
+use parser::decode::Timestamp;
+use parser::error::Error;
+use parser::error::ErrorKind;
+use parser::error::Warning;
+use parser::error::WarningKind;
+use parser::frame::DynFrame;
+use parser::id3v2::Tag;
+use parser::options::ParseOptions;
+use parser::types::Encoding;
+use parser::types::Slice;
+use parser::types::SourceEncoding;
+use parser::types::Version;
+use std::borrow::Cow;
+use std::error::Error as _;
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
+
+use self::common::frame_v3;
+use self::common::tag;
+use self::common::RawFrame;
+
+/// A UTF-16 COMM frame with a stray NUL before the description terminator.
+const COMM_STRAY: &[u8] = &[
+  0x01, b'e', b'n', b'g', 0xFF, 0xFE, b'H', 0x00, b'i', 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFE, b'O',
+  0x00, b'k', 0x00,
+];
+
+/// A UTF-16 APIC frame with picture data that starts with a NUL byte.
+const APIC_NUL_DATA: &[u8] = &[
+  0x01, b'i', b'm', b'a', b'g', b'e', b'/', b'p', b'n', b'g', 0x00, 0x03, 0xFF, 0xFE, b'A', 0x00,
+  0x00, 0x00, 0x00, 0x01, 0x02,
+];
+
+/// A UTF-16 GEOB frame with object data that starts with a NUL byte.
+const GEOB_NUL_DATA: &[u8] = &[
+  0x01, b'a', b'/', b'b', 0x00, 0xFF, 0xFE, b'f', 0x00, 0x00, 0x00, 0xFF, 0xFE, b'd', 0x00, 0x00,
+  0x00, 0x00, 0x01, 0x02,
+];
+
+/// A UTF-16 TIT2 frame that ends with an unpaired NUL.
+const TIT2_STRAY: &[u8] = &[0x01, 0xFF, 0xFE, b'H', 0x00, b'i', 0x00, 0x00];
+
+fn decode_lenient(raw: &RawFrame) -> (Content<'_>, Vec<Warning>) {
+  let options: ParseOptions = ParseOptions::new().lenient(true);
+  let mut warnings: Vec<Warning> = Vec::new();
+  let content: Content<'_> = raw.frame().decode_with(options, &mut warnings).unwrap();
+
+  (content, warnings)
+}
+
+fn stray_offset(id: &[u8; 4], payload: &[u8]) -> usize {
+  let raw: RawFrame = RawFrame::v3(id, payload);
+
+  match raw.frame().decode().unwrap_err().kind() {
+    ErrorKind::TextEncoding(offset) => offset,
+    kind => panic!("unexpected error: {kind:?}"),
+  }
+}
+
+fn warning_kinds(warnings: &[Warning]) -> Vec<WarningKind> {
+  warnings.iter().map(Warning::kind).collect()
+}
+
+#[test]
+fn test_stray_nul_comm() {
+  let raw: RawFrame = RawFrame::v3(b"COMM", COMM_STRAY);
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  let Content::Comm(comm) = content else {
+    panic!("expected COMM, got {content:?}");
+  };
+
+  assert_eq!(comm.text_summary(), "Hi");
+  assert_eq!(comm.text_details(), "Ok");
+  assert_eq!(
+    warning_kinds(&warnings),
+    [WarningKind::StrayNul { offset: 12 }]
+  );
+  assert_eq!(stray_offset(b"COMM", COMM_STRAY), 12);
+}
+
+#[test]
+fn test_stray_nul_binary_tail() {
+  // A NUL byte after the terminator belongs to the binary data that follows.
+  for options in [ParseOptions::new(), ParseOptions::new().lenient(true)] {
+    let mut warnings: Vec<Warning> = Vec::new();
+    let raw: RawFrame = RawFrame::v3(b"APIC", APIC_NUL_DATA);
+    let content: Content<'_> = raw.frame().decode_with(options, &mut warnings).unwrap();
+
+    let Content::Apic(apic) = content else {
+      panic!("expected APIC, got {content:?}");
+    };
+
+    assert_eq!(apic.description(), "A");
+    assert_eq!(apic.picture_data().unwrap().as_ref(), b"\x00\x01\x02");
+    assert!(warnings.is_empty());
+
+    let raw: RawFrame = RawFrame::v3(b"GEOB", GEOB_NUL_DATA);
+    let content: Content<'_> = raw.frame().decode_with(options, &mut warnings).unwrap();
+
+    let Content::Geob(geob) = content else {
+      panic!("expected GEOB, got {content:?}");
+    };
+
+    assert_eq!(geob.filename(), "f");
+    assert_eq!(geob.description(), "d");
+    assert_eq!(geob.object_data().unwrap().as_ref(), b"\x00\x01\x02");
+    assert!(warnings.is_empty());
+  }
+}
+
+#[test]
+fn test_stray_nul_unpaired() {
+  let raw: RawFrame = RawFrame::v3(b"TIT2", TIT2_STRAY);
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  let Content::Text(text) = content else {
+    panic!("expected TIT2, got {content:?}");
+  };
+
+  assert_eq!(text.text_content().to_string(), "Hi");
+  assert_eq!(
+    warning_kinds(&warnings),
+    [WarningKind::StrayNul { offset: 7 }]
+  );
+  assert_eq!(stray_offset(b"TIT2", TIT2_STRAY), 7);
+}
+
+#[test]
+fn test_utf16_be_leading_nul() {
+  // An empty description followed by big-endian text is not a stray NUL.
+  let payload: &[u8] = &[0x02, b'e', b'n', b'g', 0x00, 0x00, 0x00, b'H', 0x00, b'i'];
+
+  let raw: RawFrame = RawFrame::v3(b"COMM", payload);
+  let Content::Comm(comm) = raw.frame().decode().unwrap() else {
+    panic!("expected COMM");
+  };
+
+  assert_eq!(comm.text_summary(), "");
+  assert_eq!(comm.text_details(), "Hi");
+}
+
+#[test]
+fn test_trailing_nul_padding() {
+  let mut warnings: Vec<Warning> = Vec::new();
+  let raw: RawFrame = RawFrame::v3(b"TIT2", b"\x00Title\x00\x00\x00");
+  let content: Content<'_> = raw
+    .frame()
+    .decode_with(ParseOptions::new(), &mut warnings)
+    .unwrap();
+
+  let Content::Text(text) = content else {
+    panic!("expected TIT2, got {content:?}");
+  };
+
+  assert_eq!(format!("{:?}", text.text_content()), "\"Title\"");
+  assert_eq!(
+    warning_kinds(&warnings),
+    [WarningKind::TrailingData { length: 2 }]
+  );
+}
+
+#[test]
+fn test_trailing_data() {
+  let payload: &[u8] = &[0x00; 15];
+  let garbage: &[u8] = b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00xyz";
+
+  let raw: RawFrame = RawFrame::v3(b"RVRB", payload);
+  assert!(matches!(raw.frame().decode(), Ok(Content::Rvrb(_))));
+
+  let raw: RawFrame = RawFrame::v3(b"RVRB", garbage);
+  match raw.frame().decode().unwrap_err().kind() {
+    ErrorKind::TrailingData(length) => assert_eq!(length, 3),
+    kind => panic!("unexpected error: {kind:?}"),
+  }
+
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  assert!(matches!(content, Content::Rvrb(_)));
+  assert_eq!(
+    warning_kinds(&warnings),
+    [WarningKind::TrailingData { length: 3 }]
+  );
+}
+
+/// Corrupt frames that fail strictly but are recovered in lenient mode.
+const CORRUPT: &[(&[u8; 4], &[u8])] = &[
+  (b"TIT2", b"\x07Title"),
+  (b"TIT2", b"\x03Caf\xE9"),
+  (b"TIT2", b"\x01H\x00i\x00"),
+  (b"APIC", b"\x00image/png\x00\x42\x00\x89PNG"),
+  (b"RVRB", b"\x00\x01\x00\x02\x03\x04\x05\x06\x07\x08"),
+];
+
+fn text(content: &Content<'_>) -> String {
+  match content {
+    Content::Text(text) => text.text_content().to_string(),
+    _ => panic!("expected text, got {content:?}"),
+  }
+}
+
+#[test]
+fn test_corrupt_frames_strict() {
+  for (id, payload) in CORRUPT {
+    let raw: RawFrame = RawFrame::v3(id, payload);
+    assert!(raw.frame().decode().is_err());
+  }
+}
+
+#[test]
+fn test_lenient_encoding_byte() {
+  let raw: RawFrame = RawFrame::v3(CORRUPT[0].0, CORRUPT[0].1);
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  assert_eq!(text(&content), "Title");
+  assert_eq!(
+    warning_kinds(&warnings),
+    [WarningKind::TextEncodingByte { byte: 0x07 }]
+  );
+}
+
+#[test]
+fn test_lenient_invalid_text() {
+  let raw: RawFrame = RawFrame::v3(CORRUPT[1].0, CORRUPT[1].1);
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  assert_eq!(text(&content), "Caf\u{FFFD}");
+  assert_eq!(
+    warning_kinds(&warnings),
+    [WarningKind::InvalidText { offset: 1 }]
+  );
+
+  let raw: RawFrame = RawFrame::v3(CORRUPT[2].0, CORRUPT[2].1);
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  assert_eq!(text(&content), "Hi");
+  assert_eq!(
+    warning_kinds(&warnings),
+    [WarningKind::InvalidText { offset: 1 }]
+  );
+}
+
+#[test]
+fn test_lenient_picture_type() {
+  let raw: RawFrame = RawFrame::v3(CORRUPT[3].0, CORRUPT[3].1);
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  let Content::Apic(apic) = content else {
+    panic!("expected APIC, got {content:?}");
+  };
+
+  assert_eq!(apic.picture_type(), PicType::Other);
+  assert_eq!(apic.picture_data().unwrap().as_ref(), b"\x89PNG");
+  assert_eq!(
+    warning_kinds(&warnings),
+    [WarningKind::PictureType { byte: 0x42 }]
+  );
+}
+
+#[test]
+fn test_lenient_short_field() {
+  let raw: RawFrame = RawFrame::v3(CORRUPT[4].0, CORRUPT[4].1);
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  assert!(matches!(content, Content::Rvrb(_)));
+  assert_eq!(
+    warning_kinds(&warnings),
+    [
+      WarningKind::ShortField { offset: 10 },
+      WarningKind::ShortField { offset: 10 },
+    ]
+  );
+}
+
+#[test]
+fn test_owne_price() {
+  let payload: &[u8] = b"\x00USD12.99\x0020240315Shop\x00";
+
+  let raw: RawFrame = RawFrame::v3(b"OWNE", payload);
+  let Content::Owne(owne) = raw.frame().decode().unwrap() else {
+    panic!("expected OWNE");
+  };
+
+  let price: Price<'_> = owne.price().unwrap();
+  let date: Date = owne.date_of_purchase().unwrap();
+
+  assert_eq!(price.currency(), "USD");
+  assert_eq!(price.amount(), "12.99");
+  assert_eq!(price.cents(), Some(1299));
+  assert_eq!((date.year(), date.month(), date.day()), (2024, 3, 15));
+  assert_eq!(owne.seller(), "Shop");
+  assert_eq!(
+    Content::Owne(owne).encode(Version::ID3v23).unwrap(),
+    payload
+  );
+}
+
+#[test]
+fn test_owne_date_range() {
+  for (date, valid) in [
+    (b"20231399", false),
+    (b"20230230", false),
+    (b"20230015", false),
+    (b"20240229", true),
+    (b"21000229", false),
+    (b"20001231", true),
+  ] {
+    let mut payload: Vec<u8> = b"\x00USD1\x00".to_vec();
+    payload.extend(date);
+    payload.extend(b"Shop");
+
+    let raw: RawFrame = RawFrame::v3(b"OWNE", &payload);
+    let Content::Owne(owne) = raw.frame().decode().unwrap() else {
+      panic!("expected OWNE");
+    };
+
+    assert_eq!(owne.purchase_date().as_array(), date);
+    assert_eq!(owne.date_of_purchase().is_some(), valid);
+  }
+}
+
+#[test]
+fn test_owne_malformed() {
+  let payload: &[u8] = b"\x00US$9\x002024-3-1Shop";
+  let raw: RawFrame = RawFrame::v3(b"OWNE", payload);
+  let error: Error = raw.frame().decode().unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::InvalidFrameData));
+
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  let Content::Owne(owne) = content else {
+    panic!("expected OWNE, got {content:?}");
+  };
+
+  assert_eq!(owne.price_paid(), "US$9");
+  assert_eq!(owne.price(), None);
+  assert_eq!(owne.purchase_date().as_str(), "2024-3-1");
+  assert_eq!(owne.date_of_purchase(), None);
+  assert_eq!(owne.seller(), "Shop");
+  assert_eq!(
+    warning_kinds(&warnings),
+    [
+      WarningKind::InvalidField { offset: 1 },
+      WarningKind::InvalidField { offset: 6 },
+    ]
+  );
+}
+
+#[test]
+fn test_empty_utf16_string() {
+  let raw: RawFrame = RawFrame::v3(b"TIT2", b"\x01");
+  assert_eq!(text(&raw.frame().decode().unwrap()), "");
+}
+
+#[test]
+fn test_lenient_tag() {
+  let body: Vec<u8> = CORRUPT
+    .iter()
+    .flat_map(|(id, payload)| frame_v3(id, 0x0000, payload))
+    .collect();
+
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let options: ParseOptions = ParseOptions::new().lenient(true);
+
+  for (tag, expected) in [
+    (Tag::from_reader(data.as_slice()).unwrap(), false),
+    (
+      Tag::from_reader_with(data.as_slice(), options).unwrap(),
+      true,
+    ),
+  ] {
+    for frame in tag.frames() {
+      let frame: DynFrame<'_> = frame.unwrap();
+      let mut warnings: Vec<Warning> = Vec::new();
+
+      assert_eq!(tag.decode_frame(&frame, &mut warnings).is_ok(), expected);
+      assert_eq!(warnings.is_empty(), !expected);
+    }
+  }
+}
+
+fn source_encodings(id: &[u8; 4], payload: &[u8]) -> Vec<(usize, SourceEncoding)> {
+  let options: ParseOptions = ParseOptions::new().lenient(true).record_encodings(true);
+  let mut warnings: Vec<Warning> = Vec::new();
+
+  RawFrame::v3(id, payload)
+    .frame()
+    .decode_with(options, &mut warnings)
+    .unwrap();
+
+  warnings
+    .iter()
+    .filter_map(|warning| match warning.kind() {
+      WarningKind::TextSource { offset, encoding } => Some((offset, encoding)),
+      _ => None,
+    })
+    .collect()
+}
+
+#[test]
+fn test_source_encoding() {
+  for (payload, expected) in [
+    (&b"\x00Hi"[..], SourceEncoding::Latin1),
+    (&b"\x01\xFF\xFEH\x00i\x00"[..], SourceEncoding::Utf16LEBom),
+    (&b"\x01\xFE\xFF\x00H\x00i"[..], SourceEncoding::Utf16BEBom),
+    (&b"\x01H\x00i\x00"[..], SourceEncoding::Utf16LE),
+    (&b"\x02\x00H\x00i"[..], SourceEncoding::Utf16BE),
+    (&b"\x03Hi"[..], SourceEncoding::Utf8),
+  ] {
+    assert_eq!(source_encodings(b"TIT2", payload), [(1, expected)]);
+    assert_eq!(expected.has_bom(), payload.len() == 7 && payload[0] == 0x01);
+    assert_eq!(expected.encoding() as u8, payload[0]);
+  }
+}
+
+#[test]
+fn test_source_encoding_per_string() {
+  // The description and text of a UTF-16 frame have different byte orders.
+  let payload: &[u8] = &[
+    0x01, b'e', b'n', b'g', 0xFE, 0xFF, 0x00, b'A', 0x00, 0x00, 0xFF, 0xFE, b'B', 0x00,
+  ];
+
+  assert_eq!(
+    source_encodings(b"COMM", payload),
+    [
+      (4, SourceEncoding::Utf16BEBom),
+      (10, SourceEncoding::Utf16LEBom)
+    ]
+  );
+
+  // Nothing is recorded unless enabled.
+  let raw: RawFrame = RawFrame::v3(b"COMM", payload);
+  let (_, warnings) = decode_lenient(&raw);
+
+  assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_decoder_peek() {
+  let input: &Slice = Slice::new(b"\x03Hi\x00Ok");
+  let mut decoder: Decoder<'_> = Decoder::new(input);
+
+  assert_eq!(decoder.peek_u8().unwrap(), 0x03);
+  assert_eq!(decoder.peek_array::<3>().unwrap(), *b"\x03Hi");
+  assert_eq!(decoder.peek_slice(6).unwrap().as_ref(), input.as_ref());
+  assert!(decoder.peek_slice(7).is_err());
+
+  // Peeking does not advance the decoder.
+  assert_eq!(decoder.decode::<[u8; 3]>().unwrap(), *b"\x03Hi");
+  assert_eq!(decoder.peek_array::<3>().unwrap(), *b"\x00Ok");
+  assert_eq!(decoder.until_nul().as_ref(), b"");
+  assert_eq!(decoder.peek_slice(2).unwrap().as_ref(), b"Ok");
+  assert_eq!(decoder.remaining().as_ref(), b"Ok");
+  assert!(decoder.peek_u8().is_err());
+  assert_eq!(decoder.peek_slice(0).unwrap().as_ref(), b"");
+}
+
+#[test]
+fn test_decoder_checkpoint() {
+  let input: &Slice = Slice::new(b"\x01\xFF\xFEH\x00i\x00");
+  let mut decoder: Decoder<'_> = Decoder::new(input);
+  let checkpoint: Checkpoint = decoder.checkpoint();
+
+  assert_eq!(decoder.decode::<Encoding>().unwrap(), Encoding::Utf16);
+  assert_eq!(decoder.decode::<Cow<'_, str>>().unwrap(), "Hi");
+  assert!(decoder.is_empty());
+
+  // The text format is reset with the position.
+  decoder.restore(checkpoint);
+
+  assert_eq!(decoder.peek_u8().unwrap(), 0x01);
+  assert_eq!(decoder.decode::<u8>().unwrap(), 0x01);
+  assert_eq!(decoder.decode::<Cow<'_, str>>().unwrap(), "\u{FF}\u{FE}H");
+}
+
+#[test]
+fn test_decoder_take() {
+  let input: &Slice = Slice::new(b"\x01\x02\x03\x04\x05\x06\x07\x08");
+  let mut parent: Decoder<'_> = Decoder::new(input);
+
+  assert_eq!(parent.decode::<u8>().unwrap(), 0x01);
+
+  let mut child: Decoder<'_> = parent.take(5).unwrap();
+
+  assert_eq!(parent.peek_u8().unwrap(), 0x07);
+
+  let mut inner: Decoder<'_> = child.take(2).unwrap();
+
+  assert_eq!(child.peek_slice(3).unwrap().as_ref(), b"\x04\x05\x06");
+  assert_eq!(inner.remaining().as_ref(), b"\x02\x03");
+  assert!(inner.is_empty());
+
+  // Fields that consume the rest stop at the end of the child.
+  assert_eq!(child.decode::<u8>().unwrap(), 0x04);
+  assert_eq!(child.remaining().as_ref(), b"\x05\x06");
+  assert!(child.take(1).is_err());
+  assert!(child.take(0).unwrap().is_empty());
+
+  assert!(parent.take(3).is_err());
+  assert_eq!(parent.remaining().as_ref(), b"\x07\x08");
+}
+
+#[test]
+fn test_decoder_take_format() {
+  let input: &Slice = Slice::new(b"\x03Hi\x00Ok");
+  let mut decoder: Decoder<'_> = Decoder::new(input);
+
+  assert_eq!(decoder.decode::<Encoding>().unwrap(), Encoding::Utf8);
+
+  let mut child: Decoder<'_> = decoder.take(2).unwrap();
+
+  assert_eq!(child.decode::<Cow<'_, str>>().unwrap(), "Hi");
+  assert_eq!(decoder.decode::<Cow<'_, str>>().unwrap(), "");
+  assert_eq!(decoder.decode::<Cow<'_, str>>().unwrap(), "Ok");
+}
+
+#[test]
+fn test_decoder_position() {
+  let input: &Slice = Slice::new(b"\x01A\x00\x00B\x00\x00\x00C");
+  let mut decoder: Decoder<'_> = Decoder::new(input);
+
+  assert_eq!(decoder.position(), 0);
+  assert_eq!(decoder.remaining_len(), 9);
+  assert_eq!(decoder.total_len(), 9);
+
+  assert_eq!(decoder.decode::<u8>().unwrap(), 0x01);
+  assert_eq!(decoder.position(), 1);
+
+  // The terminator is skipped.
+  assert_eq!(decoder.until_nul().as_ref(), b"A");
+  assert_eq!(decoder.position(), 3);
+  assert_eq!(decoder.remaining_len(), 6);
+
+  assert_eq!(decoder.until_nul2().as_ref(), b"\x00B");
+  assert_eq!(decoder.position(), 7);
+  assert_eq!(decoder.remaining_len(), 2);
+
+  // A missing terminator is not skipped past the end.
+  assert_eq!(decoder.until_nul2().as_ref(), b"\x00C");
+  assert_eq!(decoder.position(), 9);
+  assert_eq!(decoder.remaining_len(), 0);
+  assert_eq!(decoder.total_len(), 9);
+}
+
+#[test]
+fn test_error_offset() {
+  // The second field is cut short.
+  let raw: RawFrame = RawFrame::v3(b"RVRB", &[0x00; 3]);
+  let error: Error = raw.frame().decode().unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::IO));
+  assert_eq!(error.offset(), Some(2));
+  assert_eq!(
+    error
+      .source()
+      .and_then(|source| source.downcast_ref::<IoError>())
+      .map(IoError::kind),
+    Some(IoErrorKind::UnexpectedEof)
+  );
+
+  let raw: RawFrame = RawFrame::v3(
+    b"RVRB",
+    b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00xyz",
+  );
+  let error: Error = raw.frame().decode().unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::TrailingData(3)));
+  assert_eq!(error.offset(), Some(12));
+
+  let raw: RawFrame = RawFrame::v3(b"TIT2", b"\x05Title");
+  let error: Error = raw.frame().decode().unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::InvalidFrameData));
+  assert_eq!(error.offset(), Some(1));
+}
+
+#[test]
+fn test_utf16_misaligned_nul_pair() {
+  // "aĀ" contains a NUL pair that straddles two code units.
+  let payload: &[u8] = &[
+    0x01, b'e', b'n', b'g', 0xFF, 0xFE, b'a', 0x00, 0x00, 0x01, 0x00, 0x00, 0xFF, 0xFE, b'O', 0x00,
+    b'k', 0x00,
+  ];
+
+  let raw: RawFrame = RawFrame::v3(b"COMM", payload);
+  let Content::Comm(comm) = raw.frame().decode().unwrap() else {
+    panic!("expected COMM");
+  };
+
+  assert_eq!(comm.text_summary(), "a\u{100}");
+  assert_eq!(comm.text_details(), "Ok");
+
+  let payload: &[u8] = &[
+    0x02, b'e', b'n', b'g', 0x01, 0x00, 0x00, b'a', 0x00, 0x00, 0x00, b'O', 0x00, b'k',
+  ];
+
+  let raw: RawFrame = RawFrame::v3(b"COMM", payload);
+  let Content::Comm(comm) = raw.frame().decode().unwrap() else {
+    panic!("expected COMM");
+  };
+
+  assert_eq!(comm.text_summary(), "\u{100}a");
+  assert_eq!(comm.text_details(), "Ok");
+}
+
+#[test]
+fn test_utf16_dangling_byte() {
+  // A 7-byte UTF-16LE payload: BOM, "Hi" and one dangling byte.
+  let payload: &[u8] = &[0x01, 0xFF, 0xFE, b'H', 0x00, b'i', 0x00, b'!'];
+  let raw: RawFrame = RawFrame::v3(b"TIT2", payload);
+  let error: Error = raw.frame().decode().unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::InvalidFrameData));
+  assert_eq!(error.offset(), Some(7));
+
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  assert_eq!(text(&content), "Hi");
+  assert_eq!(
+    warning_kinds(&warnings),
+    [WarningKind::DanglingByte { offset: 7 }]
+  );
+
+  // The terminated description keeps the following field aligned.
+  let payload: &[u8] = &[
+    0x01, b'e', b'n', b'g', 0xFF, 0xFE, b'H', 0x00, 0x00, 0x00, 0xFF, 0xFE, b'O', 0x00, b'k', 0x00,
+    b'!',
+  ];
+
+  let raw: RawFrame = RawFrame::v3(b"COMM", payload);
+  assert!(raw.frame().decode().is_err());
+
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  let Content::Comm(comm) = content else {
+    panic!("expected COMM, got {content:?}");
+  };
+
+  assert_eq!(comm.text_summary(), "H");
+  assert_eq!(comm.text_details(), "Ok");
+  assert_eq!(
+    warning_kinds(&warnings),
+    [WarningKind::DanglingByte { offset: 16 }]
+  );
+}
+
+#[test]
+fn test_latin1_control_bytes() {
+  let payload: &[u8] = b"\x00Ti\x01tle";
+
+  let raw: RawFrame = RawFrame::v3(b"TIT2", payload);
+  assert!(matches!(
+    raw.frame().decode().unwrap_err().kind(),
+    ErrorKind::InvalidFrameData
+  ));
+
+  let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  assert_eq!(text(&content), "Ti\u{1}tle");
+  assert_eq!(
+    warning_kinds(&warnings),
+    [WarningKind::InvalidText { offset: 1 }]
+  );
+
+  // Newlines and characters above 0x7F are permitted.
+  let raw: RawFrame = RawFrame::v3(b"TIT2", b"\x00Caf\xE9\nBar");
+  let content: Content<'_> = raw.frame().decode().unwrap();
+
+  assert_eq!(text(&content), "Caf\u{E9}\nBar");
+}
+
+#[test]
+fn test_utf16_missing_bom() {
+  let little: &[u8] = b"\x01H\x00e\x00l\x00l\x00o\x00";
+  let big: &[u8] = b"\x01\x00H\x00e\x00l\x00l\x00o";
+
+  for payload in [little, big, b"\x01H"] {
+    let raw: RawFrame = RawFrame::v3(b"TIT2", payload);
+    assert!(matches!(
+      raw.frame().decode().unwrap_err().kind(),
+      ErrorKind::InvalidFrameData
+    ));
+  }
+
+  for payload in [little, big] {
+    let raw: RawFrame = RawFrame::v3(b"TIT2", payload);
+    let (content, warnings): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+    assert_eq!(text(&content), "Hello");
+    assert_eq!(
+      warning_kinds(&warnings),
+      [WarningKind::InvalidText { offset: 1 }]
+    );
+  }
+
+  // Little-endian has more NUL high bytes, but only big-endian is valid.
+  let raw: RawFrame = RawFrame::v3(b"TIT2", b"\x01A\x00\x30\xD8");
+  let (content, _): (Content<'_>, Vec<Warning>) = decode_lenient(&raw);
+
+  assert_eq!(text(&content), "\u{4100}\u{30D8}");
+}
+
+#[test]
+fn test_decoder_decode_all() {
+  let input: &Slice = Slice::new(b"\x00\x01\x00\x02\x00\x03");
+  let values: Vec<u16> = Decoder::new(input).decode_all().unwrap();
+
+  assert_eq!(values, [1, 2, 3]);
+
+  // A trailing partial record.
+  let input: &Slice = Slice::new(b"\x00\x01\x00\x02\x00");
+  let error: Error = Decoder::new(input).decode_all::<u16>().unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::IO));
+  assert_eq!(error.index(), Some(2));
+
+  let mut decoder: Decoder<'_> = Decoder::new(input);
+  let mut iter: DecodeIter<'_, u16> = decoder.decode_iter();
+
+  assert!(decoder.is_empty());
+  assert_eq!(iter.next().unwrap().unwrap(), 1);
+  assert_eq!(iter.next().unwrap().unwrap(), 2);
+  assert_eq!(iter.next().unwrap().unwrap_err().index(), Some(2));
+  assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_etco_extended_event() {
+  let payload: &[u8] = &[
+    0x02, 0xFF, 0x2A, 0x00, 0x00, 0x01, 0x00, 0xFF, 0xFF, 0x07, 0x00, 0x00, 0x02, 0x00, 0x03, 0x00,
+    0x00, 0x03, 0x00,
+  ];
+
+  let raw: RawFrame = RawFrame::v3(b"ETCO", payload);
+  let Content::Etco(etco) = raw.frame().decode().unwrap() else {
+    panic!("expected ETCO");
+  };
+
+  let events: Vec<EventData> = etco.events().map(Result::unwrap).collect();
+
+  assert_eq!(events.len(), 3);
+
+  assert_eq!(events[0].kind(), EventType::OneMoreByte);
+  assert_eq!(events[0].extended_code(), Some(0x2A));
+  assert_eq!(events[0].extended_depth(), 1);
+  assert_eq!(events[0].time(), 256);
+
+  assert_eq!(events[1].kind(), EventType::OneMoreByte);
+  assert_eq!(events[1].extended_code(), Some(0x07));
+  assert_eq!(events[1].extended_depth(), 2);
+  assert_eq!(events[1].time(), 512);
+
+  assert_eq!(events[2].kind(), EventType::MainStart);
+  assert_eq!(events[2].extended_code(), None);
+  assert_eq!(events[2].extended_depth(), 0);
+  assert_eq!(events[2].time(), 768);
+}
+
+#[test]
+fn test_etco_partial_event() {
+  let payload: &[u8] = &[0x02, 0x03, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00];
+
+  let raw: RawFrame = RawFrame::v3(b"ETCO", payload);
+  let Content::Etco(etco) = raw.frame().decode().unwrap() else {
+    panic!("expected ETCO");
+  };
+
+  let mut events: EtcoIter<'_> = etco.events();
+
+  assert_eq!(events.next().unwrap().unwrap().time(), 256);
+  assert_eq!(events.next().unwrap().unwrap_err().index(), Some(1));
+  assert!(events.next().is_none());
+}
+
+#[test]
+fn test_sytc_tempos() {
+  let payload: &[u8] = &[
+    0x02, 0x78, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x2D, 0x00, 0x00, 0x03, 0xE8, 0x00, 0x00, 0x00, 0x07,
+    0xD0, 0x01, 0x00, 0x00, 0x0B, 0xB8, 0xFF, 0xFF, 0x00, 0x00, 0x0F, 0xA0,
+  ];
+
+  let raw: RawFrame = RawFrame::v3(b"SYTC", payload);
+  let Content::Sytc(sytc) = raw.frame().decode().unwrap() else {
+    panic!("expected SYTC");
+  };
+
+  assert_eq!(sytc.time_format(), Timestamp::Milliseconds);
+
+  let tempos: Vec<TempoEvent> = sytc.tempos().map(Result::unwrap).collect();
+  let values: Vec<(u16, u32)> = tempos
+    .iter()
+    .map(|tempo| (tempo.bpm(), tempo.time()))
+    .collect();
+
+  assert_eq!(
+    values,
+    [(120, 0), (300, 1000), (0, 2000), (1, 3000), (510, 4000)]
+  );
+
+  assert!(!tempos[1].is_beat_free());
+  assert!(tempos[2].is_beat_free());
+  assert!(!tempos[2].is_single_beat());
+  assert!(tempos[3].is_single_beat());
+}
+
+#[test]
+fn test_sytc_partial_tempo() {
+  let payload: &[u8] = &[0x01, 0x78, 0x00, 0x00, 0x00, 0x00, 0xFF];
+
+  let raw: RawFrame = RawFrame::v3(b"SYTC", payload);
+  let Content::Sytc(sytc) = raw.frame().decode().unwrap() else {
+    panic!("expected SYTC");
+  };
+
+  let mut tempos: SytcIter<'_> = sytc.tempos();
+
+  assert_eq!(sytc.time_format(), Timestamp::MpegFrames);
+  assert_eq!(tempos.next().unwrap().unwrap().bpm(), 120);
+  assert_eq!(tempos.next().unwrap().unwrap_err().index(), Some(1));
+  assert!(tempos.next().is_none());
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use parser::content::Apic;
+use parser::content::Content;
+use parser::frame::DynFrame;
+use parser::id3v2::Tag;
+use parser::options::ParseOptions;
+use parser::types::DeferredSlice;
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use self::common::apic;
+use self::common::frame_v3;
+use self::common::tag;
+
+/// Records the size of the largest allocation made.
+struct Tracking;
+
+static LARGEST: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Tracking {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    LARGEST.fetch_max(layout.size(), Ordering::Relaxed);
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout)
+  }
+}
+
+#[global_allocator]
+static ALLOCATOR: Tracking = Tracking;
+
+const PICTURE_SIZE: usize = 5 * 1024 * 1024;
+const SMALL_SIZE: usize = 512;
+
+fn decode_all(tag: &Tag) -> Vec<Content<'_>> {
+  tag
+    .frames()
+    .map(|frame| tag.decode_frame(&frame.unwrap(), &mut Vec::new()).unwrap())
+    .collect()
+}
+
+fn pictures<'a>(content: &'a [Content<'_>]) -> Vec<&'a Apic<'a>> {
+  content
+    .iter()
+    .map(|content| content.as_apic().unwrap())
+    .collect()
+}
+
+// Note: A single test keeps allocations from other tests out of the tracking.
+#[test]
+fn test_defer_binary() {
+  let picture: Vec<u8> = (0..PICTURE_SIZE).map(|index| index as u8).collect();
+  let small: Vec<u8> = vec![0xAB; SMALL_SIZE];
+  let body: Vec<u8> = [
+    frame_v3(b"APIC", 0x0000, &apic("image/png", "Large", &picture)),
+    frame_v3(b"APIC", 0x0000, &apic("image/png", "Small", &small)),
+  ]
+  .concat();
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+
+  let options: ParseOptions = ParseOptions::new().defer_binary(1024);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), options).unwrap();
+
+  // Light decoding performs no large allocations.
+  LARGEST.store(0, Ordering::Relaxed);
+
+  let content: Vec<Content<'_>> = decode_all(&tag);
+  let [large, small]: [&Apic<'_>; 2] = pictures(&content).try_into().unwrap();
+
+  assert_eq!(large.picture_data(), None);
+  assert_eq!(large.sniff_format(), None);
+  assert_eq!(large.description(), "Large");
+  assert_eq!(small.picture_data().unwrap().as_ref(), [0xAB; SMALL_SIZE]);
+  assert_eq!(small.picture_data_deferred(), None);
+
+  let deferred: DeferredSlice = large.picture_data_deferred().unwrap();
+
+  assert_eq!(deferred.len(), PICTURE_SIZE);
+  assert_eq!(deferred.load(&tag).unwrap().as_ref(), picture);
+  assert_eq!(large.load_picture_data(&tag).unwrap().as_ref(), picture);
+  assert!(LARGEST.load(Ordering::Relaxed) < PICTURE_SIZE);
+
+  // Decoding without a tag buffer always loads the data.
+  let frame: DynFrame<'_> = tag.frames().next().unwrap().unwrap();
+  let content: Content<'_> = frame.decode_with(options, &mut Vec::new()).unwrap();
+
+  assert_eq!(
+    content.as_apic().unwrap().picture_data().unwrap().len(),
+    PICTURE_SIZE
+  );
+
+  // Owning the loaded data is a large allocation.
+  let owned: Vec<u8> = large.load_picture_data(&tag).unwrap().as_ref().to_vec();
+
+  assert_eq!(owned.len(), PICTURE_SIZE);
+  assert!(LARGEST.load(Ordering::Relaxed) >= PICTURE_SIZE);
+
+  // Data is loaded by default.
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let content: Vec<Content<'_>> = decode_all(&tag);
+
+  assert_eq!(
+    pictures(&content)[0].picture_data().unwrap().as_ref(),
+    picture
+  );
+  assert_eq!(pictures(&content)[0].picture_data_deferred(), None);
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use parser::error::ErrorKind;
+use parser::id3v2::DigestScope;
+use parser::id3v2::Tag;
+use parser::options::ParseOptions;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+use self::common::frame_v3;
+use self::common::tag;
+use self::common::text_latin1;
+
+const OPTIONS: ParseOptions = ParseOptions::new().retain_raw(true);
+
+fn frames() -> Vec<u8> {
+  let mut output: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  output.extend(frame_v3(b"TPE1", 0x0000, &text_latin1("Artist")));
+  output
+}
+
+fn padded(padding: usize) -> Vec<u8> {
+  let mut body: Vec<u8> = frames();
+  body.resize(body.len() + padding, 0x00);
+  tag(0x03, 0x00, &body)
+}
+
+fn digest(data: &[u8], scope: DigestScope) -> u64 {
+  let tag: Tag = Tag::from_reader_with(data, OPTIONS).unwrap();
+  let mut hasher: DefaultHasher = DefaultHasher::new();
+
+  tag.raw_digest(&mut hasher, scope).unwrap();
+  hasher.finish()
+}
+
+fn expected(data: &[u8]) -> u64 {
+  let mut hasher: DefaultHasher = DefaultHasher::new();
+
+  hasher.write(data);
+  hasher.finish()
+}
+
+#[test]
+fn test_raw_unavailable() {
+  let data: Vec<u8> = padded(16);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  assert!(tag.raw().is_none());
+
+  let error = tag
+    .raw_digest(&mut DefaultHasher::new(), DigestScope::Tag)
+    .unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::RawUnavailable));
+}
+
+#[test]
+fn test_raw_digest_padding() {
+  let data: Vec<u8> = padded(16);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), OPTIONS).unwrap();
+
+  assert_eq!(tag.raw().unwrap().as_ref(), data);
+  assert_eq!(digest(&data, DigestScope::Tag), expected(&data));
+  assert_eq!(
+    digest(&data, DigestScope::Frames),
+    expected(&data[10..10 + frames().len()])
+  );
+
+  // A flipped padding byte is only detected when hashing the entire tag.
+  let mut flipped: Vec<u8> = data.clone();
+  *flipped.last_mut().unwrap() = 0x01;
+
+  assert_ne!(
+    digest(&flipped, DigestScope::Tag),
+    digest(&data, DigestScope::Tag)
+  );
+  assert_eq!(
+    digest(&flipped, DigestScope::Frames),
+    digest(&data, DigestScope::Frames)
+  );
+
+  // As is a different amount of padding.
+  let resized: Vec<u8> = padded(64);
+
+  assert_ne!(
+    digest(&resized, DigestScope::Tag),
+    digest(&data, DigestScope::Tag)
+  );
+  assert_eq!(
+    digest(&resized, DigestScope::Frames),
+    digest(&data, DigestScope::Frames)
+  );
+}
+
+#[test]
+fn test_raw_digest_unsynchronisation() {
+  // PRIV frame with the data [0xFF, 0xE0] and 4 bytes of padding.
+  let body: &[u8] = &[
+    b'P', b'R', b'I', b'V', 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, b'a', 0x00, 0xFF, 0x00, 0xE0, 0x00,
+    0x00, 0x00, 0x00,
+  ];
+
+  let data: Vec<u8> = tag(0x03, 0x80, body);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), OPTIONS).unwrap();
+
+  assert_eq!(tag.buffer().len(), body.len() - 1);
+  assert_eq!(tag.raw().unwrap().as_ref(), data);
+  assert_eq!(digest(&data, DigestScope::Frames), expected(&data[10..25]));
+}
+
+#[test]
+fn test_raw_digest_footer() {
+  let body: Vec<u8> = frames();
+  let mut data: Vec<u8> = tag(0x04, 0x10, &body);
+  let mut footer: Vec<u8> = data[..10].to_vec();
+
+  footer[..3].copy_from_slice(b"3DI");
+  data.extend_from_slice(&footer);
+
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), OPTIONS).unwrap();
+
+  assert_eq!(tag.raw().unwrap().as_ref(), data);
+  assert_eq!(digest(&data, DigestScope::Tag), expected(&data));
+  assert_eq!(digest(&data, DigestScope::Frames), expected(&body));
+}
@@ -0,0 +1,252 @@
+mod common;
+
+use parser::content::Content;
+use parser::decode::Decoder;
+use parser::error::ErrorKind;
+use parser::options::DecoderConfig;
+use parser::options::ParseOptions;
+use parser::types::DynFrameId;
+use parser::types::Encoding;
+use parser::types::Slice;
+use parser::types::Version;
+use std::borrow::Cow;
+use std::convert::Infallible;
+
+use self::common::text_latin1;
+use self::common::text_utf16;
+use self::common::text_utf8;
+
+fn decode<'a>(version: Version, id: &str, data: &'a [u8]) -> Content<'a> {
+  Content::decode(version, DynFrameId::try_from(id).unwrap(), Slice::new(data)).unwrap()
+}
+
+fn round_trip(version: Version, id: &str, data: &[u8]) -> Vec<u8> {
+  let content: Content<'_> = decode(version, id, data);
+  let encoded: Vec<u8> = content.encode(version).unwrap();
+
+  assert_eq!(
+    decode(version, id, &encoded),
+    content,
+    "{id}: {encoded:02X?}"
+  );
+
+  encoded
+}
+
+#[test]
+fn test_encode_text() {
+  for version in [Version::ID3v23, Version::ID3v24] {
+    round_trip(version, "TIT2", &text_latin1("Title"));
+    round_trip(version, "TIT2", &text_latin1(""));
+    round_trip(version, "TPE1", &text_utf16("Björk"));
+    round_trip(version, "TPE1", &text_utf8("日本"));
+    round_trip(
+      version,
+      "TPE1",
+      &[&text_utf8("One")[..], b"\0Two\0Three"].concat(),
+    );
+    round_trip(version, "TALB", &[0x02, 0x00, b'A', 0x00, 0x00, 0x00, b'B']);
+  }
+
+  round_trip(Version::ID3v22, "TT2", &text_latin1("Title"));
+
+  // Strings are always terminated.
+  assert_eq!(
+    round_trip(Version::ID3v23, "TIT2", &text_latin1("Title")),
+    b"\0Title\0"
+  );
+}
+
+#[test]
+fn test_encode_comm() {
+  let data: Vec<u8> = [
+    &[0x01][..],
+    b"eng",
+    &text_utf16("Summary")[1..],
+    &[0x00, 0x00],
+    &text_utf16("Details")[1..],
+  ]
+  .concat();
+
+  round_trip(Version::ID3v23, "COMM", &data);
+  round_trip(Version::ID3v22, "COM", &data);
+  round_trip(Version::ID3v24, "COMM", b"\x03deu\0Kommentar \xC3\xBC\0");
+  round_trip(Version::ID3v24, "COMM", b"\x00eng\0\0");
+}
+
+#[test]
+fn test_encode_apic() {
+  let v3: &[u8] = b"\x00image/jpeg\0\x03Cover\0\xFF\xD8\xFF\xE0\x00\x10JFIF\x00";
+  let v2: &[u8] = b"\x00PNG\x04Back\0\x89PNG\r\n\x1A\n";
+
+  assert_eq!(round_trip(Version::ID3v23, "APIC", v3), v3);
+  assert_eq!(round_trip(Version::ID3v22, "PIC", v2), v2);
+
+  round_trip(Version::ID3v24, "APIC", b"\x03image/png\0\x00\0\x89PNG");
+}
+
+#[test]
+fn test_encode_comr() {
+  let data: Vec<u8> = [
+    &[0x01][..],
+    b"USD9.99\0",
+    b"20261231",
+    b"https://example.com\0",
+    &[0x02],
+    &text_utf16("Seller")[1..],
+    &[0x00, 0x00],
+    &text_utf16("Description")[1..],
+    &[0x00, 0x00],
+    b"image/png\0",
+    b"\x89PNG",
+  ]
+  .concat();
+
+  // Latin-1 fields are written as such regardless of the text encoding.
+  assert_eq!(round_trip(Version::ID3v23, "COMR", &data), data);
+
+  let data: &[u8] = b"\x03EUR1\x0020250101\0\x08Verk\xC3\xA4ufer\0\0\0";
+
+  assert_eq!(round_trip(Version::ID3v24, "COMR", data), data);
+
+  // The seller logo is optional.
+  let data: &[u8] = b"\x00USD1\x0020021231\x00\x03Seller\x00\x00";
+
+  assert_eq!(round_trip(Version::ID3v23, "COMR", data), data);
+}
+
+#[test]
+fn test_encode_sylt() {
+  let data: &[u8] = b"\x00eng\x02\x01Lyrics\0Hello\0\x00\x00\x03\xE8World\0\x00\x00\x07\xD0";
+
+  assert_eq!(round_trip(Version::ID3v23, "SYLT", data), data);
+  assert_eq!(round_trip(Version::ID3v22, "SLT", data), data);
+}
+
+#[test]
+fn test_encode_frames() {
+  for (version, id, data) in [
+    (Version::ID3v23, "PCNT", &b"\x00\x00\x00\x2A"[..]),
+    (Version::ID3v23, "PCNT", b"\x00\x00\x00\x01\x00\x00\x00\x00"),
+    (
+      Version::ID3v23,
+      "POPM",
+      b"user@example.com\0\xFF\x00\x00\x01\x00",
+    ),
+    (
+      Version::ID3v23,
+      "RBUF",
+      b"\x00\x00\x10\x00\x01\x00\x00\x00\x20",
+    ),
+    (
+      Version::ID3v23,
+      "RVRB",
+      b"\x00\x01\x00\x02\x03\x04\x05\x06\x07\x08\x09\x0A",
+    ),
+    (Version::ID3v23, "ETCO", b"\x02\x01\x00\x00\x00\x10"),
+    (
+      Version::ID3v23,
+      "UFID",
+      b"https://musicbrainz.org\0\x01\x02\x03",
+    ),
+    (Version::ID3v23, "PRIV", b"owner\0\xDE\xAD"),
+    (
+      Version::ID3v23,
+      "WXXX",
+      b"\x01\xFF\xFEL\x00\0\0https://example.com\0",
+    ),
+    (
+      Version::ID3v24,
+      "CHAP",
+      b"ch1\0\x00\x00\x00\x00\x00\x00\x03\xE8\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+    ),
+    (Version::ID3v24, "CTOC", b"toc\0\x03\x01ch1\0"),
+    (Version::ID3v24, "XABC", b"\x00\x01\x02"),
+  ] {
+    assert_eq!(round_trip(version, id, data), data, "{id}");
+  }
+}
+
+#[test]
+fn test_encode_unsupported() {
+  let config: DecoderConfig = DecoderConfig::new(ParseOptions::new()).register(
+    DynFrameId::try_from("XMYA").unwrap(),
+    |slice: &Slice, _: Version| Ok::<_, Infallible>(slice.len()),
+  );
+
+  let content: Content<'_> = Content::decode_with_config(
+    Version::ID3v23,
+    DynFrameId::try_from("XMYA").unwrap(),
+    Slice::new(b"data"),
+    &config,
+    &mut Vec::new(),
+  )
+  .unwrap();
+
+  let error = content.encode(Version::ID3v23).unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::Unencodable));
+
+  let data: Vec<u8> = text_latin1("Title");
+  let content: Content<'_> = decode(Version::ID3v23, "TIT2", &data);
+  let error = content.encode(Version::ID3v11).unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::InvalidVersion));
+}
+
+#[test]
+fn test_encoding_encode() {
+  const ENCODINGS: [Encoding; 4] = [
+    Encoding::Latin1,
+    Encoding::Utf16,
+    Encoding::Utf16BE,
+    Encoding::Utf8,
+  ];
+
+  for encoding in ENCODINGS {
+    for text in [
+      "",
+      "Title",
+      "Caf\u{E9}",
+      "\u{65E5}\u{672C}",
+      "Clef \u{1D11E}\u{1F3B5}",
+    ] {
+      if !encoding.can_encode(text) {
+        assert!(matches!(
+          encoding.encode(text, true).unwrap_err().kind(),
+          ErrorKind::TextEncoding(0)
+        ));
+        continue;
+      }
+
+      let bytes: Vec<u8> = encoding.encode(text, true).unwrap();
+      let unterminated: Vec<u8> = encoding.encode(text, false).unwrap();
+
+      assert_eq!(bytes.len(), unterminated.len() + encoding.terminator_len());
+
+      let input: &Slice = Slice::new(&bytes);
+      let mut decoder: Decoder<'_> = Decoder::with_format(input, encoding);
+
+      assert_eq!(decoder.decode::<Cow<'_, str>>().unwrap(), text);
+      assert!(decoder.is_empty());
+    }
+  }
+
+  assert!(Encoding::Latin1.can_encode("Caf\u{E9}"));
+  assert!(!Encoding::Latin1.can_encode("\u{65E5}\u{672C}"));
+  assert!(!Encoding::Utf8.can_encode("A\0B"));
+
+  assert_eq!(
+    Encoding::Latin1.encode("Caf\u{E9}", false).unwrap(),
+    b"Caf\xE9"
+  );
+  assert_eq!(
+    Encoding::Utf16.encode("A", true).unwrap(),
+    b"\xFF\xFEA\x00\x00\x00"
+  );
+  assert_eq!(
+    Encoding::Utf16BE.encode("\u{1D11E}", false).unwrap(),
+    b"\xD8\x34\xDD\x1E"
+  );
+  assert_eq!(Encoding::Utf8.encode("A", true).unwrap(), b"A\x00");
+}
@@ -0,0 +1,171 @@
+mod common;
+
+use core::convert::Infallible;
+use parser::content::Content;
+use parser::error::Error;
+use parser::error::ErrorKind;
+use parser::frame::DynFrame;
+use parser::id3v2::AudioEncryption;
+use parser::id3v2::Tag;
+use parser::options::DecoderConfig;
+use parser::options::ParseOptions;
+use parser::types::Slice;
+use std::error::Error as _;
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
+
+use self::common::frame_v3;
+use self::common::frame_v4;
+use self::common::tag;
+use self::common::text_latin1;
+
+fn aenc(owner: &str, start: u16, length: u16) -> Vec<u8> {
+  let mut payload: Vec<u8> = owner.as_bytes().to_vec();
+  payload.push(0x00);
+  payload.extend_from_slice(&start.to_be_bytes());
+  payload.extend_from_slice(&length.to_be_bytes());
+  payload.extend_from_slice(&[0xAA, 0xBB]);
+  frame_v3(b"AENC", 0x0000, &payload)
+}
+
+fn encr(owner: &str, symbol: u8, data: &[u8]) -> Vec<u8> {
+  let mut payload: Vec<u8> = owner.as_bytes().to_vec();
+  payload.push(0x00);
+  payload.push(symbol);
+  payload.extend_from_slice(data);
+  frame_v3(b"ENCR", 0x0000, &payload)
+}
+
+fn parse(frames: &[Vec<u8>]) -> Tag {
+  Tag::from_reader(tag(0x03, 0x00, &frames.concat()).as_slice()).unwrap()
+}
+
+#[test]
+fn test_audio_encryption_matched() {
+  let tag: Tag = parse(&[
+    encr("mailto:other@example.com", 0x80, &[0x00]),
+    aenc("mailto:drm@example.com", 10, 20),
+    encr("mailto:drm@example.com", 0x81, &[0x01, 0x02]),
+  ]);
+
+  let output: Vec<AudioEncryption<'_>> = tag.audio_encryption(None).unwrap();
+
+  assert_eq!(output.len(), 1);
+  assert_eq!(output[0].owner(), "mailto:drm@example.com");
+  assert_eq!(output[0].method().unwrap().method_symbol(), 0x81);
+  assert_eq!(output[0].method_data().unwrap().as_ref(), &[0x01, 0x02]);
+  assert_eq!(output[0].aenc().encryption_info().as_ref(), &[0xAA, 0xBB]);
+  assert_eq!(output[0].preview(), 10..30);
+  assert_eq!(output[0].is_preview_valid(), None);
+}
+
+#[test]
+fn test_audio_encryption_unmatched() {
+  let tag: Tag = parse(&[
+    aenc("mailto:drm@example.com", 0, 0),
+    encr("MAILTO:DRM@EXAMPLE.COM", 0x80, &[0x00]),
+  ]);
+
+  let output: Vec<AudioEncryption<'_>> = tag.audio_encryption(Some(100)).unwrap();
+
+  assert_eq!(output.len(), 1);
+  assert_eq!(output[0].method(), None);
+  assert_eq!(output[0].method_data(), None);
+  assert_eq!(output[0].preview(), 0..0);
+  assert_eq!(output[0].is_preview_valid(), Some(true));
+}
+
+#[test]
+fn test_audio_encryption_out_of_range() {
+  let tag: Tag = parse(&[
+    aenc("first", 90, 10),
+    aenc("second", 90, 11),
+    aenc("third", u16::MAX, u16::MAX),
+  ]);
+
+  let output: Vec<AudioEncryption<'_>> = tag.audio_encryption(Some(100)).unwrap();
+  let valid: Vec<Option<bool>> = output
+    .iter()
+    .map(AudioEncryption::is_preview_valid)
+    .collect();
+
+  assert_eq!(valid, [Some(true), Some(false), Some(false)]);
+  assert_eq!(output[2].preview(), 65535..131070);
+}
+
+#[test]
+fn test_audio_encryption_empty() {
+  let tag: Tag = parse(&[encr("owner", 0x80, &[])]);
+
+  assert!(tag.audio_encryption(Some(100)).unwrap().is_empty());
+}
+
+fn xor(data: &[u8]) -> Vec<u8> {
+  data.iter().map(|byte| byte ^ 0x5A).collect()
+}
+
+fn encrypted(frame: fn(&[u8; 4], u16, &[u8]) -> Vec<u8>, flags: u16, text: &str) -> Vec<u8> {
+  let mut payload: Vec<u8> = vec![0x80];
+  payload.extend(xor(&text_latin1(text)));
+  frame(b"TIT2", flags, &payload)
+}
+
+fn config() -> DecoderConfig {
+  DecoderConfig::new(ParseOptions::new())
+    .decryptor(0x80, |data: &Slice| Ok::<_, Infallible>(xor(data.as_ref())))
+}
+
+#[test]
+fn test_decrypt_frame_v3() {
+  let tag: Tag = parse(&[
+    encr("https://example.com/xor", 0x80, &[]),
+    encrypted(frame_v3, 0x0040, "Secret"),
+  ]);
+
+  assert_eq!(
+    tag.encryption_method("https://example.com/xor").unwrap(),
+    Some(0x80)
+  );
+  assert_eq!(tag.encryption_method("https://example.com").unwrap(), None);
+
+  let frame: DynFrame<'_> = tag.frames().nth(1).unwrap().unwrap();
+
+  let error: Error = frame.decode().unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::EncryptedFrame));
+
+  let content: Content<'_> = frame
+    .decode_with_config(&config(), &mut Vec::new())
+    .unwrap();
+  assert_eq!(content.to_string(), "Secret");
+}
+
+#[test]
+fn test_decrypt_frame_v4() {
+  let data: Vec<u8> = tag(0x04, 0x00, &encrypted(frame_v4, 0x0004, "Secret"));
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let frame: DynFrame<'_> = tag.frames().next().unwrap().unwrap();
+
+  let content: Content<'_> = tag
+    .decode_frame_with_config(&frame, &config(), &mut Vec::new())
+    .unwrap();
+
+  assert_eq!(content.to_string(), "Secret");
+}
+
+#[test]
+fn test_decrypt_frame_error() {
+  let tag: Tag = parse(&[encrypted(frame_v3, 0x0040, "Secret")]);
+  let frame: DynFrame<'_> = tag.frames().next().unwrap().unwrap();
+
+  let config: DecoderConfig = DecoderConfig::new(ParseOptions::new())
+    .decryptor(0x80, |_: &Slice| {
+      Err(IoError::from(IoErrorKind::InvalidData))
+    });
+
+  let error: Error = frame
+    .decode_with_config(&config, &mut Vec::new())
+    .unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::EncryptedFrame));
+  assert!(error.source().is_some());
+}
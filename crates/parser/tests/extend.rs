@@ -0,0 +1,401 @@
+mod common;
+
+use parser::error::ErrorKind;
+use parser::error::TagField;
+use parser::error::Warning;
+use parser::error::WarningKind;
+use parser::frame::DynFrame;
+use parser::id3v2::ExtFlagData;
+use parser::id3v2::ExtHeader;
+use parser::id3v2::ExtHeaderBuilder;
+use parser::id3v2::ImageEncRestriction;
+use parser::id3v2::ImageLenRestriction;
+use parser::id3v2::Restrictions;
+use parser::id3v2::Tag;
+use parser::id3v2::TagSizeRestriction;
+use parser::id3v2::TextEncRestriction;
+use parser::id3v2::TextLenRestriction;
+use parser::options::ParseOptions;
+use parser::types::Encoding;
+use parser::types::FrameId;
+use parser::types::Version;
+
+use self::common::frame_ids;
+use self::common::frame_v3;
+use self::common::frame_v4;
+use self::common::synchsafe;
+use self::common::tag;
+use self::common::text_latin1;
+use self::common::text_utf16;
+use self::common::text_utf8;
+
+const LENIENT: ParseOptions = ParseOptions::new().lenient(true);
+
+fn tag_v3(ext_size: u32, ext_data: &[u8]) -> Vec<u8> {
+  let mut body: Vec<u8> = ext_size.to_be_bytes().to_vec();
+  body.extend_from_slice(ext_data);
+  body.extend(frame_v3(b"TIT2", 0x0000, &text_latin1("Title")));
+  body.extend(frame_v3(b"TALB", 0x0000, &text_latin1("Album")));
+  body.extend([0x00; 16]);
+  tag(0x03, 0x40, &body)
+}
+
+fn tag_v4(ext_size: u32, ext_data: &[u8]) -> Vec<u8> {
+  let mut body: Vec<u8> = synchsafe(ext_size).to_vec();
+  body.extend_from_slice(ext_data);
+  body.extend(frame_v4(b"TIT2", 0x0000, &text_latin1("Title")));
+  body.extend(frame_v4(b"TALB", 0x0000, &text_latin1("Album")));
+  body.extend([0x00; 16]);
+  tag(0x04, 0x40, &body)
+}
+
+#[test]
+fn test_ext_header_v3_valid() {
+  let data: Vec<u8> = tag_v3(6, &[0x00, 0x00, 0x00, 0x00, 0x00, 0x10]);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let ext = tag.header().exheader().unwrap();
+
+  assert_eq!(ext.ext_size(), 6);
+  assert_eq!(ext.total_size(), 10);
+  assert_eq!(ext.pad_size(), 16);
+  assert!(tag.warnings().is_empty());
+  assert_eq!(frame_ids(&tag), ["TIT2", "TALB"]);
+}
+
+#[test]
+fn test_ext_header_v3_missing_crc() {
+  // Declares 10 bytes but the CRC flag is not set.
+  let data: Vec<u8> = tag_v3(
+    10,
+    &[0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0xAA, 0xBB, 0xCC, 0xDD],
+  );
+
+  let error = Tag::from_reader(data.as_slice()).unwrap_err();
+  assert!(matches!(
+    error.kind(),
+    ErrorKind::InvalidField(TagField::ExtSize)
+  ));
+
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), LENIENT).unwrap();
+  let kind: WarningKind = tag.warnings()[0].kind();
+
+  assert_eq!(
+    kind,
+    WarningKind::ExtHeaderSize {
+      declared: 10,
+      consumed: 6
+    }
+  );
+  assert_eq!(frame_ids(&tag), ["TIT2", "TALB"]);
+}
+
+#[test]
+fn test_ext_header_v3_vendor_bytes() {
+  // Declares 14 bytes: CRC flag plus 4 vendor bytes.
+  let data: Vec<u8> = tag_v3(
+    14,
+    &[
+      0x80, 0x00, 0x00, 0x00, 0x00, 0x10, 0x12, 0x34, 0x56, 0x78, 0x01, 0x02, 0x03, 0x04,
+    ],
+  );
+
+  let error = Tag::from_reader(data.as_slice()).unwrap_err();
+  assert!(matches!(
+    error.kind(),
+    ErrorKind::InvalidField(TagField::ExtSize)
+  ));
+
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), LENIENT).unwrap();
+
+  assert_eq!(
+    tag.header().exheader().unwrap().crc_data(),
+    Some(0x12345678)
+  );
+  assert_eq!(tag.warnings().len(), 1);
+  assert_eq!(frame_ids(&tag), ["TIT2", "TALB"]);
+}
+
+#[test]
+fn test_ext_header_v4_too_large() {
+  // Declares 10 bytes but only 6 are used by the fields.
+  let data: Vec<u8> = tag_v4(10, &[0x01, 0x00, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+  let error = Tag::from_reader(data.as_slice()).unwrap_err();
+  assert!(matches!(
+    error.kind(),
+    ErrorKind::InvalidField(TagField::ExtSize)
+  ));
+
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), LENIENT).unwrap();
+  let kind: WarningKind = tag.warnings()[0].kind();
+
+  assert_eq!(
+    kind,
+    WarningKind::ExtHeaderSize {
+      declared: 10,
+      consumed: 6
+    }
+  );
+  assert_eq!(frame_ids(&tag), ["TIT2", "TALB"]);
+}
+
+#[test]
+fn test_ext_header_exceeds_tag() {
+  let mut body: Vec<u8> = 0x1000_u32.to_be_bytes().to_vec();
+  body.extend([0x00; 6]);
+
+  let data: Vec<u8> = tag(0x03, 0x40, &body);
+  let error = Tag::from_reader_with(data.as_slice(), LENIENT).unwrap_err();
+
+  assert!(matches!(
+    error.kind(),
+    ErrorKind::InvalidField(TagField::ExtSize)
+  ));
+}
+
+#[test]
+fn test_ext_header_v4_vendor_flag() {
+  // CRC + restrictions + an undefined vendor flag (0x08) with 2 bytes of data.
+  let data: Vec<u8> = tag_v4(
+    17,
+    &[
+      0x01, 0x38, 0x05, 0x01, 0x02, 0x03, 0x04, 0x05, 0x01, 0x20, 0x02, 0xAB, 0xCD,
+    ],
+  );
+
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let ext = tag.header().exheader().unwrap();
+
+  assert_eq!(ext.crc_data(), Some(0x1040_C205));
+  assert_eq!(
+    ext.restrictions().unwrap().text_enc(),
+    TextEncRestriction::Some
+  );
+  assert_eq!(ext.flag_data().len(), 3);
+
+  let unknown: Vec<&ExtFlagData> = ext.unknown_flag_data().collect();
+
+  assert_eq!(unknown.len(), 1);
+  assert_eq!(unknown[0].flag().bits(), 0x08);
+  assert_eq!(unknown[0].data().as_ref(), &[0xAB, 0xCD]);
+  assert_eq!(frame_ids(&tag), ["TIT2", "TALB"]);
+}
+
+#[test]
+fn test_ext_header_v4_known_flag_length() {
+  // Restrictions flag declaring 2 bytes of data instead of 1.
+  let data: Vec<u8> = tag_v4(9, &[0x01, 0x10, 0x02, 0x20, 0x00]);
+
+  let error = Tag::from_reader(data.as_slice()).unwrap_err();
+  assert!(matches!(
+    error.kind(),
+    ErrorKind::InvalidField(TagField::ExtFlagData)
+  ));
+
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), LENIENT).unwrap();
+  let kind: WarningKind = tag.warnings()[0].kind();
+
+  assert_eq!(
+    kind,
+    WarningKind::ExtFlagData {
+      flag: 0x10,
+      size: 2
+    }
+  );
+  assert_eq!(tag.header().exheader().unwrap().restrictions(), None);
+  assert_eq!(frame_ids(&tag), ["TIT2", "TALB"]);
+}
+
+#[test]
+fn test_text_enc_restriction_violation() {
+  // Restrictions flag with "text encoding" (ISO-8859-1 or UTF-8 only) set.
+  let mut body: Vec<u8> = synchsafe(8).to_vec();
+  body.extend([0x01, 0x10, 0x01, 0x20]);
+  body.extend(frame_v4(b"TIT2", 0x0000, &text_utf16("Title")));
+  body.extend(frame_v4(b"TALB", 0x0000, &text_utf8("Album")));
+
+  let data: Vec<u8> = tag(0x04, 0x40, &body);
+  let options: ParseOptions = ParseOptions::new().check_restrictions(true);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), options).unwrap();
+
+  let restriction: TextEncRestriction = tag
+    .header()
+    .exheader()
+    .and_then(|exheader| exheader.restrictions())
+    .unwrap()
+    .text_enc();
+
+  let mut warnings: Vec<Warning> = Vec::new();
+  let mut encodings: Vec<Encoding> = Vec::new();
+
+  for frame in tag.frames() {
+    let frame: DynFrame<'_> = frame.unwrap();
+    let content = tag.decode_frame(&frame, &mut warnings).unwrap();
+    let encoding: Encoding = content.text_encoding().unwrap();
+
+    // The encoding a writer honoring the restrictions would choose.
+    encodings.push(restriction.select(encoding));
+  }
+
+  let kinds: Vec<WarningKind> = warnings.iter().map(Warning::kind).collect();
+
+  assert_eq!(
+    kinds,
+    [WarningKind::TextEncRestriction {
+      frame: FrameId::try_from(*b"TIT2").unwrap(),
+      encoding: Encoding::Utf16,
+    }]
+  );
+  assert_eq!(encodings, [Encoding::Utf8, Encoding::Utf8]);
+
+  // Restrictions are not checked by default.
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let frame: DynFrame<'_> = tag.frames().next().unwrap().unwrap();
+  let mut warnings: Vec<Warning> = Vec::new();
+
+  tag.decode_frame(&frame, &mut warnings).unwrap();
+
+  assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_text_enc_restriction_select() {
+  assert!(TextEncRestriction::None.permits(Encoding::Utf16));
+  assert!(TextEncRestriction::Some.permits(Encoding::Latin1));
+  assert!(TextEncRestriction::Some.permits(Encoding::Utf8));
+  assert!(!TextEncRestriction::Some.permits(Encoding::Utf16BE));
+
+  assert_eq!(
+    TextEncRestriction::None.select(Encoding::Utf16),
+    Encoding::Utf16
+  );
+  assert_eq!(
+    TextEncRestriction::Some.select(Encoding::Utf16),
+    Encoding::Utf8
+  );
+  assert_eq!(
+    TextEncRestriction::Some.select(Encoding::Utf16BE),
+    Encoding::Utf8
+  );
+  assert_eq!(
+    TextEncRestriction::Some.select(Encoding::Latin1),
+    Encoding::Latin1
+  );
+}
+
+const RESTRICTIONS: Restrictions = Restrictions::new(
+  TagSizeRestriction::R3,
+  TextEncRestriction::Some,
+  TextLenRestriction::R2,
+  ImageEncRestriction::Some,
+  ImageLenRestriction::R4,
+);
+
+fn write_v3(header: &ExtHeader) -> Vec<u8> {
+  let mut output: Vec<u8> = Vec::new();
+  let size: usize = header.write_v3(&mut output).unwrap();
+
+  assert_eq!(size, output.len());
+  output
+}
+
+fn write_v4(header: &ExtHeader) -> Vec<u8> {
+  let mut output: Vec<u8> = Vec::new();
+  let size: usize = header.write_v4(&mut output).unwrap();
+
+  assert_eq!(size, output.len());
+  output
+}
+
+#[test]
+fn test_ext_header_write_v3() {
+  for crc in [None, Some(0xDEAD_BEEF)] {
+    for padding in [0, 0x400] {
+      let mut builder: ExtHeaderBuilder = ExtHeader::builder().padding(padding);
+
+      if let Some(crc) = crc {
+        builder = builder.crc(crc);
+      }
+
+      let header: ExtHeader = builder.build(Version::ID3v23).unwrap();
+      let output: Vec<u8> = write_v3(&header);
+      let parsed: ExtHeader = ExtHeader::from_reader_v3(output.as_slice()).unwrap();
+
+      assert_eq!(output.len(), if crc.is_some() { 14 } else { 10 });
+      assert_eq!(output.len(), header.total_size() as usize);
+      assert_eq!(parsed, header);
+      assert_eq!(parsed.crc_data(), crc);
+      assert_eq!(parsed.pad_size(), padding);
+    }
+  }
+
+  let header: ExtHeader = ExtHeader::builder()
+    .crc(0x0102_0304)
+    .padding(0x10)
+    .build(Version::ID3v23)
+    .unwrap();
+
+  assert_eq!(
+    write_v3(&header),
+    [0x00, 0x00, 0x00, 0x0A, 0x80, 0x00, 0x00, 0x00, 0x00, 0x10, 0x01, 0x02, 0x03, 0x04]
+  );
+}
+
+#[test]
+fn test_ext_header_write_v4() {
+  for bits in 0..8 {
+    let mut builder: ExtHeaderBuilder = ExtHeader::builder().update(bits & 1 != 0);
+
+    if bits & 2 != 0 {
+      builder = builder.crc(u32::MAX);
+    }
+
+    if bits & 4 != 0 {
+      builder = builder.restrictions(RESTRICTIONS);
+    }
+
+    let header: ExtHeader = builder.build(Version::ID3v24).unwrap();
+    let output: Vec<u8> = write_v4(&header);
+    let parsed: ExtHeader = ExtHeader::from_reader_v4(output.as_slice()).unwrap();
+
+    assert_eq!(output.len(), header.total_size() as usize);
+    assert_eq!(parsed, header);
+    assert_eq!(parsed.flag_update(), bits & 1 != 0);
+    assert_eq!(parsed.crc_data(), (bits & 2 != 0).then_some(u32::MAX));
+    assert_eq!(
+      parsed.restrictions(),
+      (bits & 4 != 0).then_some(RESTRICTIONS)
+    );
+  }
+
+  let header: ExtHeader = ExtHeader::builder()
+    .update(true)
+    .crc(u32::MAX)
+    .restrictions(RESTRICTIONS)
+    .build(Version::ID3v24)
+    .unwrap();
+
+  assert_eq!(
+    write_v4(&header),
+    [0x00, 0x00, 0x00, 0x0F, 0x01, 0x70, 0x00, 0x05, 0x0F, 0x7F, 0x7F, 0x7F, 0x7F, 0x01, 0xAF,]
+  );
+}
+
+#[test]
+fn test_ext_header_write_unknown_flag() {
+  let data: &[u8] = &[
+    0x00, 0x00, 0x00, 0x11, 0x01, 0x38, 0x05, 0x01, 0x02, 0x03, 0x04, 0x05, 0x01, 0x20, 0x02, 0xAB,
+    0xCD,
+  ];
+
+  let header: ExtHeader = ExtHeader::from_reader_v4(data).unwrap();
+
+  assert_eq!(write_v4(&header), data);
+
+  // Only the CRC data is written in ID3v2.3 form.
+  let output: Vec<u8> = write_v3(&header);
+  let parsed: ExtHeader = ExtHeader::from_reader_v3(output.as_slice()).unwrap();
+
+  assert_eq!(parsed.crc_data(), header.crc_data());
+  assert_eq!(parsed.restrictions(), None);
+}
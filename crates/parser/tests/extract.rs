@@ -0,0 +1,186 @@
+mod common;
+
+use parser::content::ContentKind;
+use parser::id3v2::ExtractOptions;
+use parser::id3v2::ExtractedFile;
+use parser::id3v2::Tag;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use self::common::apic;
+use self::common::frame_v3;
+use self::common::tag;
+
+const PNG: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0x01, 0x02];
+const JPG: &[u8] = &[0xFF, 0xD8, 0xFF, 0xE0, 0x03, 0x04];
+
+/// A scratch directory that is removed when dropped.
+struct TempDir(PathBuf);
+
+impl TempDir {
+  fn new(name: &str) -> Self {
+    let path: PathBuf = std::env::temp_dir().join(format!("errai-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    Self(path)
+  }
+
+  fn path(&self) -> &Path {
+    &self.0
+  }
+}
+
+impl Drop for TempDir {
+  fn drop(&mut self) {
+    let _ = fs::remove_dir_all(&self.0);
+  }
+}
+
+fn geob(filename: &str, data: &[u8]) -> Vec<u8> {
+  let mut payload: Vec<u8> = vec![0x00];
+  payload.extend_from_slice(b"application/octet-stream\x00");
+  payload.extend_from_slice(filename.as_bytes());
+  payload.extend_from_slice(b"\x00Object\x00");
+  payload.extend_from_slice(data);
+
+  frame_v3(b"GEOB", 0x0000, &payload)
+}
+
+fn private(owner: &str, data: &[u8]) -> Vec<u8> {
+  let mut payload: Vec<u8> = owner.as_bytes().to_vec();
+  payload.push(0x00);
+  payload.extend_from_slice(data);
+
+  frame_v3(b"PRIV", 0x0000, &payload)
+}
+
+fn parse(frames: &[Vec<u8>]) -> Tag {
+  Tag::from_reader(tag(0x03, 0x00, &frames.concat()).as_slice()).unwrap()
+}
+
+fn file_names(files: &[ExtractedFile]) -> Vec<String> {
+  files
+    .iter()
+    .map(|file| {
+      file
+        .path()
+        .file_name()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_owned()
+    })
+    .collect()
+}
+
+#[test]
+fn test_extract_unique_names() {
+  let temp: TempDir = TempDir::new("unique");
+  let tag: Tag = parse(&[
+    frame_v3(b"APIC", 0x0000, &apic("image/png", "Cover", PNG)),
+    frame_v3(b"APIC", 0x0000, &apic("image/png", "Cover", PNG)),
+    frame_v3(b"APIC", 0x0000, &apic("image/jpeg", "Cover", JPG)),
+  ]);
+  let files: Vec<ExtractedFile> = tag
+    .extract_binaries(temp.path(), &ExtractOptions::new())
+    .unwrap();
+
+  assert_eq!(
+    file_names(&files),
+    ["APIC-Cover.png", "APIC-Cover-2.png", "APIC-Cover.jpg"]
+  );
+  assert_eq!(files[0].identifier(), "APIC");
+  assert_eq!(files[0].size(), PNG.len());
+  assert_eq!(fs::read(files[0].path()).unwrap(), PNG);
+  assert_eq!(fs::read(files[1].path()).unwrap(), PNG);
+  assert_eq!(fs::read(files[2].path()).unwrap(), JPG);
+}
+
+#[test]
+fn test_extract_existing_file() {
+  let temp: TempDir = TempDir::new("existing");
+  let tag: Tag = parse(&[frame_v3(b"APIC", 0x0000, &apic("image/png", "Cover", PNG))]);
+
+  fs::create_dir_all(temp.path()).unwrap();
+  fs::write(temp.path().join("APIC-Cover.png"), b"keep").unwrap();
+
+  let files: Vec<ExtractedFile> = tag
+    .extract_binaries(temp.path(), &ExtractOptions::new())
+    .unwrap();
+
+  assert_eq!(file_names(&files), ["APIC-Cover-2.png"]);
+  assert_eq!(
+    fs::read(temp.path().join("APIC-Cover.png")).unwrap(),
+    b"keep"
+  );
+}
+
+#[test]
+fn test_extract_sanitized_names() {
+  let temp: TempDir = TempDir::new("sanitized");
+  let tag: Tag = parse(&[
+    geob("../../etc/passwd.txt", b"object"),
+    private("/abs/path\\..", b"private"),
+  ]);
+
+  let files: Vec<ExtractedFile> = tag
+    .extract_binaries(temp.path(), &ExtractOptions::new())
+    .unwrap();
+
+  assert_eq!(
+    file_names(&files),
+    ["GEOB-etc_passwd.txt", "PRIV-abs_path.bin"]
+  );
+
+  for file in files.iter() {
+    assert_eq!(file.path().parent(), Some(temp.path()));
+  }
+}
+
+#[test]
+fn test_extract_kinds() {
+  let temp: TempDir = TempDir::new("kinds");
+  let tag: Tag = parse(&[
+    frame_v3(b"APIC", 0x0000, &apic("image/png", "", PNG)),
+    private("owner", b"private"),
+  ]);
+  let options: ExtractOptions = ExtractOptions::new().kinds([ContentKind::Priv]);
+  let files: Vec<ExtractedFile> = tag.extract_binaries(temp.path(), &options).unwrap();
+
+  assert_eq!(file_names(&files), ["PRIV-owner.bin"]);
+}
+
+#[test]
+fn test_extract_size_budgets() {
+  let temp: TempDir = TempDir::new("budgets");
+  let tag: Tag = parse(&[
+    private("a", &[0x00; 8]),
+    private("b", &[0x00; 32]),
+    private("c", &[0x00; 8]),
+    private("d", &[0x00; 8]),
+  ]);
+
+  let options: ExtractOptions = ExtractOptions::new().max_file_size(16).max_total_size(20);
+  let files: Vec<ExtractedFile> = tag.extract_binaries(temp.path(), &options).unwrap();
+
+  assert_eq!(file_names(&files), ["PRIV-a.bin", "PRIV-c.bin"]);
+  assert_eq!(files.iter().map(ExtractedFile::size).sum::<usize>(), 16);
+}
+
+#[test]
+fn test_extract_invalid_frames() {
+  let temp: TempDir = TempDir::new("invalid");
+  let tag: Tag = parse(&[
+    frame_v3(b"APIC", 0x0000, &apic("image/png", "Cover", PNG)),
+    frame_v3(b"APIC", 0x0000, b"\x07image/png\x00\x03\x00data"),
+    frame_v3(b"A!IC", 0x0000, b"corrupt"),
+    private("owner", b"private"),
+  ]);
+
+  // Frames that cannot be parsed or decoded don't lose the other files.
+  let files: Vec<ExtractedFile> = tag
+    .extract_binaries(temp.path(), &ExtractOptions::new())
+    .unwrap();
+
+  assert_eq!(file_names(&files), ["APIC-Cover.png", "PRIV-owner.bin"]);
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use parser::error::ErrorKind;
+use parser::error::TagField;
+use parser::id3v2::Footer;
+use parser::id3v2::HeaderFlags;
+use parser::id3v2::Tag;
+use parser::types::Version;
+use std::io::Cursor;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+use self::common::frame_v4;
+use self::common::tag;
+use self::common::tag_footer;
+use self::common::text_latin1;
+
+#[test]
+fn test_parse_footer() {
+  let body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Title"));
+  let data: Vec<u8> = tag_footer(&body);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let footer: &Footer = tag.footer().unwrap();
+
+  assert_eq!(footer.version(), Version::ID3v24);
+  assert_eq!(footer.bitflags(), HeaderFlags::FOOTER_PRESENT);
+  assert_eq!(footer.data_len(), tag.header().data_len());
+  assert_eq!(footer.tag_len(), data.len() as u64);
+  assert!(footer.matches(tag.header()));
+  assert_eq!(tag.frames().count(), 1);
+}
+
+#[test]
+fn test_parse_footer_absent() {
+  let body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Title"));
+  let data: Vec<u8> = tag(0x04, 0x00, &body);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  assert_eq!(tag.footer(), None);
+}
+
+#[test]
+fn test_parse_footer_mismatch() {
+  let body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Title"));
+  let mut data: Vec<u8> = tag_footer(&body);
+  let index: usize = data.len() - 1;
+
+  data[index] += 1;
+
+  let error = Tag::from_reader(data.as_slice()).unwrap_err();
+
+  assert!(matches!(
+    error.kind(),
+    ErrorKind::InvalidField(TagField::Footer)
+  ));
+}
+
+#[test]
+fn test_parse_footer_identifier() {
+  let body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Title"));
+  let mut data: Vec<u8> = tag_footer(&body);
+  let index: usize = data.len() - 10;
+
+  data[index..index + 3].copy_from_slice(b"ID3");
+
+  let error = Tag::from_reader(data.as_slice()).unwrap_err();
+
+  assert!(matches!(
+    error.kind(),
+    ErrorKind::InvalidField(TagField::Identifier)
+  ));
+}
+
+#[test]
+fn test_footer_from_reader_backwards() {
+  let body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Title"));
+  let mut data: Vec<u8> = vec![0xFF; 32];
+
+  data.extend_from_slice(&tag_footer(&body));
+
+  let mut reader: Cursor<&[u8]> = Cursor::new(data.as_slice());
+
+  reader.seek(SeekFrom::End(0)).unwrap();
+
+  let footer: Footer = Footer::from_reader_backwards(&mut reader).unwrap().unwrap();
+
+  assert_eq!(reader.position(), 32);
+  assert_eq!(footer.tag_len(), data.len() as u64 - 32);
+
+  let tag: Tag = Tag::from_reader(&mut reader).unwrap();
+
+  assert_eq!(tag.footer(), Some(&footer));
+}
+
+#[test]
+fn test_footer_from_reader_backwards_absent() {
+  let body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Title"));
+  let data: Vec<u8> = tag(0x04, 0x00, &body);
+  let mut reader: Cursor<&[u8]> = Cursor::new(data.as_slice());
+
+  reader.seek(SeekFrom::End(0)).unwrap();
+
+  assert_eq!(Footer::from_reader_backwards(&mut reader).unwrap(), None);
+  assert_eq!(reader.position(), data.len() as u64);
+
+  // Too short to contain a footer.
+  let mut reader: Cursor<&[u8]> = Cursor::new(&data[..4]);
+
+  reader.seek(SeekFrom::End(0)).unwrap();
+
+  assert_eq!(Footer::from_reader_backwards(&mut reader).unwrap(), None);
+}
@@ -0,0 +1,554 @@
+mod common;
+
+use parser::content::Content;
+use parser::error::ErrorKind;
+use parser::error::Warning;
+use parser::error::WarningKind;
+use parser::frame::header_len;
+use parser::frame::parse_size;
+use parser::frame::DynFrame;
+use parser::frame::FrameV2;
+use parser::frame::FrameV3;
+use parser::frame::FrameV3Extra;
+use parser::frame::FrameV3Flags;
+use parser::frame::FrameV4;
+use parser::frame::FrameV4Extra;
+use parser::frame::FrameV4Flags;
+use parser::frame::Layout;
+use parser::frame::SizeEncoding;
+use parser::id3v2::Tag;
+use parser::options::ParseOptions;
+use parser::types::FrameId;
+use parser::types::Slice;
+use parser::types::Version;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use self::common::tag;
+use self::common::zlib_stored;
+
+#[test]
+fn test_layout_values() {
+  let layout: Layout = Version::ID3v22.frame_layout().unwrap();
+
+  assert_eq!(layout, Layout::V2);
+  assert_eq!(layout.id_len(), 3);
+  assert_eq!(layout.size_len(), 3);
+  assert_eq!(layout.size_encoding(), SizeEncoding::U24);
+  assert!(!layout.has_flags());
+  assert_eq!(layout.header_len(), 6);
+
+  let layout: Layout = Version::ID3v23.frame_layout().unwrap();
+
+  assert_eq!(layout, Layout::V3);
+  assert_eq!(layout.id_len(), 4);
+  assert_eq!(layout.size_len(), 4);
+  assert_eq!(layout.size_encoding(), SizeEncoding::U32);
+  assert_eq!(layout.flag_len(), 2);
+  assert_eq!(layout.header_len(), 10);
+
+  let layout: Layout = Version::ID3v24.frame_layout().unwrap();
+
+  assert_eq!(layout, Layout::V4);
+  assert_eq!(layout.size_encoding(), SizeEncoding::U28Unsync);
+  assert_eq!(layout.header_len(), 10);
+
+  assert_eq!(Version::ID3v11.frame_layout(), None);
+  assert_eq!(Version::ID3v12.frame_layout(), None);
+}
+
+#[test]
+fn test_header_len() {
+  assert_eq!(header_len(Version::ID3v22), Some(FrameV2::SIZE));
+  assert_eq!(header_len(Version::ID3v23), Some(FrameV3::SIZE));
+  assert_eq!(header_len(Version::ID3v24), Some(FrameV4::SIZE));
+  assert_eq!(header_len(Version::ID3v11), None);
+  assert_eq!((FrameV2::SIZE, FrameV3::SIZE, FrameV4::SIZE), (6, 10, 10));
+}
+
+#[test]
+fn test_parse_size() {
+  let header: &[u8] = b"TIT2\x00\x00\x02\x01\x00\x00";
+
+  assert_eq!(parse_size(header, Version::ID3v23), Some(0x0201));
+  assert_eq!(parse_size(header, Version::ID3v24), Some(0x0101));
+  assert_eq!(
+    parse_size(b"TT2\x01\x02\x03", Version::ID3v22),
+    Some(0x010203)
+  );
+  assert_eq!(parse_size(b"TIT2\x00\x00", Version::ID3v23), None);
+  assert_eq!(parse_size(header, Version::ID3v11), None);
+}
+
+#[test]
+fn test_layout_matches_fixtures() {
+  let root: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+
+  for entry in fs::read_dir(root).unwrap() {
+    let path: PathBuf = entry.unwrap().path();
+
+    if path
+      .extension()
+      .is_some_and(|extension| extension == "json")
+    {
+      continue;
+    }
+
+    let data: Vec<u8> = fs::read(&path).unwrap();
+    let Ok(tag) = Tag::from_reader_with(data.as_slice(), Default::default()) else {
+      continue;
+    };
+
+    let version: Version = tag.header().version();
+    let mut buffer: &[u8] = tag.buffer().as_ref();
+
+    for frame in tag.frames() {
+      let frame: DynFrame<'_> = frame.unwrap();
+      let size: u32 = parse_size(buffer, version).unwrap();
+
+      assert_eq!(frame.header_size(), header_len(version).unwrap());
+      assert_eq!(frame.descriptor(), size);
+      assert_eq!(frame.total_size(), frame.header_size() + size as usize);
+
+      buffer = &buffer[frame.total_size()..];
+    }
+  }
+}
+
+/// A minimal xorshift generator for reproducible pseudo-random inputs.
+struct Rng(u64);
+
+impl Rng {
+  fn next(&mut self) -> u64 {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    self.0
+  }
+}
+
+/// Build a frame from a header written with `write` and `length` bytes of
+/// content.
+fn write_frame<F>(length: u32, write: F) -> Vec<u8>
+where
+  F: FnOnce(&mut Vec<u8>) -> usize,
+{
+  let mut output: Vec<u8> = Vec::new();
+  let size: usize = write(&mut output);
+
+  assert_eq!(output.len(), size);
+
+  output.extend((0..length).map(|index| index as u8));
+  output
+}
+
+#[test]
+fn test_write_header_v3() {
+  let identifier: FrameId = FrameId::try_from(*b"TIT2").unwrap();
+  let mut rng: Rng = Rng(0x2545_F491_4F6C_DD1D);
+
+  for bits in 0..=u16::MAX {
+    let flags: FrameV3Flags = FrameV3Flags::from_bits_retain(bits) - FrameV3Flags::EXTRA;
+
+    if !rng.next().is_multiple_of(64) && bits & 0x1FFF != 0 {
+      continue;
+    }
+
+    let mut extra: FrameV3Extra = FrameV3Extra::new();
+    let length: u32 = (rng.next() % 300) as u32 + 1;
+
+    if rng.next().is_multiple_of(2) {
+      extra = extra.with_comp(rng.next() as u32);
+    }
+
+    if rng.next().is_multiple_of(2) {
+      extra = extra.with_encr(rng.next() as u8);
+    }
+
+    if rng.next().is_multiple_of(2) {
+      extra = extra.with_grid(rng.next() as u8);
+    }
+
+    let data: Vec<u8> = write_frame(length, |output| {
+      FrameV3::write_header(output, identifier, length, flags, extra).unwrap()
+    });
+
+    let frame: FrameV3<'_> = FrameV3::from_slice(Slice::new(&data)).unwrap().unwrap();
+
+    assert_eq!(frame.identifier(), identifier);
+    assert_eq!(frame.flag_bytes(), flags | extra.bitflags());
+    assert_eq!(*frame.extra_data(), extra);
+    assert_eq!(frame.frame_data().len(), length as usize);
+    assert_eq!(frame.total_size(), data.len());
+  }
+}
+
+#[test]
+fn test_write_header_v4() {
+  let identifier: FrameId = FrameId::try_from(*b"TIT2").unwrap();
+  let mut rng: Rng = Rng(0x9E37_79B9_7F4A_7C15);
+
+  for bits in 0..=u16::MAX {
+    let flags: FrameV4Flags = FrameV4Flags::from_bits_retain(bits) - FrameV4Flags::EXTRA;
+
+    if !rng.next().is_multiple_of(64) && bits & 0x0FFF != 0 {
+      continue;
+    }
+
+    let mut extra: FrameV4Extra = FrameV4Extra::new();
+    let length: u32 = (rng.next() % 300) as u32 + 1;
+
+    if rng.next().is_multiple_of(2) {
+      extra = extra.with_grid(rng.next() as u8);
+    }
+
+    if rng.next().is_multiple_of(2) {
+      extra = extra.with_encr(rng.next() as u8);
+    }
+
+    if rng.next().is_multiple_of(2) || flags.contains(FrameV4Flags::COMPRESSION) {
+      extra = extra.with_dlen(rng.next() as u32 & 0x0FFF_FFFF);
+    }
+
+    let data: Vec<u8> = write_frame(length, |output| {
+      FrameV4::write_header(output, identifier, length, flags, extra).unwrap()
+    });
+
+    let frame: FrameV4<'_> = FrameV4::from_slice(Slice::new(&data)).unwrap().unwrap();
+
+    assert_eq!(frame.identifier(), identifier);
+    assert_eq!(frame.flag_bytes(), flags | extra.bitflags());
+    assert_eq!(*frame.extra_data(), extra);
+    assert_eq!(frame.frame_data().len(), length as usize);
+    assert_eq!(frame.total_size(), data.len());
+  }
+}
+
+#[test]
+fn test_write_header_size() {
+  let identifier: FrameId = FrameId::try_from(*b"TIT2").unwrap();
+  let mut output: Vec<u8> = Vec::new();
+
+  FrameV3::write_header(
+    &mut output,
+    identifier,
+    0x1234_5678,
+    FrameV3Flags::empty(),
+    FrameV3Extra::new(),
+  )
+  .unwrap();
+  FrameV4::write_header(
+    &mut output,
+    identifier,
+    0x0FFF_FFFF,
+    FrameV4Flags::empty(),
+    FrameV4Extra::new(),
+  )
+  .unwrap();
+
+  assert_eq!(&output[4..8], &[0x12, 0x34, 0x56, 0x78]);
+  assert_eq!(&output[14..18], &[0x7F, 0x7F, 0x7F, 0x7F]);
+
+  let error = FrameV4::write_header(
+    Vec::new(),
+    identifier,
+    0x1000_0000,
+    FrameV4Flags::empty(),
+    FrameV4Extra::new(),
+  )
+  .unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::Int));
+
+  let error = FrameV3::write_header(
+    Vec::new(),
+    identifier,
+    0,
+    FrameV3Flags::empty(),
+    FrameV3Extra::new(),
+  )
+  .unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::Int));
+}
+
+#[test]
+fn test_write_header_flags() {
+  let identifier: FrameId = FrameId::try_from(*b"TIT2").unwrap();
+
+  // Flags indicating extra data require the data.
+  let error = FrameV3::write_header(
+    Vec::new(),
+    identifier,
+    1,
+    FrameV3Flags::GROUPING_IDENTITY,
+    FrameV3Extra::new(),
+  )
+  .unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::InvalidBitFlag));
+
+  let error = FrameV4::write_header(
+    Vec::new(),
+    identifier,
+    1,
+    FrameV4Flags::COMPRESSION,
+    FrameV4Extra::new(),
+  )
+  .unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::InvalidBitFlag));
+
+  let mut output: Vec<u8> = Vec::new();
+  let size: usize = FrameV4::write_header(
+    &mut output,
+    identifier,
+    1,
+    FrameV4Flags::COMPRESSION,
+    FrameV4Extra::new().with_dlen(16),
+  )
+  .unwrap();
+
+  assert_eq!(size, 14);
+  assert_eq!(&output[8..10], &[0x00, 0x09]);
+}
+
+#[test]
+fn test_decode_undefined_flags() {
+  // ID3v2.3 TIT2 frame with the undefined flag bit 0x0001 set.
+  let data: &[u8] = b"TIT2\x00\x00\x00\x06\x00\x01\x00Title";
+  let frame: FrameV3<'_> = FrameV3::from_slice(Slice::new(data)).unwrap().unwrap();
+
+  assert_eq!(frame.flag_bytes().undefined_bits(), 0x0001);
+  assert_eq!(FrameV3Flags::all().undefined_bits(), 0x0000);
+  assert!(DynFrame::V3(frame).has_unknown_flags());
+
+  let error = frame.decode().unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::InvalidBitFlag));
+
+  let mut warnings: Vec<Warning> = Vec::new();
+  let options: ParseOptions = ParseOptions::new().lenient(true);
+  let content: Content<'_> = frame.decode_with(options, &mut warnings).unwrap();
+
+  assert_eq!(content.to_string(), "Title");
+  assert_eq!(
+    warnings.iter().map(Warning::kind).collect::<Vec<_>>(),
+    [WarningKind::FrameFlags {
+      frame: frame.identifier(),
+      bits: 0x0001,
+    }]
+  );
+
+  // ID3v2.4 TIT2 frame with the undefined flag bit 0x8000 set.
+  let data: &[u8] = b"TIT2\x00\x00\x00\x06\x80\x00\x00Title";
+  let frame: FrameV4<'_> = FrameV4::from_slice(Slice::new(data)).unwrap().unwrap();
+
+  assert_eq!(frame.flag_bytes().undefined_bits(), 0x8000);
+  assert!(DynFrame::V4(frame).has_unknown_flags());
+
+  let error = frame.decode().unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::InvalidBitFlag));
+}
+
+#[test]
+fn test_decode_data_length_indicator() {
+  let text: String = "A".repeat(299);
+  let mut content: Vec<u8> = vec![0x00];
+  content.extend(text.as_bytes());
+
+  let compressed: Vec<u8> = zlib_stored(&content);
+  let length: u32 = compressed.len() as u32 + 4;
+
+  // ID3v2.4 TIT2 frame with COMPRESSION and DATA_LENGTH_INDICATOR set.
+  let mut data: Vec<u8> = b"TIT2".to_vec();
+  data.extend([0x00, 0x00, (length >> 7) as u8, length as u8 & 0x7F]);
+  data.extend([0x00, 0x09]);
+  data.extend([0x00, 0x00, 0x02, 0x2C]);
+  data.extend(compressed);
+
+  let frame: FrameV4<'_> = FrameV4::from_slice(Slice::new(&data)).unwrap().unwrap();
+
+  // The indicator is synchsafe, read as a plain integer it would be 556.
+  assert_eq!(frame.extra_data().dlen(), Some(300));
+
+  #[cfg(feature = "zlib")]
+  assert_eq!(frame.decode().unwrap().to_string(), text);
+
+  let mut output: Vec<u8> = Vec::new();
+  let extra: FrameV4Extra = FrameV4Extra::new().with_dlen(300);
+  FrameV4::write_header(
+    &mut output,
+    frame.identifier(),
+    1,
+    FrameV4Flags::empty(),
+    extra,
+  )
+  .unwrap();
+
+  assert_eq!(output[10..], [0x00, 0x00, 0x02, 0x2C]);
+}
+
+#[test]
+fn test_decode_unsynchronisation_v4() {
+  // ID3v2.4 TIT2 frame with UNSYNCHRONISATION and DATA_LENGTH_INDICATOR set.
+  let content: &[u8] = b"\x01\xFF\x00\xFET\x00i\x00t\x00l\x00e\x00";
+  let mut data: Vec<u8> = b"TIT2\x00\x00\x00\x12\x00\x03\x00\x00\x00\x0D".to_vec();
+  data.extend(content);
+
+  let frame: FrameV4<'_> = FrameV4::from_slice(Slice::new(&data)).unwrap().unwrap();
+
+  assert_eq!(frame.extra_data().dlen(), Some(13));
+  assert_eq!(frame.decode().unwrap().to_string(), "Title");
+}
+
+#[test]
+fn test_decode_unsynchronisation_dlen() {
+  // ID3v2.4 TIT2 frame with UNSYNCHRONISATION set and a bogus data length.
+  let data: &[u8] = b"TIT2\x00\x00\x00\x0A\x00\x03\x7F\x7F\x7F\x7F\x00Title";
+  let frame: FrameV4<'_> = FrameV4::from_slice(Slice::new(data)).unwrap().unwrap();
+
+  let error = frame.decode().unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::InvalidFrameData));
+
+  let mut warnings: Vec<Warning> = Vec::new();
+  let options: ParseOptions = ParseOptions::new().lenient(true);
+  let content: Content<'_> = frame.decode_with(options, &mut warnings).unwrap();
+
+  assert_eq!(content.to_string(), "Title");
+  assert_eq!(
+    warnings.iter().map(Warning::kind).collect::<Vec<_>>(),
+    [WarningKind::DataLength {
+      frame: frame.identifier(),
+      declared: 0x0FFF_FFFF,
+      actual: 6,
+    }]
+  );
+}
+
+/// Build an ID3v2.3 tag with a compressed `TIT2` frame.
+fn compressed_v3(text: &str) -> Vec<u8> {
+  let mut content: Vec<u8> = vec![0x00];
+  content.extend(text.as_bytes());
+
+  let compressed: Vec<u8> = zlib_stored(&content);
+  let length: u32 = compressed.len() as u32 + 4;
+
+  let mut body: Vec<u8> = b"TIT2".to_vec();
+  body.extend(length.to_be_bytes());
+  body.extend([0x00, 0x80]);
+  body.extend((content.len() as u32).to_be_bytes());
+  body.extend(compressed);
+
+  let mut data: Vec<u8> = vec![b'I', b'D', b'3', 0x03, 0x00, 0x00, 0x00, 0x00, 0x00];
+  data.push(body.len() as u8);
+  data.extend(body);
+  data
+}
+
+#[cfg(feature = "zlib")]
+#[test]
+fn test_decode_compressed_v3() {
+  let tag: Tag = Tag::from_reader(compressed_v3("Title").as_slice()).unwrap();
+  let frame: DynFrame<'_> = tag.frames().next().unwrap().unwrap();
+
+  assert_eq!(frame.decode().unwrap().to_string(), "Title");
+}
+
+#[cfg(not(feature = "zlib"))]
+#[test]
+fn test_decode_compressed_v3() {
+  let tag: Tag = Tag::from_reader(compressed_v3("Title").as_slice()).unwrap();
+  let frame: DynFrame<'_> = tag.frames().next().unwrap().unwrap();
+  let error = frame.decode().unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::Unsupported));
+}
+
+#[test]
+fn test_from_slice_padding() {
+  for version in [Version::ID3v22, Version::ID3v23, Version::ID3v24] {
+    // Pure padding.
+    let frame = DynFrame::from_slice(version, Slice::new(&[0x00; 16])).unwrap();
+    assert!(frame.is_none());
+
+    // An identifier partially overwritten by padding.
+    let frame =
+      DynFrame::from_slice(version, Slice::new(b"\x00IT2\x00\x00\x00\x01\x00\x00\x00")).unwrap();
+    assert!(frame.is_none());
+
+    // Lowercase letters are never valid.
+    let error =
+      DynFrame::from_slice(version, Slice::new(b"tit2\x00\x00\x00\x01\x00\x00\x00")).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::InvalidFrameId));
+  }
+}
+
+#[test]
+fn test_from_slice_overrun() {
+  let data: &[u8] = b"TIT2\xFF\xFF\xFF\xFF\x00\x00\x00Title";
+  let error = FrameV3::from_slice(Slice::new(data)).unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::FrameOverrun));
+
+  let data: &[u8] = b"TIT2\x7F\x7F\x7F\x7F\x00\x00\x00Title";
+  let error = FrameV4::from_slice(Slice::new(data)).unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::FrameOverrun));
+
+  let data: &[u8] = b"TT2\xFF\xFF\xFF\x00Title";
+  let error = FrameV2::from_slice(Slice::new(data)).unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::FrameOverrun));
+
+  // One byte past the end of the buffer.
+  let data: &[u8] = b"TIT2\x00\x00\x00\x07\x00\x00\x00Title";
+  let error = FrameV3::from_slice(Slice::new(data)).unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::FrameOverrun));
+}
+
+#[test]
+fn test_from_slice_extra_data_overrun() {
+  // The data length indicator does not fit in a descriptor of 1 byte.
+  let data: &[u8] = b"TIT2\x00\x00\x00\x01\x00\x01\x00\x00\x00\x06Title";
+  let error = FrameV4::from_slice(Slice::new(data)).unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::InvalidFrameData));
+
+  // The decompressed size does not fit in a descriptor of 2 bytes.
+  let data: &[u8] = b"TIT2\x00\x00\x00\x02\x00\x80\x00\x00\x00\x06Title";
+  let error = FrameV3::from_slice(Slice::new(data)).unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::InvalidFrameData));
+}
+
+#[test]
+fn test_frames_overrun() {
+  let mut body: Vec<u8> = b"TIT2\x00\x00\x00\x06\x00\x00\x00Title".to_vec();
+  body.extend(b"TPE1\x00\x00\x01\x00\x00\x00\x00Artist");
+
+  let mut data: Vec<u8> = vec![b'I', b'D', b'3', 0x03, 0x00, 0x00, 0x00, 0x00, 0x00];
+  data.push(body.len() as u8);
+  data.extend(body);
+
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let frames: Vec<_> = tag.frames().collect();
+
+  assert_eq!(frames.len(), 2);
+  assert_eq!(frames[0].as_ref().unwrap().identifier_str(), "TIT2");
+  assert!(matches!(
+    frames[1].as_ref().unwrap_err().kind(),
+    ErrorKind::FrameOverrun
+  ));
+}
+
+#[test]
+fn test_frames_short_trailing_frame() {
+  for major in [0x03, 0x04] {
+    for trailing in [&b"TPE1\x00\x00\x00\x01"[..], b"TPE1\x00\x00\x00\x01\x00"] {
+      let mut body: Vec<u8> = b"TIT2\x00\x00\x00\x06\x00\x00\x00Title".to_vec();
+      body.extend(trailing);
+
+      let tag: Tag = Tag::from_reader(tag(major, 0x00, &body).as_slice()).unwrap();
+      let frames: Vec<_> = tag.frames().collect();
+
+      assert_eq!(frames.len(), 2);
+      assert_eq!(frames[0].as_ref().unwrap().identifier_str(), "TIT2");
+      assert!(matches!(
+        frames[1].as_ref().unwrap_err().kind(),
+        ErrorKind::FrameOverrun
+      ));
+    }
+  }
+}
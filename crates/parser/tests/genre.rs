@@ -0,0 +1,66 @@
+mod common;
+
+use parser::content::Content;
+use parser::id3v1::TagV1;
+use parser::types::FrameId;
+use parser::types::Genre;
+use parser::types::Slice;
+use parser::types::Version;
+
+use self::common::text_latin1;
+
+fn tcon(version: Version, text: &str) -> Option<Genre> {
+  let data: Vec<u8> = text_latin1(text);
+  let id: FrameId = FrameId::try_from(*b"TCON").unwrap();
+  let content: Content<'_> = Content::decode(version, id, Slice::new(&data)).unwrap();
+
+  content.as_text().unwrap().genre()
+}
+
+#[test]
+fn test_genre_boundaries() {
+  assert_eq!(Genre::new(0).map(Genre::name), Some("Blues"));
+  assert_eq!(Genre::new(79).map(Genre::name), Some("Hard Rock"));
+  assert_eq!(Genre::new(80).map(Genre::name), Some("Folk"));
+  assert_eq!(Genre::new(191).map(Genre::name), Some("Psybient"));
+  assert_eq!(Genre::new(192), None);
+  assert_eq!(Genre::new(255), None);
+}
+
+#[test]
+fn test_genre_from_name() {
+  assert_eq!(Genre::from_name("Rock").map(Genre::index), Some(17));
+  assert_eq!(Genre::from_name("hard rock").map(Genre::index), Some(79));
+  assert_eq!(Genre::from_name("PSYBIENT").map(Genre::index), Some(191));
+  assert_eq!(Genre::from_name("Rock "), None);
+  assert_eq!(Genre::from_name(""), None);
+
+  for index in 0..Genre::COUNT as u8 {
+    let genre: Genre = Genre::new(index).unwrap();
+
+    assert_eq!(Genre::from_name(genre.name()), Some(genre));
+    assert_eq!(genre.to_string(), genre.name());
+  }
+}
+
+#[test]
+fn test_genre_tcon() {
+  let rock: Option<Genre> = Genre::new(17);
+
+  assert_eq!(tcon(Version::ID3v23, "(17)"), rock);
+  assert_eq!(tcon(Version::ID3v23, "(17)Rock"), rock);
+  assert_eq!(tcon(Version::ID3v24, "17"), rock);
+  assert_eq!(tcon(Version::ID3v24, "rock"), rock);
+  assert_eq!(tcon(Version::ID3v23, "(RX)"), None);
+  assert_eq!(tcon(Version::ID3v24, "Unknown"), None);
+
+  // The ID3v1 genre byte resolves identically.
+  let mut block: Vec<u8> = b"TAG".to_vec();
+  block.resize(127, 0x00);
+  block.push(17);
+
+  let tag: TagV1 = TagV1::from_bytes(&block.try_into().unwrap()).unwrap();
+
+  assert_eq!(tag.standard_genre(), rock);
+  assert_eq!(tag.standard_genre().map(Genre::name), Some("Rock"));
+}
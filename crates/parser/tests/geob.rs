@@ -0,0 +1,85 @@
+mod common;
+
+use parser::content::Geob;
+use parser::id3v2::Tag;
+
+use self::common::frame_v3;
+use self::common::tag;
+
+/// The start of a Serato overview payload (version 1.5, then waveform rows).
+const OVERVIEW: &[u8] = &[0x01, 0x05, 0x01, 0x01, 0x01, 0x02, 0x03, 0x05, 0x08];
+
+fn geob(filename: &str, description: &str, data: &[u8]) -> Vec<u8> {
+  let mut payload: Vec<u8> = vec![0x00];
+  payload.extend_from_slice(b"application/octet-stream\x00");
+  payload.extend_from_slice(filename.as_bytes());
+  payload.push(0x00);
+  payload.extend_from_slice(description.as_bytes());
+  payload.push(0x00);
+  payload.extend_from_slice(data);
+
+  frame_v3(b"GEOB", 0x0000, &payload)
+}
+
+fn serato() -> Tag {
+  let frames: Vec<Vec<u8>> = vec![
+    frame_v3(b"TIT2", 0x0000, b"\x00Title"),
+    geob("", "Serato Analysis", &[0x02, 0x01]),
+    geob("", "Serato Overview", OVERVIEW),
+    geob("", "Serato Markers_", &[0x02, 0x05, 0x00, 0x00, 0x00, 0x0E]),
+  ];
+
+  Tag::from_reader(tag(0x03, 0x00, &frames.concat()).as_slice()).unwrap()
+}
+
+#[test]
+fn test_tag_geob_by_description() {
+  let tag: Tag = serato();
+
+  let descriptions: Vec<String> = tag
+    .geobs()
+    .map(|geob| geob.unwrap().description().to_owned())
+    .collect();
+
+  assert_eq!(
+    descriptions,
+    ["Serato Analysis", "Serato Overview", "Serato Markers_"]
+  );
+
+  let overview: Geob<'_> = tag.geob_by_description("Serato Overview").unwrap().unwrap();
+
+  assert_eq!(overview.mime_type(), "application/octet-stream");
+  assert_eq!(overview.filename(), "");
+  assert_eq!(overview.object_data().unwrap().as_ref(), OVERVIEW);
+  assert_eq!(overview.extract().unwrap().0, "Serato Overview");
+  assert_eq!(overview.extract().unwrap().1.as_ref(), OVERVIEW);
+
+  assert!(tag
+    .geob_by_description("Serato Autotags")
+    .unwrap()
+    .is_none());
+  assert!(tag
+    .geob_by_description("serato overview")
+    .unwrap()
+    .is_none());
+}
+
+#[test]
+fn test_geob_suggested_filename() {
+  let cases: [(&str, &str, &str); 6] = [
+    ("notes.txt", "Notes", "notes.txt"),
+    ("../../etc/passwd", "Notes", "passwd"),
+    ("C:\\Music\\cover.jpg", "Notes", "cover.jpg"),
+    (".hidden", "Notes", "hidden"),
+    ("dir/", "Notes", "Notes"),
+    ("", "", "object"),
+  ];
+
+  for (filename, description, expected) in cases {
+    let data: Vec<u8> = geob(filename, description, b"data");
+    let tag: Tag = Tag::from_reader(tag(0x03, 0x00, &data).as_slice()).unwrap();
+    let geob: Geob<'_> = tag.geobs().next().unwrap().unwrap();
+
+    assert_eq!(geob.suggested_filename(), expected, "{filename:?}");
+  }
+}
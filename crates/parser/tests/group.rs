@@ -0,0 +1,74 @@
+mod common;
+
+use parser::content::Grid;
+use parser::id3v2::Tag;
+
+use self::common::frame_v3;
+use self::common::frame_v4;
+use self::common::tag;
+use self::common::text_latin1;
+
+fn grid(owner: &str, symbol: u8) -> Vec<u8> {
+  let mut payload: Vec<u8> = owner.as_bytes().to_vec();
+  payload.push(0x00);
+  payload.push(symbol);
+  payload.extend_from_slice(&[0x01, 0x02]);
+  frame_v4(b"GRID", 0x0000, &payload)
+}
+
+fn grouped(id: &[u8; 4], group: u8, text: &str) -> Vec<u8> {
+  let mut payload: Vec<u8> = vec![group];
+  payload.extend(text_latin1(text));
+  frame_v4(id, 0x0040, &payload)
+}
+
+fn members(tag: &Tag, group: u8) -> Vec<String> {
+  tag
+    .frames_in_group(group)
+    .map(|frame| frame.unwrap().identifier_str().to_owned())
+    .collect()
+}
+
+#[test]
+fn test_frames_in_group() {
+  let frames: Vec<Vec<u8>> = vec![
+    grid("https://example.com/group", 0x81),
+    grouped(b"TIT2", 0x81, "Title"),
+    frame_v4(b"TPE1", 0x0000, &text_latin1("Artist")),
+    grouped(b"TALB", 0x81, "Album"),
+    grouped(b"TCON", 0x82, "Genre"),
+  ];
+
+  let tag: Tag = Tag::from_reader(tag(0x04, 0x00, &frames.concat()).as_slice()).unwrap();
+
+  let groups: Vec<Option<u8>> = tag
+    .frames()
+    .map(|frame| frame.unwrap().group_id())
+    .collect();
+
+  assert_eq!(groups, [None, Some(0x81), None, Some(0x81), Some(0x82)]);
+  assert_eq!(members(&tag, 0x81), ["TIT2", "TALB"]);
+  assert_eq!(members(&tag, 0x82), ["TCON"]);
+  assert!(members(&tag, 0x83).is_empty());
+
+  // Grouped frames decode without the group identifier.
+  let frame = tag.frames_in_group(0x81).next().unwrap().unwrap();
+  assert_eq!(frame.decode().unwrap().to_string(), "Title");
+
+  let grid: Grid<'_> = tag.group_registration(0x81).unwrap().unwrap();
+
+  assert_eq!(grid.owner_identifier(), "https://example.com/group");
+  assert_eq!(grid.group_data().as_ref(), &[0x01, 0x02]);
+  assert_eq!(tag.group_registration(0x82).unwrap(), None);
+}
+
+#[test]
+fn test_frames_in_group_v3() {
+  let mut payload: Vec<u8> = vec![0x81];
+  payload.extend(text_latin1("Title"));
+
+  let frames: Vec<u8> = frame_v3(b"TIT2", 0x0020, &payload);
+  let tag: Tag = Tag::from_reader(tag(0x03, 0x00, &frames).as_slice()).unwrap();
+
+  assert_eq!(members(&tag, 0x81), ["TIT2"]);
+}
@@ -1,8 +1,20 @@
+mod common;
+
+use parser::error::Error;
+use parser::error::ErrorKind;
+use parser::error::TagField;
+use parser::error::Warning;
+use parser::error::WarningKind;
 use parser::id3v2::Header;
 use parser::id3v2::HeaderFlags;
+use parser::id3v2::Tag;
+use parser::options::ParseOptions;
 use parser::types::Version;
 use std::io::Cursor;
 
+use self::common::synchsafe;
+use self::common::tag;
+
 #[test]
 fn test_parse_header_v2() {
   let buffer: &[u8] = &[b'I', b'D', b'3', 0x02, 0x00, 0x00, 0x00, 0x00, 0x02, 0x01];
@@ -38,3 +50,88 @@ fn test_parse_header_v4() {
   assert_eq!(header.data_len(), 257);
   assert_eq!(header.exheader(), None);
 }
+
+#[test]
+fn test_header_sizes() {
+  let header: Header = Header::from_reader(tag(0x03, 0x00, &[0x00; 100]).as_slice()).unwrap();
+
+  assert_eq!(header.data_len(), 100);
+  assert_eq!(header.frames_len(), 100);
+  assert_eq!(header.total_size(), 110);
+}
+
+#[test]
+fn test_header_sizes_exheader() {
+  // ID3v2.3 extended header with CRC (14 bytes).
+  let mut body: Vec<u8> = vec![0x00, 0x00, 0x00, 0x0A, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00];
+  body.extend([0x00; 4 + 100]);
+
+  let header: Header = Header::from_reader(tag(0x03, 0x40, &body).as_slice()).unwrap();
+
+  assert_eq!(header.frames_len(), 100);
+  assert_eq!(header.total_size(), 124);
+
+  // ID3v2.4 extended header with restrictions (8 bytes) and a footer.
+  let mut body: Vec<u8> = synchsafe(8).to_vec();
+  body.extend([0x01, 0x10, 0x01, 0x00]);
+  body.extend([0x00; 100]);
+
+  let header: Header = Header::from_reader(tag(0x04, 0x50, &body).as_slice()).unwrap();
+
+  assert!(header.flag_footer());
+  assert_eq!(header.frames_len(), 100);
+  assert_eq!(header.total_size(), 128);
+}
+
+#[test]
+fn test_header_sizes_footer() {
+  let header: Header = Header::from_reader(tag(0x04, 0x10, &[0x00; 100]).as_slice()).unwrap();
+
+  assert_eq!(header.frames_len(), 100);
+  assert_eq!(header.total_size(), 120);
+
+  // The footer flag is undefined before ID3v2.4.
+  let data: Vec<u8> = tag(0x03, 0x10, &[0x00; 100]);
+  let options: ParseOptions = ParseOptions::new().lenient(true);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), options).unwrap();
+
+  assert_eq!(tag.header().total_size(), 110);
+}
+
+#[test]
+fn test_header_undefined_flags() {
+  let data: Vec<u8> = tag(0x03, 0x01, &[0x00; 16]);
+  let error: Error = Header::from_reader(data.as_slice()).unwrap_err();
+
+  assert!(matches!(
+    error.kind(),
+    ErrorKind::InvalidField(TagField::Flags)
+  ));
+
+  let error: Error = Header::from_slice(&data).unwrap_err();
+
+  assert!(matches!(
+    error.kind(),
+    ErrorKind::InvalidField(TagField::Flags)
+  ));
+
+  let options: ParseOptions = ParseOptions::new().lenient(true);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), options).unwrap();
+
+  assert_eq!(tag.header().bitflags().bits(), 0x01);
+  assert_eq!(
+    tag.warnings().iter().map(Warning::kind).collect::<Vec<_>>(),
+    [WarningKind::HeaderFlags { bits: 0x01 }]
+  );
+}
+
+#[test]
+fn test_header_defined_flags() {
+  assert_eq!(HeaderFlags::defined(Version::ID3v22).bits(), 0xC0);
+  assert_eq!(HeaderFlags::defined(Version::ID3v23).bits(), 0xE0);
+  assert_eq!(HeaderFlags::defined(Version::ID3v24).bits(), 0xF0);
+
+  assert_eq!(HeaderFlags::all().undefined_bits(Version::ID3v22), 0x30);
+  assert_eq!(HeaderFlags::all().undefined_bits(Version::ID3v23), 0x10);
+  assert_eq!(HeaderFlags::all().undefined_bits(Version::ID3v24), 0x00);
+}
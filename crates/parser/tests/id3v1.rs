@@ -0,0 +1,149 @@
+use parser::id3v1::Speed;
+use parser::id3v1::TagPlus;
+use parser::id3v1::TagV1;
+use std::io::Cursor;
+
+/// Build a 128-byte ID3v1 block, padding each field with `fill`.
+fn block(fields: [&[u8]; 5], fill: u8, genre: u8) -> Vec<u8> {
+  let mut block: Vec<u8> = b"TAG".to_vec();
+
+  for (field, size) in fields.into_iter().zip([30, 30, 30, 4, 30]) {
+    let mut field: Vec<u8> = field.to_vec();
+    field.resize(size, fill);
+    block.extend(field);
+  }
+
+  block.push(genre);
+  block
+}
+
+/// Build a 227-byte enhanced `TAG+` block.
+fn enhanced(fields: [&[u8]; 3], speed: u8, genre: &[u8], times: [&[u8]; 2]) -> Vec<u8> {
+  let mut block: Vec<u8> = b"TAG+".to_vec();
+
+  for field in fields {
+    let mut field: Vec<u8> = field.to_vec();
+    field.resize(60, 0x00);
+    block.extend(field);
+  }
+
+  block.push(speed);
+
+  let mut genre: Vec<u8> = genre.to_vec();
+  genre.resize(30, 0x00);
+  block.extend(genre);
+
+  for time in times {
+    let mut time: Vec<u8> = time.to_vec();
+    time.resize(6, 0x00);
+    block.extend(time);
+  }
+
+  block
+}
+
+fn parse(audio: &[u8], block: &[u8]) -> Option<TagV1> {
+  let data: Vec<u8> = [audio, block].concat();
+  TagV1::from_reader(Cursor::new(data)).unwrap()
+}
+
+#[test]
+fn test_id3v10() {
+  let block: Vec<u8> = block(
+    [b"Title", b"Artist", b"Album", b"2004", b"A comment"],
+    b' ',
+    17,
+  );
+
+  let tag: TagV1 = parse(&[0xFF; 512], &block).unwrap();
+
+  assert_eq!(tag.title(), "Title");
+  assert_eq!(tag.artist(), "Artist");
+  assert_eq!(tag.album(), "Album");
+  assert_eq!(tag.year(), "2004");
+  assert_eq!(tag.comment(), "A comment");
+  assert_eq!(tag.track(), None);
+  assert_eq!(tag.genre(), 17);
+  assert!(!tag.is_v11());
+}
+
+#[test]
+fn test_id3v11() {
+  let mut comment: Vec<u8> = b"Comment".to_vec();
+  comment.resize(28, 0x00);
+  comment.extend([0x00, 0x07]);
+
+  let block: Vec<u8> = block([b"Caf\xE9", b"Artist  ", b"", b"1999", &comment], 0x00, 255);
+
+  let tag: TagV1 = parse(&[0xFF; 512], &block).unwrap();
+
+  assert_eq!(tag.title(), "Café");
+  assert_eq!(tag.artist(), "Artist");
+  assert_eq!(tag.album(), "");
+  assert_eq!(tag.comment(), "Comment");
+  assert_eq!(tag.track(), Some(7));
+  assert_eq!(tag.genre(), 255);
+  assert!(tag.is_v11());
+
+  assert_eq!(TagV1::from_bytes(&block.try_into().unwrap()), Some(tag));
+}
+
+#[test]
+fn test_id3v1_missing() {
+  assert_eq!(parse(&[0xFF; 512], &[0x00; 128]), None);
+  assert_eq!(parse(b"TAG", &[]), None);
+  assert_eq!(parse(&[], &[]), None);
+}
+
+#[test]
+fn test_id3v12_enhanced() {
+  let title: &[u8] = b"A Title Longer Than Thirty Byt";
+  let block: Vec<u8> = [
+    enhanced(
+      [b"es In Total", b"", b""],
+      0x03,
+      b"Progressive Trance",
+      [b"000:05", b"004:31"],
+    ),
+    block([title, b"Artist", b"Album", b"2004", b"Comment"], 0x00, 255),
+  ]
+  .concat();
+
+  let tag: TagV1 = parse(&[0xFF; 512], &block).unwrap();
+  let plus: &TagPlus = tag.enhanced().unwrap();
+
+  assert_eq!(tag.title(), "A Title Longer Than Thirty Bytes In Total");
+  assert_eq!(tag.artist(), "Artist");
+  assert_eq!(tag.album(), "Album");
+  assert_eq!(plus.speed(), Speed::Fast);
+  assert_eq!(plus.genre(), "Progressive Trance");
+  assert_eq!(plus.start_time(), "000:05");
+  assert_eq!(plus.end_time(), "004:31");
+
+  // The enhanced block is only detected immediately before the tag.
+  let (plus, v1): (&[u8], &[u8]) = block.split_at(TagPlus::SIZE);
+  let tag: TagV1 = parse(&[plus, &[0x00]].concat(), v1).unwrap();
+
+  assert!(tag.enhanced().is_none());
+  assert_eq!(tag.title(), "A Title Longer Than Thirty Byt");
+}
+
+#[test]
+fn test_id3v12_absent() {
+  let v1: Vec<u8> = block([b"Title", b"", b"", b"", b""], 0x00, 0);
+
+  // Only the 128-byte tag exists.
+  let tag: TagV1 = parse(&[], &v1).unwrap();
+
+  assert!(tag.enhanced().is_none());
+  assert_eq!(tag.title(), "Title");
+
+  // Audio data that happens to contain "TAG+".
+  let mut audio: Vec<u8> = vec![0xFF; TagPlus::SIZE];
+  audio[..4].copy_from_slice(b"TAG+");
+
+  let tag: TagV1 = parse(&audio, &v1).unwrap();
+
+  assert!(tag.enhanced().is_none());
+  assert_eq!(tag.title(), "Title");
+}
@@ -0,0 +1,356 @@
+mod common;
+
+use parser::content::Chap;
+use parser::content::Content;
+use parser::content::Ctoc;
+use parser::content::CtocItem;
+use parser::error::ErrorKind;
+use parser::error::Result;
+use parser::error::SizeLimit;
+use parser::frame::DynFrame;
+use parser::id3v2::ParseEvent;
+use parser::id3v2::Tag;
+use parser::id3v2::TagParser;
+use parser::id3v2::TagStats;
+use parser::options::ParseOptions;
+use std::time::Duration;
+use std::time::Instant;
+
+use self::common::frame_v3;
+use self::common::frame_v4;
+use self::common::tag;
+use self::common::text_latin1;
+#[cfg(feature = "zlib")]
+use self::common::zlib_stored;
+use self::common::RawFrame;
+
+/// Build an ID3v2.3 tag with `count` minimal frames.
+fn minimal_tag(count: usize) -> Vec<u8> {
+  let frame: Vec<u8> = frame_v3(b"TIT2", 0x0000, &[0x00]);
+  let body: Vec<u8> = frame.repeat(count);
+
+  tag(0x03, 0x00, &body)
+}
+
+fn sub_frames(count: usize) -> Vec<u8> {
+  (0..count)
+    .flat_map(|index| frame_v4(b"TIT2", 0x0000, &text_latin1(&format!("Part {index}"))))
+    .collect()
+}
+
+fn decode(raw: &RawFrame) -> Content<'_> {
+  raw.frame().decode().unwrap()
+}
+
+fn error_kinds<T>(items: impl Iterator<Item = Result<T>>) -> (usize, Vec<ErrorKind>) {
+  let mut count: usize = 0;
+  let mut errors: Vec<ErrorKind> = Vec::new();
+
+  for item in items {
+    match item {
+      Ok(_) => count += 1,
+      Err(error) => errors.push(error.kind()),
+    }
+  }
+
+  (count, errors)
+}
+
+#[test]
+fn test_frame_limit_default() {
+  let input: Vec<u8> = minimal_tag(ParseOptions::DEFAULT_MAX_FRAMES + 50);
+  let start: Instant = Instant::now();
+  let tag: Tag = Tag::from_reader(input.as_slice()).unwrap();
+
+  let (count, errors): (usize, Vec<ErrorKind>) = error_kinds(tag.frames());
+
+  assert_eq!(count, ParseOptions::DEFAULT_MAX_FRAMES);
+  assert!(matches!(errors[..], [ErrorKind::FrameLimit]));
+
+  let stats: TagStats = tag.stats();
+
+  assert_eq!(stats.frame_count(), ParseOptions::DEFAULT_MAX_FRAMES);
+  assert_eq!(stats.frame_limit(), ParseOptions::DEFAULT_MAX_FRAMES);
+  assert!(stats.is_limit_reached());
+  assert!(!stats.is_invalid());
+
+  assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn test_frame_limit_custom() {
+  let input: Vec<u8> = minimal_tag(10);
+  let options: ParseOptions = ParseOptions::new().max_frames(3);
+  let tag: Tag = Tag::from_reader_with(input.as_slice(), options).unwrap();
+
+  let (count, errors): (usize, Vec<ErrorKind>) = error_kinds(tag.frames());
+
+  assert_eq!(count, 3);
+  assert!(matches!(errors[..], [ErrorKind::FrameLimit]));
+  assert_eq!(tag.stats().frame_limit(), 3);
+
+  // Exactly as many frames as the limit is not an error.
+  let tag: Tag = Tag::from_reader_with(minimal_tag(3).as_slice(), options).unwrap();
+  let stats: TagStats = tag.stats();
+
+  assert_eq!(stats.frame_count(), 3);
+  assert!(!stats.is_limit_reached());
+}
+
+#[test]
+fn test_frame_limit_parser() {
+  let input: Vec<u8> = minimal_tag(10);
+  let mut parser: TagParser = TagParser::new(ParseOptions::new().max_frames(4));
+  let mut frames: Vec<Option<ErrorKind>> = Vec::new();
+
+  parser.feed(&input);
+
+  loop {
+    match parser.poll().unwrap() {
+      ParseEvent::NeedMore(_) => panic!("parser requested more data"),
+      ParseEvent::Header(_) => {}
+      ParseEvent::Frame(frame) => frames.push(frame.err().map(|error| error.kind())),
+      ParseEvent::Done => break,
+    }
+  }
+
+  assert_eq!(frames.len(), 5);
+  assert!(frames[..4].iter().all(Option::is_none));
+  assert!(matches!(frames[4], Some(ErrorKind::FrameLimit)));
+  assert_eq!(parser.consumed(), input.len());
+}
+
+#[test]
+fn test_sub_frame_limit_chap() {
+  let mut payload: Vec<u8> = b"ch1\x00".to_vec();
+  payload.extend([0x00; 16]);
+  payload.extend(sub_frames(5));
+
+  let raw: RawFrame = RawFrame::v4(b"CHAP", &payload);
+  let Content::Chap(chap) = decode(&raw) else {
+    panic!("expected CHAP");
+  };
+
+  let chap: &Chap<'_> = &chap;
+  let (count, errors): (usize, Vec<ErrorKind>) = error_kinds(chap.frames());
+
+  assert_eq!(count, 5);
+  assert!(errors.is_empty());
+
+  let options: ParseOptions = ParseOptions::new().max_sub_frames(2);
+  let (count, errors): (usize, Vec<ErrorKind>) = error_kinds(chap.frames_with(options));
+
+  assert_eq!(count, 2);
+  assert!(matches!(errors[..], [ErrorKind::FrameLimit]));
+}
+
+#[test]
+fn test_sub_frame_limit_ctoc() {
+  let mut payload: Vec<u8> = b"toc\x00".to_vec();
+  payload.extend([0x03, 0x02]);
+  payload.extend(b"ch1\x00ch2\x00");
+  payload.extend(sub_frames(4));
+
+  let raw: RawFrame = RawFrame::v4(b"CTOC", &payload);
+  let Content::Ctoc(ctoc) = decode(&raw) else {
+    panic!("expected CTOC");
+  };
+
+  let ctoc: &Ctoc<'_> = &ctoc;
+  let items: Vec<CtocItem<'_>> = ctoc.elements().collect::<Result<_>>().unwrap();
+
+  assert!(matches!(
+    items[..2],
+    [CtocItem::Entry(_), CtocItem::Entry(_)]
+  ));
+  assert!(items[2..]
+    .iter()
+    .all(|item| matches!(item, CtocItem::Frame(_))));
+  assert_eq!(items.len(), 6);
+
+  let options: ParseOptions = ParseOptions::new().max_sub_frames(1);
+  let (count, errors): (usize, Vec<ErrorKind>) = error_kinds(ctoc.elements_with(options));
+
+  assert_eq!(count, 3);
+  assert!(matches!(errors[..], [ErrorKind::FrameLimit]));
+}
+
+#[test]
+fn test_sub_frame_corrupt() {
+  // A valid sub-frame followed by one with an invalid identifier.
+  let mut frames: Vec<u8> = sub_frames(1);
+  frames.extend(frame_v4(b"T!T2", 0x0000, &text_latin1("Part 1")));
+  frames.extend(sub_frames(1));
+
+  let mut payload: Vec<u8> = b"ch1\x00".to_vec();
+  payload.extend([0x00; 16]);
+  payload.extend(&frames);
+
+  let raw: RawFrame = RawFrame::v4(b"CHAP", &payload);
+  let Content::Chap(chap) = decode(&raw) else {
+    panic!("expected CHAP");
+  };
+
+  let (count, errors): (usize, Vec<ErrorKind>) = error_kinds(chap.frames().take(16));
+
+  assert_eq!(count, 1);
+  assert!(matches!(errors[..], [ErrorKind::InvalidFrameId]));
+
+  let mut payload: Vec<u8> = b"toc\x00".to_vec();
+  payload.extend([0x03, 0x01]);
+  payload.extend(b"ch1\x00");
+  payload.extend(&frames);
+
+  let raw: RawFrame = RawFrame::v4(b"CTOC", &payload);
+  let Content::Ctoc(ctoc) = decode(&raw) else {
+    panic!("expected CTOC");
+  };
+
+  let (count, errors): (usize, Vec<ErrorKind>) = error_kinds(ctoc.elements().take(16));
+
+  assert_eq!(count, 2);
+  assert!(matches!(errors[..], [ErrorKind::InvalidFrameId]));
+}
+
+fn is_size_limit<T>(result: &Result<T>, limit: SizeLimit) -> bool {
+  match result {
+    Ok(_) => false,
+    Err(error) => match error.kind() {
+      ErrorKind::SizeLimit(kind) => format!("{kind:?}") == format!("{limit:?}"),
+      _ => false,
+    },
+  }
+}
+
+#[test]
+fn test_tag_size_limit() {
+  // A header declaring a 256 MB tag, without any tag data.
+  let data: &[u8] = b"ID3\x04\x00\x00\x7F\x7F\x7F\x7F";
+
+  let result: Result<Tag> = Tag::from_reader(data);
+  assert!(is_size_limit(&result, SizeLimit::Tag));
+
+  let mut parser: TagParser = TagParser::new(ParseOptions::new());
+  parser.feed(data);
+  assert!(is_size_limit(&parser.poll(), SizeLimit::Tag));
+
+  // Without limits the missing tag data is reported instead.
+  let options: ParseOptions = ParseOptions::new().no_limits();
+  let error = Tag::from_reader_with(data, options).unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::IO));
+
+  let data: Vec<u8> = minimal_tag(4);
+  let options: ParseOptions = ParseOptions::new().max_tag_size(data.len() - 1);
+  assert!(is_size_limit(
+    &Tag::from_reader_with(data.as_slice(), options),
+    SizeLimit::Tag
+  ));
+
+  let options: ParseOptions = ParseOptions::new().max_tag_size(data.len());
+  assert!(Tag::from_reader_with(data.as_slice(), options).is_ok());
+}
+
+#[test]
+fn test_frame_size_limit() {
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(frame_v3(b"TXXX", 0x0000, &[0x00; 1000]));
+  body.extend(frame_v3(b"TPE1", 0x0000, &text_latin1("Artist")));
+
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let options: ParseOptions = ParseOptions::new().max_frame_size(100);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), options).unwrap();
+  let frames: Vec<Result<DynFrame<'_>>> = tag.frames().collect();
+
+  assert_eq!(frames.len(), 2);
+  assert!(frames[0].is_ok());
+  assert!(is_size_limit(&frames[1], SizeLimit::Frame));
+
+  let mut parser: TagParser = TagParser::new(options);
+  let mut events: Vec<Result<usize>> = Vec::new();
+
+  parser.feed(&data);
+
+  loop {
+    match parser.poll().unwrap() {
+      ParseEvent::Header(_) => {}
+      ParseEvent::Frame(frame) => events.push(frame.map(|frame| frame.total_size())),
+      ParseEvent::NeedMore(_) | ParseEvent::Done => break,
+    }
+  }
+
+  assert_eq!(events.len(), 2);
+  assert!(is_size_limit(&events[1], SizeLimit::Frame));
+}
+
+#[test]
+fn test_decompressed_size_limit() {
+  // ID3v2.3 TIT2 frame with COMPRESSION set, declaring 2 GB of content.
+  let mut data: Vec<u8> = 0x7FFF_FFFF_u32.to_be_bytes().to_vec();
+  data.extend([0x78, 0x01]);
+
+  let body: Vec<u8> = frame_v3(b"TIT2", 0x0080, &data);
+  let tag: Tag = Tag::from_reader(self::common::tag(0x03, 0x00, &body).as_slice()).unwrap();
+  let frame: DynFrame<'_> = tag.frames().next().unwrap().unwrap();
+
+  assert!(is_size_limit(
+    &tag.decode_frame(&frame, &mut Vec::new()),
+    SizeLimit::Decompressed
+  ));
+}
+
+#[test]
+fn test_decompressed_size_limit_unsync() {
+  // ID3v2.4 TIT2 frame with COMPRESSION and UNSYNCHRONISATION set, declaring
+  // 256 MB of content.
+  let mut data: Vec<u8> = vec![0x7F, 0x7F, 0x7F, 0x7F];
+  data.extend([0x78, 0x01]);
+
+  let body: Vec<u8> = frame_v4(b"TIT2", 0x000B, &data);
+  let data: Vec<u8> = tag(0x04, 0x00, &body);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let frame: DynFrame<'_> = tag.frames().next().unwrap().unwrap();
+
+  assert!(is_size_limit(
+    &tag.decode_frame(&frame, &mut Vec::new()),
+    SizeLimit::Decompressed
+  ));
+
+  // Uncompressed content is bounded by the frame data instead.
+  let mut data: Vec<u8> = vec![0x7F, 0x7F, 0x7F, 0x7F];
+  data.extend(text_latin1("Title"));
+
+  let body: Vec<u8> = frame_v4(b"TIT2", 0x0003, &data);
+  let data: Vec<u8> = self::common::tag(0x04, 0x00, &body);
+  let options: ParseOptions = ParseOptions::new().lenient(true);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), options).unwrap();
+  let frame: DynFrame<'_> = tag.frames().next().unwrap().unwrap();
+
+  assert!(tag.decode_frame(&frame, &mut Vec::new()).is_ok());
+}
+
+#[cfg(feature = "zlib")]
+#[test]
+fn test_decompressed_size_limit_content() {
+  // The content decompresses to more than its declared size.
+  let mut content: Vec<u8> = vec![0x00];
+  content.extend([b'A'; 64]);
+
+  let mut data: Vec<u8> = 16_u32.to_be_bytes().to_vec();
+  data.extend(zlib_stored(&content));
+
+  let body: Vec<u8> = frame_v3(b"TIT2", 0x0080, &data);
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let options: ParseOptions = ParseOptions::new().max_decompressed_size(32);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), options).unwrap();
+  let frame: DynFrame<'_> = tag.frames().next().unwrap().unwrap();
+
+  assert!(is_size_limit(
+    &tag.decode_frame(&frame, &mut Vec::new()),
+    SizeLimit::Decompressed
+  ));
+
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let frame: DynFrame<'_> = tag.frames().next().unwrap().unwrap();
+
+  assert!(tag.decode_frame(&frame, &mut Vec::new()).is_ok());
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use parser::id3v2::Placement;
+use parser::id3v2::Tag;
+use parser::id3v2::TagLocation;
+use std::io::Cursor;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+use self::common::frame_v4;
+use self::common::tag;
+use self::common::tag_footer;
+use self::common::text_latin1;
+use self::common::title;
+
+const AUDIO: &[u8] = &[0xFF; 64];
+
+fn tag_v1() -> Vec<u8> {
+  let mut data: Vec<u8> = vec![0x00; 128];
+
+  data[..3].copy_from_slice(b"TAG");
+  data[3..8].copy_from_slice(b"Title");
+  data
+}
+
+#[test]
+fn test_locate_appended() {
+  let appended: Vec<u8> = tag_footer(&frame_v4(b"TIT2", 0x0000, &text_latin1("End")));
+  let data: Vec<u8> = [AUDIO, &appended].concat();
+  let mut reader: Cursor<&[u8]> = Cursor::new(data.as_slice());
+
+  reader.seek(SeekFrom::Start(5)).unwrap();
+
+  let locations: Vec<TagLocation> = Tag::locate(&mut reader).unwrap();
+
+  assert_eq!(reader.position(), 5);
+  assert_eq!(locations.len(), 1);
+  assert_eq!(locations[0].offset(), AUDIO.len() as u64);
+  assert_eq!(locations[0].size(), appended.len() as u64);
+  assert_eq!(locations[0].placement(), Placement::Appended);
+
+  let tags: Vec<(TagLocation, Tag)> = Tag::from_reader_all(&mut reader).unwrap();
+
+  assert_eq!(tags.len(), 1);
+  assert_eq!(title(&tags[0].1).as_deref(), Some("End"));
+}
+
+#[test]
+fn test_locate_appended_before_id3v1() {
+  let appended: Vec<u8> = tag_footer(&frame_v4(b"TIT2", 0x0000, &text_latin1("End")));
+  let data: Vec<u8> = [AUDIO, &appended, &tag_v1()].concat();
+  let mut reader: Cursor<&[u8]> = Cursor::new(data.as_slice());
+  let locations: Vec<TagLocation> = Tag::locate(&mut reader).unwrap();
+
+  assert_eq!(locations.len(), 1);
+  assert_eq!(locations[0].offset(), AUDIO.len() as u64);
+  assert_eq!(locations[0].placement(), Placement::Appended);
+}
+
+#[test]
+fn test_locate_prepended_and_appended() {
+  let prepended: Vec<u8> = tag(
+    0x04,
+    0x00,
+    &frame_v4(b"TIT2", 0x0000, &text_latin1("Start")),
+  );
+  let appended: Vec<u8> = tag_footer(&frame_v4(b"TIT2", 0x0000, &text_latin1("End")));
+  let data: Vec<u8> = [&prepended, AUDIO, &appended].concat();
+  let mut reader: Cursor<&[u8]> = Cursor::new(data.as_slice());
+  let tags: Vec<(TagLocation, Tag)> = Tag::from_reader_all(&mut reader).unwrap();
+
+  assert_eq!(tags.len(), 2);
+
+  assert_eq!(tags[0].0.offset(), 0);
+  assert_eq!(tags[0].0.size(), prepended.len() as u64);
+  assert_eq!(tags[0].0.placement(), Placement::Prepended);
+  assert_eq!(title(&tags[0].1).as_deref(), Some("Start"));
+
+  assert_eq!(tags[1].0.offset(), (prepended.len() + AUDIO.len()) as u64);
+  assert_eq!(tags[1].0.placement(), Placement::Appended);
+  assert_eq!(title(&tags[1].1).as_deref(), Some("End"));
+}
+
+#[test]
+fn test_locate_prepended_footer() {
+  // A tag with a footer and no audio is only reported once.
+  let data: Vec<u8> = tag_footer(&frame_v4(b"TIT2", 0x0000, &text_latin1("Only")));
+  let mut reader: Cursor<&[u8]> = Cursor::new(data.as_slice());
+  let locations: Vec<TagLocation> = Tag::locate(&mut reader).unwrap();
+
+  assert_eq!(locations.len(), 1);
+  assert_eq!(locations[0].size(), data.len() as u64);
+  assert_eq!(locations[0].placement(), Placement::Prepended);
+}
+
+#[test]
+fn test_locate_none() {
+  let data: Vec<u8> = [AUDIO, &tag_v1()].concat();
+  let mut reader: Cursor<&[u8]> = Cursor::new(data.as_slice());
+
+  assert!(Tag::locate(&mut reader).unwrap().is_empty());
+  assert!(Tag::from_reader_all(&mut reader).unwrap().is_empty());
+}
@@ -0,0 +1,355 @@
+mod common;
+
+use parser::content::Apic;
+use parser::content::Comm;
+use parser::content::PicType;
+use parser::content::Uslt;
+use parser::id3v2::LangFrameIter;
+use parser::id3v2::Tag;
+use parser::options::ParseOptions;
+use std::time::Duration;
+
+use self::common::comm;
+use self::common::frame_v2;
+use self::common::frame_v3;
+use self::common::frame_v4;
+use self::common::tag;
+use self::common::text_latin1;
+use self::common::text_utf8;
+
+fn tag_v22() -> Tag {
+  let mut body: Vec<u8> = frame_v2(b"TT2", &text_latin1("Title"));
+  body.extend(frame_v2(b"TP1", &text_latin1("Artist")));
+
+  Tag::from_reader(tag(0x02, 0x00, &body).as_slice()).unwrap()
+}
+
+fn tag_v23() -> Tag {
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(frame_v3(b"TPE1", 0x0000, &text_latin1("Artist")));
+  body.extend(frame_v3(b"TPE1", 0x0000, &text_latin1("Other")));
+
+  Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap()
+}
+
+#[test]
+fn test_get() {
+  let tag: Tag = tag_v23();
+
+  assert_eq!(tag.get("TIT2").unwrap().identifier_str(), "TIT2");
+  assert_eq!(
+    tag.get_content("TPE1").unwrap().unwrap().to_string(),
+    "Artist"
+  );
+  assert!(tag.get("TALB").is_none());
+  assert!(tag.get_content("TALB").is_none());
+
+  // Lookups are case-sensitive unless requested otherwise.
+  assert!(tag.get("tit2").is_none());
+  assert_eq!(tag.get_caseless("tit2").unwrap().identifier_str(), "TIT2");
+}
+
+#[test]
+fn test_get_alias() {
+  let tag: Tag = tag_v22();
+
+  assert_eq!(tag.get("TIT2").unwrap().identifier_str(), "TT2");
+  assert_eq!(
+    tag.get_content("TPE1").unwrap().unwrap().to_string(),
+    "Artist"
+  );
+  assert_eq!(
+    tag.get_content("TP1").unwrap().unwrap().to_string(),
+    "Artist"
+  );
+  assert_eq!(tag.get_caseless("tpe1").unwrap().identifier_str(), "TP1");
+  assert!(tag.get("TALB").is_none());
+
+  let tag: Tag = tag_v23();
+
+  assert_eq!(tag.get("TT2").unwrap().identifier_str(), "TIT2");
+}
+
+#[test]
+fn test_frames_by_id() {
+  let txxx = |description: &str, text: &str| -> Vec<u8> {
+    let mut data: Vec<u8> = vec![0x00];
+    data.extend(description.as_bytes());
+    data.push(0x00);
+    data.extend(text.as_bytes());
+    frame_v3(b"TXXX", 0x0000, &data)
+  };
+
+  let mut body: Vec<u8> = frame_v3(b"COMM", 0x0000, &comm(b"eng", "", "First"));
+  body.extend(txxx("A", "1"));
+  body.extend(frame_v3(b"COMM", 0x0000, &comm(b"eng", "Second", "2")));
+  body.extend(frame_v3(b"TIT2", 0x0000, &text_latin1("Title")));
+  body.extend(txxx("B", "2"));
+  body.extend(frame_v3(b"COMM", 0x0000, &comm(b"eng", "Third", "3")));
+
+  let tag: Tag = Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap();
+
+  let comments: Vec<String> = tag
+    .frames_by_id("COMM")
+    .filter_map(|frame| frame.decode().ok())
+    .map(|content| content.to_string())
+    .collect();
+
+  assert_eq!(
+    comments,
+    ["()[eng]: First", "(Second)[eng]: 2", "(Third)[eng]: 3"]
+  );
+  assert_eq!(tag.frames_by_id("TXXX").count(), 2);
+  assert_eq!(tag.frames_by_id("TXX").count(), 2);
+  assert_eq!(tag.frames_by_id("APIC").count(), 0);
+  assert_eq!(tag.frames_by_id("comm").count(), 0);
+
+  let descriptions: Vec<String> = tag
+    .frames_by_id("TXXX")
+    .filter_map(|frame| frame.decode().ok())
+    .map(|content| content.to_string())
+    .collect();
+
+  assert_eq!(descriptions, ["(A): 1", "(B): 2"]);
+}
+
+/// Build the same metadata in an ID3v2.2, ID3v2.3 and ID3v2.4 tag.
+fn metadata() -> [Tag; 3] {
+  let fields: [(&[u8; 3], &[u8; 4], &str); 8] = [
+    (b"TT2", b"TIT2", "Title"),
+    (b"TP1", b"TPE1", "Artist"),
+    (b"TAL", b"TALB", "Album"),
+    (b"TP2", b"TPE2", "Band"),
+    (b"TRK", b"TRCK", "3/12"),
+    (b"TPA", b"TPOS", "1/2"),
+    (b"TCO", b"TCON", "(17)"),
+    (b"TLE", b"TLEN", "215000"),
+  ];
+
+  let mut v22: Vec<u8> = frame_v2(b"TYE", &text_latin1("1999"));
+  let mut v23: Vec<u8> = frame_v3(b"TYER", 0x0000, &text_latin1("1999"));
+  let mut v24: Vec<u8> = frame_v4(b"TDRC", 0x0000, &text_utf8("1999-04-01"));
+
+  for (id22, id23, value) in fields {
+    v22.extend(frame_v2(id22, &text_latin1(value)));
+    v23.extend(frame_v3(id23, 0x0000, &text_latin1(value)));
+    v24.extend(frame_v4(id23, 0x0000, &text_utf8(value)));
+  }
+
+  [(0x02, v22), (0x03, v23), (0x04, v24)]
+    .map(|(major, body)| Tag::from_reader(tag(major, 0x00, &body).as_slice()).unwrap())
+}
+
+#[test]
+fn test_accessors() {
+  for tag in metadata() {
+    assert_eq!(tag.title().as_deref(), Some("Title"));
+    assert_eq!(tag.artist().as_deref(), Some("Artist"));
+    assert_eq!(tag.album().as_deref(), Some("Album"));
+    assert_eq!(tag.album_artist().as_deref(), Some("Band"));
+    assert_eq!(tag.genre().as_deref(), Some("Rock"));
+    assert_eq!(tag.year(), Some(1999));
+    assert_eq!(tag.duration(), Some(Duration::from_millis(215000)));
+
+    let track = tag.track().unwrap();
+    assert_eq!((track.current(), track.total()), (3, Some(12)));
+
+    let disc = tag.disc().unwrap();
+    assert_eq!((disc.current(), disc.total()), (1, Some(2)));
+  }
+
+  let [v22, v23, v24] = metadata();
+
+  assert_eq!(v22.date_recorded().as_deref(), Some("1999"));
+  assert_eq!(v23.date_recorded().as_deref(), Some("1999"));
+  assert_eq!(v24.date_recorded().as_deref(), Some("1999-04-01"));
+}
+
+#[test]
+fn test_accessors_multiple() {
+  let mut body: Vec<u8> = frame_v4(b"TPE1", 0x0000, &text_utf8("One\0Two"));
+  body.extend(frame_v4(b"TCON", 0x0000, &text_utf8("17\0Jazz Fusion")));
+
+  let multiple: Tag = Tag::from_reader(tag(0x04, 0x00, &body).as_slice()).unwrap();
+
+  assert_eq!(multiple.artist().as_deref(), Some("One"));
+  assert_eq!(multiple.artist_all(), ["One", "Two"]);
+  assert_eq!(multiple.genre_all(), ["Rock", "Jazz Fusion"]);
+
+  // Missing and undecodable frames yield nothing.
+  assert_eq!(multiple.title(), None);
+  assert!(multiple.title_all().is_empty());
+  assert_eq!(multiple.track(), None);
+  assert_eq!(multiple.year(), None);
+  assert_eq!(multiple.duration(), None);
+
+  let body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &[0x07, b'X']);
+  let invalid: Tag = Tag::from_reader(tag(0x04, 0x00, &body).as_slice()).unwrap();
+
+  assert_eq!(invalid.title(), None);
+}
+
+#[test]
+fn test_accessors_lenient() {
+  let mut body: Vec<u8> = frame_v4(b"TIT2", 0x0000, b"\x03Ti\xFFle");
+  body.extend(frame_v4(b"T!T2", 0x0000, &text_utf8("Bad")));
+
+  let data: Vec<u8> = tag(0x04, 0x00, &body);
+  let strict: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let lenient: Tag =
+    Tag::from_reader_with(data.as_slice(), ParseOptions::new().lenient(true)).unwrap();
+
+  // Text is decoded with the options used to parse the tag.
+  assert_eq!(strict.title(), None);
+  assert_eq!(lenient.title().as_deref(), Some("Ti\u{FFFD}le"));
+  assert!(strict.get_content("TIT2").unwrap().is_err());
+  assert_eq!(
+    lenient.get_content("TIT2").unwrap().unwrap().to_string(),
+    "Ti\u{FFFD}le"
+  );
+
+  // An invalid frame does not affect lookups of other frames.
+  assert!(strict.grouping().unwrap().is_none());
+  assert!(strict.album_sort().unwrap().is_none());
+}
+
+/// Build an attached picture frame of the given `major` version.
+fn picture(major: u8, kind: u8, description: &str) -> Vec<u8> {
+  let mut data: Vec<u8> = vec![0x00];
+
+  if major == 0x02 {
+    data.extend(b"PNG");
+  } else {
+    data.extend(b"image/png\x00");
+  }
+
+  data.push(kind);
+  data.extend(description.as_bytes());
+  data.extend(b"\x00\x89PNG");
+
+  match major {
+    0x02 => frame_v2(b"PIC", &data),
+    0x03 => frame_v3(b"APIC", 0x0000, &data),
+    _ => frame_v4(b"APIC", 0x0000, &data),
+  }
+}
+
+#[test]
+fn test_pictures() {
+  for major in [0x02, 0x03, 0x04] {
+    let mut body: Vec<u8> = picture(major, 0x04, "Back");
+    body.extend(picture(major, 0x03, "Front"));
+
+    let tag: Tag = Tag::from_reader(tag(major, 0x00, &body).as_slice()).unwrap();
+    let pictures: Vec<Apic<'_>> = tag.pictures().map(Result::unwrap).collect();
+
+    assert_eq!(pictures.len(), 2);
+    assert_eq!(pictures[0].picture_type(), PicType::CoverBack);
+    assert_eq!(pictures[1].picture_type(), PicType::CoverFront);
+    assert_eq!(tag.front_cover().unwrap().description(), "Front");
+  }
+}
+
+#[test]
+fn test_front_cover_fallback() {
+  let mut body: Vec<u8> = picture(0x03, 0x04, "Back");
+  body.extend(picture(0x03, 0x00, "Other"));
+
+  let fallback: Tag = Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap();
+
+  assert_eq!(fallback.front_cover().unwrap().description(), "Back");
+
+  // Pictures that cannot be decoded are reported, but skipped for the cover.
+  let mut body: Vec<u8> = frame_v3(b"APIC", 0x0000, &[0x07, b'X']);
+  body.extend(picture(0x03, 0x03, "Front"));
+
+  let invalid: Tag = Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap();
+  let pictures: Vec<_> = invalid.pictures().collect();
+
+  assert_eq!(pictures.len(), 2);
+  assert!(pictures[0].is_err());
+  assert_eq!(invalid.front_cover().unwrap().description(), "Front");
+
+  let empty: Tag = Tag::from_reader(tag(0x03, 0x00, &[0x00; 8]).as_slice()).unwrap();
+
+  assert!(empty.front_cover().is_none());
+}
+
+#[test]
+fn test_comments() {
+  let mut body: Vec<u8> = frame_v3(b"COMM", 0x0000, &comm(b"eng", "", "English"));
+  body.extend(frame_v3(
+    b"COMM",
+    0x0000,
+    &comm(b"eng", "iTunNORM", " 0000"),
+  ));
+  body.extend(frame_v3(b"COMM", 0x0000, &comm(b"deu", "", "Deutsch")));
+  body.extend(frame_v3(b"COMM", 0x0000, &comm(b"eng", "Note", "Other")));
+  body.extend(frame_v3(b"USLT", 0x0000, &comm(b"eng", "", "Lyrics")));
+
+  let tag: Tag = Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap();
+
+  let details = |iter: LangFrameIter<'_, Comm<'_>>| -> Vec<String> {
+    iter
+      .map(|comm| comm.unwrap().text_details().to_owned())
+      .collect()
+  };
+
+  assert_eq!(tag.comments().count(), 4);
+  assert_eq!(
+    details(tag.comments().with_language("ENG")),
+    ["English", " 0000", "Other"]
+  );
+  assert_eq!(
+    details(tag.comments().skip_itunes()),
+    ["English", "Deutsch", "Other"]
+  );
+  assert_eq!(
+    details(tag.comments().with_language("eng").with_description("")),
+    ["English"]
+  );
+
+  let lyrics: Vec<Uslt<'_>> = tag.lyrics_all().map(Result::unwrap).collect();
+
+  assert_eq!(lyrics.len(), 1);
+  assert_eq!(lyrics[0].lyrics(), "Lyrics");
+}
+
+#[test]
+fn test_comments_v22() {
+  let mut body: Vec<u8> = frame_v2(b"COM", &comm(b"eng", "", "Comment"));
+  body.extend(frame_v2(b"COM", &[0x07]));
+  body.extend(frame_v2(b"ULT", &comm(b"eng", "", "Lyrics")));
+
+  let tag: Tag = Tag::from_reader(tag(0x02, 0x00, &body).as_slice()).unwrap();
+  let comments: Vec<_> = tag.comments().collect();
+
+  // Decode failures are reported without ending iteration.
+  assert_eq!(comments.len(), 2);
+  assert_eq!(comments[0].as_ref().unwrap().text_details(), "Comment");
+  assert!(comments[1].is_err());
+  assert_eq!(tag.lyrics_all().count(), 1);
+}
+
+#[test]
+fn test_contents() {
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(frame_v3(b"TALB", 0x0000, &[0x07, b'X']));
+  body.extend(frame_v3(b"TPE1", 0x0000, &text_latin1("Artist")));
+
+  let tag: Tag = Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap();
+  let contents: Vec<_> = tag.contents().collect();
+
+  assert_eq!(contents.len(), 3);
+
+  let (frame, content) = contents[0].as_ref().unwrap();
+  assert_eq!(frame.identifier_str(), "TIT2");
+  assert_eq!(content.to_string(), "Title");
+
+  let error = contents[1].as_ref().unwrap_err();
+  assert_eq!(error.frame().unwrap().to_string(), "TALB");
+
+  let (frame, content) = contents[2].as_ref().unwrap();
+  assert_eq!(frame.identifier_str(), "TPE1");
+  assert_eq!(content.to_string(), "Artist");
+}
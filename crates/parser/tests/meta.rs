@@ -0,0 +1,125 @@
+mod common;
+
+use parser::meta::read_path;
+use parser::meta::FileMeta;
+use parser::meta::Precedence;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use self::common::frame_v3;
+use self::common::tag;
+use self::common::text_latin1;
+
+/// A scratch file that is removed when dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+  fn new(name: &str, data: &[u8]) -> Self {
+    let path: PathBuf =
+      std::env::temp_dir().join(format!("errai-{name}-{}.mp3", std::process::id()));
+    fs::write(&path, data).unwrap();
+    Self(path)
+  }
+
+  fn path(&self) -> &Path {
+    &self.0
+  }
+}
+
+impl Drop for TempFile {
+  fn drop(&mut self) {
+    let _ = fs::remove_file(&self.0);
+  }
+}
+
+/// Build a 128-byte ID3v1.1 tag.
+fn v1(title: &str, artist: &str, track: u8, genre: u8) -> Vec<u8> {
+  let mut block: Vec<u8> = b"TAG".to_vec();
+
+  for (field, size) in [
+    (title, 30),
+    (artist, 30),
+    ("", 30),
+    ("1999", 4),
+    ("V1 comment", 28),
+  ] {
+    let mut field: Vec<u8> = field.as_bytes().to_vec();
+    field.resize(size, 0x00);
+    block.extend(field);
+  }
+
+  block.extend([0x00, track, genre]);
+  block
+}
+
+fn v2() -> Vec<u8> {
+  let body: Vec<u8> = [
+    frame_v3(b"TIT2", 0x0000, &text_latin1("V2 Title")),
+    frame_v3(b"TRCK", 0x0000, &text_latin1("3/10")),
+    frame_v3(b"TYER", 0x0000, &text_latin1("2004")),
+    frame_v3(b"TCON", 0x0000, &text_latin1("(8)")),
+  ]
+  .concat();
+
+  tag(0x03, 0x00, &body)
+}
+
+#[test]
+fn test_meta_precedence() {
+  let data: Vec<u8> = [v2(), vec![0xFF; 256], v1("V1 Title", "V1 Artist", 5, 17)].concat();
+  let file: TempFile = TempFile::new("meta-precedence", &data);
+  let meta: FileMeta = read_path(file.path()).unwrap();
+
+  assert!(meta.tag_v2().is_some());
+  assert!(meta.tag_v1().is_some());
+  assert_eq!(meta.precedence(), Precedence::PreferV2);
+
+  // ID3v2 frames win, missing frames fall back to ID3v1 fields.
+  assert_eq!(meta.title().unwrap().as_deref(), Some("V2 Title"));
+  assert_eq!(meta.artist().unwrap().as_deref(), Some("V1 Artist"));
+  assert_eq!(meta.album().unwrap(), None);
+  assert_eq!(meta.year().unwrap().as_deref(), Some("2004"));
+  assert_eq!(meta.track().unwrap(), Some(3));
+  assert_eq!(meta.genre().unwrap().as_deref(), Some("Jazz"));
+  assert_eq!(meta.comment().unwrap().as_deref(), Some("V1 comment"));
+
+  let meta: FileMeta = meta.with_precedence(Precedence::PreferV1);
+
+  assert_eq!(meta.title().unwrap().as_deref(), Some("V1 Title"));
+  assert_eq!(meta.artist().unwrap().as_deref(), Some("V1 Artist"));
+  assert_eq!(meta.year().unwrap().as_deref(), Some("1999"));
+  assert_eq!(meta.track().unwrap(), Some(5));
+  assert_eq!(meta.genre().unwrap().as_deref(), Some("Rock"));
+}
+
+#[test]
+fn test_meta_single_tag() {
+  let file: TempFile = TempFile::new("meta-v2", &[v2(), vec![0xFF; 256]].concat());
+  let meta: FileMeta = read_path(file.path()).unwrap();
+
+  assert!(meta.tag_v1().is_none());
+  assert_eq!(meta.title().unwrap().as_deref(), Some("V2 Title"));
+  assert_eq!(meta.artist().unwrap(), None);
+
+  let file: TempFile = TempFile::new(
+    "meta-v1",
+    &[vec![0xFF; 256], v1("V1 Title", "", 0, 255)].concat(),
+  );
+  let meta: FileMeta = read_path(file.path()).unwrap();
+
+  assert!(meta.tag_v2().is_none());
+  assert_eq!(meta.title().unwrap().as_deref(), Some("V1 Title"));
+  assert_eq!(meta.track().unwrap(), None);
+  assert_eq!(meta.genre().unwrap(), None);
+}
+
+#[test]
+fn test_meta_no_tags() {
+  let file: TempFile = TempFile::new("meta-none", &[0xFF; 16]);
+  let meta: FileMeta = read_path(file.path()).unwrap();
+
+  assert!(meta.tag_v2().is_none());
+  assert!(meta.tag_v1().is_none());
+  assert_eq!(meta.title().unwrap(), None);
+}
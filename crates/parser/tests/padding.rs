@@ -0,0 +1,117 @@
+mod common;
+
+use parser::error::Result;
+use parser::error::Warning;
+use parser::error::WarningKind;
+use parser::frame::DynFrame;
+use parser::id3v2::Tag;
+use parser::options::ParseOptions;
+
+use self::common::frame_ids;
+use self::common::frame_v3;
+use self::common::tag;
+use self::common::text_latin1;
+
+const LENIENT: ParseOptions = ParseOptions::new().lenient(true);
+
+/// Frames of the original (larger) tag.
+fn large_frames() -> Vec<u8> {
+  [
+    frame_v3(
+      b"TIT2",
+      0x0000,
+      &text_latin1("A much longer original title"),
+    ),
+    frame_v3(b"TALB", 0x0000, &text_latin1("Album")),
+    frame_v3(b"TPE1", 0x0000, &text_latin1("Artist")),
+  ]
+  .concat()
+}
+
+/// Frames of the rewritten (smaller) tag.
+fn small_frames() -> Vec<u8> {
+  frame_v3(b"TIT2", 0x0000, &text_latin1("Title"))
+}
+
+/// Build the frame area of a tag shrunk in place: the smaller frames are
+/// written over the larger ones, leaving the tail of the larger frame area in
+/// the padding.
+fn shrunk_body() -> (Vec<u8>, usize) {
+  let large: Vec<u8> = large_frames();
+  let small: Vec<u8> = small_frames();
+
+  ([&small[..], &large[small.len()..]].concat(), small.len())
+}
+
+#[test]
+fn test_stale_padding_lenient() {
+  let (body, offset): (Vec<u8>, usize) = shrunk_body();
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), LENIENT).unwrap();
+
+  assert_eq!(frame_ids(&tag), ["TIT2"]);
+  assert_eq!(tag.warnings().len(), 1);
+  assert_eq!(
+    tag.warnings()[0].kind(),
+    WarningKind::StalePadding {
+      offset,
+      length: body.len() - offset,
+    }
+  );
+}
+
+#[test]
+fn test_stale_padding_strict() {
+  let (body, _): (Vec<u8>, usize) = shrunk_body();
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let frames: Vec<Result<DynFrame<'_>>> = tag.frames().collect();
+
+  assert!(tag.warnings().is_empty());
+  assert!(frames[0].is_ok());
+  assert!(frames[1..].iter().any(Result::is_err));
+}
+
+#[test]
+fn test_stale_padding_declared_size() {
+  // The stale frames are well-formed, only the declared padding size marks
+  // them as padding.
+  let small: Vec<u8> = small_frames();
+  let stale: Vec<u8> = large_frames();
+
+  let mut body: Vec<u8> = 6_u32.to_be_bytes().to_vec();
+  body.extend([0x00, 0x00]);
+  body.extend((stale.len() as u32).to_be_bytes());
+  body.extend(&small);
+  body.extend(&stale);
+
+  let data: Vec<u8> = tag(0x03, 0x40, &body);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), LENIENT).unwrap();
+
+  assert_eq!(frame_ids(&tag), ["TIT2"]);
+  assert!(matches!(
+    tag.warnings(),
+    [warning] if warning.kind() == WarningKind::StalePadding {
+      offset: small.len(),
+      length: stale.len(),
+    }
+  ));
+
+  // Strict parsing yields the stale frames.
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  assert_eq!(frame_ids(&tag), ["TIT2", "TIT2", "TALB", "TPE1"]);
+}
+
+#[test]
+fn test_zero_padding_no_warning() {
+  let mut body: Vec<u8> = small_frames();
+  body.extend([0x00; 64]);
+
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), LENIENT).unwrap();
+  let warnings: &[Warning] = tag.warnings();
+
+  assert_eq!(frame_ids(&tag), ["TIT2"]);
+  assert!(warnings.is_empty());
+}
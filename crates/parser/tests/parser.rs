@@ -0,0 +1,368 @@
+mod common;
+
+use parser::error::Error;
+use parser::error::ErrorKind;
+use parser::id3v2::ExtHeader;
+use parser::id3v2::Header;
+use parser::id3v2::ParseEvent;
+use parser::id3v2::Tag;
+use parser::id3v2::TagParser;
+use parser::options::ParseOptions;
+use std::io::Cursor;
+use std::io::Read;
+
+use self::common::frame_v2;
+use self::common::frame_v3;
+use self::common::frame_v4;
+use self::common::synchsafe;
+use self::common::tag;
+use self::common::text_latin1;
+
+const CHUNKS: &[usize] = &[1, 2, 3, 7, 64, usize::MAX];
+
+fn fixtures() -> Vec<Vec<u8>> {
+  let mut output: Vec<Vec<u8>> = Vec::new();
+
+  // ID3v2.2 with padding.
+  let mut body: Vec<u8> = frame_v2(b"TT2", &text_latin1("Title"));
+  body.extend(frame_v2(b"TAL", &text_latin1("Album")));
+  body.extend([0x00; 8]);
+  output.push(tag(0x02, 0x00, &body));
+
+  // ID3v2.3 without padding.
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(frame_v3(b"TALB", 0x0000, &text_latin1("Album")));
+  output.push(tag(0x03, 0x00, &body));
+
+  // ID3v2.3 with an extended header and CRC.
+  let mut body: Vec<u8> = vec![0x00, 0x00, 0x00, 0x0A, 0x80, 0x00, 0x00, 0x00, 0x00, 0x04];
+  body.extend([0xDE, 0xAD, 0xBE, 0xEF]);
+  body.extend(frame_v3(b"TIT2", 0x0000, &text_latin1("Title")));
+  body.extend([0x00; 4]);
+  output.push(tag(0x03, 0x40, &body));
+
+  // ID3v2.4 with an extended header and restrictions.
+  let mut body: Vec<u8> = synchsafe(8).to_vec();
+  body.extend([0x01, 0x10, 0x01, 0x20]);
+  body.extend(frame_v4(b"TIT2", 0x0000, &text_latin1("Title")));
+  body.extend(frame_v4(b"TPE1", 0x0000, &text_latin1("Artist")));
+  body.extend([0x00; 16]);
+  output.push(tag(0x04, 0x40, &body));
+
+  // ID3v2.3 with unsynchronisation applied to the frame data.
+  let mut body: Vec<u8> = frame_v3(b"PRIV", 0x0000, b"owner\x00\xFF\xE0\x01");
+  body.splice(17..17, [0x00]);
+  body.extend(frame_v3(b"TIT2", 0x0000, &text_latin1("Title")));
+  output.push(tag(0x03, 0x80, &body));
+
+  // ID3v2.3 with an invalid frame followed by more data.
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(b"T!T2\x00\x00\x00\x01\x00\x00\x00");
+  body.extend(frame_v3(b"TALB", 0x0000, &text_latin1("Album")));
+  output.push(tag(0x03, 0x00, &body));
+
+  // ID3v2.4 with a frame that claims more data than the tag contains.
+  let mut body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(b"TALB\x00\x00\x01\x00\x00\x00\x00Album");
+  output.push(tag(0x04, 0x00, &body));
+
+  output
+}
+
+/// Feed `input` to a new parser in chunks of `chunk` bytes.
+///
+/// Returns the parsed tag and the debug output of every frame event.
+fn parse_chunked(input: &[u8], chunk: usize) -> (Tag, Vec<String>, usize) {
+  let mut parser: TagParser = TagParser::new(ParseOptions::new());
+  let mut frames: Vec<String> = Vec::new();
+  let mut input: &[u8] = input;
+
+  loop {
+    match parser.poll().unwrap() {
+      ParseEvent::NeedMore(size) => {
+        assert!(size > 0);
+
+        let (head, tail): (&[u8], &[u8]) = input.split_at(chunk.min(input.len()));
+
+        assert!(
+          !head.is_empty(),
+          "parser requested data past the end of the tag"
+        );
+
+        parser.feed(head);
+        input = tail;
+      }
+      ParseEvent::Header(_) => {
+        assert!(frames.is_empty());
+      }
+      ParseEvent::Frame(frame) => {
+        frames.push(format!("{:?}", frame.map_err(|error| error.kind())));
+      }
+      ParseEvent::Done => break,
+    }
+  }
+
+  let consumed: usize = parser.consumed();
+
+  (parser.finish().unwrap(), frames, consumed)
+}
+
+fn iter_frames(tag: &Tag) -> Vec<String> {
+  tag
+    .frames()
+    .map(|frame| format!("{:?}", frame.map_err(|error| error.kind())))
+    .collect()
+}
+
+#[test]
+fn test_parser_matches_reader() {
+  for fixture in fixtures() {
+    let expected: Tag = Tag::from_reader(fixture.as_slice()).unwrap();
+
+    for &chunk in CHUNKS {
+      let (tag, frames, consumed): (Tag, Vec<String>, usize) = parse_chunked(&fixture, chunk);
+
+      assert_eq!(tag, expected);
+      assert_eq!(frames, iter_frames(&expected));
+      assert_eq!(consumed, fixture.len());
+    }
+  }
+}
+
+#[test]
+fn test_header_from_slice_matches_reader() {
+  for fixture in fixtures() {
+    let expected: Header = Header::from_reader(fixture.as_slice()).unwrap();
+    let (header, consumed): (Header, usize) = Header::from_slice(&fixture).unwrap();
+
+    let ext_size: usize = header
+      .exheader()
+      .map_or(0, |exheader| exheader.total_size() as usize);
+
+    assert_eq!(header, expected);
+    assert_eq!(consumed, Header::SIZE + ext_size);
+    assert_eq!(consumed + header.data_len() as usize, fixture.len());
+  }
+}
+
+#[test]
+fn test_ext_header_from_slice_matches_reader() {
+  let data: &[u8] = &[
+    0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0xFF,
+  ];
+  let (exheader, consumed): (ExtHeader, usize) = ExtHeader::from_slice_v3(data).unwrap();
+
+  assert_eq!(exheader, ExtHeader::from_reader_v3(data).unwrap());
+  assert_eq!(consumed, 10);
+
+  let data: &[u8] = &[0x00, 0x00, 0x00, 0x08, 0x01, 0x10, 0x01, 0x20, 0xFF];
+  let (exheader, consumed): (ExtHeader, usize) = ExtHeader::from_slice_v4(data).unwrap();
+
+  assert_eq!(exheader, ExtHeader::from_reader_v4(data).unwrap());
+  assert_eq!(consumed, 8);
+}
+
+#[test]
+fn test_header_from_slice_incomplete() {
+  let fixture: Vec<u8> = fixtures().swap_remove(2);
+
+  for length in [0, 5, Header::SIZE + 2, Header::SIZE + 8] {
+    let error = Header::from_slice(&fixture[..length]).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::IO));
+  }
+}
+
+#[test]
+fn test_reader_stops_at_end_of_tag() {
+  for fixture in fixtures() {
+    let mut input: Vec<u8> = fixture.clone();
+    input.extend(b"\xFF\xFBaudio");
+
+    let mut reader: Cursor<&[u8]> = Cursor::new(&input);
+    let mut rest: Vec<u8> = Vec::new();
+
+    Tag::from_reader(&mut reader).unwrap();
+    reader.read_to_end(&mut rest).unwrap();
+
+    assert_eq!(rest, b"\xFF\xFBaudio");
+  }
+}
+
+#[test]
+fn test_parser_ignores_trailing_input() {
+  let fixture: Vec<u8> = fixtures().swap_remove(1);
+
+  let mut parser: TagParser = TagParser::new(ParseOptions::new());
+  parser.feed(&fixture);
+  parser.feed(b"\xFF\xFBaudio");
+
+  while !matches!(parser.poll().unwrap(), ParseEvent::Done) {}
+
+  assert_eq!(parser.consumed(), fixture.len());
+  assert_eq!(
+    parser.finish().unwrap(),
+    Tag::from_reader(fixture.as_slice()).unwrap()
+  );
+}
+
+#[test]
+fn test_parser_finish_incomplete() {
+  let fixture: Vec<u8> = fixtures().swap_remove(1);
+
+  let mut parser: TagParser = TagParser::new(ParseOptions::new());
+  parser.feed(&fixture[..fixture.len() - 1]);
+
+  while let ParseEvent::Header(_) | ParseEvent::Frame(_) = parser.poll().unwrap() {}
+
+  assert!(!parser.is_done());
+  assert!(matches!(parser.finish().unwrap_err().kind(), ErrorKind::IO));
+}
+
+#[test]
+fn test_reader_at_restores_position() {
+  let fixture: Vec<u8> = fixtures().swap_remove(1);
+  let expected: Tag = Tag::from_reader(fixture.as_slice()).unwrap();
+
+  let mut input: Vec<u8> = b"RIFF\x00\x00\x00\x00WAVE".to_vec();
+  input.extend(&fixture);
+  input.extend(b"\xFF\xFBaudio");
+
+  let mut reader: Cursor<&[u8]> = Cursor::new(&input);
+  reader.set_position(4);
+
+  assert_eq!(Tag::from_reader_at_mut(&mut reader, 12).unwrap(), expected);
+  assert_eq!(reader.position(), 4);
+
+  assert_eq!(Tag::from_reader_at(&mut reader, 12).unwrap(), expected);
+  assert_eq!(reader.position(), 4);
+
+  assert_eq!(
+    Tag::from_reader_at(Cursor::new(&input), 12).unwrap(),
+    expected
+  );
+}
+
+#[test]
+fn test_reader_at_restores_position_on_error() {
+  let fixture: Vec<u8> = fixtures().swap_remove(1);
+
+  let mut input: Vec<u8> = b"RIFF\x00\x00\x00\x00WAVE".to_vec();
+  input.extend(&fixture[..fixture.len() - 1]);
+
+  let mut reader: Cursor<&[u8]> = Cursor::new(&input);
+  reader.set_position(8);
+
+  assert!(Tag::from_reader_at_mut(&mut reader, 0).is_err());
+  assert_eq!(reader.position(), 8);
+
+  let error: Error = Tag::from_reader_at_mut(&mut reader, 12).unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::IO));
+  assert_eq!(reader.position(), 8);
+}
+
+/// ID3v2.3 with unsynchronisation applied to the extended header and frames.
+///
+/// The CRC of the extended header contains an `0xFF` byte, which is followed
+/// by an inserted `0x00` byte.
+fn fixture_unsync_exheader() -> Vec<u8> {
+  let mut body: Vec<u8> = vec![0x00, 0x00, 0x00, 0x0A, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00];
+  body.extend([0xFF, 0x00, 0xE0, 0x12, 0x34]);
+  body.extend(frame_v3(b"TIT2", 0x0000, &text_latin1("Title")));
+  body.extend([0x00; 4]);
+  tag(0x03, 0xC0, &body)
+}
+
+#[test]
+fn test_unsync_exheader_v3() {
+  let fixture: Vec<u8> = fixture_unsync_exheader();
+  let tag: Tag = Tag::from_reader(fixture.as_slice()).unwrap();
+  let exheader: &ExtHeader = tag.header().exheader().unwrap();
+
+  assert_eq!(exheader.crc_data(), Some(0xFFE01234));
+  assert_eq!(exheader.total_size(), 14);
+  assert_eq!(iter_frames(&tag).len(), 1);
+  assert_eq!(tag.buffer().len(), fixture.len() - Header::SIZE - 15);
+
+  for &chunk in CHUNKS {
+    let (parsed, frames, consumed): (Tag, Vec<String>, usize) = parse_chunked(&fixture, chunk);
+
+    assert_eq!(parsed, tag);
+    assert_eq!(frames, iter_frames(&tag));
+    assert_eq!(consumed, fixture.len());
+  }
+
+  // The inserted byte is part of the extended header.
+  let (header, consumed): (Header, usize) = Header::from_slice(&fixture).unwrap();
+
+  assert_eq!(&header, tag.header());
+  assert_eq!(consumed, Header::SIZE + 15);
+  assert_eq!(Header::from_reader(fixture.as_slice()).unwrap(), header);
+}
+
+#[test]
+fn test_unsync_exheader_v3_stops_at_end_of_tag() {
+  let mut input: Vec<u8> = fixture_unsync_exheader();
+  input.extend(b"\xFF\xFBaudio");
+
+  let mut reader: Cursor<&[u8]> = Cursor::new(&input);
+  let mut rest: Vec<u8> = Vec::new();
+
+  Tag::from_reader(&mut reader).unwrap();
+  reader.read_to_end(&mut rest).unwrap();
+
+  assert_eq!(rest, b"\xFF\xFBaudio");
+}
+
+#[test]
+fn test_unsync_exheader_v4() {
+  // The ID3v2.4 extended header is never unsynchronised.
+  let mut body: Vec<u8> = synchsafe(12).to_vec();
+  body.extend([0x01, 0x20, 0x05, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F]);
+  body.extend(frame_v4(b"PRIV", 0x0000, b"a\x00\xFF\x00\xE0"));
+  body.extend([0x00; 4]);
+
+  let fixture: Vec<u8> = tag(0x04, 0xC0, &body);
+  let tag: Tag = Tag::from_reader(fixture.as_slice()).unwrap();
+  let exheader: &ExtHeader = tag.header().exheader().unwrap();
+
+  assert_eq!(exheader.crc_data(), Some(0xFFFFFFFF));
+  assert_eq!(exheader.total_size(), 12);
+  assert_eq!(iter_frames(&tag).len(), 1);
+
+  for &chunk in CHUNKS {
+    let (parsed, _, consumed): (Tag, Vec<String>, usize) = parse_chunked(&fixture, chunk);
+
+    assert_eq!(parsed, tag);
+    assert_eq!(consumed, fixture.len());
+  }
+
+  let (_, consumed): (Header, usize) = Header::from_slice(&fixture).unwrap();
+
+  assert_eq!(consumed, Header::SIZE + 12);
+}
+
+#[test]
+fn test_compressed_v2() {
+  let body: Vec<u8> = frame_v2(b"TT2", &text_latin1("Title"));
+  let fixture: Vec<u8> = tag(0x02, 0x40, &body);
+
+  let error: Error = Tag::from_reader(fixture.as_slice()).unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::Unsupported));
+
+  // The header is still available to skip the tag.
+  let header: Header = Header::from_reader(fixture.as_slice()).unwrap();
+
+  assert!(header.flag_compression());
+  assert_eq!(Header::SIZE + header.data_len() as usize, fixture.len());
+
+  let mut parser: TagParser = TagParser::new(ParseOptions::new());
+  parser.feed(&fixture);
+
+  assert!(matches!(parser.poll().unwrap(), ParseEvent::Header(_)));
+
+  let error: Error = parser.poll().unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::Unsupported));
+}
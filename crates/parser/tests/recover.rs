@@ -0,0 +1,195 @@
+mod common;
+
+use parser::error::Error;
+use parser::error::ErrorKind;
+use parser::frame::DynFrame;
+use parser::id3v2::FrameIter;
+use parser::id3v2::IterOutcome;
+use parser::id3v2::Tag;
+use parser::options::ParseOptions;
+
+use self::common::frame_v3;
+use self::common::tag;
+use self::common::text_latin1;
+
+const IDENTIFIERS: [&[u8; 4]; 5] = [b"TIT2", b"TPE1", b"TALB", b"TRCK", b"TYER"];
+
+/// Build an ID3v2.3 tag with five text frames, corrupting the identifier of
+/// the frame at `index`.
+fn corrupted(index: usize) -> (Tag, usize) {
+  corrupted_with(index, ParseOptions::new())
+}
+
+fn corrupted_with(index: usize, options: ParseOptions) -> (Tag, usize) {
+  let mut body: Vec<u8> = Vec::new();
+  let mut size: usize = 0;
+
+  for (position, identifier) in IDENTIFIERS.iter().enumerate() {
+    let mut frame: Vec<u8> = frame_v3(identifier, 0x0000, &text_latin1("Value"));
+
+    if position == index {
+      frame[1..3].copy_from_slice(b"@#");
+      size = frame.len();
+    }
+
+    body.extend(frame);
+  }
+
+  (parse(&body, options), size)
+}
+
+fn parse(body: &[u8], options: ParseOptions) -> Tag {
+  Tag::from_reader_with(tag(0x03, 0x00, body).as_slice(), options).unwrap()
+}
+
+fn identifiers<'a>(frames: &'a [DynFrame<'_>]) -> Vec<&'a str> {
+  frames.iter().map(DynFrame::identifier_str).collect()
+}
+
+#[test]
+fn test_frames_recovering() {
+  let (tag, size): (Tag, usize) = corrupted(2);
+  let mut iter: FrameIter<'_> = tag.frames_recovering();
+  let mut frames: Vec<DynFrame<'_>> = Vec::new();
+  let mut errors: usize = 0;
+
+  for frame in iter.by_ref() {
+    match frame {
+      Ok(frame) => frames.push(frame),
+      Err(error) => {
+        assert!(matches!(error.kind(), ErrorKind::InvalidFrameId));
+        errors += 1;
+      }
+    }
+  }
+
+  assert_eq!(identifiers(&frames), ["TIT2", "TPE1", "TRCK", "TYER"]);
+  assert_eq!(errors, 1);
+  assert_eq!(iter.skipped(), size);
+}
+
+#[test]
+fn test_frames_recovering_last() {
+  let (tag, size): (Tag, usize) = corrupted(4);
+  let mut iter: FrameIter<'_> = tag.frames_recovering();
+  let frames: Vec<DynFrame<'_>> = iter.by_ref().filter_map(Result::ok).collect();
+
+  // Nothing plausible follows the last frame, so the rest is skipped.
+  assert_eq!(identifiers(&frames), ["TIT2", "TPE1", "TALB", "TRCK"]);
+  assert_eq!(iter.skipped(), size);
+}
+
+#[test]
+fn test_frames_strict() {
+  let (tag, _): (Tag, usize) = corrupted(2);
+  let frames: Vec<_> = tag.frames().collect();
+
+  assert_eq!(frames.len(), 3);
+  assert!(frames[..2].iter().all(Result::is_ok));
+  assert!(matches!(
+    frames[2].as_ref().unwrap_err().kind(),
+    ErrorKind::InvalidFrameId
+  ));
+}
+
+#[test]
+fn test_frames_recovering_lenient() {
+  let (tag, _): (Tag, usize) = corrupted_with(1, ParseOptions::new().lenient(true));
+  let frames: Vec<DynFrame<'_>> = tag.frames_recovering().filter_map(Result::ok).collect();
+
+  // The frames after the corrupted one are not mistaken for stale padding.
+  assert_eq!(identifiers(&frames), ["TIT2", "TALB", "TRCK", "TYER"]);
+  assert_eq!(tag.frames().count(), 1);
+}
+
+#[test]
+fn test_finish_clean_padding() {
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend([0x00; 32]);
+
+  let tag: Tag = parse(&body, ParseOptions::new());
+  let mut iter: FrameIter<'_> = tag.frames();
+
+  assert!(iter.next().unwrap().is_ok());
+  assert_eq!(iter.finish(), IterOutcome::CleanPadding(32));
+
+  let body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  let tag: Tag = parse(&body, ParseOptions::new());
+
+  assert_eq!(tag.frames().finish(), IterOutcome::CleanPadding(0));
+}
+
+#[test]
+fn test_finish_trailing_garbage() {
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend([0x00; 8]);
+  body.extend(b"garbage!");
+
+  let tag: Tag = parse(&body, ParseOptions::new());
+
+  assert_eq!(tag.frames().finish(), IterOutcome::TrailingGarbage(16));
+
+  // Stale data is excluded from the frames of lenient tags, but still reported.
+  let tag: Tag = parse(&body, ParseOptions::new().lenient(true));
+
+  assert_eq!(tag.frames().count(), 1);
+  assert_eq!(tag.frames().finish(), IterOutcome::TrailingGarbage(16));
+}
+
+#[test]
+fn test_finish_error() {
+  let (corrupt, _): (Tag, usize) = corrupted(2);
+
+  assert_eq!(corrupt.frames().finish(), IterOutcome::Error);
+  assert_eq!(corrupt.frames_recovering().finish(), IterOutcome::Error);
+
+  // A truncated tag ends with an error, not with padding.
+  let body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let options: ParseOptions = ParseOptions::new().allow_truncated(true);
+  let truncated: Tag = Tag::from_reader_with(&data[..data.len() - 2], options).unwrap();
+
+  assert!(truncated.is_truncated());
+  assert_eq!(truncated.frames().finish(), IterOutcome::Error);
+}
+
+#[test]
+fn test_frames_lossy() {
+  let mut body: Vec<u8> = Vec::new();
+  let mut offsets: Vec<usize> = Vec::new();
+
+  for (position, identifier) in IDENTIFIERS[..4].iter().enumerate() {
+    let mut frame: Vec<u8> = frame_v3(identifier, 0x0000, &text_latin1("Value"));
+
+    // The second frame claims to extend past the end of the tag.
+    if position == 1 {
+      frame[4..8].copy_from_slice(&0x1000_u32.to_be_bytes());
+    }
+
+    offsets.push(body.len());
+    body.extend(frame);
+  }
+
+  let tag: Tag = parse(&body, ParseOptions::new());
+  let items: Vec<(usize, Result<DynFrame<'_>, Error>)> = tag.frames_lossy().collect();
+
+  assert_eq!(items.len(), 4);
+  assert_eq!(
+    items
+      .iter()
+      .map(|(offset, _)| *offset)
+      .collect::<Vec<usize>>(),
+    offsets
+  );
+  assert_eq!(items[0].1.as_ref().unwrap().identifier_str(), "TIT2");
+  assert!(matches!(
+    items[1].1.as_ref().unwrap_err().kind(),
+    ErrorKind::FrameOverrun
+  ));
+  assert_eq!(items[2].1.as_ref().unwrap().identifier_str(), "TALB");
+  assert_eq!(items[3].1.as_ref().unwrap().identifier_str(), "TRCK");
+
+  // The default iterator stops at the invalid frame.
+  assert_eq!(tag.frames().count(), 2);
+  assert_eq!(tag.frames_recovering().count(), 2);
+}
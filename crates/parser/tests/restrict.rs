@@ -0,0 +1,176 @@
+mod common;
+
+use parser::id3v2::ImageEncRestriction;
+use parser::id3v2::ImageLenRestriction;
+use parser::id3v2::RestrictionViolation;
+use parser::id3v2::Restrictions;
+use parser::id3v2::Tag;
+use parser::id3v2::TagSizeRestriction;
+use parser::id3v2::TextEncRestriction;
+use parser::id3v2::TextLenRestriction;
+use parser::id3v2::ViolationKind;
+
+use self::common::apic;
+use self::common::frame_v4;
+use self::common::synchsafe;
+use self::common::tag;
+use self::common::text_latin1;
+use self::common::text_utf16;
+use self::common::text_utf8;
+
+/// Build an ID3v2.4 tag with the `restrictions` byte and `frames`.
+fn restricted(restrictions: u8, frames: &[u8]) -> Tag {
+  let mut body: Vec<u8> = synchsafe(8).to_vec();
+  body.extend([0x01, 0x10, 0x01, restrictions]);
+  body.extend(frames);
+
+  Tag::from_reader(tag(0x04, 0x40, &body).as_slice()).unwrap()
+}
+
+fn check(tag: &Tag) -> Vec<(Option<String>, ViolationKind)> {
+  let restrictions: Restrictions = tag.header().exheader().unwrap().restrictions().unwrap();
+
+  restrictions
+    .check(tag)
+    .iter()
+    .map(|violation: &RestrictionViolation| {
+      let frame: Option<String> = violation.frame().map(|frame| frame.to_string());
+      (frame, violation.kind())
+    })
+    .collect()
+}
+
+fn png(width: u32, height: u32) -> Vec<u8> {
+  let mut data: Vec<u8> = b"\x89PNG\r\n\x1A\n\x00\x00\x00\x0DIHDR".to_vec();
+  data.extend(width.to_be_bytes());
+  data.extend(height.to_be_bytes());
+  data.extend([0x08, 0x06, 0x00, 0x00, 0x00]);
+  data
+}
+
+fn jpg(width: u16, height: u16) -> Vec<u8> {
+  let mut data: Vec<u8> = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00];
+  data.extend([0xFF, 0xC0, 0x00, 0x0B, 0x08]);
+  data.extend(height.to_be_bytes());
+  data.extend(width.to_be_bytes());
+  data.extend([0x01, 0x01, 0x11, 0x00]);
+  data
+}
+
+#[test]
+fn test_check_text() {
+  let restrictions: u8 = TextEncRestriction::Some as u8 | TextLenRestriction::R4 as u8;
+
+  let mut frames: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_utf16("Title"));
+  frames.extend(frame_v4(b"TALB", 0x0000, &text_latin1(&"A".repeat(31))));
+  frames.extend(frame_v4(b"TPE1", 0x0000, &text_utf8(&"B".repeat(30))));
+  frames.extend(frame_v4(b"TPE2", 0x0000, &text_utf16(&"C".repeat(31))));
+
+  let tag: Tag = restricted(restrictions, &frames);
+
+  assert_eq!(
+    check(&tag),
+    [
+      (
+        Some("TIT2".to_owned()),
+        ViolationKind::TextEnc(TextEncRestriction::Some)
+      ),
+      (
+        Some("TALB".to_owned()),
+        ViolationKind::TextLen(TextLenRestriction::R4)
+      ),
+      (
+        Some("TPE2".to_owned()),
+        ViolationKind::TextEnc(TextEncRestriction::Some)
+      ),
+      (
+        Some("TPE2".to_owned()),
+        ViolationKind::TextLen(TextLenRestriction::R4)
+      ),
+    ]
+  );
+}
+
+#[test]
+fn test_check_compliant() {
+  let restrictions: u8 = TextEncRestriction::Some as u8 | TextLenRestriction::R4 as u8;
+  let frames: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_utf8("Title"));
+  let tag: Tag = restricted(restrictions, &frames);
+
+  assert!(check(&tag).is_empty());
+}
+
+#[test]
+fn test_check_tag_size() {
+  let frames: Vec<u8> = (0..33)
+    .flat_map(|_| frame_v4(b"TXXX", 0x0000, &[0x00; 128]))
+    .collect();
+
+  let tag: Tag = restricted(TagSizeRestriction::R4 as u8, &frames);
+
+  assert_eq!(
+    check(&tag),
+    [
+      (None, ViolationKind::FrameCount(TagSizeRestriction::R4)),
+      (None, ViolationKind::TagSize(TagSizeRestriction::R4)),
+    ]
+  );
+
+  let tag: Tag = restricted(TagSizeRestriction::R2 as u8, &frames);
+
+  assert!(check(&tag).is_empty());
+}
+
+#[test]
+fn test_check_images() {
+  let restrictions: u8 = ImageEncRestriction::Some as u8 | ImageLenRestriction::R3 as u8;
+
+  let frames: Vec<u8> = [
+    apic("image/gif", "", b"GIF89a"),
+    apic("image/png", "", &png(300, 200)),
+    apic("image/jpeg", "", &jpg(64, 48)),
+    apic("image/jpeg", "", &jpg(64, 65)),
+  ]
+  .iter()
+  .flat_map(|data: &Vec<u8>| frame_v4(b"APIC", 0x0000, data))
+  .collect();
+
+  let tag: Tag = restricted(restrictions, &frames);
+
+  assert_eq!(
+    check(&tag),
+    [
+      (
+        Some("APIC".to_owned()),
+        ViolationKind::ImageEnc(ImageEncRestriction::Some)
+      ),
+      (
+        Some("APIC".to_owned()),
+        ViolationKind::ImageLen(ImageLenRestriction::R3)
+      ),
+      (
+        Some("APIC".to_owned()),
+        ViolationKind::ImageLen(ImageLenRestriction::R3)
+      ),
+    ]
+  );
+
+  // Exactly 64x64 pixels.
+  let frames: Vec<u8> = [
+    apic("image/png", "", &png(64, 64)),
+    apic("image/jpeg", "", &jpg(64, 48)),
+  ]
+  .iter()
+  .flat_map(|data: &Vec<u8>| frame_v4(b"APIC", 0x0000, data))
+  .collect();
+
+  let tag: Tag = restricted(ImageLenRestriction::R4 as u8, &frames);
+
+  assert_eq!(
+    check(&tag),
+    [(
+      Some("APIC".to_owned()),
+      ViolationKind::ImageLen(ImageLenRestriction::R4)
+    )]
+  );
+}
@@ -0,0 +1,159 @@
+mod common;
+
+use parser::id3v2::Tag;
+use parser::options::Padding;
+use parser::options::WriteOptions;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use self::common::contents;
+use self::common::frame_v3;
+use self::common::tag;
+use self::common::text_latin1;
+
+/// A scratch file that is removed when dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+  fn new(name: &str, data: &[u8]) -> Self {
+    let path: PathBuf =
+      std::env::temp_dir().join(format!("errai-{name}-{}.mp3", std::process::id()));
+    fs::write(&path, data).unwrap();
+    Self(path)
+  }
+
+  fn path(&self) -> &Path {
+    &self.0
+  }
+}
+
+impl Drop for TempFile {
+  fn drop(&mut self) {
+    let _ = fs::remove_file(&self.0);
+  }
+}
+
+/// Fake MPEG audio frames following the tag.
+fn audio() -> Vec<u8> {
+  let mut audio: Vec<u8> = Vec::new();
+
+  for index in 0..64_u8 {
+    audio.extend([0xFF, 0xFB, 0x90, 0x64]);
+    audio.extend((0..=255_u8).map(|byte| byte ^ index));
+  }
+
+  audio
+}
+
+/// Build an ID3v2.3 tag with a title frame followed by `padding` bytes.
+fn title_tag(title: &str, padding: usize) -> Vec<u8> {
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1(title));
+  body.resize(body.len() + padding, 0x00);
+  tag(0x03, 0x00, &body)
+}
+
+#[test]
+fn test_save_in_place() {
+  let existing: Vec<u8> = title_tag("A much longer original title", 256);
+  let file: TempFile = TempFile::new("save-in-place", &[&existing[..], &audio()].concat());
+
+  let data: Vec<u8> = title_tag("Title", 0);
+  let original: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let length: u64 = original.save_to_path(file.path()).unwrap();
+  let written: Vec<u8> = fs::read(file.path()).unwrap();
+
+  // The reclaimed space is converted into padding.
+  assert_eq!(length, existing.len() as u64);
+  assert_eq!(written.len(), existing.len() + audio().len());
+  assert_eq!(&written[existing.len()..], audio());
+
+  let parsed: Tag = Tag::from_path(file.path()).unwrap();
+
+  assert_eq!(parsed.header().data_len() as usize, existing.len() - 10);
+  assert_eq!(contents(&parsed), contents(&original));
+}
+
+#[test]
+fn test_save_grow() {
+  let existing: Vec<u8> = title_tag("Title", 0);
+  let file: TempFile = TempFile::new("save-grow", &[&existing[..], &audio()].concat());
+
+  let data: Vec<u8> = title_tag("A much longer replacement title", 0);
+  let original: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let options: WriteOptions = WriteOptions::new().padding(Padding::Fixed(512));
+  let length: u64 = original.save_to_path_with(file.path(), options).unwrap();
+  let written: Vec<u8> = fs::read(file.path()).unwrap();
+
+  assert_eq!(length, (data.len() + 512) as u64);
+  assert_eq!(&written[length as usize..], audio());
+
+  let parsed: Tag = Tag::from_path(file.path()).unwrap();
+
+  assert_eq!(parsed.header().data_len() as usize, data.len() - 10 + 512);
+  assert_eq!(contents(&parsed), contents(&original));
+}
+
+#[test]
+fn test_save_without_tag() {
+  let file: TempFile = TempFile::new("save-without-tag", &audio());
+
+  let data: Vec<u8> = title_tag("Title", 0);
+  let original: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let length: u64 = original.save_to_path(file.path()).unwrap();
+  let written: Vec<u8> = fs::read(file.path()).unwrap();
+
+  assert_eq!(&written[..length as usize], data);
+  assert_eq!(&written[length as usize..], audio());
+}
+
+#[test]
+fn test_save_temp_files() {
+  let file: TempFile = TempFile::new("save-temp-files", &audio());
+  let name: String = file
+    .path()
+    .file_name()
+    .unwrap()
+    .to_str()
+    .unwrap()
+    .to_owned();
+
+  // Unrelated files are not overwritten by the temporary file.
+  let other: TempFile = TempFile(file.path().with_file_name(format!(".{name}.errai")));
+  fs::write(other.path(), b"keep").unwrap();
+
+  let data: Vec<u8> = title_tag("Title", 0);
+  let original: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  original.save_to_path(file.path()).unwrap();
+
+  assert_eq!(fs::read(other.path()).unwrap(), b"keep");
+
+  // The temporary file is renamed over the original.
+  let leftover: usize = fs::read_dir(file.path().parent().unwrap())
+    .unwrap()
+    .filter_map(|entry| entry.unwrap().file_name().into_string().ok())
+    .filter(|entry| entry.starts_with(&format!(".{name}.")))
+    .count();
+
+  assert_eq!(leftover, 1);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_save_permissions() {
+  use std::os::unix::fs::PermissionsExt;
+
+  let file: TempFile = TempFile::new("save-permissions", &audio());
+
+  fs::set_permissions(file.path(), fs::Permissions::from_mode(0o640)).unwrap();
+
+  let data: Vec<u8> = title_tag("Title", 0);
+  let original: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  original.save_to_path(file.path()).unwrap();
+
+  let mode: u32 = fs::metadata(file.path()).unwrap().permissions().mode();
+
+  assert_eq!(mode & 0o777, 0o640);
+}
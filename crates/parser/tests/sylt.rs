@@ -0,0 +1,148 @@
+use parser::content::Content;
+use parser::content::Lyric;
+use parser::content::SyltIter;
+use parser::decode::Timestamp;
+use parser::error::ErrorKind;
+use parser::options::ParseOptions;
+use parser::types::DynFrameId;
+use parser::types::Encoding;
+use parser::types::Slice;
+use parser::types::Version;
+use std::time::Duration;
+
+/// A karaoke-style song with one entry per syllable.
+const KARAOKE: &[(&str, u32)] = &[
+  ("\nTwin", 0),
+  ("kle ", 500),
+  ("twin", 1000),
+  ("kle ", 1500),
+  ("lit", 2000),
+  ("tle ", 2500),
+  ("star\n", 3000),
+  ("\nHow ", 4000),
+  ("I ", 4500),
+  ("wönder", 5000),
+];
+
+/// Encode the synchronised text of `entries`.
+fn sync_text(encoding: Encoding, entries: &[(&str, u32)]) -> Vec<u8> {
+  let mut output: Vec<u8> = Vec::new();
+
+  for (text, time) in entries {
+    output.extend(encoding.encode(text, true).unwrap());
+    output.extend(time.to_be_bytes());
+  }
+
+  output
+}
+
+fn sylt(encoding: Encoding, time_format: Timestamp, entries: &[(&str, u32)]) -> Vec<u8> {
+  let mut output: Vec<u8> = vec![encoding as u8];
+  output.extend(b"eng");
+  output.extend([time_format as u8, 0x01]);
+  output.extend(encoding.encode("Song", true).unwrap());
+  output.extend(sync_text(encoding, entries));
+  output
+}
+
+fn decode(data: &[u8]) -> Content<'_> {
+  let id: DynFrameId = DynFrameId::try_from("SYLT").unwrap();
+
+  Content::decode(Version::ID3v24, id, Slice::new(data)).unwrap()
+}
+
+fn lyrics<'a>(content: &'a Content<'_>, options: ParseOptions) -> Vec<Lyric<'a>> {
+  let Content::Sylt(sylt) = content else {
+    panic!("expected SYLT, got {content:?}");
+  };
+
+  sylt.lyrics_with(options).map(Result::unwrap).collect()
+}
+
+#[test]
+fn test_sylt_lyrics() {
+  for encoding in [Encoding::Latin1, Encoding::Utf16] {
+    let data: Vec<u8> = sylt(encoding, Timestamp::Milliseconds, KARAOKE);
+    let content: Content<'_> = decode(&data);
+    let lyrics: Vec<Lyric<'_>> = lyrics(&content, ParseOptions::new());
+
+    let entries: Vec<(&str, u32)> = lyrics
+      .iter()
+      .map(|lyric| (lyric.data(), lyric.time()))
+      .collect();
+
+    assert_eq!(entries, KARAOKE, "{encoding:?}");
+    assert!(data.ends_with(&sync_text(encoding, &entries)));
+    assert_eq!(content.encode(Version::ID3v24).unwrap(), data);
+  }
+}
+
+#[test]
+fn test_sylt_trim_lyrics() {
+  let options: ParseOptions = ParseOptions::new().trim_lyrics(true);
+
+  assert!(options.trims_lyrics());
+  assert!(!ParseOptions::new().trims_lyrics());
+
+  for encoding in [Encoding::Latin1, Encoding::Utf16] {
+    let data: Vec<u8> = sylt(encoding, Timestamp::Milliseconds, KARAOKE);
+    let content: Content<'_> = decode(&data);
+    let lyrics: Vec<Lyric<'_>> = lyrics(&content, options);
+
+    assert_eq!(lyrics.len(), KARAOKE.len());
+    assert_eq!(lyrics[0].data(), "Twin");
+    assert_eq!(lyrics[6].data(), "star\n");
+    assert_eq!(lyrics[7].data(), "How ");
+  }
+}
+
+#[test]
+fn test_sylt_time_format() {
+  let data: Vec<u8> = sylt(Encoding::Latin1, Timestamp::Milliseconds, KARAOKE);
+  let content: Content<'_> = decode(&data);
+  let lyric: &Lyric<'_> = &lyrics(&content, ParseOptions::new())[1];
+  let frame: Duration = Duration::from_millis(26);
+
+  assert_eq!(lyric.time_format(), Timestamp::Milliseconds);
+  assert_eq!(lyric.milliseconds(), Some(500));
+  assert_eq!(lyric.mpeg_frames(), None);
+  assert_eq!(lyric.duration(frame), Duration::from_millis(500));
+
+  let data: Vec<u8> = sylt(Encoding::Latin1, Timestamp::MpegFrames, KARAOKE);
+  let content: Content<'_> = decode(&data);
+  let lyric: &Lyric<'_> = &lyrics(&content, ParseOptions::new())[1];
+
+  assert_eq!(lyric.time_format(), Timestamp::MpegFrames);
+  assert_eq!(lyric.milliseconds(), None);
+  assert_eq!(lyric.mpeg_frames(), Some(500));
+  assert_eq!(lyric.duration(frame), Duration::from_millis(13_000));
+}
+
+#[test]
+fn test_sylt_empty() {
+  let data: Vec<u8> = sylt(Encoding::Utf16, Timestamp::Milliseconds, &[]);
+  let content: Content<'_> = decode(&data);
+
+  assert!(lyrics(&content, ParseOptions::new()).is_empty());
+}
+
+#[test]
+fn test_sylt_truncated_entry() {
+  let mut data: Vec<u8> = sylt(Encoding::Latin1, Timestamp::Milliseconds, &KARAOKE[..2]);
+  data.truncate(data.len() - 2);
+
+  let content: Content<'_> = decode(&data);
+
+  let Content::Sylt(sylt) = &content else {
+    panic!("expected SYLT, got {content:?}");
+  };
+
+  let mut iter: SyltIter<'_> = sylt.lyrics();
+
+  assert_eq!(iter.next().unwrap().unwrap().data(), "\nTwin");
+  assert!(matches!(
+    iter.next().unwrap().unwrap_err().kind(),
+    ErrorKind::IO
+  ));
+  assert!(iter.next().is_none());
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use parser::content::Content;
+use parser::content::ContentKind;
+use parser::content::Custom;
+use parser::error::Error;
+use parser::error::ErrorKind;
+use parser::error::Warning;
+use parser::error::WarningKind;
+use parser::frame::DynFrame;
+use parser::frame::FrameV2;
+use parser::frame::FrameV3;
+use parser::frame::FrameV4;
+use parser::id3v2::ExtHeader;
+use parser::id3v2::ExtractOptions;
+use parser::id3v2::ExtractedFile;
+use parser::id3v2::FrameIter;
+use parser::id3v2::Header;
+use parser::id3v2::ParseEvent;
+use parser::id3v2::Tag;
+use parser::id3v2::TagParser;
+use parser::id3v2::TagStats;
+use parser::options::DecoderConfig;
+use parser::options::ParseOptions;
+use parser::options::WriteOptions;
+use parser::types::Bytes;
+use parser::types::DeferredSlice;
+use parser::types::LanguagePreference;
+use parser::types::LazySlice;
+use parser::types::Slice;
+use std::thread;
+
+use self::common::frame_v3;
+use self::common::tag;
+use self::common::text_latin1;
+
+fn assert_send_sync<T: Send + Sync + ?Sized>() {}
+
+#[test]
+fn test_send_sync() {
+  assert_send_sync::<Tag>();
+  assert_send_sync::<TagParser>();
+  assert_send_sync::<TagStats>();
+  assert_send_sync::<ParseEvent>();
+  assert_send_sync::<Header>();
+  assert_send_sync::<ExtHeader>();
+  assert_send_sync::<FrameIter<'static>>();
+  assert_send_sync::<DynFrame<'static>>();
+  assert_send_sync::<FrameV2<'static>>();
+  assert_send_sync::<FrameV3<'static>>();
+  assert_send_sync::<FrameV4<'static>>();
+  assert_send_sync::<Content<'static>>();
+  assert_send_sync::<ContentKind>();
+  assert_send_sync::<Custom>();
+  assert_send_sync::<Error>();
+  assert_send_sync::<ErrorKind>();
+  assert_send_sync::<Warning>();
+  assert_send_sync::<WarningKind>();
+  assert_send_sync::<ParseOptions>();
+  assert_send_sync::<DecoderConfig>();
+  assert_send_sync::<WriteOptions>();
+  assert_send_sync::<ExtractOptions>();
+  assert_send_sync::<ExtractedFile>();
+  assert_send_sync::<LanguagePreference>();
+  assert_send_sync::<Bytes>();
+  assert_send_sync::<DeferredSlice>();
+  assert_send_sync::<LazySlice<'static>>();
+  assert_send_sync::<Slice>();
+}
+
+#[test]
+fn test_send_across_threads() {
+  let data: Vec<u8> = tag(
+    0x03,
+    0x00,
+    &frame_v3(b"TIT2", 0x0000, &text_latin1("Title")),
+  );
+
+  let tag: Tag = thread::spawn(move || Tag::from_reader(data.as_slice()))
+    .join()
+    .unwrap()
+    .unwrap();
+
+  let content: Content<'_> = thread::scope(|scope| {
+    scope
+      .spawn(|| tag.frames().next().unwrap()?.decode())
+      .join()
+      .unwrap()
+  })
+  .unwrap();
+
+  assert!(matches!(content, Content::Text(_)));
+
+  let error: Error = thread::spawn(|| Tag::from_reader(&b"ID3"[..]).unwrap_err())
+    .join()
+    .unwrap();
+
+  assert!(matches!(error.kind(), ErrorKind::IO));
+}
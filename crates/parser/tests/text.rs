@@ -0,0 +1,304 @@
+mod common;
+
+use parser::content::Content;
+use parser::error::Warning;
+use parser::error::WarningKind;
+use parser::frame::DynFrame;
+use parser::options::ParseOptions;
+use parser::text::caseless_eq;
+use parser::text::repair_mojibake;
+use parser::types::Encoding;
+use parser::types::Slice;
+use parser::types::Version;
+use std::borrow::Cow;
+
+use self::common::frame_v3;
+use self::common::frame_v4;
+use self::common::text_latin1;
+use self::common::text_utf16;
+use self::common::RawFrame;
+
+/// Double-encoded text and the original text.
+const REPAIRED: &[(&str, &str)] = &[
+  ("CafÃ©", "Café"),
+  ("BeyoncÃ©", "Beyoncé"),
+  ("MotÃ¶rhead", "Motörhead"),
+  ("Sigur RÃ³s", "Sigur Rós"),
+  ("BjÃ¶rk GuÃ°mundsdÃ³ttir", "Björk Guðmundsdóttir"),
+  ("Ã‰dith Piaf", "Édith Piaf"),
+  ("Donâ€™t Stop", "Don’t Stop"),
+  ("Don\u{E2}\u{80}\u{99}t Stop", "Don’t Stop"),
+  ("æ—¥æœ¬", "日本"),
+  ("Ð¡Ð¿Ð»Ð¸Ð½", "Сплин"),
+];
+
+/// Legitimate text that must not be altered.
+const UNTOUCHED: &[&str] = &[
+  "",
+  "Plain ASCII",
+  "Café",
+  "Ã",
+  "SÃO PAULO",
+  "NÃO",
+  "Ãgua de Beber",
+  "Ã la carte",
+  "naïve",
+  "Â",
+  "日本",
+  "Сплин",
+  "Don’t Stop",
+];
+
+fn decode(raw: &RawFrame, options: ParseOptions) -> (Content<'_>, Vec<Warning>) {
+  let mut warnings: Vec<Warning> = Vec::new();
+  let content: Content<'_> = raw.frame().decode_with(options, &mut warnings).unwrap();
+
+  (content, warnings)
+}
+
+#[test]
+fn test_repair_mojibake() {
+  for (input, expected) in REPAIRED {
+    assert_eq!(
+      repair_mojibake(input).as_deref(),
+      Some(*expected),
+      "{input}"
+    );
+  }
+}
+
+#[test]
+fn test_repair_mojibake_untouched() {
+  for input in UNTOUCHED {
+    assert_eq!(repair_mojibake(input), None, "{input}");
+  }
+}
+
+#[test]
+fn test_repair_mojibake_option() {
+  let payload: Vec<u8> = text_utf16("CafÃ©");
+
+  let raw: RawFrame = RawFrame::v3(b"TIT2", &payload);
+  let (content, warnings) = decode(&raw, ParseOptions::new());
+
+  assert_eq!(content.to_string(), "CafÃ©");
+  assert!(warnings.is_empty());
+
+  let (content, warnings) = decode(&raw, ParseOptions::new().repair_mojibake(true));
+
+  assert_eq!(content.to_string(), "Café");
+  assert_eq!(
+    warnings.iter().map(Warning::kind).collect::<Vec<_>>(),
+    [WarningKind::MojibakeRepaired { offset: 1 }]
+  );
+
+  let raw: RawFrame = RawFrame::v3(b"TIT2", &text_utf16("SÃO PAULO"));
+  let (content, warnings) = decode(&raw, ParseOptions::new().repair_mojibake(true));
+
+  assert_eq!(content.to_string(), "SÃO PAULO");
+  assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_caseless_eq_ascii() {
+  assert!(caseless_eq("iTunNORM", "ITUNNORM"));
+  assert!(caseless_eq("www.example.com", "WWW.Example.COM"));
+  assert!(caseless_eq("", ""));
+  assert!(!caseless_eq("iTunNORM", "iTunSMPB"));
+  assert!(!caseless_eq("eng", "en"));
+}
+
+#[test]
+fn test_caseless_eq_non_ascii() {
+  // Non-ASCII characters are never altered by default.
+  assert_eq!(
+    caseless_eq("ärger", "ÄRGER"),
+    cfg!(feature = "unicode-case")
+  );
+  assert_eq!(
+    caseless_eq("Сплин", "СПЛИН"),
+    cfg!(feature = "unicode-case")
+  );
+  assert_eq!(caseless_eq("ΟΔΟΣ", "οδος"), cfg!(feature = "unicode-case"));
+  assert_eq!(caseless_eq("ΟΔΟΣ", "οδοσ"), cfg!(feature = "unicode-case"));
+
+  assert!(caseless_eq("日本", "日本"));
+  assert!(!caseless_eq("Straße", "STRASSE"));
+  assert!(!caseless_eq("é", "e"));
+}
+
+fn decode_tpe1(version: Version, payload: &[u8], options: ParseOptions) -> (String, Vec<Warning>) {
+  let data: Vec<u8> = match version {
+    Version::ID3v24 => frame_v4(b"TPE1", 0x0000, payload),
+    _ => frame_v3(b"TPE1", 0x0000, payload),
+  };
+
+  let frame: DynFrame<'_> = DynFrame::from_slice(version, Slice::new(&data))
+    .unwrap()
+    .unwrap();
+
+  let mut warnings: Vec<Warning> = Vec::new();
+
+  let Content::Text(text) = frame.decode_with(options, &mut warnings).unwrap() else {
+    panic!("expected text");
+  };
+
+  (text.text_content().to_string(), warnings)
+}
+
+#[test]
+fn test_truncate_text_v23() {
+  let options: ParseOptions = ParseOptions::new().truncate_text(true);
+
+  // Trailing NUL padding is never a list.
+  let padded: &[u8] = b"\x00Artist\x00\x00\x00";
+
+  for options in [ParseOptions::new(), options] {
+    let (text, warnings) = decode_tpe1(Version::ID3v23, padded, options);
+
+    assert_eq!(text, "Artist");
+    assert_eq!(
+      warnings.iter().map(Warning::kind).collect::<Vec<_>>(),
+      [WarningKind::TrailingData { length: 2 }]
+    );
+  }
+
+  let multiple: Vec<u8> = text_latin1("A/B\0C");
+
+  assert_eq!(
+    decode_tpe1(Version::ID3v23, &multiple, ParseOptions::new()),
+    ("A/B:C".to_owned(), Vec::new())
+  );
+
+  let (text, warnings) = decode_tpe1(Version::ID3v23, &multiple, options);
+
+  assert_eq!(text, "A/B");
+  assert_eq!(
+    warnings.iter().map(Warning::kind).collect::<Vec<_>>(),
+    [WarningKind::TrailingData { length: 1 }]
+  );
+}
+
+#[test]
+fn test_truncate_text_v24() {
+  let options: ParseOptions = ParseOptions::new().truncate_text(true);
+  let (text, warnings) = decode_tpe1(Version::ID3v24, &text_latin1("A\0B\0C"), options);
+
+  assert_eq!(text, "A:B:C");
+  assert!(warnings.is_empty());
+}
+
+/// A fake codepage that reverses the bytes of strings with a `\xE9` byte.
+fn reverse(slice: &Slice) -> Option<Cow<'_, str>> {
+  let bytes: &[u8] = slice.as_ref();
+
+  if !bytes.contains(&0xE9) {
+    return None;
+  }
+
+  Some(Cow::Owned(
+    bytes.iter().rev().copied().map(char::from).collect(),
+  ))
+}
+
+#[test]
+fn test_legacy_codepage() {
+  let options: ParseOptions = ParseOptions::new().legacy_codepage(reverse);
+
+  assert!(options.codepage_decoder().is_some());
+  assert!(ParseOptions::new().codepage_decoder().is_none());
+
+  let raw: RawFrame = RawFrame::v3(b"TIT2", b"\x00Caf\xE9");
+  let (content, _) = decode(&raw, options);
+
+  assert_eq!(content.to_string(), "\u{E9}faC");
+
+  // ASCII text and strings rejected by the codepage are ISO-8859-1.
+  let raw: RawFrame = RawFrame::v3(b"TIT2", b"\x00Cafe");
+  let (content, _) = decode(&raw, options);
+
+  assert_eq!(content.to_string(), "Cafe");
+
+  let raw: RawFrame = RawFrame::v3(b"TIT2", b"\x00Caf\xEB");
+  let (content, _) = decode(&raw, options);
+
+  assert_eq!(content.to_string(), "Caf\u{EB}");
+
+  // Other encodings are not affected.
+  let raw: RawFrame = RawFrame::v3(b"TIT2", b"\x03Caf\xC3\xA9");
+  let (content, _) = decode(&raw, options);
+
+  assert_eq!(content.to_string(), "Caf\u{E9}");
+}
+
+#[test]
+fn test_latin1_as_cp1252() {
+  let payload: &[u8] = b"\x00Don\x92t Stop";
+
+  let raw: RawFrame = RawFrame::v3(b"TIT2", payload);
+  let (content, _) = decode(&raw, ParseOptions::new());
+
+  assert_eq!(content.to_string(), "Don\u{92}t Stop");
+
+  let options: ParseOptions = ParseOptions::new().latin1_as_cp1252(true);
+
+  assert!(options.decodes_cp1252());
+  assert!(!ParseOptions::new().decodes_cp1252());
+
+  let (content, _) = decode(&raw, options);
+
+  assert_eq!(content.to_string(), "Don\u{2019}t Stop");
+
+  // Bytes outside of 0x80-0x9F and unassigned bytes are unchanged.
+  let raw: RawFrame = RawFrame::v3(b"TIT2", b"\x00\x80 \x81 \xE9");
+  let (content, _) = decode(&raw, options);
+
+  assert_eq!(content.to_string(), "\u{20AC} \u{81} \u{E9}");
+}
+
+#[test]
+fn test_decode_lossy() {
+  let cases: [(Encoding, &[u8], &str); 6] = [
+    (Encoding::Utf8, b"Ti\xFFle", "Ti\u{FFFD}le"),
+    (Encoding::Utf8, b"Caf\xC3", "Caf\u{FFFD}"),
+    (Encoding::Utf16, b"\xFF\xFEA\x00\x00\xD8B\x00", "A\u{FFFD}B"),
+    (Encoding::Utf16BE, b"\x00A\xDC\x00", "A\u{FFFD}"),
+    (Encoding::Latin1, b"Ti\x01le\n", "Ti\u{FFFD}le\n"),
+    (Encoding::Latin1, b"Caf\xE9", "Caf\u{E9}"),
+  ];
+
+  for (encoding, input, expected) in cases {
+    assert_eq!(encoding.decode_lossy(Slice::new(input)), expected);
+  }
+}
+
+#[test]
+fn test_replace_invalid_text_option() {
+  let payload: &[u8] = b"\x03Ti\xFFle";
+  let data: Vec<u8> = frame_v3(b"TIT2", 0x0000, payload);
+  let frame: DynFrame<'_> = DynFrame::from_slice(Version::ID3v23, Slice::new(&data))
+    .unwrap()
+    .unwrap();
+
+  assert!(frame.decode().is_err());
+
+  let options: ParseOptions = ParseOptions::new().replace_invalid_text(true);
+  let raw: RawFrame = RawFrame::v3(b"TIT2", payload);
+  let (content, warnings) = decode(&raw, options);
+
+  assert_eq!(content.to_string(), "Ti\u{FFFD}le");
+  assert_eq!(
+    warnings.iter().map(Warning::kind).collect::<Vec<_>>(),
+    [WarningKind::InvalidText { offset: 1 }]
+  );
+
+  // ISO-8859-1 control characters are replaced, unlike in lenient mode.
+  let raw: RawFrame = RawFrame::v3(b"TIT2", b"\x00Ti\x01le");
+  let (content, _) = decode(&raw, options);
+
+  assert_eq!(content.to_string(), "Ti\u{FFFD}le");
+
+  let (content, _) = decode(&raw, ParseOptions::new().lenient(true));
+
+  assert_eq!(content.to_string(), "Ti\u{1}le");
+}
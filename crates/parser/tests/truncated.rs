@@ -0,0 +1,149 @@
+mod common;
+
+use parser::id3v2::ParseEvent;
+use parser::id3v2::Tag;
+use parser::id3v2::TagParser;
+use parser::options::ParseOptions;
+
+use self::common::frame_v3;
+use self::common::frame_v4;
+use self::common::tag;
+use self::common::text_latin1;
+
+const OPTIONS: ParseOptions = ParseOptions::new().allow_truncated(true);
+
+fn fixture() -> Vec<u8> {
+  let mut body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(frame_v4(b"TPE1", 0x0000, &text_latin1("Artist")));
+  body.extend(frame_v4(b"TALB", 0x0000, &text_latin1("Album")));
+  body.extend([0x00; 16]);
+  tag(0x04, 0x00, &body)
+}
+
+/// Get the identifier of each frame, or the kind of error yielded.
+fn identifiers(tag: &Tag) -> Vec<String> {
+  tag
+    .frames()
+    .map(|frame| match frame {
+      Ok(frame) => frame.identifier_str().to_owned(),
+      Err(error) => format!("{:?}", error.kind()),
+    })
+    .collect()
+}
+
+#[test]
+fn test_truncated_mid_frame() {
+  let data: Vec<u8> = fixture();
+  let input: &[u8] = &data[..data.len() - 20];
+
+  assert!(Tag::from_reader(input).is_err());
+
+  let tag: Tag = Tag::from_reader_with(input, OPTIONS).unwrap();
+
+  assert!(tag.is_truncated());
+  assert_eq!(tag.missing_len(), 20);
+  assert_eq!(tag.buffer().len(), data.len() - 30);
+
+  assert_eq!(identifiers(&tag), ["TIT2", "TPE1", "IO"]);
+}
+
+#[test]
+fn test_truncated_mid_frame_lenient() {
+  let data: Vec<u8> = fixture();
+  let input: &[u8] = &data[..data.len() - 20];
+  let tag: Tag = Tag::from_reader_with(input, OPTIONS.lenient(true)).unwrap();
+
+  assert!(tag.warnings().is_empty());
+
+  assert_eq!(identifiers(&tag), ["TIT2", "TPE1", "IO"]);
+}
+
+#[test]
+fn test_truncated_mid_frame_header() {
+  let data: Vec<u8> = fixture();
+  let input: &[u8] = &data[..data.len() - 30];
+  let tag: Tag = Tag::from_reader_with(input, OPTIONS).unwrap();
+
+  assert_eq!(identifiers(&tag), ["TIT2", "TPE1", "IO"]);
+}
+
+#[test]
+fn test_truncated_padding() {
+  let data: Vec<u8> = fixture();
+  let input: &[u8] = &data[..data.len() - 8];
+  let tag: Tag = Tag::from_reader_with(input, OPTIONS).unwrap();
+
+  assert!(tag.is_truncated());
+  assert_eq!(tag.missing_len(), 8);
+
+  assert_eq!(identifiers(&tag), ["TIT2", "TPE1", "TALB"]);
+}
+
+#[test]
+fn test_truncated_footer() {
+  let mut data: Vec<u8> = tag(
+    0x04,
+    0x10,
+    &frame_v4(b"TIT2", 0x0000, &text_latin1("Title")),
+  );
+
+  data.extend_from_slice(b"3DI\x04\x00\x10");
+
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), OPTIONS).unwrap();
+
+  assert!(tag.footer().is_none());
+  assert_eq!(tag.missing_len(), 4);
+  assert_eq!(identifiers(&tag), ["TIT2"]);
+}
+
+#[test]
+fn test_truncated_unsync() {
+  let mut body: Vec<u8> = frame_v3(b"PRIV", 0x0000, b"owner\x00\xFF\xE0\x01");
+  body.splice(17..17, [0x00]);
+  body.extend(frame_v3(b"TIT2", 0x0000, &text_latin1("Title")));
+
+  let data: Vec<u8> = tag(0x03, 0x80, &body);
+  let input: &[u8] = &data[..data.len() - 2];
+  let tag: Tag = Tag::from_reader_with(input, OPTIONS).unwrap();
+
+  assert_eq!(tag.missing_len(), 2);
+  assert_eq!(identifiers(&tag), ["PRIV", "IO"]);
+}
+
+#[test]
+fn test_truncated_complete() {
+  let data: Vec<u8> = fixture();
+  let tag: Tag = Tag::from_reader_with(data.as_slice(), OPTIONS).unwrap();
+
+  assert!(!tag.is_truncated());
+  assert_eq!(tag.missing_len(), 0);
+  assert_eq!(
+    tag.buffer(),
+    Tag::from_reader(data.as_slice()).unwrap().buffer()
+  );
+}
+
+#[test]
+fn test_truncated_header() {
+  let data: Vec<u8> = fixture();
+
+  assert!(Tag::from_reader_with(&data[..6], OPTIONS).is_err());
+  assert!(Tag::from_reader_with(&data[..10], OPTIONS)
+    .unwrap()
+    .is_truncated());
+}
+
+#[test]
+fn test_truncated_parser() {
+  let data: Vec<u8> = fixture();
+  let mut parser: TagParser = TagParser::new(OPTIONS);
+
+  parser.feed(&data[..data.len() - 20]);
+
+  while !matches!(parser.poll().unwrap(), ParseEvent::NeedMore(_)) {}
+
+  let tag: Tag = parser.finish().unwrap();
+
+  assert_eq!(tag.missing_len(), 20);
+  assert_eq!(identifiers(&tag).len(), 3);
+}
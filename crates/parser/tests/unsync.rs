@@ -1,10 +1,33 @@
 use parser::unsync::Unsync;
+use parser::unsync::UnsyncWriter;
 use std::io::Cursor;
 use std::io::Read;
+use std::io::Write;
 
 const INPUT: &[u8] = &[0xFF, 0x00, 0x00, 0x01, 0x02, 0xFF, 0x00, 0x00, 0x03];
 const OUTPUT: &[u8] = &[0xFF, 0x00, 0x01, 0x02, 0xFF, 0x00, 0x03];
 
+fn round_trip(chunks: &[&[u8]]) -> (Vec<u8>, Vec<u8>) {
+  let mut writer: UnsyncWriter<Vec<u8>> = UnsyncWriter::new(Vec::new());
+
+  for chunk in chunks {
+    writer.write_all(chunk).unwrap();
+  }
+
+  writer.flush().unwrap();
+
+  assert_eq!(writer.written(), writer.get_ref().len() as u64);
+
+  let encoded: Vec<u8> = writer.into_inner();
+  let mut decoded: Vec<u8> = Vec::new();
+
+  Unsync::new(encoded.as_slice())
+    .read_to_end(&mut decoded)
+    .unwrap();
+
+  (encoded, decoded)
+}
+
 #[test]
 fn test_unsync() {
   let cursor: Cursor<&[u8]> = Cursor::new(INPUT);
@@ -16,3 +39,58 @@ fn test_unsync() {
 
   assert_eq!(output, OUTPUT);
 }
+
+#[test]
+fn test_unsync_writer() {
+  let input: &[u8] = &[0x01, 0xFF, 0xE0, 0xFF, 0x00, 0x02];
+  let (encoded, decoded) = round_trip(&[input]);
+
+  assert_eq!(encoded, [0x01, 0xFF, 0x00, 0xE0, 0xFF, 0x00, 0x00, 0x02]);
+  assert_eq!(decoded, input);
+}
+
+#[test]
+fn test_unsync_writer_trailing() {
+  let input: &[u8] = &[0x01, 0x02, 0xFF];
+  let (encoded, decoded) = round_trip(&[input]);
+
+  assert_eq!(encoded, [0x01, 0x02, 0xFF, 0x00]);
+  assert_eq!(decoded, input);
+
+  let (encoded, decoded) = round_trip(&[&[0xFF, 0xFF]]);
+
+  assert_eq!(encoded, [0xFF, 0x00, 0xFF, 0x00]);
+  assert_eq!(decoded, [0xFF, 0xFF]);
+}
+
+#[test]
+fn test_unsync_writer_boundary() {
+  // 0xFF at the end of one write, followed by a byte that would be a false
+  // sync at the start of the next.
+  let (encoded, decoded) = round_trip(&[&[0x01, 0xFF], &[0xE0, 0x02], &[0xFF], &[0x00]]);
+
+  assert_eq!(encoded, [0x01, 0xFF, 0x00, 0xE0, 0x02, 0xFF, 0x00, 0x00]);
+  assert_eq!(decoded, [0x01, 0xFF, 0xE0, 0x02, 0xFF, 0x00]);
+}
+
+#[test]
+fn test_unsync_round_trip() {
+  let input: Vec<u8> = (0..4096_u32)
+    .map(|index| match index % 7 {
+      0 | 3 => 0xFF,
+      1 => 0x00,
+      2 => 0xE7,
+      _ => index as u8,
+    })
+    .collect();
+
+  for size in [1, 2, 3, 64, 4096] {
+    let chunks: Vec<&[u8]> = input.chunks(size).collect();
+    let (encoded, decoded) = round_trip(&chunks);
+
+    assert!(!encoded
+      .windows(2)
+      .any(|pair| pair[0] == 0xFF && pair[1] >= 0xE0));
+    assert_eq!(decoded, input);
+  }
+}
@@ -0,0 +1,219 @@
+mod common;
+
+use parser::content::Content;
+use parser::error::ErrorKind;
+use parser::id3v2::Tag;
+use parser::types::FrameId;
+use parser::types::Slice;
+use parser::types::Version;
+use std::io::Cursor;
+
+use self::common::comm;
+use self::common::contents;
+use self::common::frame_v3;
+use self::common::frame_v4;
+use self::common::tag;
+use self::common::text_latin1;
+use self::common::title;
+
+const AUDIO: &[u8] = &[0xFF; 100];
+
+fn id(name: &[u8; 4]) -> FrameId {
+  FrameId::try_from(*name).unwrap()
+}
+
+fn text<'a>(name: &[u8; 4], data: &'a [u8]) -> (FrameId, Content<'a>) {
+  (
+    id(name),
+    Content::decode(Version::ID3v24, id(name), Slice::new(data)).unwrap(),
+  )
+}
+
+fn base() -> Vec<u8> {
+  let mut body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Old Title"));
+  body.extend(frame_v4(b"TPE1", 0x0000, &text_latin1("Artist")));
+  body.extend(frame_v4(
+    b"SEEK",
+    0x0000,
+    &(AUDIO.len() as u32).to_be_bytes(),
+  ));
+  body.resize(body.len() + 32, 0x00);
+  tag(0x04, 0x00, &body)
+}
+
+#[test]
+fn test_write_update() {
+  let base_data: Vec<u8> = base();
+  let base: Tag = Tag::from_reader(base_data.as_slice()).unwrap();
+
+  let title_data: Vec<u8> = text_latin1("New Title");
+  let artist_data: Vec<u8> = text_latin1("Artist");
+  let changes: Vec<(FrameId, Content<'_>)> =
+    vec![text(b"TIT2", &title_data), text(b"TPE1", &artist_data)];
+
+  let mut file: Vec<u8> = [base_data.as_slice(), AUDIO].concat();
+  let position: u64 = file.len() as u64;
+  let length: u64 = Tag::write_update(&mut file, &base, &changes).unwrap();
+
+  assert_eq!(file.len() as u64, position + length);
+
+  // The primary tag and the audio are untouched.
+  assert_eq!(&file[..base_data.len()], base_data);
+  assert_eq!(&file[base_data.len()..position as usize], AUDIO);
+
+  // The SEEK frame of the base tag links to the update tag.
+  assert_eq!(base.seek_offset().unwrap(), Some(AUDIO.len() as u32));
+  assert_eq!(base.seek_target(0).unwrap(), Some(position));
+
+  let update: Tag = Tag::from_reader_at(Cursor::new(&file), position).unwrap();
+
+  assert_eq!(update.header().version(), Version::ID3v24);
+  assert!(update.header().exheader().unwrap().flag_update());
+
+  // Only the changed title is written.
+  let names: Vec<String> = update
+    .frames()
+    .map(|frame| frame.unwrap().identifier_str().to_owned())
+    .collect();
+
+  assert_eq!(names, ["TIT2"]);
+
+  // Frames in the update tag override the frames of the base tag.
+  let merged: Option<String> = title(&update).or_else(|| title(&base));
+
+  assert_eq!(merged.as_deref(), Some("New Title"));
+  assert_eq!(title(&base).as_deref(), Some("Old Title"));
+}
+
+#[test]
+fn test_write_update_forbidden() {
+  let base: Tag = Tag::from_reader(base().as_slice()).unwrap();
+  let changes: Vec<(FrameId, Content<'_>)> = vec![text(b"SEEK", &[0x00, 0x00, 0x00, 0x10])];
+
+  let error = Tag::write_update(Vec::new(), &base, &changes).unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::ForbiddenFrame(frame) if frame == id(b"SEEK")));
+}
+
+#[test]
+fn test_write_update_version() {
+  let data: Vec<u8> = tag(
+    0x03,
+    0x00,
+    &frame_v3(b"TIT2", 0x0000, &text_latin1("Title")),
+  );
+  let base: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  let error = Tag::write_update(Vec::new(), &base, &[]).unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::InvalidVersion));
+}
+
+#[test]
+fn test_seek_missing() {
+  let data: Vec<u8> = tag(
+    0x04,
+    0x00,
+    &frame_v4(b"TIT2", 0x0000, &text_latin1("Title")),
+  );
+  let base: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  assert_eq!(base.seek_offset().unwrap(), None);
+  assert_eq!(base.seek_target(0).unwrap(), None);
+}
+
+fn txxx(description: &str, value: &str) -> Vec<u8> {
+  let data: Vec<u8> = text_latin1(&format!("{description}\0{value}"));
+  frame_v4(b"TXXX", 0x0000, &data)
+}
+
+#[test]
+fn test_apply_update() {
+  let mut body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Old Title"));
+  body.extend(frame_v4(b"TPE1", 0x0000, &text_latin1("Artist")));
+  body.extend(txxx("a", "1"));
+  body.extend(txxx("b", "2"));
+  body.extend(frame_v4(b"COMM", 0x0000, &comm(b"eng", "", "Old")));
+  body.extend(frame_v4(b"COMM", 0x0000, &comm(b"deu", "", "Alt")));
+  body.resize(body.len() + 32, 0x00);
+
+  let base: Tag = Tag::from_reader(tag(0x04, 0x00, &body).as_slice()).unwrap();
+
+  let mut body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("New Title"));
+  body.extend(txxx("a", "9"));
+  body.extend(frame_v4(b"COMM", 0x0000, &comm(b"eng", "", "New")));
+
+  let update: Tag = Tag::from_reader(tag(0x04, 0x00, &body).as_slice()).unwrap();
+  let merged: Tag = base.apply_update(&update).unwrap();
+
+  // Single frames are replaced by identifier, others by their description
+  // and language.
+  let expected: Vec<(String, Content<'_>)> = [
+    &contents(&base)[1..2],
+    &contents(&base)[3..4],
+    &contents(&base)[5..6],
+    &contents(&update)[..],
+  ]
+  .concat();
+
+  assert_eq!(contents(&merged), expected);
+  assert_eq!(title(&merged).as_deref(), Some("New Title"));
+  assert_eq!(merged.header().exheader(), None);
+  assert_eq!(merged.header().frames_len() as usize, merged.buffer().len());
+}
+
+#[test]
+fn test_apply_update_written() {
+  let base: Tag = Tag::from_reader(base().as_slice()).unwrap();
+
+  let title_data: Vec<u8> = text_latin1("New Title");
+  let changes: Vec<(FrameId, Content<'_>)> = vec![text(b"TIT2", &title_data)];
+
+  let mut data: Vec<u8> = Vec::new();
+  Tag::write_update(&mut data, &base, &changes).unwrap();
+
+  let update: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let merged: Tag = base.apply_update(&update).unwrap();
+
+  let names: Vec<String> = merged
+    .frames()
+    .map(|frame| frame.unwrap().identifier_str().to_owned())
+    .collect();
+
+  assert_eq!(names, ["TPE1", "SEEK", "TIT2"]);
+  assert_eq!(title(&merged).as_deref(), Some("New Title"));
+}
+
+#[test]
+fn test_apply_update_version() {
+  let data: Vec<u8> = tag(
+    0x03,
+    0x00,
+    &frame_v3(b"TIT2", 0x0000, &text_latin1("Title")),
+  );
+  let other: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let base: Tag = Tag::from_reader(base().as_slice()).unwrap();
+
+  let error = base.apply_update(&other).unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::InvalidVersion));
+
+  let error = other.apply_update(&base).unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::InvalidVersion));
+}
+
+#[test]
+fn test_apply_update_invalid_frame() {
+  let mut body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(frame_v4(b"T!T2", 0x0000, &text_latin1("Bad")));
+  body.extend(frame_v4(b"TPE1", 0x0000, &text_latin1("Artist")));
+
+  let invalid: Tag = Tag::from_reader(tag(0x04, 0x00, &body).as_slice()).unwrap();
+  let base: Tag = Tag::from_reader(base().as_slice()).unwrap();
+
+  // The frames after the invalid one are not silently dropped.
+  let error = base.apply_update(&invalid).unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::InvalidFrameId));
+
+  let error = invalid.apply_update(&base).unwrap_err();
+  assert!(matches!(error.kind(), ErrorKind::InvalidFrameId));
+}
@@ -0,0 +1,218 @@
+mod common;
+
+use parser::error::ErrorKind;
+use parser::id3v2::Tag;
+use parser::options::Padding;
+use parser::options::ParseOptions;
+use parser::options::WriteOptions;
+use parser::types::Version;
+
+use self::common::contents;
+use self::common::frame_v2;
+use self::common::frame_v3;
+use self::common::frame_v4;
+use self::common::tag;
+use self::common::text_latin1;
+use self::common::text_utf16;
+
+fn rewrite(tag: &Tag, options: WriteOptions) -> Vec<u8> {
+  let mut output: Vec<u8> = Vec::new();
+  let length: u64 = tag.write_to_with(&mut output, options).unwrap();
+
+  assert_eq!(length, output.len() as u64);
+  output
+}
+
+fn assert_round_trip(data: &[u8]) {
+  let original: Tag = Tag::from_reader(data).unwrap();
+  let written: Vec<u8> = rewrite(&original, WriteOptions::new());
+  let parsed: Tag = Tag::from_reader(written.as_slice()).unwrap();
+
+  assert_eq!(parsed.header().version(), original.header().version());
+  assert_eq!(contents(&parsed), contents(&original));
+}
+
+#[test]
+fn test_write_identical() {
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(frame_v3(b"TPE1", 0x0000, &text_utf16("Artist")));
+
+  let data: Vec<u8> = tag(0x03, 0x00, &body);
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  assert_eq!(tag.write_to(Vec::new()).unwrap(), data.len() as u64);
+  assert_eq!(rewrite(&tag, WriteOptions::new()), data);
+}
+
+#[test]
+fn test_write_versions() {
+  assert_round_trip(&tag(0x02, 0x00, &frame_v2(b"TT2", &text_latin1("Title"))));
+  assert_round_trip(&tag(
+    0x03,
+    0x00,
+    &frame_v3(b"TIT2", 0x0000, &text_latin1("Title")),
+  ));
+  assert_round_trip(&tag(
+    0x04,
+    0x00,
+    &frame_v4(b"TIT2", 0x0000, &text_latin1("Title")),
+  ));
+}
+
+#[test]
+fn test_write_padding() {
+  let body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Title"));
+  let mut padded: Vec<u8> = body.clone();
+  padded.resize(body.len() + 100, 0x00);
+
+  let tag: Tag = Tag::from_reader(tag(0x04, 0x00, &padded).as_slice()).unwrap();
+
+  // Existing padding is replaced.
+  let written: Vec<u8> = rewrite(&tag, WriteOptions::new());
+
+  assert_eq!(written.len(), 10 + body.len());
+  assert_eq!(&written[10..], body);
+
+  let written: Vec<u8> = rewrite(&tag, WriteOptions::new().padding(Padding::Fixed(2048)));
+  let parsed: Tag = Tag::from_reader(written.as_slice()).unwrap();
+
+  assert_eq!(written.len(), 10 + body.len() + 2048);
+  assert_eq!(parsed.header().data_len() as usize, body.len() + 2048);
+  assert_eq!(contents(&parsed), contents(&tag));
+}
+
+#[test]
+fn test_write_padding_policies() {
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(frame_v3(b"TPE1", 0x0000, &text_latin1("Artist")));
+
+  let original: Tag = Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap();
+
+  for (padding, expected) in [
+    (Padding::None, 0),
+    (Padding::Fixed(0), 0),
+    (Padding::Fixed(2048), 2048),
+    (Padding::Percent(10), body.len().div_ceil(10)),
+  ] {
+    let written: Vec<u8> = rewrite(&original, WriteOptions::new().padding(padding));
+    let options: ParseOptions = ParseOptions::new().lenient(true);
+    let parsed: Tag = Tag::from_reader_with(written.as_slice(), options).unwrap();
+
+    assert_eq!(parsed.header().data_len() as usize, body.len() + expected);
+    assert!(written[10 + body.len()..].iter().all(|byte| *byte == 0x00));
+    assert!(parsed.warnings().is_empty());
+    assert_eq!(contents(&parsed), contents(&original));
+  }
+}
+
+#[test]
+fn test_write_padding_exheader() {
+  let frames: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+
+  // ID3v2.3 extended header: size, flags and padding size.
+  let mut body: Vec<u8> = 6_u32.to_be_bytes().to_vec();
+  body.extend([0x00, 0x00]);
+  body.extend(100_u32.to_be_bytes());
+  body.extend(&frames);
+  body.extend([0x00; 100]);
+
+  let original: Tag = Tag::from_reader(tag(0x03, 0x40, &body).as_slice()).unwrap();
+  let written: Vec<u8> = rewrite(&original, WriteOptions::new().padding(Padding::Fixed(2048)));
+  let parsed: Tag = Tag::from_reader(written.as_slice()).unwrap();
+
+  assert!(parsed.header().flag_extended_header());
+  assert_eq!(parsed.header().exheader().unwrap().pad_size(), 2048);
+  assert_eq!(parsed.header().data_len() as usize, frames.len() + 2048);
+  assert_eq!(written.len(), 10 + 10 + frames.len() + 2048);
+  assert_eq!(contents(&parsed), contents(&original));
+}
+
+#[test]
+fn test_write_clears_unsynchronisation() {
+  // PRIV frame with the data [0xFF, 0xE0].
+  let body: &[u8] = &[
+    b'P', b'R', b'I', b'V', 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, b'a', 0x00, 0xFF, 0x00, 0xE0,
+  ];
+
+  let data: Vec<u8> = tag(0x03, 0x80, body);
+  let original: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let written: Vec<u8> = rewrite(&original, WriteOptions::new());
+  let parsed: Tag = Tag::from_reader(written.as_slice()).unwrap();
+
+  assert!(!parsed.header().flag_unsynchronisation());
+  assert_eq!(parsed.header().version(), Version::ID3v23);
+  assert_eq!(&written[10..], original.buffer().as_ref());
+  assert_eq!(contents(&parsed), contents(&original));
+}
+
+#[test]
+fn test_write_invalid_size() {
+  let tag: Tag = Tag::from_reader(tag(0x04, 0x00, &[]).as_slice()).unwrap();
+  let error = tag
+    .write_to_with(
+      Vec::new(),
+      WriteOptions::new().padding(Padding::Fixed(1 << 28)),
+    )
+    .unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::Int));
+}
+
+#[test]
+fn test_write_invalid_frame() {
+  let mut body: Vec<u8> = frame_v3(b"TIT2", 0x0000, &text_latin1("Title"));
+  body.extend(frame_v3(b"T!T2", 0x0000, &text_latin1("Bad")));
+  body.extend(frame_v3(b"TPE1", 0x0000, &text_latin1("Artist")));
+  body.extend(frame_v3(b"TALB", 0x0000, &text_latin1("Album")));
+
+  let tag: Tag = Tag::from_reader(tag(0x03, 0x00, &body).as_slice()).unwrap();
+  let error = tag.write_to(Vec::new()).unwrap_err();
+
+  // The frames after the invalid one are not silently dropped.
+  assert!(matches!(error.kind(), ErrorKind::InvalidFrameId));
+}
+
+#[test]
+fn test_write_footer() {
+  let body: Vec<u8> = frame_v4(b"TIT2", 0x0000, &text_latin1("Title"));
+  let data: Vec<u8> = tag(0x04, 0x00, &body);
+  let original: Tag = Tag::from_reader(data.as_slice()).unwrap();
+
+  // Padding is not written with a footer.
+  let options: WriteOptions = WriteOptions::new()
+    .padding(Padding::Fixed(1024))
+    .footer(true);
+  let written: Vec<u8> = rewrite(&original, options);
+  let parsed: Tag = Tag::from_reader(written.as_slice()).unwrap();
+
+  assert_eq!(written.len(), 10 + body.len() + 10);
+  assert!(parsed.header().flag_footer());
+  assert_eq!(parsed.header().data_len() as usize, body.len());
+  assert_eq!(contents(&parsed), contents(&original));
+
+  // The footer mirrors the header, and its size locates the header.
+  let (header, footer) = (&written[..10], &written[written.len() - 10..]);
+
+  assert_eq!(&footer[..3], b"3DI");
+  assert_eq!(&footer[3..], &header[3..]);
+  assert_eq!(footer[5] & 0x10, 0x10);
+
+  let size: usize = footer[6..]
+    .iter()
+    .fold(0, |size, byte| (size << 7) | usize::from(*byte));
+
+  assert_eq!(written.len() - 10 - size - 10, 0);
+
+  // A footer is only permitted in ID3v2.4 tags.
+  let data: Vec<u8> = tag(
+    0x03,
+    0x00,
+    &frame_v3(b"TIT2", 0x0000, &text_latin1("Title")),
+  );
+  let tag: Tag = Tag::from_reader(data.as_slice()).unwrap();
+  let error = tag
+    .write_to_with(Vec::new(), WriteOptions::new().footer(true))
+    .unwrap_err();
+
+  assert!(matches!(error.kind(), ErrorKind::InvalidVersion));
+}